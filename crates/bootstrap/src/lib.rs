@@ -1,21 +1,15 @@
 mod custom_texture;
 
+use std::cell::RefCell;
 use std::fmt::Write;
-use std::sync::Arc;
-use std::time::Instant;
+use std::rc::Rc;
 
-use winit::{
-    application::ApplicationHandler,
-    event::{StartCause, WindowEvent},
-    event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
-};
-
-use winit::window::{Window, WindowAttributes, WindowId};
-use yakui::cosmic_text::fontdb;
+use winit::event::WindowEvent;
+use winit::window::Window;
 use yakui::font::Fonts;
 use yakui::paint::{Texture, TextureFilter, TextureFormat};
-use yakui::{ManagedTextureId, Rect, TextureId, UVec2, Vec2, Yakui};
-use yakui_app::Graphics;
+use yakui::{ManagedTextureId, Rect, TextureId, UVec2, Vec2};
+use yakui_app::App;
 
 pub const OPENMOJI: &[u8] = include_bytes!("../assets/OpenMoji-color-glyf_colr_0.ttf");
 
@@ -44,149 +38,6 @@ pub struct ExampleState {
     pub custom: Option<TextureId>,
 }
 
-struct App<T: ExampleBody> {
-    state: ExampleState,
-    yak: Yakui,
-
-    attributes: WindowAttributes,
-    start: Instant,
-
-    window: Option<Window>,
-    app: Option<Graphics>,
-
-    body: T,
-}
-
-impl<T: ExampleBody> ApplicationHandler for App<T> {
-    // This is a common indicator that you can create a window.
-    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
-        let window = event_loop.create_window(self.attributes.clone()).unwrap();
-        window.set_ime_allowed(true);
-
-        let sample_count = get_sample_count();
-
-        let mut app = pollster::block_on(yakui_app::Graphics::new(&window, sample_count));
-
-        // By default, yakui_winit will measure the system's scale factor and pass
-        // it to yakui.
-        //
-        // Sometimes, it might be desirable to scale the UI by a different factor,
-        // like if your game has a "UI scale" option, if you're writing tests, or
-        // you want to ensure your widgets work at a different scale.
-        //
-        // In these examples, setting the YAKUI_FORCE_SCALE environment variable to
-        // a number will override the automatic scaling.
-        if let Some(scale) = get_scale_override() {
-            app.window_mut().set_automatic_scale_factor(false);
-            self.yak.set_scale_factor(scale);
-        }
-
-        // In these examples, set YAKUI_INSET to force the UI to be contained within
-        // a sub-viewport with the given edge inset on all sides.
-        let inset = get_inset_override();
-        if inset.is_some() {
-            app.window_mut().set_automatic_viewport(false);
-        }
-
-        let custom = app.renderer.add_texture(
-            custom_texture::generate(&app.device, &app.queue),
-            wgpu::FilterMode::Nearest,
-            wgpu::FilterMode::Nearest,
-            wgpu::FilterMode::Nearest,
-            wgpu::AddressMode::ClampToEdge,
-        );
-        self.state.custom = Some(custom);
-
-        self.app = Some(app);
-        self.window = Some(window);
-    }
-
-    fn new_events(&mut self, _event_loop: &ActiveEventLoop, cause: winit::event::StartCause) {
-        if let Some(app) = self.app.as_mut() {
-            app.is_init = cause == StartCause::Init;
-        }
-    }
-
-    fn window_event(
-        &mut self,
-        event_loop: &ActiveEventLoop,
-        _window_id: WindowId,
-        event: WindowEvent,
-    ) {
-        if self
-            .app
-            .as_mut()
-            .unwrap()
-            .handle_window_event(&mut self.yak, &event, event_loop)
-        {
-            return;
-        }
-
-        // Handle window event.
-        match event {
-            WindowEvent::RedrawRequested => {
-                self.state.time = (Instant::now() - self.start).as_secs_f32();
-
-                {
-                    profiling::scope!("Build UI");
-
-                    // Every frame, call yak.start() to begin building the UI for
-                    // this frame. Any yakui widget calls that happen on this thread
-                    // between start() and finish() will be applied to this yakui
-                    // State.
-                    self.yak.start();
-
-                    // Call out to the body of the program, passing in a bit of
-                    // shared state that all the examples can use.
-                    self.body.run(&mut self.state);
-
-                    // Finish building the UI and compute this frame's layout.
-                    self.yak.finish();
-                }
-
-                // The example graphics abstraction calls yak.paint() to get
-                // access to the underlying PaintDom, which holds all the state
-                // about how to paint widgets.
-                self.app.as_mut().unwrap().paint(&mut self.yak, {
-                    let bg = yakui::colors::BACKGROUND_1.to_linear();
-                    wgpu::Color {
-                        r: bg.x.into(),
-                        g: bg.y.into(),
-                        b: bg.z.into(),
-                        a: 1.0,
-                    }
-                });
-
-                profiling::finish_frame!();
-
-                self.window.as_ref().unwrap().request_redraw();
-            }
-
-            WindowEvent::MouseInput { state, button, .. } => {
-                // This print is a handy way to show which mouse events are
-                // handled by yakui, and which ones will make it to the
-                // underlying application.
-                if button == winit::event::MouseButton::Left {
-                    println!("Left mouse button {state:?}");
-                }
-            }
-
-            WindowEvent::Resized(size) => {
-                let inset = get_inset_override();
-                if let Some(inset) = inset {
-                    let size = Vec2::new(size.width as f32, size.height as f32);
-                    self.yak.set_unscaled_viewport(Rect::from_pos_size(
-                        Vec2::splat(inset),
-                        size - Vec2::splat(inset * 2.0),
-                    ));
-                }
-            }
-
-            _ => (),
-        }
-    }
-}
-
 pub trait ExampleBody: 'static {
     fn run(&self, state: &mut ExampleState);
 }
@@ -221,47 +72,112 @@ fn run(body: impl ExampleBody) {
         write!(title, " (scale override {scale})").unwrap();
     }
 
-    // Normal winit setup for an EventLoop and Window.
-    let event_loop = EventLoop::new().unwrap();
-    let window_attribute = Window::default_attributes().with_title(title);
-
-    // Create our yakui state. This is where our UI will be built, laid out, and
-    // calculations for painting will happen.
-    let mut yak = yakui::Yakui::new();
-
-    // Preload some textures for the examples to use.
-    let monkey = yak.add_texture(load_texture(MONKEY_PNG, TextureFilter::Linear));
-    let monkey_blurred = yak.add_texture(load_texture(MONKEY_BLURRED_PNG, TextureFilter::Linear));
-    let brown_inlay = yak.add_texture(load_texture(BROWN_INLAY_PNG, TextureFilter::Nearest));
-
-    // Add a custom font for some of the examples.
-    let fonts = yak.dom().get_global_or_init(Fonts::default);
-
-    static HACK_REGULAR: &[u8] = include_bytes!("../assets/Hack-Regular.ttf");
-
-    fonts.load_font_source(fontdb::Source::Binary(Arc::from(&HACK_REGULAR)));
-    fonts.set_monospace_family("Hack");
-
-    // Set up some default state that we'll modify later.
-    let mut app = App {
-        yak,
-        attributes: window_attribute,
-        start: Instant::now(),
-        state: ExampleState {
-            time: 0.0,
-            monkey,
-            monkey_blurred,
-            brown_inlay,
-            custom: None,
-        },
-        window: None,
-        app: None,
-        body,
-    };
-
-    event_loop.set_control_flow(ControlFlow::Poll);
-
-    event_loop.run_app(&mut app).unwrap();
+    let mut app = App::new();
+    app.attributes = Window::default_attributes().with_title(title);
+    app.sample_count = get_sample_count();
+
+    // The example state is created once yakui and the renderer are ready, so
+    // it's shared between the setup hook and the UI function.
+    let state: Rc<RefCell<Option<ExampleState>>> = Rc::default();
+
+    app.on_setup({
+        let state = state.clone();
+
+        move |graphics, yak| {
+            // Preload some textures for the examples to use.
+            let monkey = yak.add_texture(load_texture(MONKEY_PNG, TextureFilter::Linear));
+            let monkey_blurred =
+                yak.add_texture(load_texture(MONKEY_BLURRED_PNG, TextureFilter::Linear));
+            let brown_inlay =
+                yak.add_texture(load_texture(BROWN_INLAY_PNG, TextureFilter::Nearest));
+
+            // Add a custom font for some of the examples.
+            let fonts = yak.dom().get_global_or_init(Fonts::default);
+
+            static HACK_REGULAR: &[u8] = include_bytes!("../assets/Hack-Regular.ttf");
+
+            fonts.add("Hack", HACK_REGULAR);
+            fonts.set_monospace_family("Hack");
+
+            // By default, yakui_winit will measure the system's scale factor and
+            // pass it to yakui.
+            //
+            // Sometimes, it might be desirable to scale the UI by a different
+            // factor, like if your game has a "UI scale" option, if you're
+            // writing tests, or you want to ensure your widgets work at a
+            // different scale.
+            //
+            // In these examples, setting the YAKUI_FORCE_SCALE environment
+            // variable to a number will override the automatic scaling.
+            if let Some(scale) = get_scale_override() {
+                graphics.window_mut().set_automatic_scale_factor(false);
+                yak.set_scale_factor(scale);
+            }
+
+            // In these examples, set YAKUI_INSET to force the UI to be contained
+            // within a sub-viewport with the given edge inset on all sides.
+            if get_inset_override().is_some() {
+                graphics.window_mut().set_automatic_viewport(false);
+            }
+
+            let custom = graphics.renderer.add_texture(
+                custom_texture::generate(&graphics.device, &graphics.queue),
+                wgpu::FilterMode::Nearest,
+                wgpu::FilterMode::Nearest,
+                wgpu::FilterMode::Nearest,
+                wgpu::AddressMode::ClampToEdge,
+            );
+
+            *state.borrow_mut() = Some(ExampleState {
+                time: 0.0,
+                monkey,
+                monkey_blurred,
+                brown_inlay,
+                custom: Some(custom),
+            });
+        }
+    });
+
+    app.on_event(|yak, event| match event {
+        WindowEvent::MouseInput {
+            state,
+            button: winit::event::MouseButton::Left,
+            ..
+        } => {
+            // This print is a handy way to show which mouse events are
+            // handled by yakui, and which ones will make it to the
+            // underlying application.
+            println!("Left mouse button {state:?}");
+        }
+
+        WindowEvent::Resized(size) => {
+            if let Some(inset) = get_inset_override() {
+                let size = Vec2::new(size.width as f32, size.height as f32);
+                yak.set_unscaled_viewport(Rect::from_pos_size(
+                    Vec2::splat(inset),
+                    size - Vec2::splat(inset * 2.0),
+                ));
+            }
+        }
+
+        _ => (),
+    });
+
+    app.run(move |ctx| {
+        let mut state = state.borrow_mut();
+        let state = state.as_mut().expect("example state wasn't set up");
+        state.time = ctx.time().as_secs_f32();
+
+        {
+            profiling::scope!("Build UI");
+
+            // Call out to the body of the program, passing in a bit of
+            // shared state that all the examples can use.
+            body.run(state);
+        }
+
+        profiling::finish_frame!();
+    });
 }
 
 /// This function takes some bytes and turns it into a yakui `Texture` object so
@@ -0,0 +1,141 @@
+//! Only builds and draws frames when yakui says something might have changed,
+//! instead of redrawing continuously. This keeps the application idle while
+//! the user isn't interacting with it, which saves power and plays well with
+//! variable refresh rate displays.
+
+use std::time::{Duration, Instant};
+
+use winit::application::ApplicationHandler;
+use winit::event::{StartCause, WindowEvent};
+use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
+use winit::window::{Window, WindowId};
+use yakui::{button, center, column, label, use_interval, use_state, Yakui};
+use yakui_app::Graphics;
+
+fn ui() {
+    let clicks = use_state(|| 0);
+    let blink = use_interval(Duration::from_secs(1));
+
+    center(|| {
+        column(|| {
+            label(format!("Clicked {} times", clicks.get()));
+
+            if button("Click me").clicked {
+                *clicks.borrow_mut() += 1;
+            }
+
+            if blink.count.is_multiple_of(2) {
+                label("Tick");
+            } else {
+                label("Tock");
+            }
+        });
+    });
+}
+
+struct EventDriven {
+    yak: Yakui,
+    start: Instant,
+    frames: u64,
+
+    graphics: Option<Graphics>,
+    window: Option<Window>,
+}
+
+impl EventDriven {
+    fn redraw(&mut self, event_loop: &ActiveEventLoop) {
+        let (Some(window), Some(graphics)) = (&self.window, &mut self.graphics) else {
+            return;
+        };
+
+        self.yak.set_time(self.start.elapsed());
+        self.yak.start();
+        ui();
+        self.yak.finish();
+
+        graphics.paint(&mut self.yak, wgpu::Color::BLACK);
+        self.frames += 1;
+
+        let info = self.yak.frame_info();
+        if let Some(build_time) = info.build_time {
+            window.set_title(&format!(
+                "Event-driven redraws: frame {} built in {:.2}ms",
+                self.frames,
+                build_time.as_secs_f64() * 1000.0
+            ));
+        }
+
+        // yakui tells us when it wants the next frame. If it doesn't want one,
+        // we can sleep until the next input event arrives.
+        match info.next_repaint() {
+            Some(time) if time <= self.start.elapsed() => window.request_redraw(),
+            Some(time) => event_loop.set_control_flow(ControlFlow::WaitUntil(self.start + time)),
+            None => event_loop.set_control_flow(ControlFlow::Wait),
+        }
+    }
+}
+
+impl ApplicationHandler for EventDriven {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.window.is_some() {
+            return;
+        }
+
+        let window = event_loop
+            .create_window(Window::default_attributes().with_title("Event-driven redraws"))
+            .unwrap();
+
+        self.graphics = Some(pollster::block_on(Graphics::new(&window, 4)));
+        self.window = Some(window);
+    }
+
+    fn new_events(&mut self, _event_loop: &ActiveEventLoop, cause: StartCause) {
+        if let Some(graphics) = &mut self.graphics {
+            graphics.is_init = cause == StartCause::Init;
+        }
+
+        // We asked to be woken up because a widget wanted to update.
+        if let StartCause::ResumeTimeReached { .. } = cause {
+            if let Some(window) = &self.window {
+                window.request_redraw();
+            }
+        }
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        _window_id: WindowId,
+        event: WindowEvent,
+    ) {
+        if let WindowEvent::RedrawRequested = event {
+            self.redraw(event_loop);
+            return;
+        }
+
+        let (Some(window), Some(graphics)) = (&self.window, &mut self.graphics) else {
+            return;
+        };
+
+        graphics.handle_window_event(&mut self.yak, &event, event_loop);
+
+        // Any other event might be input that changes the UI, so draw a frame
+        // to find out.
+        window.request_redraw();
+    }
+}
+
+fn main() {
+    let event_loop = EventLoop::new().unwrap();
+    event_loop.set_control_flow(ControlFlow::Wait);
+
+    let mut app = EventDriven {
+        yak: Yakui::new(),
+        start: Instant::now(),
+        frames: 0,
+        graphics: None,
+        window: None,
+    };
+
+    event_loop.run_app(&mut app).unwrap();
+}
@@ -0,0 +1,301 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use web_time::Instant;
+use winit::application::ApplicationHandler;
+use winit::event::{StartCause, WindowEvent};
+use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop, EventLoopProxy};
+use winit::window::{Window, WindowAttributes, WindowId};
+use yakui::{Color, Yakui};
+use yakui_wgpu::SurfaceInfo;
+
+use crate::{Graphics, GraphicsOptions};
+
+type SetupHook = Box<dyn FnOnce(&mut Graphics, &mut Yakui)>;
+type EventHook = Box<dyn FnMut(&mut Yakui, &WindowEvent)>;
+type RenderHook =
+    Box<dyn FnMut(&wgpu::Device, &wgpu::Queue, &mut wgpu::CommandEncoder, &SurfaceInfo<'_>)>;
+
+/// Opens a window and shows the UI built by `ui` in it every frame, using the
+/// default [`App`] settings.
+///
+/// ```no_run
+/// yakui_app::run(|_ctx| {
+///     yakui::center(|| {
+///         yakui::label("Hello, world!");
+///     });
+/// });
+/// ```
+pub fn run<F>(ui: F)
+where
+    F: FnMut(&mut AppContext<'_>) + 'static,
+{
+    App::new().run(ui);
+}
+
+/// A window with a yakui UI in it, for when [`run`] needs more configuration.
+///
+/// On the web, the window is a canvas that's appended to the page's body, and
+/// [`App::run`] returns right away while the app keeps running in the
+/// browser's event loop.
+///
+/// `App` covers the common case of a single window with UI drawn over a solid
+/// background. Applications that outgrow it can copy this module and build on
+/// [`Graphics`] directly.
+pub struct App {
+    /// The attributes used to create the window, including its title and
+    /// initial size.
+    pub attributes: WindowAttributes,
+
+    /// How many samples to use for multisample anti-aliasing.
+    pub sample_count: u32,
+
+    /// Whether to use an HDR surface when one is available. See
+    /// [`GraphicsOptions::hdr`].
+    pub hdr: bool,
+
+    /// The color that the window is cleared to before drawing each frame.
+    pub background: Color,
+
+    setup: Option<SetupHook>,
+    event: Option<EventHook>,
+    render: Option<RenderHook>,
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self {
+            attributes: Window::default_attributes().with_title("yakui"),
+            sample_count: 4,
+            hdr: false,
+            background: yakui::colors::BACKGROUND_1,
+            setup: None,
+            event: None,
+            render: None,
+        }
+    }
+
+    /// Called once after the window and renderer are created, before the
+    /// first frame. Useful for loading textures and fonts.
+    pub fn on_setup<F>(&mut self, setup: F)
+    where
+        F: FnOnce(&mut Graphics, &mut Yakui) + 'static,
+    {
+        self.setup = Some(Box::new(setup));
+    }
+
+    /// Called with every window event that yakui didn't handle itself, like
+    /// clicks that missed the UI.
+    pub fn on_event<F>(&mut self, event: F)
+    where
+        F: FnMut(&mut Yakui, &WindowEvent) + 'static,
+    {
+        self.event = Some(Box::new(event));
+    }
+
+    /// Called every frame after the window is cleared and before the UI is
+    /// drawn, for drawing a scene underneath the UI.
+    pub fn on_render<F>(&mut self, render: F)
+    where
+        F: FnMut(&wgpu::Device, &wgpu::Queue, &mut wgpu::CommandEncoder, &SurfaceInfo<'_>)
+            + 'static,
+    {
+        self.render = Some(Box::new(render));
+    }
+
+    /// Opens the window and runs until it's closed.
+    pub fn run<F>(self, ui: F)
+    where
+        F: FnMut(&mut AppContext<'_>) + 'static,
+    {
+        let event_loop = EventLoop::with_user_event().build().unwrap();
+        event_loop.set_control_flow(ControlFlow::Poll);
+
+        let mut handler = Handler {
+            app: self,
+            ui,
+            yak: Yakui::new(),
+            start: Instant::now(),
+            proxy: event_loop.create_proxy(),
+            graphics: None,
+            window: None,
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        event_loop.run_app(&mut handler).unwrap();
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            use winit::platform::web::EventLoopExtWebSys;
+            event_loop.spawn_app(handler);
+        }
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Passed to the UI function given to [`run`] every frame.
+pub struct AppContext<'a> {
+    window: &'a Window,
+    graphics: &'a mut Graphics,
+    time: Duration,
+    exit: bool,
+}
+
+impl AppContext<'_> {
+    /// The window the UI is being shown in.
+    pub fn window(&self) -> &Window {
+        self.window
+    }
+
+    /// The renderer state, for uploading textures or drawing with wgpu.
+    pub fn graphics(&mut self) -> &mut Graphics {
+        self.graphics
+    }
+
+    /// How much time has passed since the app started.
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Close the window and stop the app at the end of this frame.
+    pub fn exit(&mut self) {
+        self.exit = true;
+    }
+}
+
+struct Handler<F> {
+    app: App,
+    ui: F,
+    yak: Yakui,
+    start: Instant,
+    proxy: EventLoopProxy<Graphics>,
+
+    // The surface in `graphics` borrows from the window, so it has to be
+    // dropped first.
+    graphics: Option<Graphics>,
+    window: Option<Arc<Window>>,
+}
+
+impl<F> ApplicationHandler<Graphics> for Handler<F>
+where
+    F: FnMut(&mut AppContext<'_>) + 'static,
+{
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.window.is_some() {
+            return;
+        }
+
+        #[allow(unused_mut)]
+        let mut attributes = self.app.attributes.clone();
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            use winit::platform::web::WindowAttributesExtWebSys;
+            attributes = attributes.with_append(true);
+        }
+
+        let window = Arc::new(event_loop.create_window(attributes).unwrap());
+        window.set_ime_allowed(true);
+        self.window = Some(window.clone());
+
+        let options = GraphicsOptions {
+            sample_count: self.app.sample_count,
+            hdr: self.app.hdr,
+            ..Default::default()
+        };
+
+        // Browsers can't block while the GPU is set up, so the graphics are
+        // handed back to the event loop once they're ready.
+        let proxy = self.proxy.clone();
+        let create = async move {
+            let graphics = Graphics::with_options(&window, options).await;
+            let _ = proxy.send_event(graphics);
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        pollster::block_on(create);
+
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(create);
+    }
+
+    fn user_event(&mut self, _event_loop: &ActiveEventLoop, mut graphics: Graphics) {
+        if let Some(setup) = self.app.setup.take() {
+            setup(&mut graphics, &mut self.yak);
+        }
+
+        self.graphics = Some(graphics);
+
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+
+    fn new_events(&mut self, _event_loop: &ActiveEventLoop, cause: StartCause) {
+        if let Some(graphics) = self.graphics.as_mut() {
+            graphics.is_init = cause == StartCause::Init;
+        }
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        _window_id: WindowId,
+        event: WindowEvent,
+    ) {
+        let (Some(window), Some(graphics)) = (self.window.as_ref(), self.graphics.as_mut()) else {
+            return;
+        };
+
+        if graphics.handle_window_event(&mut self.yak, &event, event_loop) {
+            return;
+        }
+
+        if let Some(on_event) = self.app.event.as_mut() {
+            on_event(&mut self.yak, &event);
+        }
+
+        if let WindowEvent::RedrawRequested = event {
+            let time = self.start.elapsed();
+
+            self.yak.set_time(time);
+            self.yak.start();
+
+            let mut ctx = AppContext {
+                window,
+                graphics,
+                time,
+                exit: false,
+            };
+            (self.ui)(&mut ctx);
+            let exit = ctx.exit;
+
+            self.yak.finish();
+            graphics.window_mut().apply_cursor_icon(&self.yak, window);
+
+            let bg = self.app.background.to_linear();
+            let bg = wgpu::Color {
+                r: bg.x.into(),
+                g: bg.y.into(),
+                b: bg.z.into(),
+                a: 1.0,
+            };
+
+            match self.app.render.as_mut() {
+                Some(render) => graphics.paint_with(&mut self.yak, bg, render),
+                None => graphics.paint(&mut self.yak, bg),
+            }
+
+            if exit {
+                event_loop.exit();
+            } else {
+                window.request_redraw();
+            }
+        }
+    }
+}
@@ -1,10 +1,13 @@
 #![doc = include_str!("../README.md")]
 
-mod multisampling;
+mod app;
 
 use winit::{dpi::PhysicalSize, event::WindowEvent, event_loop::ActiveEventLoop, window::Window};
+use yakui::geometry::UVec2;
+use yakui_wgpu::{MultisampledTarget, SurfaceInfo};
 
-use multisampling::Multisampling;
+pub use app::{run, App, AppContext};
+pub use yakui_wgpu::ColorOutput;
 
 /// A helper for setting up rendering with winit and wgpu
 pub struct Graphics {
@@ -15,8 +18,7 @@ pub struct Graphics {
     surface: wgpu::Surface<'static>,
     surface_config: wgpu::SurfaceConfiguration,
     size: PhysicalSize<u32>,
-    sample_count: u32,
-    multisampling: Multisampling,
+    multisampling: MultisampledTarget,
 
     window: yakui_winit::YakuiWinit,
     pub renderer: yakui_wgpu::YakuiWgpu,
@@ -24,8 +26,45 @@ pub struct Graphics {
     pub is_init: bool,
 }
 
+/// Options for how [`Graphics`] sets up the window's surface.
+#[derive(Debug, Clone, Copy)]
+pub struct GraphicsOptions {
+    /// How many samples to use for multisample anti-aliasing. If the surface
+    /// format doesn't support this many, the closest supported count below it
+    /// is used instead.
+    pub sample_count: u32,
+
+    /// Whether to use an HDR surface format when one is available.
+    pub hdr: bool,
+
+    /// Whether translucent UI should be blended in linear space, which needs
+    /// an sRGB surface format. If this is off, a format that isn't sRGB is
+    /// picked when available, so blending matches most other UI toolkits.
+    /// Colors look the same either way; see [`ColorOutput::sdr_for_format`].
+    pub gamma_correct_blending: bool,
+}
+
+impl Default for GraphicsOptions {
+    fn default() -> Self {
+        Self {
+            sample_count: 4,
+            hdr: false,
+            gamma_correct_blending: true,
+        }
+    }
+}
+
 impl Graphics {
     pub async fn new(window: &Window, sample_count: u32) -> Self {
+        let options = GraphicsOptions {
+            sample_count,
+            ..Default::default()
+        };
+
+        Self::with_options(window, options).await
+    }
+
+    pub async fn with_options(window: &Window, options: GraphicsOptions) -> Self {
         let mut size = window.inner_size();
 
         // FIXME: On web, we're receiving (0, 0) as the initial size of the
@@ -73,7 +112,8 @@ impl Graphics {
             .unwrap();
 
         let capabilities = surface.get_capabilities(&adapter);
-        let format = capabilities.formats[0];
+        let (format, color_output) = choose_format(&capabilities.formats, options);
+        let sample_count = choose_sample_count(&adapter, format, options.sample_count);
         let surface_config = wgpu::SurfaceConfiguration {
             alpha_mode: wgpu::CompositeAlphaMode::Auto,
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
@@ -88,7 +128,9 @@ impl Graphics {
 
         // yakui_wgpu takes paint output from yakui and renders it for us using
         // wgpu.
-        let renderer = yakui_wgpu::YakuiWgpu::new(&device, &queue);
+        let mut renderer = yakui_wgpu::YakuiWgpu::new(&device, &queue);
+        renderer.set_color_output(color_output);
+        renderer.set_downlevel_flags(adapter.get_downlevel_capabilities().flags);
 
         // yakui_winit processes winit events and applies them to our yakui
         // state.
@@ -102,8 +144,7 @@ impl Graphics {
             surface,
             surface_config,
             size,
-            sample_count,
-            multisampling: Multisampling::new(),
+            multisampling: MultisampledTarget::new(sample_count),
 
             renderer,
             window,
@@ -123,6 +164,20 @@ impl Graphics {
         self.surface_config.format
     }
 
+    /// How UI colors are encoded for the surface. This is
+    /// [`ColorOutput::ScRgb`] if an HDR surface was requested and available,
+    /// and can be changed through [`Graphics::renderer`] to adjust the
+    /// brightness of the UI.
+    pub fn color_output(&self) -> ColorOutput {
+        self.renderer.color_output()
+    }
+
+    /// The number of samples used for anti-aliasing, which may be lower than
+    /// requested if the surface format doesn't support it.
+    pub fn sample_count(&self) -> u32 {
+        self.multisampling.sample_count()
+    }
+
     pub fn resize(&mut self, new_size: PhysicalSize<u32>) {
         if new_size.width > 0 && new_size.height > 0 && new_size != self.size {
             self.size = new_size;
@@ -132,8 +187,30 @@ impl Graphics {
         }
     }
 
-    #[cfg_attr(feature = "profiling", profiling::function)]
     pub fn paint(&mut self, yak: &mut yakui_core::Yakui, bg: wgpu::Color) {
+        self.paint_with(yak, bg, |_, _, _, _| {});
+    }
+
+    /// Paints the UI like [`Graphics::paint`], calling `render` after the
+    /// surface is cleared and before the UI is drawn. This can be used to draw
+    /// a scene underneath the UI.
+    #[cfg_attr(feature = "profiling", profiling::function)]
+    pub fn paint_with<F>(&mut self, yak: &mut yakui_core::Yakui, bg: wgpu::Color, render: F)
+    where
+        F: FnOnce(&wgpu::Device, &wgpu::Queue, &mut wgpu::CommandEncoder, &SurfaceInfo<'_>),
+    {
+        // The background has to be encoded the same way as the UI, or it would
+        // look different on HDR surfaces.
+        let [r, g, b] = self
+            .color_output()
+            .encode([bg.r as f32, bg.g as f32, bg.b as f32]);
+        let bg = wgpu::Color {
+            r: r.into(),
+            g: g.into(),
+            b: b.into(),
+            a: bg.a,
+        };
+
         let output = match self.surface.get_current_texture() {
             Ok(output) => output,
             Err(_) => return,
@@ -143,13 +220,10 @@ impl Graphics {
             .texture
             .create_view(&wgpu::TextureViewDescriptor::default());
 
-        let surface = self.multisampling.surface_info(
-            &self.device,
-            &view,
-            self.size,
-            self.format,
-            self.sample_count,
-        );
+        let size = UVec2::new(self.size.width, self.size.height);
+        let surface = self
+            .multisampling
+            .surface_info(&self.device, &view, size, self.format);
 
         let mut encoder = self
             .device
@@ -172,6 +246,8 @@ impl Graphics {
             });
         }
 
+        render(&self.device, &self.queue, &mut encoder, &surface);
+
         let clear = encoder.finish();
 
         let paint_yak = self.renderer.paint(yak, &self.device, &self.queue, surface);
@@ -217,3 +293,66 @@ impl Graphics {
         false
     }
 }
+
+/// Picks a surface format, preferring scRGB if HDR is requested. Otherwise, an
+/// sRGB format is preferred only if gamma-correct blending is requested.
+fn choose_format(
+    formats: &[wgpu::TextureFormat],
+    options: GraphicsOptions,
+) -> (wgpu::TextureFormat, ColorOutput) {
+    // `Rgb10a2Unorm` surfaces are usually SDR unless the platform is asked for
+    // an HDR10 color space, which wgpu can't do yet. Only scRGB is picked
+    // automatically.
+    if options.hdr && formats.contains(&wgpu::TextureFormat::Rgba16Float) {
+        let output = ColorOutput::ScRgb {
+            paper_white: ColorOutput::DEFAULT_PAPER_WHITE,
+        };
+
+        return (wgpu::TextureFormat::Rgba16Float, output);
+    }
+
+    // Only 8-bit formats are picked for SDR, since wider formats like
+    // `Rgba16Float` aren't sRGB encoded by the platform.
+    let is_8bit = |format: &wgpu::TextureFormat| {
+        matches!(
+            format.remove_srgb_suffix(),
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Rgba8Unorm
+        )
+    };
+
+    let sdr = formats
+        .iter()
+        .copied()
+        .filter(is_8bit)
+        .find(|format| format.is_srgb() == options.gamma_correct_blending)
+        .or_else(|| formats.iter().copied().find(is_8bit));
+
+    match sdr {
+        Some(format) => (format, ColorOutput::sdr_for_format(format)),
+
+        // Surfaces without an 8-bit format are rare, so the first format is
+        // used with whatever output matches it best.
+        None => {
+            let format = formats[0];
+            let output = ColorOutput::hdr_for_format(format)
+                .unwrap_or_else(|| ColorOutput::sdr_for_format(format));
+
+            (format, output)
+        }
+    }
+}
+
+fn choose_sample_count(
+    adapter: &wgpu::Adapter,
+    format: wgpu::TextureFormat,
+    requested: u32,
+) -> u32 {
+    let flags = adapter.get_texture_format_features(format).flags;
+
+    let mut count = requested.max(1);
+    while count > 1 && !flags.sample_count_supported(count) {
+        count /= 2;
+    }
+
+    count
+}
@@ -4,6 +4,7 @@ use std::cell::{Ref, RefCell};
 use std::thread::LocalKey;
 
 use crate::dom::Dom;
+use crate::id::WidgetId;
 
 type Storage<T> = RefCell<Option<T>>;
 
@@ -20,6 +21,22 @@ pub fn dom() -> Ref<'static, Dom> {
     borrow(&CURRENT_DOM)
 }
 
+/// Like [`dom`], but returns `None` instead of panicking if there is no DOM
+/// currently being updated on this thread.
+pub fn try_dom() -> Option<Ref<'static, Dom>> {
+    CURRENT_DOM.with(|context| {
+        // SAFETY: See `borrow`.
+        let context = unsafe { extend_lifetime(context) };
+        Ref::filter_map(context.borrow(), Option::as_ref).ok()
+    })
+}
+
+/// If there is a DOM bound to this thread, returns the widget that is
+/// currently being updated or handling an event.
+pub(crate) fn current_widget() -> Option<WidgetId> {
+    CURRENT_DOM.with(|dom| dom.borrow().as_ref().map(|dom| dom.current()))
+}
+
 pub(crate) fn bind_dom(dom: &Dom) {
     bind(&CURRENT_DOM, dom.clone());
 }
@@ -6,17 +6,22 @@ mod dummy;
 mod root;
 
 use std::any::{type_name, TypeId};
-use std::cell::{Ref, RefCell, RefMut};
-use std::collections::VecDeque;
+use std::cell::{Cell, Ref, RefCell, RefMut};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
 use std::mem::replace;
 use std::rc::Rc;
+use std::time::Duration;
 
 use anymap::AnyMap;
 use thunderdome::Arena;
 
 use crate::id::WidgetId;
-use crate::input::InputState;
+use crate::input::{InputState, KeyCode, LogicalKey, Modifiers, Shortcut};
 use crate::response::Response;
+use crate::state::UiActivity;
+use crate::store::{ErasedStore, Store};
 use crate::widget::{ErasedWidget, Widget};
 
 use self::dummy::DummyWidget;
@@ -33,7 +38,42 @@ struct DomInner {
     removed_nodes: RefCell<Vec<WidgetId>>,
     root: WidgetId,
     globals: RefCell<AnyMap>,
+    stores: RefCell<HashMap<TypeId, Box<dyn ErasedStore>>>,
     pending_focus_request: RefCell<Option<WidgetId>>,
+    shortcuts: RefCell<Vec<RegisteredShortcut>>,
+    key_scope: Cell<Option<KeyScope>>,
+    time: Cell<Duration>,
+    delta_time: Cell<Duration>,
+    repaint_at: Cell<Option<Duration>>,
+    changed: Cell<bool>,
+    created: Cell<usize>,
+    activity: Cell<UiActivity>,
+}
+
+/// Set by [`Dom::with_key`] while its children are being built.
+#[derive(Clone, Copy)]
+struct KeyScope {
+    /// The widget that the keyed widgets are children of.
+    parent: WidgetId,
+    key: u64,
+
+    /// How many widgets have been created in this scope so far.
+    next: u64,
+}
+
+/// A shortcut registered with [`Dom::shortcut`].
+struct RegisteredShortcut {
+    shortcut: Shortcut,
+
+    /// Whether the shortcut was registered during the current frame.
+    registered: bool,
+
+    /// Whether the shortcut was pressed since the last frame started.
+    triggered: bool,
+
+    /// Whether the shortcut was pressed before the current frame started,
+    /// which is what [`Dom::shortcut`] reports.
+    pressed: bool,
 }
 
 /// A node in the [`Dom`].
@@ -51,6 +91,10 @@ pub struct DomNode {
     /// Used when building the tree. The index of the next child if a new child
     /// starts being built.
     next_child: usize,
+
+    /// The key this node was created with, if any. Keyed nodes are matched
+    /// with their siblings by key instead of by position.
+    key: Option<u64>,
 }
 
 impl Dom {
@@ -74,6 +118,24 @@ impl Dom {
         let mut nodes = self.inner.nodes.borrow_mut();
         let root = nodes.get_mut(self.inner.root.index()).unwrap();
         root.next_child = 0;
+
+        self.inner.repaint_at.set(None);
+        self.inner.changed.set(false);
+        self.inner.created.set(0);
+        self.inner.removed_nodes.borrow_mut().clear();
+        self.inner.activity.set(UiActivity::empty());
+
+        for store in self.inner.stores.borrow().values() {
+            store.start();
+        }
+
+        // Shortcuts that nothing registered last frame are forgotten.
+        let mut shortcuts = self.inner.shortcuts.borrow_mut();
+        shortcuts.retain(|entry| entry.registered);
+        for entry in shortcuts.iter_mut() {
+            entry.registered = false;
+            entry.pressed = std::mem::take(&mut entry.triggered);
+        }
     }
 
     /// End the DOM's build phase.
@@ -83,13 +145,95 @@ impl Dom {
         let mut nodes = self.inner.nodes.borrow_mut();
         let mut removed_nodes = self.inner.removed_nodes.borrow_mut();
         let root = self.inner.root;
-        trim_children(&mut nodes, &mut removed_nodes, root);
+        if trim_children(&mut nodes, &mut removed_nodes, root) {
+            self.inner.changed.set(true);
+        }
+
+        for store in self.inner.stores.borrow().values() {
+            store.retain_readers(&|id| nodes.contains(id.index()));
+        }
 
         if let Some(widget_id) = self.inner.pending_focus_request.borrow_mut().take() {
             input.set_selection(Some(widget_id));
         }
     }
 
+    /// Returns the current time, as given to
+    /// [`Yakui::set_time`][crate::Yakui::set_time].
+    pub fn time(&self) -> Duration {
+        self.inner.time.get()
+    }
+
+    /// Returns how much [`Dom::time`] advanced between the previous frame and
+    /// this one. This is zero on the first frame and if the host never calls
+    /// [`Yakui::set_time`][crate::Yakui::set_time].
+    pub fn delta_time(&self) -> Duration {
+        self.inner.delta_time.get()
+    }
+
+    pub(crate) fn set_time(&self, time: Duration, delta_time: Duration) {
+        self.inner.time.set(time);
+        self.inner.delta_time.set(delta_time);
+    }
+
+    /// Asks the host to build another frame as soon as possible, like when a
+    /// widget is animating.
+    ///
+    /// Requests only last for the frame they were made in, so widgets that
+    /// keep changing should request a repaint every frame.
+    pub fn request_repaint(&self) {
+        self.request_repaint_at(self.time());
+    }
+
+    /// Asks the host to build another frame once [`Dom::time`] reaches the
+    /// given time, like when a timer is due to fire.
+    pub fn request_repaint_at(&self, time: Duration) {
+        let earliest = match self.inner.repaint_at.get() {
+            Some(existing) => existing.min(time),
+            None => time,
+        };
+
+        self.inner.repaint_at.set(Some(earliest));
+    }
+
+    /// Returns the earliest time a repaint was requested for during the
+    /// current frame, if any.
+    pub fn repaint_requested(&self) -> Option<Duration> {
+        self.inner.repaint_at.get()
+    }
+
+    /// Tells the host what the user is doing with the UI this frame, like
+    /// typing into a focused text box. Activity only lasts for the frame it
+    /// was reported in.
+    pub fn report_activity(&self, activity: UiActivity) {
+        self.inner
+            .activity
+            .set(self.inner.activity.get() | activity);
+    }
+
+    /// Returns everything reported with [`Dom::report_activity`] during the
+    /// current frame.
+    pub fn activity(&self) -> UiActivity {
+        self.inner.activity.get()
+    }
+
+    /// Tells whether any widgets were created, replaced, or removed while
+    /// building the current frame.
+    pub fn structure_changed(&self) -> bool {
+        self.inner.changed.get()
+    }
+
+    /// Tells how many widgets were created while building the current frame,
+    /// including ones that replaced a widget of a different type.
+    pub fn widgets_created(&self) -> usize {
+        self.inner.created.get()
+    }
+
+    /// Tells how many widgets were removed at the end of the current frame.
+    pub fn widgets_removed(&self) -> usize {
+        self.inner.removed_nodes.borrow().len()
+    }
+
     /// Tells how many nodes are currently in the DOM.
     pub fn len(&self) -> usize {
         self.inner.nodes.borrow().len()
@@ -110,6 +254,54 @@ impl Dom {
         *self.inner.pending_focus_request.borrow_mut() = Some(id);
     }
 
+    /// Registers a global keyboard shortcut for this frame and tells whether it
+    /// was pressed since the last frame.
+    ///
+    /// Shortcuts only stay registered while they're registered every frame.
+    /// Key presses go to the selected widget first, so a shortcut doesn't fire
+    /// if the selected widget handles the key itself, like a text box does
+    /// with typing.
+    pub fn shortcut(&self, shortcut: &Shortcut) -> bool {
+        let mut shortcuts = self.inner.shortcuts.borrow_mut();
+
+        if let Some(entry) = shortcuts
+            .iter_mut()
+            .find(|entry| entry.shortcut == *shortcut)
+        {
+            entry.registered = true;
+            return entry.pressed;
+        }
+
+        shortcuts.push(RegisteredShortcut {
+            shortcut: shortcut.clone(),
+            registered: true,
+            triggered: false,
+            pressed: false,
+        });
+
+        false
+    }
+
+    /// Marks every shortcut registered during the last frame that the key
+    /// press matches as pressed, returning whether there were any.
+    pub(crate) fn trigger_shortcuts(
+        &self,
+        key: KeyCode,
+        logical_key: &LogicalKey,
+        modifiers: Modifiers,
+    ) -> bool {
+        let mut triggered = false;
+
+        for entry in self.inner.shortcuts.borrow_mut().iter_mut() {
+            if entry.registered && entry.shortcut.matches_key(key, logical_key, modifiers) {
+                entry.triggered = true;
+                triggered = true;
+            }
+        }
+
+        triggered
+    }
+
     /// Gives a list of all of the nodes that were removed in the last update.
     /// This is used for synchronizing state with the primary DOM storage.
     pub(crate) fn removed_nodes(&self) -> Ref<'_, [WidgetId]> {
@@ -184,6 +376,27 @@ impl Dom {
         globals.entry::<T>().or_insert_with(init).clone()
     }
 
+    /// Registers a piece of application state with the DOM, returning a
+    /// [`Store`] that can be used to read and modify it. Any existing store
+    /// of the same type is replaced.
+    pub fn add_store<T: 'static>(&self, value: T) -> Store<T> {
+        let store = Store::new(value);
+
+        self.inner.globals.borrow_mut().insert(store.clone());
+        self.inner
+            .stores
+            .borrow_mut()
+            .insert(TypeId::of::<T>(), Box::new(store.clone()));
+
+        store
+    }
+
+    /// Fetches the store of the given type, if one has been registered with
+    /// [`Dom::add_store`].
+    pub fn store<T: 'static>(&self) -> Option<Store<T>> {
+        self.inner.globals.borrow().get::<Store<T>>().cloned()
+    }
+
     /// Convenience method for calling [`Dom::begin_widget`] immediately
     /// followed by [`Dom::end_widget`].
     pub fn do_widget<T: Widget>(&self, props: T::Props<'_>) -> Response<T::Response> {
@@ -196,11 +409,67 @@ impl Dom {
     ///
     /// After calling this method, children can be added to this widget.
     pub fn begin_widget<T: Widget>(&self, props: T::Props<'_>) -> Response<T::Response> {
+        self.begin_widget_with_key::<T>(None, props)
+    }
+
+    /// Begin building a widget with the given type and props, identified by a
+    /// key.
+    ///
+    /// Widgets are normally matched with the widgets from the previous update
+    /// by their position among their siblings. Keyed widgets are instead
+    /// matched by their key, which keeps their state intact when siblings are
+    /// inserted, removed, or reordered.
+    pub fn begin_keyed_widget<T: Widget, K: Hash>(
+        &self,
+        key: K,
+        props: T::Props<'_>,
+    ) -> Response<T::Response> {
+        self.begin_widget_with_key::<T>(Some(hash_key(key)), props)
+    }
+
+    /// Builds `children` with the widgets they create identified by a key, as
+    /// if each was started with [`Dom::begin_keyed_widget`]. If the children
+    /// create more than one widget, those widgets are told apart by their
+    /// order.
+    ///
+    /// Unlike wrapping the children in a keyed widget, this doesn't add a node
+    /// to the tree, so the children take part in their parent's layout as
+    /// usual.
+    pub fn with_key<K: Hash, R, F: FnOnce() -> R>(&self, key: K, children: F) -> R {
+        let scope = KeyScope {
+            parent: self.current(),
+            key: hash_key(key),
+            next: 0,
+        };
+
+        let previous = self.inner.key_scope.replace(Some(scope));
+        let result = children();
+        self.inner.key_scope.set(previous);
+        result
+    }
+
+    fn begin_widget_with_key<T: Widget>(
+        &self,
+        mut key: Option<u64>,
+        props: T::Props<'_>,
+    ) -> Response<T::Response> {
         log::trace!("begin_widget::<{}>({props:#?}", type_name::<T>());
 
+        if let Some(mut scope) = self.inner.key_scope.get() {
+            if key.is_none() && scope.parent == self.current() {
+                key = Some(scope.key.wrapping_add(scope.next));
+                scope.next += 1;
+                self.inner.key_scope.set(Some(scope));
+            }
+        }
+
         let (id, mut widget) = {
             let mut nodes = self.inner.nodes.borrow_mut();
-            let id = next_widget(&mut nodes, self.current());
+            let (id, moved) = next_widget(&mut nodes, self.current(), key);
+            if moved {
+                self.inner.changed.set(true);
+            }
+
             self.inner.stack.borrow_mut().push(id);
 
             // Component::update needs mutable access to both the widget and the
@@ -217,6 +486,11 @@ impl Dom {
         let response = {
             if widget.as_ref().type_id() != TypeId::of::<T>() {
                 widget = Box::new(T::new());
+                self.inner.changed.set(true);
+
+                // New nodes start out holding a placeholder, so they're
+                // counted here too.
+                self.inner.created.set(self.inner.created.get() + 1);
             }
 
             let widget = widget.downcast_mut::<T>().unwrap();
@@ -249,7 +523,9 @@ impl Dom {
 
         let mut nodes = self.inner.nodes.borrow_mut();
         let mut removed_nodes = self.inner.removed_nodes.borrow_mut();
-        trim_children(&mut nodes, &mut removed_nodes, id);
+        if trim_children(&mut nodes, &mut removed_nodes, id) {
+            self.inner.changed.set(true);
+        }
     }
 }
 
@@ -261,59 +537,115 @@ impl DomInner {
             parent: None,
             children: Vec::new(),
             next_child: 0,
+            key: None,
         });
 
         Self {
             globals: RefCell::new(AnyMap::new()),
+            stores: RefCell::new(HashMap::new()),
             nodes: RefCell::new(nodes),
             removed_nodes: RefCell::new(Vec::new()),
             stack: RefCell::new(Vec::new()),
             root: WidgetId::new(root),
             pending_focus_request: RefCell::new(None),
+            shortcuts: RefCell::new(Vec::new()),
+            key_scope: Cell::new(None),
+            time: Cell::new(Duration::ZERO),
+            delta_time: Cell::new(Duration::ZERO),
+            repaint_at: Cell::new(None),
+            changed: Cell::new(false),
+            created: Cell::new(0),
+            activity: Cell::new(UiActivity::empty()),
         }
     }
 }
 
-fn next_widget(nodes: &mut Arena<DomNode>, parent_id: WidgetId) -> WidgetId {
-    let parent = nodes.get_mut(parent_id.index()).unwrap();
-    if parent.next_child < parent.children.len() {
-        let id = parent.children[parent.next_child];
-        parent.next_child += 1;
-        id
-    } else {
-        let index = nodes.insert(DomNode {
-            widget: Box::new(DummyWidget),
-            parent: Some(parent_id),
-            children: Vec::new(),
-            next_child: 0,
-        });
+fn hash_key<K: Hash>(key: K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
 
-        let id = WidgetId::new(index);
+/// Finds or creates the next child of the given widget. Also returns whether
+/// the child was created or moved from somewhere else in the parent, which
+/// changes the structure of the tree.
+fn next_widget(
+    nodes: &mut Arena<DomNode>,
+    parent_id: WidgetId,
+    key: Option<u64>,
+) -> (WidgetId, bool) {
+    let parent = nodes.get(parent_id.index()).unwrap();
+    let start = parent.next_child;
+    let remaining = &parent.children[start.min(parent.children.len())..];
+
+    // Keyed widgets can match any of the remaining children, while unkeyed
+    // widgets only match the next child, and only if it isn't keyed.
+    let found = match key {
+        Some(_) => remaining
+            .iter()
+            .position(|id| nodes.get(id.index()).unwrap().key == key),
+        None => remaining
+            .first()
+            .filter(|id| nodes.get(id.index()).unwrap().key.is_none())
+            .map(|_| 0),
+    };
+
+    let id = match found {
+        // The common case: the next child matches, so it stays where it is.
+        Some(0) => {
+            let parent = nodes.get_mut(parent_id.index()).unwrap();
+            parent.next_child += 1;
+            return (parent.children[start], false);
+        }
 
-        let parent = nodes.get_mut(parent_id.index()).unwrap();
-        parent.children.push(id);
-        parent.next_child += 1;
-        id
-    }
+        Some(offset) => {
+            let parent = nodes.get_mut(parent_id.index()).unwrap();
+            parent.children.remove(start + offset)
+        }
+
+        None => {
+            let index = nodes.insert(DomNode {
+                widget: Box::new(DummyWidget),
+                parent: Some(parent_id),
+                children: Vec::new(),
+                next_child: 0,
+                key,
+            });
+
+            WidgetId::new(index)
+        }
+    };
+
+    let parent = nodes.get_mut(parent_id.index()).unwrap();
+    parent.children.insert(start, id);
+    parent.next_child += 1;
+    (id, true)
 }
 
 /// Remove children from the given node that weren't present in the latest
-/// traversal through the tree.
-fn trim_children(nodes: &mut Arena<DomNode>, removed_nodes: &mut Vec<WidgetId>, id: WidgetId) {
+/// traversal through the tree. Returns whether any children were removed.
+fn trim_children(
+    nodes: &mut Arena<DomNode>,
+    removed_nodes: &mut Vec<WidgetId>,
+    id: WidgetId,
+) -> bool {
     let node = nodes.get_mut(id.index()).unwrap();
 
-    if node.next_child < node.children.len() {
-        let mut queue: VecDeque<WidgetId> = VecDeque::new();
-        let to_drop = &node.children[node.next_child..];
-        queue.extend(to_drop);
-        removed_nodes.extend_from_slice(to_drop);
+    if node.next_child >= node.children.len() {
+        return false;
+    }
 
-        node.children.truncate(node.next_child);
+    let mut queue: VecDeque<WidgetId> = VecDeque::new();
+    let to_drop = &node.children[node.next_child..];
+    queue.extend(to_drop);
 
-        while let Some(child_id) = queue.pop_front() {
-            removed_nodes.push(child_id);
-            let child = nodes.remove(child_id.index()).unwrap();
-            queue.extend(child.children);
-        }
+    node.children.truncate(node.next_child);
+
+    while let Some(child_id) = queue.pop_front() {
+        removed_nodes.push(child_id);
+        let child = nodes.remove(child_id.index()).unwrap();
+        queue.extend(child.children);
     }
+
+    true
 }
@@ -1,9 +1,15 @@
 //! Defines the events that can be sent to yakui and handled by widgets.
 
+use std::any::Any;
+use std::rc::Rc;
+
 use glam::Vec2;
 
 use crate::geometry::Rect;
-use crate::input::{KeyCode, Modifiers, MouseButton};
+use crate::input::{
+    ClipboardContent, KeyCode, KeyLocation, LogicalKey, Modifiers, MouseButton, NavAction,
+    NavDirection, ScrollUnit, TouchPhase,
+};
 
 /// An event that can be handled by yakui.
 #[derive(Debug)]
@@ -26,17 +32,52 @@ pub enum Event {
 
     /// The user scrolled with the mouse.
     MouseScroll {
-        /// How far the mouse scrolled in physical pixels.
+        /// How far the mouse scrolled, measured in `unit`. Pixel deltas are in
+        /// logical pixels.
         delta: Vec2,
+
+        /// Whether `delta` counts lines or pixels.
+        unit: ScrollUnit,
+    },
+
+    /// A finger touched, moved on, or left a touchscreen.
+    ///
+    /// The first finger to touch down also acts like the mouse, so widgets
+    /// that only handle the mouse can still be tapped. Every finger is sent to
+    /// widgets with [`EventInterest::TOUCH`].
+    Touch {
+        /// Identifies the finger. Stays the same from when the touch starts
+        /// until it ends.
+        id: u64,
+
+        /// Whether the touch started, moved or ended.
+        phase: TouchPhase,
+
+        /// Where the finger is, in physical pixels.
+        pos: Vec2,
     },
 
     /// A key changed, telling whether it is now pressed.
     KeyChanged {
-        /// Which key's state was changed.
+        /// Which physical key's state was changed, independent of the user's
+        /// keyboard layout.
         key: KeyCode,
 
+        /// What the key means with the user's keyboard layout and current
+        /// modifiers, like `LogicalKey::Character("q")` for the key labeled Q
+        /// on a QWERTY keyboard, even though it is `KeyCode::KeyA` on an AZERTY
+        /// keyboard. Use [`LogicalKey::Unidentified`] if the host doesn't know.
+        logical_key: LogicalKey,
+
+        /// Where the key is on the keyboard, which tells apart keys like left
+        /// and right shift or the numpad and regular digits.
+        location: KeyLocation,
+
         /// Whether the key is now down.
         down: bool,
+
+        /// Whether this press was generated by the key being held down.
+        repeat: bool,
     },
 
     /// The state of the keyboard modifiers keys changed.
@@ -44,6 +85,33 @@ pub enum Event {
 
     /// A Unicode codepoint was typed in the window.
     TextInput(char),
+
+    /// The user pasted something, usually by pressing Ctrl+V or Command+V.
+    /// Hosts read the system clipboard and send its contents with this event.
+    Paste(ClipboardContent),
+
+    /// The user moved the selection, like with a gamepad's D-pad or the arrow
+    /// keys. The selection moves to the nearest widget with
+    /// [`EventInterest::FOCUS`] in that direction, unless the selected widget
+    /// or one of its ancestors picks a different one in
+    /// [`Widget::navigate`][crate::widget::Widget::navigate].
+    Navigate(NavDirection),
+
+    /// The user activated or backed out of the selected widget, like with a
+    /// gamepad's A or B buttons.
+    NavAction(NavAction),
+
+    /// An event defined by the application, like input from a VR controller
+    /// or a MIDI device. It's sent to widgets with [`EventInterest::CUSTOM`]
+    /// as [`WidgetEvent::Custom`]. See [`Event::custom`].
+    Custom(Rc<dyn Any>),
+}
+
+impl Event {
+    /// Wraps a value of any type into an [`Event::Custom`].
+    pub fn custom<T: Any>(value: T) -> Self {
+        Self::Custom(Rc::new(value))
+    }
 }
 
 /// An event that can be handled by an individual widget.
@@ -55,17 +123,28 @@ pub enum WidgetEvent {
     /// The mouse left the widget's layout rectangle.
     MouseLeave,
 
-    /// The mouse moved.
+    /// The mouse moved, giving its new position in logical pixels relative to
+    /// the viewport. Use [`EventContext::to_local`][crate::widget::EventContext::to_local]
+    /// to convert it into the widget's local space.
     MouseMoved(Option<Vec2>),
 
     /// The user scrolled with the mouse.
     MouseScroll {
-        /// How much the wheel scrolled in logical pixels.
+        /// How much the wheel scrolled, measured in `unit`.
         delta: Vec2,
+
+        /// Whether `delta` counts lines, like from a mouse wheel, or logical
+        /// pixels, like from a trackpad.
+        unit: ScrollUnit,
     },
 
-    /// A mouse button changed state while the cursor was inside the widget's
-    /// layout rectangle.
+    /// A mouse button changed state.
+    ///
+    /// Widgets receive this event when the cursor is inside their layout
+    /// rectangle. Releases are also always sent to widgets that received the
+    /// matching press, even if the cursor has moved away since then. Widgets
+    /// with [`EventInterest::MOUSE_OUTSIDE`] receive every other button change
+    /// as well.
     MouseButtonChanged {
         /// Which button was changed.
         button: MouseButton,
@@ -76,21 +155,67 @@ pub enum WidgetEvent {
         /// Whether the button is inside the widget's layout rectangle.
         inside: bool,
 
-        /// The position of the mouse cursor at the time of the event.
+        /// The position of the mouse cursor at the time of the event, in
+        /// logical pixels relative to the viewport.
         position: Vec2,
 
         /// The current state of the keyboard modifier keys.
         modifiers: Modifiers,
+
+        /// For releases, whether the button was pressed down somewhere other
+        /// than this widget. Widgets that respond to clicks should ignore
+        /// releases where this is true. Always false for presses.
+        pressed_elsewhere: bool,
+
+        /// How many times in a row this button has been pressed in quick
+        /// succession without the mouse moving, counting this press. This is
+        /// 2 for the second press of a double click. Releases have the same
+        /// count as the press they finish.
+        ///
+        /// Clicks are timed using [`Yakui::set_time`][crate::Yakui::set_time].
+        /// If the host doesn't call it, presses in the same spot keep counting
+        /// up no matter how far apart they are.
+        click_count: u32,
+    },
+
+    /// A finger touched, moved on, or left a touchscreen.
+    ///
+    /// Widgets with [`EventInterest::TOUCH`] receive every event for a touch
+    /// that started inside their layout rectangle. If a widget sinks the start
+    /// of a touch, that touch won't act like the mouse.
+    Touch {
+        /// Identifies the finger for as long as it's touching the screen.
+        id: u64,
+
+        /// Whether the touch started, moved or ended.
+        phase: TouchPhase,
+
+        /// Where the finger is, in logical pixels relative to the viewport.
+        position: Vec2,
+
+        /// Whether the finger is inside the widget's layout rectangle.
+        inside: bool,
     },
 
     /// A keyboard key changed.
     KeyChanged {
-        /// Which key was changed.
+        /// Which physical key was changed.
         key: KeyCode,
 
+        /// What the key means with the user's keyboard layout. Shortcuts
+        /// labeled with a letter, like Ctrl+C, should use this instead of
+        /// `key`.
+        logical_key: LogicalKey,
+
+        /// Where the key is on the keyboard.
+        location: KeyLocation,
+
         /// Whether the key is down or up.
         down: bool,
 
+        /// Whether this press was generated by the key being held down.
+        repeat: bool,
+
         /// The current state of the keyboard modifier keys.
         modifiers: Modifiers,
     },
@@ -100,6 +225,38 @@ pub enum WidgetEvent {
 
     /// The widget was focused or unfocused.
     FocusChanged(bool),
+
+    /// The user pasted something. Sent to widgets with
+    /// [`EventInterest::PASTE_TARGET`], starting from the selected widget and
+    /// its ancestors, then falling back to the widgets under the mouse.
+    /// Widgets should bubble content they can't use, like text pasted onto an
+    /// image picker.
+    Paste(ClipboardContent),
+
+    /// The user activated or backed out of something using a gamepad or
+    /// keyboard. Sent to the selected widget and then its ancestors until one
+    /// of them sinks it, so a menu can close on [`NavAction::Cancel`] while
+    /// one of its buttons is selected.
+    NavAction(NavAction),
+
+    /// An event defined by the application, sent with [`Event::Custom`].
+    ///
+    /// Widgets with [`EventInterest::CUSTOM`] receive it, starting with the
+    /// selected widget and its ancestors, then every other interested widget
+    /// in DOM order, until one of them sinks it. Widgets should bubble events
+    /// of types they don't know about.
+    Custom(Rc<dyn Any>),
+}
+
+impl WidgetEvent {
+    /// If this is a [`WidgetEvent::Custom`] holding a value of type `T`,
+    /// returns that value.
+    pub fn custom<T: Any>(&self) -> Option<&T> {
+        match self {
+            Self::Custom(value) => value.downcast_ref(),
+            _ => None,
+        }
+    }
 }
 
 /// Responses that can be given to an event.
@@ -135,6 +292,17 @@ bitflags::bitflags! {
         /// If this widget is focused, it should receive keyboard events.
         const FOCUSED_KEYBOARD = 16;
 
+        /// This widget accepts content pasted from the clipboard.
+        const PASTE_TARGET = 32;
+
+        /// Notify this widget of every finger that touches down inside its
+        /// layout rectangle.
+        const TOUCH = 64;
+
+        /// Notify this widget of events defined by the application, sent with
+        /// [`Event::Custom`].
+        const CUSTOM = 128;
+
         /// Notify this widget of all mouse events.
         const MOUSE_ALL = Self::MOUSE_INSIDE.bits() | Self::MOUSE_OUTSIDE.bits() | Self::MOUSE_MOVE.bits();
     }
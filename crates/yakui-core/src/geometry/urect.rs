@@ -91,4 +91,13 @@ impl URect {
 
         x_intersect && y_intersect
     }
+
+    /// Returns the smallest rectangle that contains both rectangles.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        let pos = self.pos.min(other.pos);
+        let max = self.max().max(other.max());
+
+        Self::from_pos_size(pos, max - pos)
+    }
 }
@@ -0,0 +1,14 @@
+use crate::paint::Texture;
+
+/// Something the user pasted from the system clipboard, sent to yakui with
+/// [`Event::Paste`][crate::event::Event::Paste].
+#[derive(Debug, Clone)]
+pub enum ClipboardContent {
+    /// Plain text.
+    Text(String),
+
+    /// An image, like a screenshot or a picture copied from a browser. Hosts
+    /// should convert images into [`TextureFormat::Rgba8Srgb`][crate::paint::TextureFormat::Rgba8Srgb]
+    /// so that widgets don't need to handle every format.
+    Image(Texture),
+}
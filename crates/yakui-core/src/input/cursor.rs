@@ -0,0 +1,50 @@
+/// The icon that the mouse cursor should show, requested by widgets with
+/// [`EventContext::set_cursor_icon`][crate::widget::EventContext::set_cursor_icon].
+///
+/// Hosts read the winning request with
+/// [`Yakui::cursor_icon`][crate::Yakui::cursor_icon] and apply it to the
+/// window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CursorIcon {
+    /// The platform's normal cursor, usually an arrow.
+    #[default]
+    Default,
+
+    /// A pointing hand, used for links.
+    Pointer,
+
+    /// An I-beam, used over text that can be edited or selected.
+    Text,
+
+    /// An open hand, used over something that can be dragged.
+    Grab,
+
+    /// A closed hand, used while something is being dragged.
+    Grabbing,
+
+    /// Arrows pointing in every direction, used over something that can be
+    /// moved.
+    Move,
+
+    /// Used when the action the user is trying to do isn't allowed.
+    NotAllowed,
+
+    /// A crosshair, used for precise selection like in a color picker.
+    Crosshair,
+
+    /// Arrows pointing left and right, used over something that can be
+    /// resized horizontally.
+    ResizeHorizontal,
+
+    /// Arrows pointing up and down, used over something that can be resized
+    /// vertically.
+    ResizeVertical,
+
+    /// Arrows pointing to the top left and bottom right, used over the corner
+    /// of something that can be resized in both directions.
+    ResizeNwSe,
+
+    /// Arrows pointing to the top right and bottom left, used over the corner
+    /// of something that can be resized in both directions.
+    ResizeNeSw,
+}
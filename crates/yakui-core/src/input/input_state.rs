@@ -1,5 +1,8 @@
+use std::any::Any;
 use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
 
 use glam::Vec2;
 use smallvec::SmallVec;
@@ -8,9 +11,14 @@ use crate::dom::{Dom, DomNode};
 use crate::event::{Event, EventInterest, EventResponse, WidgetEvent};
 use crate::id::WidgetId;
 use crate::layout::LayoutDom;
-use crate::widget::EventContext;
-
-use super::mouse::MouseButton;
+use crate::widget::{EventContext, NavigateContext};
+
+use super::clipboard::ClipboardContent;
+use super::cursor::CursorIcon;
+use super::mouse::{MouseButton, ScrollUnit};
+use super::navigation::{self, NavAction, NavDirection};
+use super::player::PlayerId;
+use super::touch::TouchPhase;
 use super::{KeyCode, Modifiers};
 
 /// Holds yakui's input state, like cursor position, hovered, and selected
@@ -31,6 +39,60 @@ pub struct InputState {
 
     /// The widget that was selected last frame.
     last_selection: Cell<Option<WidgetId>>,
+
+    /// The kind of input the user most recently interacted with.
+    modality: Cell<InputModality>,
+
+    /// The kind of input the user was interacting with when the current
+    /// selection was made.
+    selection_modality: Cell<InputModality>,
+
+    /// The widget that each player has selected, separate from the shared
+    /// selection.
+    player_selections: RefCell<HashMap<PlayerId, WidgetId>>,
+
+    /// The widget that each player had selected last frame.
+    last_player_selections: RefCell<HashMap<PlayerId, WidgetId>>,
+
+    /// The player that sent the event currently being handled, if any.
+    current_player: Cell<Option<PlayerId>>,
+
+    /// The widgets with touch interest that each finger touched down in.
+    /// Every later event for the finger is sent to them.
+    touches: RefCell<HashMap<u64, Vec<WidgetId>>>,
+
+    /// The finger that's acting like the mouse, if any.
+    primary_touch: Cell<Option<u64>>,
+
+    /// Subtrees that navigation is currently trapped inside of, with the
+    /// innermost last.
+    focus_scopes: RefCell<Vec<FocusScope>>,
+
+    /// The cursor icon that each widget wants shown while the mouse is over
+    /// it or pressing it.
+    cursor_icons: RefCell<HashMap<WidgetId, CursorIcon>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FocusScope {
+    /// The widget whose descendants navigation is limited to.
+    root: WidgetId,
+
+    /// The widget that was selected when the scope was pushed, which is
+    /// selected again when the scope is popped.
+    restore: Option<WidgetId>,
+}
+
+/// The kind of input that the user is interacting with yakui through. Used to
+/// decide whether focus should be visibly indicated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputModality {
+    /// A mouse or other pointing device.
+    Pointer,
+
+    /// A keyboard, or another device used to navigate between widgets like a
+    /// gamepad.
+    Keyboard,
 }
 
 #[derive(Debug)]
@@ -41,8 +103,26 @@ struct Mouse {
     /// The state of each mouse button. If missing from the map, the button is
     /// up and has not yet been pressed.
     buttons: HashMap<MouseButton, ButtonState>,
+
+    /// The most recent button press, used to detect double clicks.
+    last_press: Option<Press>,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    button: MouseButton,
+    position: Vec2,
+    time: Duration,
+    click_count: u32,
+}
+
+/// The longest time between presses that still count as multiple clicks.
+const MULTI_CLICK_TIME: Duration = Duration::from_millis(500);
+
+/// How far the mouse can move between presses that count as multiple clicks,
+/// in logical pixels.
+const MULTI_CLICK_DISTANCE: f32 = 4.0;
+
 #[derive(Debug)]
 struct Intersections {
     /// All of the widgets with mouse interest that the current mouse position
@@ -63,8 +143,8 @@ struct Intersections {
     mouse_entered_and_sunk: Vec<WidgetId>,
 
     /// All widgets that had the corresponding mouse button pressed while the
-    /// mouse cursor was over them.
-    #[allow(unused)]
+    /// mouse cursor was over them. Releases are always sent to these widgets,
+    /// even if the cursor has left them since.
     mouse_down_in: HashMap<MouseButton, Vec<WidgetId>>,
 }
 
@@ -94,6 +174,48 @@ impl ButtonState {
     }
 }
 
+impl Mouse {
+    /// Records a press or release, returning how many clicks in a row it's
+    /// part of.
+    fn click_count(
+        &mut self,
+        now: Duration,
+        button: MouseButton,
+        down: bool,
+        position: Vec2,
+    ) -> u32 {
+        if !down {
+            return match self.last_press {
+                Some(press) if press.button == button => press.click_count,
+                _ => 1,
+            };
+        }
+
+        // Presses follow on from the one before them unless too much time
+        // has passed. Presses made in the same frame, or when the host never
+        // sets the time, have no time between them.
+        let click_count = match self.last_press {
+            Some(press)
+                if press.button == button
+                    && now.saturating_sub(press.time) <= MULTI_CLICK_TIME
+                    && press.position.distance(position) <= MULTI_CLICK_DISTANCE =>
+            {
+                press.click_count + 1
+            }
+            _ => 1,
+        };
+
+        self.last_press = Some(Press {
+            button,
+            position,
+            time: now,
+            click_count,
+        });
+
+        click_count
+    }
+}
+
 impl InputState {
     /// Create a new, empty `InputState`.
     pub fn new() -> Self {
@@ -101,6 +223,7 @@ impl InputState {
             mouse: RefCell::new(Mouse {
                 position: None,
                 buttons: HashMap::new(),
+                last_press: None,
             }),
             modifiers: Cell::new(Modifiers::default()),
             intersections: RefCell::new(Intersections {
@@ -111,11 +234,42 @@ impl InputState {
             }),
             last_selection: Cell::new(None),
             selection: Cell::new(None),
+            modality: Cell::new(InputModality::Pointer),
+            selection_modality: Cell::new(InputModality::Pointer),
+            player_selections: RefCell::new(HashMap::new()),
+            last_player_selections: RefCell::new(HashMap::new()),
+            current_player: Cell::new(None),
+            touches: RefCell::new(HashMap::new()),
+            primary_touch: Cell::new(None),
+            focus_scopes: RefCell::new(Vec::new()),
+            cursor_icons: RefCell::new(HashMap::new()),
         }
     }
 
     /// Begin a new frame for input handling.
     pub fn start(&self, dom: &Dom, layout: &LayoutDom) {
+        // Scopes whose widget was removed last frame are closed, like when a
+        // modal stops being shown.
+        loop {
+            let closed = self
+                .focus_scopes
+                .borrow()
+                .iter()
+                .rev()
+                .find(|scope| dom.get(scope.root).is_none())
+                .map(|scope| scope.root);
+
+            let Some(root) = closed else {
+                break;
+            };
+
+            self.close_focus_scope(dom, root);
+        }
+
+        self.cursor_icons
+            .borrow_mut()
+            .retain(|&id, _| dom.get(id).is_some());
+
         self.notify_selection(dom, layout);
     }
 
@@ -125,13 +279,165 @@ impl InputState {
     }
 
     /// Return the currently selected widget, if there is one.
+    ///
+    /// While an event from a player is being handled, this is the widget that
+    /// player has selected instead.
     pub fn selection(&self) -> Option<WidgetId> {
-        self.selection.get()
+        match self.current_player.get() {
+            Some(player) => self.player_selection(player),
+            None => self.selection.get(),
+        }
     }
 
     /// Set the currently selected widget.
+    ///
+    /// While an event from a player is being handled, this sets the widget
+    /// that player has selected instead.
     pub fn set_selection(&self, id: Option<WidgetId>) {
+        if let Some(player) = self.current_player.get() {
+            self.set_player_selection(player, id);
+            return;
+        }
+
         self.selection.set(id);
+        self.selection_modality.set(self.modality.get());
+    }
+
+    /// Returns the widget that the given player has selected. Each player's
+    /// selection is separate from the others, so that every player can
+    /// navigate their own part of the UI at the same time.
+    pub fn player_selection(&self, player: PlayerId) -> Option<WidgetId> {
+        self.player_selections.borrow().get(&player).copied()
+    }
+
+    /// Sets the widget that the given player has selected. Keyboard events
+    /// sent by that player will go to this widget.
+    pub fn set_player_selection(&self, player: PlayerId, id: Option<WidgetId>) {
+        let mut selections = self.player_selections.borrow_mut();
+        match id {
+            Some(id) => selections.insert(player, id),
+            None => selections.remove(&player),
+        };
+    }
+
+    /// Traps navigation inside of the given widget's descendants, like for a
+    /// modal or a menu that's been opened. Tab and directional navigation
+    /// won't select anything outside of it until the scope is popped.
+    ///
+    /// The current selection is remembered and restored when the scope is
+    /// popped with [`InputState::pop_focus_scope`], or automatically when the
+    /// widget is removed. Pushing a scope that's already open does nothing, so
+    /// widgets can push their scope every time they're laid out.
+    pub fn push_focus_scope(&self, root: WidgetId) {
+        let mut scopes = self.focus_scopes.borrow_mut();
+        if scopes.iter().any(|scope| scope.root == root) {
+            return;
+        }
+
+        scopes.push(FocusScope {
+            root,
+            restore: self.selection.get(),
+        });
+    }
+
+    /// Stops trapping navigation inside of the given widget, closing any
+    /// scopes opened inside of it too. If the selection is gone or was inside
+    /// the scope, the widget that was selected when the scope was pushed is
+    /// selected again.
+    pub fn pop_focus_scope(&self, dom: &Dom, root: WidgetId) {
+        self.close_focus_scope(dom, root);
+    }
+
+    /// Returns the widget that navigation is currently trapped inside of, if
+    /// any.
+    pub fn focus_scope(&self) -> Option<WidgetId> {
+        self.focus_scopes.borrow().last().map(|scope| scope.root)
+    }
+
+    /// Sets the cursor icon that the given widget wants while the mouse is
+    /// over it, or while it's being pressed. The request lasts until it's
+    /// replaced or the widget is removed, so widgets usually only need to make
+    /// it when the mouse enters them.
+    ///
+    /// Widgets usually call this through
+    /// [`EventContext::set_cursor_icon`][crate::widget::EventContext::set_cursor_icon].
+    pub fn set_cursor_icon(&self, id: WidgetId, icon: CursorIcon) {
+        self.cursor_icons.borrow_mut().insert(id, icon);
+    }
+
+    /// Returns the cursor icon that the host should show.
+    ///
+    /// Widgets that are being pressed win, so that a resize handle keeps its
+    /// cursor while the mouse is dragged off of it. Otherwise, the deepest
+    /// hovered widget with a request wins.
+    pub fn cursor_icon(&self) -> CursorIcon {
+        if self.mouse.borrow().position.is_none() {
+            return CursorIcon::Default;
+        }
+
+        let icons = self.cursor_icons.borrow();
+        let intersections = self.intersections.borrow();
+
+        let pressed = intersections.mouse_down_in.values().flatten();
+        let hovered = intersections
+            .mouse_hit
+            .iter()
+            .filter(|id| intersections.mouse_entered.contains(id));
+
+        pressed
+            .chain(hovered)
+            .find_map(|id| icons.get(id).copied())
+            .unwrap_or_default()
+    }
+
+    /// Returns the player that sent the event currently being handled, if it
+    /// was sent with
+    /// [`Yakui::handle_player_event`][crate::Yakui::handle_player_event].
+    pub fn current_player(&self) -> Option<PlayerId> {
+        self.current_player.get()
+    }
+
+    /// Returns the current mouse position in physical pixels relative to the
+    /// viewport.
+    pub(crate) fn raw_mouse_position(&self) -> Option<Vec2> {
+        self.mouse.borrow().position
+    }
+
+    /// Returns the kind of input the user most recently interacted with.
+    pub fn modality(&self) -> InputModality {
+        self.modality.get()
+    }
+
+    /// Overrides the kind of input the user is interacting with. yakui tracks
+    /// this automatically for mouse and keyboard events, but integrations that
+    /// move focus through other means should call this before doing so.
+    pub fn set_modality(&self, modality: InputModality) {
+        self.modality.set(modality);
+    }
+
+    /// Tells whether the selected widget should visibly indicate that it has
+    /// focus, like by drawing a selection halo.
+    ///
+    /// Focus is visible when the selection was made using the keyboard, but
+    /// not when it was made by clicking on a widget. Selections made by
+    /// players are always visible, since players navigate with their own
+    /// controllers.
+    pub fn focus_visible(&self) -> bool {
+        if let Some(player) = self.current_player.get() {
+            return self.player_selection(player).is_some();
+        }
+
+        self.selection.get().is_some() && self.selection_modality.get() == InputModality::Keyboard
+    }
+
+    pub(crate) fn handle_player_event(
+        &self,
+        dom: &Dom,
+        layout: &LayoutDom,
+        player: PlayerId,
+        event: &Event,
+    ) -> EventResponse {
+        self.as_player(Some(player), || self.handle_event(dom, layout, event))
     }
 
     pub(crate) fn handle_event(
@@ -164,15 +470,71 @@ impl InputState {
 
                 response
             }
-            Event::MouseScroll { delta } => self.send_mouse_scroll(dom, layout, *delta),
-            Event::KeyChanged { key, down } => self.keyboard_key_changed(dom, layout, *key, *down),
+            Event::MouseScroll { delta, unit } => {
+                self.send_mouse_scroll(dom, layout, *delta, *unit)
+            }
+            Event::Touch { id, phase, pos } => self.touch(dom, layout, *id, *phase, *pos),
+            Event::KeyChanged {
+                key,
+                logical_key,
+                location,
+                down,
+                repeat,
+            } => {
+                let event = WidgetEvent::KeyChanged {
+                    key: *key,
+                    logical_key: logical_key.clone(),
+                    location: *location,
+                    down: *down,
+                    repeat: *repeat,
+                    modifiers: self.modifiers.get(),
+                };
+
+                let response = self.keyboard_key_changed(dom, layout, *down, &event);
+
+                // Shortcuts only get keys that the selected widget didn't use.
+                if response == EventResponse::Bubble
+                    && *down
+                    && !*repeat
+                    && dom.trigger_shortcuts(*key, logical_key, self.modifiers.get())
+                {
+                    return EventResponse::Sink;
+                }
+
+                // Tab moves the selection unless the selected widget used it.
+                let modifiers = self.modifiers.get();
+                if response == EventResponse::Bubble
+                    && *down
+                    && *key == KeyCode::Tab
+                    && !modifiers.intersects(Modifiers::CONTROL | Modifiers::ALT | Modifiers::META)
+                {
+                    let dir = if modifiers.shift() {
+                        NavDirection::Previous
+                    } else {
+                        NavDirection::Next
+                    };
+
+                    return self.navigate(dom, layout, dir);
+                }
+
+                response
+            }
             Event::ModifiersChanged(modifiers) => self.modifiers_changed(modifiers),
             Event::TextInput(c) => self.text_input(dom, layout, *c),
+            Event::Paste(content) => self.paste(dom, layout, content),
+            Event::Navigate(dir) => self.navigate(dom, layout, *dir),
+            Event::NavAction(action) => self.nav_action(dom, layout, *action),
+            Event::Custom(value) => self.custom(dom, layout, value),
             _ => EventResponse::Bubble,
         }
     }
 
     fn notify_selection(&self, dom: &Dom, layout: &LayoutDom) {
+        self.as_player(None, || self.notify_shared_selection(dom, layout));
+        self.notify_player_selections(dom, layout);
+    }
+
+    fn notify_shared_selection(&self, dom: &Dom, layout: &LayoutDom) {
         let mut current = self.selection.get();
         let last = self.last_selection.get();
 
@@ -210,6 +572,152 @@ impl InputState {
         self.last_selection.set(current);
     }
 
+    fn notify_player_selections(&self, dom: &Dom, layout: &LayoutDom) {
+        let selections = self.player_selections.borrow().clone();
+        let last = self.last_player_selections.take();
+        let mut current = selections.clone();
+
+        for (&player, &entered) in &selections {
+            if last.get(&player) == Some(&entered) {
+                continue;
+            }
+
+            if let Some(mut node) = dom.get_mut(entered) {
+                let event = WidgetEvent::FocusChanged(true);
+                self.as_player(Some(player), || {
+                    self.fire_event(dom, layout, entered, &mut node, &event)
+                });
+            } else {
+                self.player_selections.borrow_mut().remove(&player);
+                current.remove(&player);
+            }
+        }
+
+        for (&player, &left) in &last {
+            if current.get(&player) == Some(&left) {
+                continue;
+            }
+
+            if let Some(mut node) = dom.get_mut(left) {
+                let event = WidgetEvent::FocusChanged(false);
+                self.as_player(Some(player), || {
+                    self.fire_event(dom, layout, left, &mut node, &event)
+                });
+            }
+        }
+
+        *self.last_player_selections.borrow_mut() = current;
+    }
+
+    fn close_focus_scope(&self, dom: &Dom, root: WidgetId) {
+        let mut scopes = self.focus_scopes.borrow_mut();
+        let Some(index) = scopes.iter().position(|scope| scope.root == root) else {
+            return;
+        };
+
+        let restore = scopes[index].restore;
+        scopes.truncate(index);
+        drop(scopes);
+
+        // Only take the selection back if it's still inside of the scope, so
+        // that something the user selected elsewhere in the meantime stays
+        // selected.
+        let selection = self.selection.get();
+        let inside = selection
+            .is_none_or(|id| dom.get(id).is_none() || navigation::is_descendant(dom, id, root));
+
+        if inside {
+            self.selection
+                .set(restore.filter(|&id| dom.get(id).is_some()));
+        }
+    }
+
+    fn navigate(&self, dom: &Dom, layout: &LayoutDom, dir: NavDirection) -> EventResponse {
+        self.modality.set(InputModality::Keyboard);
+
+        let current = self.selection();
+        let root = self.focus_scope().unwrap_or_else(|| dom.root());
+        let candidates =
+            navigation::focusable_widgets(dom, layout, root, self.current_player.get());
+
+        // The selected widget and its ancestors get the first say in where the
+        // selection goes, so containers can implement their own navigation.
+        let mut overridden = None;
+        let mut next = current;
+        while let Some(id) = next {
+            let Some(node) = dom.get(id) else {
+                break;
+            };
+
+            let context = NavigateContext {
+                dom,
+                layout,
+                input: self,
+            };
+
+            overridden = node.widget.navigate(context, dir);
+            if overridden.is_some() {
+                break;
+            }
+
+            next = node.parent;
+        }
+
+        let target = overridden.or_else(|| match dir {
+            NavDirection::Next | NavDirection::Previous => {
+                let order = navigation::tab_order(dom, &candidates);
+                let len = order.len();
+
+                // Tabbing from nothing, or from a widget that isn't in the
+                // tab order, starts at the first widget, or the last one when
+                // going backwards.
+                let index = current.and_then(|current| order.iter().position(|&id| id == current));
+                let index = match (dir, index) {
+                    (NavDirection::Next, Some(index)) => index + 1,
+                    (NavDirection::Next, None) => 0,
+                    (_, Some(index)) => index + len - 1,
+                    (_, None) => len.checked_sub(1)?,
+                };
+
+                order.get(index % len.max(1)).copied()
+            }
+            _ => match current.filter(|id| candidates.contains(id)) {
+                Some(current) => navigation::find_in_direction(layout, &candidates, current, dir),
+                None => navigation::first_widget(layout, &candidates),
+            },
+        });
+
+        match target {
+            Some(target) if Some(target) != current => {
+                self.set_selection(Some(target));
+                self.notify_selection(dom, layout);
+                EventResponse::Sink
+            }
+            _ => EventResponse::Bubble,
+        }
+    }
+
+    fn nav_action(&self, dom: &Dom, layout: &LayoutDom, action: NavAction) -> EventResponse {
+        self.modality.set(InputModality::Keyboard);
+
+        let event = WidgetEvent::NavAction(action);
+
+        let mut next = self.selection();
+        while let Some(id) = next {
+            let Some(mut node) = dom.get_mut(id) else {
+                break;
+            };
+
+            next = node.parent;
+
+            if self.fire_event(dom, layout, id, &mut node, &event) == EventResponse::Sink {
+                return EventResponse::Sink;
+            }
+        }
+
+        EventResponse::Bubble
+    }
+
     /// Signal that the mouse has moved.
     fn mouse_moved(&self, dom: &Dom, layout: &LayoutDom, pos: Option<Vec2>) {
         let pos = pos.map(|pos| pos - layout.unscaled_viewport().pos());
@@ -220,7 +728,7 @@ impl InputState {
         }
 
         self.send_mouse_move(dom, layout);
-        self.mouse_hit_test(dom, layout);
+        self.mouse_hit_test(layout);
         self.send_mouse_enter(dom, layout);
         self.send_mouse_leave(dom, layout);
     }
@@ -233,6 +741,10 @@ impl InputState {
         button: MouseButton,
         down: bool,
     ) -> EventResponse {
+        if down {
+            self.modality.set(InputModality::Pointer);
+        }
+
         {
             let mut mouse = self.mouse.borrow_mut();
             let state = mouse.buttons.entry(button).or_insert(ButtonState::Up);
@@ -255,14 +767,144 @@ impl InputState {
         self.send_button_change(dom, layout, button, down)
     }
 
+    /// Signal that a finger touched, moved on, or left the screen.
+    fn touch(
+        &self,
+        dom: &Dom,
+        layout: &LayoutDom,
+        id: u64,
+        phase: TouchPhase,
+        pos: Vec2,
+    ) -> EventResponse {
+        let mut response = self.send_touch(dom, layout, id, phase, pos);
+
+        // The first finger down acts like the mouse, unless a widget handling
+        // touches directly sank it.
+        if phase == TouchPhase::Started
+            && self.primary_touch.get().is_none()
+            && response == EventResponse::Bubble
+        {
+            self.primary_touch.set(Some(id));
+        }
+
+        if self.primary_touch.get() != Some(id) {
+            return response;
+        }
+
+        let mut press = |down| {
+            let event = Event::MouseButtonChanged {
+                button: MouseButton::One,
+                down,
+            };
+
+            if self.handle_event(dom, layout, &event) == EventResponse::Sink {
+                response = EventResponse::Sink;
+            }
+        };
+
+        match phase {
+            TouchPhase::Started => {
+                self.mouse_moved(dom, layout, Some(pos));
+                press(true);
+            }
+            TouchPhase::Moved => {
+                self.mouse_moved(dom, layout, Some(pos));
+            }
+
+            // Fingers don't hover once they're lifted, so the cursor leaves
+            // the window with them.
+            TouchPhase::Ended => {
+                self.mouse_moved(dom, layout, Some(pos));
+                press(false);
+                self.mouse_moved(dom, layout, None);
+                self.primary_touch.set(None);
+            }
+
+            // Moving the cursor away first means that the release isn't inside
+            // any widget, so nothing is clicked.
+            TouchPhase::Cancelled => {
+                self.mouse_moved(dom, layout, None);
+                press(false);
+                self.primary_touch.set(None);
+            }
+        }
+
+        response
+    }
+
+    fn send_touch(
+        &self,
+        dom: &Dom,
+        layout: &LayoutDom,
+        id: u64,
+        phase: TouchPhase,
+        pos: Vec2,
+    ) -> EventResponse {
+        let position = (pos - layout.unscaled_viewport().pos()) / layout.scale_factor();
+
+        let targets = if phase == TouchPhase::Started {
+            let mut hit = Vec::new();
+            hit_test(layout, position, &mut hit);
+            hit.retain(|&id| {
+                layout
+                    .get(id)
+                    .is_some_and(|node| node.event_interest.contains(EventInterest::TOUCH))
+            });
+
+            self.touches.borrow_mut().insert(id, hit.clone());
+            hit
+        } else if matches!(phase, TouchPhase::Ended | TouchPhase::Cancelled) {
+            self.touches.borrow_mut().remove(&id).unwrap_or_default()
+        } else {
+            self.touches.borrow().get(&id).cloned().unwrap_or_default()
+        };
+
+        let mut overall_response = EventResponse::Bubble;
+
+        for (index, &target) in targets.iter().enumerate() {
+            let Some(layout_node) = layout.get(target) else {
+                continue;
+            };
+
+            let local = layout.untransform_point(target, position);
+            let event = WidgetEvent::Touch {
+                id,
+                phase,
+                position,
+                inside: layout_node.hit_rect().contains_point(local),
+            };
+
+            // Panic safety: if this node is in the layout DOM, it must be in
+            // the DOM.
+            let mut node = dom.get_mut(target).unwrap();
+            let response = self.fire_event(dom, layout, target, &mut node, &event);
+
+            // Like mouse presses, only the start of a touch can be sunk. The
+            // rest of it is sent to every widget that saw it start.
+            if phase == TouchPhase::Started && response == EventResponse::Sink {
+                overall_response = response;
+                self.touches
+                    .borrow_mut()
+                    .insert(id, targets[..=index].to_vec());
+                break;
+            }
+        }
+
+        overall_response
+    }
+
     fn keyboard_key_changed(
         &self,
         dom: &Dom,
         layout: &LayoutDom,
-        key: KeyCode,
         down: bool,
+        event: &WidgetEvent,
     ) -> EventResponse {
-        let selected = self.selection.get();
+        if down {
+            self.modality.set(InputModality::Keyboard);
+        }
+
+        let selected = self.selection();
         if let Some(id) = selected {
             let Some(layout_node) = layout.get(id) else {
                 return EventResponse::Bubble;
@@ -275,12 +917,7 @@ impl InputState {
                 // Panic safety: if this node is in the layout DOM, it must be
                 // in the DOM.
                 let mut node = dom.get_mut(id).unwrap();
-                let event = WidgetEvent::KeyChanged {
-                    key,
-                    down,
-                    modifiers: self.modifiers.get(),
-                };
-                return self.fire_event(dom, layout, id, &mut node, &event);
+                return self.fire_event(dom, layout, id, &mut node, event);
             }
         }
 
@@ -293,7 +930,7 @@ impl InputState {
     }
 
     fn text_input(&self, dom: &Dom, layout: &LayoutDom, c: char) -> EventResponse {
-        let selected = self.selection.get();
+        let selected = self.selection();
         if let Some(id) = selected {
             let Some(layout_node) = layout.get(id) else {
                 return EventResponse::Bubble;
@@ -314,6 +951,99 @@ impl InputState {
         EventResponse::Bubble
     }
 
+    fn paste(&self, dom: &Dom, layout: &LayoutDom, content: &ClipboardContent) -> EventResponse {
+        let mut targets = Vec::new();
+
+        // Pastes go to the selected widget first, or whichever of its
+        // ancestors knows what to do with pasted content, like a container
+        // around a focused canvas.
+        let mut next = self.selection();
+        while let Some(id) = next {
+            next = dom.get(id).and_then(|node| node.parent);
+            targets.push(id);
+        }
+
+        // If nothing selected wants it, the widgets under the mouse get a
+        // chance, so that things like image pickers can be pasted into just by
+        // hovering over them.
+        for &id in &self.intersections.borrow().mouse_hit {
+            if !targets.contains(&id) {
+                targets.push(id);
+            }
+        }
+
+        let event = WidgetEvent::Paste(content.clone());
+
+        for id in targets {
+            let Some(layout_node) = layout.get(id) else {
+                continue;
+            };
+
+            if !layout_node
+                .event_interest
+                .contains(EventInterest::PASTE_TARGET)
+            {
+                continue;
+            }
+
+            // Panic safety: if this node is in the layout DOM, it must be in
+            // the DOM.
+            let mut node = dom.get_mut(id).unwrap();
+            if self.fire_event(dom, layout, id, &mut node, &event) == EventResponse::Sink {
+                return EventResponse::Sink;
+            }
+        }
+
+        EventResponse::Bubble
+    }
+
+    fn custom(&self, dom: &Dom, layout: &LayoutDom, value: &Rc<dyn Any>) -> EventResponse {
+        let mut targets = Vec::new();
+
+        // The selected widget and its ancestors get the first chance, the
+        // same as with keyboard input.
+        let mut next = self.selection();
+        while let Some(id) = next {
+            next = dom.get(id).and_then(|node| node.parent);
+            targets.push(id);
+        }
+
+        let selected = targets.len();
+        let mut stack = vec![dom.root()];
+        while let Some(id) = stack.pop() {
+            let Some(node) = dom.get(id) else {
+                continue;
+            };
+
+            stack.extend(node.children.iter().rev());
+
+            if !targets[..selected].contains(&id) {
+                targets.push(id);
+            }
+        }
+
+        let event = WidgetEvent::Custom(value.clone());
+
+        for id in targets {
+            let Some(layout_node) = layout.get(id) else {
+                continue;
+            };
+
+            if !layout_node.event_interest.contains(EventInterest::CUSTOM) {
+                continue;
+            }
+
+            // Panic safety: if this node is in the layout DOM, it must be in
+            // the DOM.
+            let mut node = dom.get_mut(id).unwrap();
+            if self.fire_event(dom, layout, id, &mut node, &event) == EventResponse::Sink {
+                return EventResponse::Sink;
+            }
+        }
+
+        EventResponse::Bubble
+    }
+
     fn send_button_change(
         &self,
         dom: &Dom,
@@ -321,20 +1051,42 @@ impl InputState {
         button: MouseButton,
         down: bool,
     ) -> EventResponse {
-        let mouse = self.mouse.borrow();
-        let intersections = self.intersections.borrow();
+        let mut mouse = self.mouse.borrow_mut();
+        let mut intersections = self.intersections.borrow_mut();
+        let intersections = &mut *intersections;
         let mut overall_response = EventResponse::Bubble;
 
+        let position = mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor();
+        let click_count = mouse.click_count(dom.time(), button, down, position);
+        drop(mouse);
+
+        // Presses start a new set of widgets that the release will be sent to,
+        // while releases finish the set from the last press.
+        let pressed_in = if down {
+            intersections.mouse_down_in.remove(&button);
+            Vec::new()
+        } else {
+            intersections
+                .mouse_down_in
+                .remove(&button)
+                .unwrap_or_default()
+        };
+
+        let mut notified = SmallVec::<[WidgetId; 8]>::new();
+
         for &id in &intersections.mouse_hit {
             if let Some(mut node) = dom.get_mut(id) {
                 let event = WidgetEvent::MouseButtonChanged {
                     button,
                     down,
                     inside: true,
-                    position: mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor(),
+                    position,
                     modifiers: self.modifiers.get(),
+                    pressed_elsewhere: !down && !pressed_in.contains(&id),
+                    click_count,
                 };
                 let response = self.fire_event(dom, layout, id, &mut node, &event);
+                notified.push(id);
 
                 if response == EventResponse::Sink {
                     overall_response = response;
@@ -343,17 +1095,48 @@ impl InputState {
             }
         }
 
+        if down {
+            intersections
+                .mouse_down_in
+                .insert(button, notified.to_vec());
+        }
+
+        // Widgets that were pressed always find out when the press ends, so
+        // that they don't get stuck thinking the button is still down.
+        for &id in &pressed_in {
+            if notified.contains(&id) {
+                continue;
+            }
+
+            if let Some(mut node) = dom.get_mut(id) {
+                let event = WidgetEvent::MouseButtonChanged {
+                    button,
+                    down,
+                    inside: false,
+                    position,
+                    modifiers: self.modifiers.get(),
+                    pressed_elsewhere: false,
+                    click_count,
+                };
+                self.fire_event(dom, layout, id, &mut node, &event);
+                notified.push(id);
+            }
+        }
+
         for (id, interest) in layout.interest_mouse.iter() {
             if interest.contains(EventInterest::MOUSE_OUTSIDE)
                 && !intersections.mouse_hit.contains(&id)
+                && !notified.contains(&id)
             {
                 if let Some(mut node) = dom.get_mut(id) {
                     let event = WidgetEvent::MouseButtonChanged {
                         button,
                         down,
                         inside: false,
-                        position: mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor(),
+                        position,
                         modifiers: self.modifiers.get(),
+                        pressed_elsewhere: !down,
+                        click_count,
                     };
                     self.fire_event(dom, layout, id, &mut node, &event);
                 }
@@ -363,14 +1146,20 @@ impl InputState {
         overall_response
     }
 
-    fn send_mouse_scroll(&self, dom: &Dom, layout: &LayoutDom, delta: Vec2) -> EventResponse {
+    fn send_mouse_scroll(
+        &self,
+        dom: &Dom,
+        layout: &LayoutDom,
+        delta: Vec2,
+        unit: ScrollUnit,
+    ) -> EventResponse {
         let intersections = self.intersections.borrow();
 
         let mut overall_response = EventResponse::Bubble;
 
         for &id in &intersections.mouse_hit {
             if let Some(mut node) = dom.get_mut(id) {
-                let event = WidgetEvent::MouseScroll { delta };
+                let event = WidgetEvent::MouseScroll { delta, unit };
                 let response = self.fire_event(dom, layout, id, &mut node, &event);
 
                 if response == EventResponse::Sink {
@@ -447,7 +1236,7 @@ impl InputState {
         }
     }
 
-    fn mouse_hit_test(&self, dom: &Dom, layout: &LayoutDom) {
+    fn mouse_hit_test(&self, layout: &LayoutDom) {
         let mut intersections = self.intersections.borrow_mut();
         let mouse = self.mouse.borrow();
 
@@ -455,7 +1244,7 @@ impl InputState {
 
         if let Some(mut mouse_pos) = mouse.position {
             mouse_pos /= layout.scale_factor();
-            hit_test(dom, layout, mouse_pos, &mut intersections.mouse_hit);
+            hit_test(layout, mouse_pos, &mut intersections.mouse_hit);
         }
     }
 
@@ -478,6 +1267,13 @@ impl InputState {
         node: &mut DomNode,
         event: &WidgetEvent,
     ) -> EventResponse {
+        // Widgets that belong to one player ignore events sent by the others.
+        if let (Some(player), Some(layout_node)) = (self.current_player.get(), layout.get(id)) {
+            if matches!(layout_node.player, Some(owner) if owner != player) {
+                return EventResponse::Bubble;
+            }
+        }
+
         let context = EventContext {
             dom,
             layout,
@@ -490,24 +1286,41 @@ impl InputState {
 
         response
     }
+
+    /// Runs the given function as if handling an event sent by the given
+    /// player.
+    fn as_player<R>(&self, player: Option<PlayerId>, f: impl FnOnce() -> R) -> R {
+        let previous = self.current_player.replace(player);
+        let result = f();
+        self.current_player.set(previous);
+        result
+    }
 }
 
 #[profiling::function]
-fn hit_test(_dom: &Dom, layout: &LayoutDom, coords: Vec2, output: &mut Vec<WidgetId>) {
-    for (id, _interest) in layout.interest_mouse.iter() {
+fn hit_test(layout: &LayoutDom, coords: Vec2, output: &mut Vec<WidgetId>) {
+    'widgets: for (id, _interest) in layout.interest_mouse.iter() {
         let Some(layout_node) = layout.get(id) else {
             continue;
         };
 
-        let mut rect = layout_node.rect;
+        // Widgets are tested where they were painted, which can be different
+        // from where they were laid out if an ancestor has a transform.
+        let local = layout.untransform_point(id, coords);
+        if !layout_node.hit_rect().contains_point(local) {
+            continue;
+        }
+
         let mut node = layout_node;
         while let Some(parent) = node.clipped_by {
             node = layout.get(parent).unwrap();
-            rect = rect.constrain(node.rect);
-        }
 
-        if rect.contains_point(coords) {
-            output.push(id);
+            let local = layout.untransform_point(parent, coords);
+            if !node.rect.contains_point(local) {
+                continue 'widgets;
+            }
         }
+
+        output.push(id);
     }
 }
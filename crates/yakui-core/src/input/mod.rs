@@ -1,14 +1,26 @@
 //! Defines how yakui responds to input and delegates it to widgets.
 
+mod clipboard;
+mod cursor;
 mod input_state;
 mod mouse;
 mod mouse_interest;
 mod navigation;
+mod player;
+mod shortcut;
+mod touch;
+mod viewports;
 
 pub(crate) use self::mouse_interest::*;
 
+pub use self::clipboard::*;
+pub use self::cursor::*;
 pub use self::input_state::*;
 pub use self::mouse::*;
 pub use self::navigation::*;
+pub use self::player::*;
+pub use self::shortcut::*;
+pub use self::touch::*;
+pub use self::viewports::*;
 
-pub use keyboard_types::{Code as KeyCode, Modifiers};
+pub use keyboard_types::{Code as KeyCode, Key as LogicalKey, Location as KeyLocation, Modifiers};
@@ -10,4 +10,27 @@ pub enum MouseButton {
 
     /// The user's third mouse button. This is usually the middle mouse button.
     Three,
+
+    /// The side button that browsers use to go back a page.
+    Back,
+
+    /// The side button that browsers use to go forward a page.
+    Forward,
+
+    /// Any other mouse button, identified by a number given by the host. Mice
+    /// with many buttons, like ones made for games, number them differently,
+    /// so these are mostly useful for letting users bind them to actions.
+    Other(u16),
+}
+
+/// The unit that a scroll delta is measured in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollUnit {
+    /// The delta counts lines of text, which is what most mouse wheels report.
+    /// Widgets decide how far a line is.
+    Lines,
+
+    /// The delta is in pixels, which is what trackpads and other precise
+    /// devices report.
+    Pixels,
 }
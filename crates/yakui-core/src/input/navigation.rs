@@ -1,3 +1,12 @@
+use glam::Vec2;
+
+use crate::dom::Dom;
+use crate::event::EventInterest;
+use crate::id::WidgetId;
+use crate::layout::LayoutDom;
+
+use super::player::PlayerId;
+
 /// Possible directions that a user can navigate in when using a gamepad or
 /// keyboard in a UI.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -14,3 +23,165 @@ pub enum NavDirection {
     /// The previous widget in the layout, used if the user presses shift+tab.
     Previous,
 }
+
+/// Something the user can do to the selected widget with a gamepad or
+/// keyboard, other than moving the selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavAction {
+    /// Activate the selected widget, like clicking a button. Usually the A
+    /// button on a gamepad.
+    Accept,
+
+    /// Back out of whatever the selected widget is part of, like closing a
+    /// menu. Usually the B button on a gamepad.
+    Cancel,
+}
+
+/// Where a focusable widget falls in the order that Tab moves the selection
+/// through, returned by [`Widget::tab_index`][crate::widget::Widget::tab_index].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TabIndex {
+    /// The widget is reached in the order it appears in the DOM, after every
+    /// widget with an explicit order.
+    #[default]
+    Auto,
+
+    /// The widget is reached before any widgets using [`TabIndex::Auto`], in
+    /// ascending order. Widgets with the same order are reached in the order
+    /// they appear in the DOM.
+    Order(u32),
+
+    /// The widget is skipped by Tab, but can still be selected by clicking on
+    /// it or navigating in a direction.
+    Skip,
+}
+
+/// Returns every widget inside of `root` that can be navigated to, in the
+/// order they appear in the DOM. Widgets that belong to a different player
+/// than `player` are skipped, as are widgets with no area.
+pub(crate) fn focusable_widgets(
+    dom: &Dom,
+    layout: &LayoutDom,
+    root: WidgetId,
+    player: Option<PlayerId>,
+) -> Vec<WidgetId> {
+    let mut output = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(id) = stack.pop() {
+        let Some(node) = dom.get(id) else {
+            continue;
+        };
+
+        stack.extend(node.children.iter().rev());
+
+        let Some(layout_node) = layout.get(id) else {
+            continue;
+        };
+
+        let owned_by_other = matches!(
+            (player, layout_node.player),
+            (Some(player), Some(owner)) if owner != player
+        );
+
+        if layout_node.event_interest.contains(EventInterest::FOCUS)
+            && !owned_by_other
+            && layout_node.rect.size().cmpgt(Vec2::ZERO).all()
+        {
+            output.push(id);
+        }
+    }
+
+    output
+}
+
+/// Sorts focusable widgets into the order Tab moves through them, leaving out
+/// widgets that opted out of it.
+pub(crate) fn tab_order(dom: &Dom, candidates: &[WidgetId]) -> Vec<WidgetId> {
+    let mut ordered: Vec<(u32, WidgetId)> = candidates
+        .iter()
+        .filter_map(|&id| {
+            let node = dom.get(id)?;
+            match node.widget.tab_index() {
+                TabIndex::Order(order) => Some((order, id)),
+                TabIndex::Auto => Some((u32::MAX, id)),
+                TabIndex::Skip => None,
+            }
+        })
+        .collect();
+
+    // The sort is stable, so widgets with the same order stay in DOM order.
+    ordered.sort_by_key(|&(order, _)| order);
+    ordered.into_iter().map(|(_, id)| id).collect()
+}
+
+/// Finds the focusable widget closest to `from` in the given direction.
+///
+/// Candidates have to be further along the direction than `from`'s center.
+/// Of those, the closest one wins, with distance across the direction counting
+/// double so that widgets lined up with `from` are preferred over ones that
+/// are slightly nearer but off to the side.
+pub(crate) fn find_in_direction(
+    layout: &LayoutDom,
+    candidates: &[WidgetId],
+    from: WidgetId,
+    dir: NavDirection,
+) -> Option<WidgetId> {
+    let axis = match dir {
+        NavDirection::Down => Vec2::Y,
+        NavDirection::Up => Vec2::NEG_Y,
+        NavDirection::Right => Vec2::X,
+        NavDirection::Left => Vec2::NEG_X,
+        NavDirection::Next | NavDirection::Previous => return None,
+    };
+
+    let origin = center(layout, from)?;
+
+    candidates
+        .iter()
+        .filter(|&&id| id != from)
+        .filter_map(|&id| {
+            let offset = center(layout, id)? - origin;
+            let along = offset.dot(axis);
+            let across = offset.dot(axis.perp()).abs();
+
+            (along > 0.0).then_some((id, along + across * 2.0))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(id, _)| id)
+}
+
+/// Picks a widget to start navigating from when nothing is selected yet: the
+/// one closest to the top left.
+pub(crate) fn first_widget(layout: &LayoutDom, candidates: &[WidgetId]) -> Option<WidgetId> {
+    candidates.iter().copied().min_by(|&a, &b| {
+        let a = layout
+            .get(a)
+            .map(|node| node.rect.pos())
+            .unwrap_or_default();
+        let b = layout
+            .get(b)
+            .map(|node| node.rect.pos())
+            .unwrap_or_default();
+        a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))
+    })
+}
+
+/// Tells whether `id` is `ancestor` or one of its descendants.
+pub(crate) fn is_descendant(dom: &Dom, id: WidgetId, ancestor: WidgetId) -> bool {
+    let mut next = Some(id);
+    while let Some(id) = next {
+        if id == ancestor {
+            return true;
+        }
+
+        next = dom.get(id).and_then(|node| node.parent);
+    }
+
+    false
+}
+
+fn center(layout: &LayoutDom, id: WidgetId) -> Option<Vec2> {
+    let rect = layout.get(id)?.rect;
+    Some(rect.pos() + rect.size() / 2.0)
+}
@@ -0,0 +1,9 @@
+/// Identifies one of several players sharing the same UI, like in a
+/// split-screen game where each player has their own gamepad.
+///
+/// Events can be tagged with the player that sent them using
+/// [`Yakui::handle_player_event`][crate::Yakui::handle_player_event], and
+/// parts of the UI can be limited to a single player with
+/// [`LayoutDom::set_player`][crate::layout::LayoutDom::set_player].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PlayerId(pub u32);
@@ -0,0 +1,201 @@
+use std::fmt;
+
+use crate::event::WidgetEvent;
+
+use super::{KeyCode, LogicalKey, Modifiers};
+
+/// The modifiers that take part in shortcuts. Lock keys like caps lock are
+/// ignored when matching.
+const SHORTCUT_MODIFIERS: Modifiers = Modifiers::SHIFT
+    .union(Modifiers::CONTROL)
+    .union(Modifiers::ALT)
+    .union(Modifiers::META);
+
+/// Returns the modifier that most shortcuts use on this platform: Command on
+/// macOS and Ctrl everywhere else.
+pub fn primary_modifier() -> Modifiers {
+    if cfg!(target_os = "macos") {
+        Modifiers::META
+    } else {
+        Modifiers::CONTROL
+    }
+}
+
+/// Extra queries on [`Modifiers`] for platform-dependent modifier keys.
+pub trait ModifiersExt {
+    /// Tells whether the platform's primary modifier is held. See
+    /// [`primary_modifier`].
+    fn primary(&self) -> bool;
+}
+
+impl ModifiersExt for Modifiers {
+    fn primary(&self) -> bool {
+        self.contains(primary_modifier())
+    }
+}
+
+/// A key combination, like Ctrl+C, that triggers an action.
+///
+/// Shortcuts match on the logical key so that they follow the user's keyboard
+/// layout, and the modifiers held must match exactly. On layouts that don't
+/// type Latin letters, like Cyrillic, shortcuts for letters fall back to the
+/// physical key, the way most applications behave. Use
+/// [`Shortcut::primary`] for shortcuts that should use Command on macOS and
+/// Ctrl on other platforms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    /// The modifiers that must be held.
+    pub modifiers: Modifiers,
+
+    /// The key that triggers the shortcut.
+    pub key: LogicalKey,
+}
+
+impl Shortcut {
+    /// Create a shortcut from a set of modifiers and a key.
+    pub fn new(modifiers: Modifiers, key: LogicalKey) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Create a shortcut that uses the platform's primary modifier with the
+    /// given character, like Ctrl+C on Windows and Command+C on macOS.
+    pub fn primary(key: &str) -> Self {
+        Self::new(primary_modifier(), LogicalKey::Character(key.to_owned()))
+    }
+
+    /// Also require shift to be held.
+    pub fn shift(mut self) -> Self {
+        self.modifiers |= Modifiers::SHIFT;
+        self
+    }
+
+    /// Also require alt, or Option on macOS, to be held.
+    pub fn alt(mut self) -> Self {
+        self.modifiers |= Modifiers::ALT;
+        self
+    }
+
+    /// Tells whether pressing the given key with the given modifiers triggers
+    /// this shortcut. Letters match regardless of case, since holding shift
+    /// changes the character a key types.
+    pub fn matches(&self, key: &LogicalKey, modifiers: Modifiers) -> bool {
+        if modifiers & SHORTCUT_MODIFIERS != self.modifiers {
+            return false;
+        }
+
+        match (&self.key, key) {
+            (LogicalKey::Character(expected), LogicalKey::Character(actual)) => {
+                expected.to_lowercase() == actual.to_lowercase()
+            }
+            (expected, actual) => expected == actual,
+        }
+    }
+
+    /// Returns a label for each key that has to be pressed, modifiers first,
+    /// named the way the platform usually names them. On macOS, modifiers are
+    /// shown as symbols like `⌘`.
+    pub fn key_labels(&self) -> Vec<String> {
+        let modifiers = if cfg!(target_os = "macos") {
+            [
+                (Modifiers::CONTROL, "⌃"),
+                (Modifiers::ALT, "⌥"),
+                (Modifiers::SHIFT, "⇧"),
+                (Modifiers::META, "⌘"),
+            ]
+        } else {
+            [
+                (Modifiers::CONTROL, "Ctrl"),
+                (Modifiers::ALT, "Alt"),
+                (Modifiers::SHIFT, "Shift"),
+                (Modifiers::META, "Super"),
+            ]
+        };
+
+        let mut labels: Vec<String> = modifiers
+            .into_iter()
+            .filter(|&(modifier, _)| self.modifiers.contains(modifier))
+            .map(|(_, label)| label.to_owned())
+            .collect();
+
+        labels.push(key_label(&self.key));
+        labels
+    }
+
+    /// Like [`Shortcut::matches`], but if the key typed a character that isn't
+    /// ASCII, the physical key is matched against letters instead. Ctrl+C on a
+    /// Russian layout types `с`, but is still the key that types `c` on a
+    /// QWERTY layout.
+    pub fn matches_key(
+        &self,
+        key: KeyCode,
+        logical_key: &LogicalKey,
+        modifiers: Modifiers,
+    ) -> bool {
+        if self.matches(logical_key, modifiers) {
+            return true;
+        }
+
+        let LogicalKey::Character(typed) = logical_key else {
+            return false;
+        };
+
+        if typed.is_ascii() {
+            return false;
+        }
+
+        // Letter keys are named like `KeyC`.
+        let name = key.to_string();
+        match name.strip_prefix("Key") {
+            Some(letter) if letter.len() == 1 => {
+                self.matches(&LogicalKey::Character(letter.to_owned()), modifiers)
+            }
+            _ => false,
+        }
+    }
+
+    /// Tells whether the event is a key press that triggers this shortcut.
+    pub fn matches_event(&self, event: &WidgetEvent) -> bool {
+        match event {
+            WidgetEvent::KeyChanged {
+                key,
+                logical_key,
+                down: true,
+                modifiers,
+                ..
+            } => self.matches_key(*key, logical_key, *modifiers),
+            _ => false,
+        }
+    }
+}
+
+/// Formats the shortcut the way the platform usually shows it, like `Ctrl+C`
+/// on Windows or `⌘C` on macOS.
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let labels = self.key_labels();
+
+        if cfg!(target_os = "macos") {
+            f.write_str(&labels.concat())
+        } else {
+            f.write_str(&labels.join("+"))
+        }
+    }
+}
+
+fn key_label(key: &LogicalKey) -> String {
+    let label = match key {
+        LogicalKey::Character(c) if c == " " => "Space",
+        LogicalKey::Character(c) => return c.to_uppercase(),
+        LogicalKey::ArrowUp => "↑",
+        LogicalKey::ArrowDown => "↓",
+        LogicalKey::ArrowLeft => "←",
+        LogicalKey::ArrowRight => "→",
+        LogicalKey::Escape => "Esc",
+        LogicalKey::Delete => "Del",
+        LogicalKey::PageUp => "PgUp",
+        LogicalKey::PageDown => "PgDn",
+        other => return other.to_string(),
+    };
+
+    label.to_owned()
+}
@@ -0,0 +1,201 @@
+use std::time::Duration;
+
+use glam::Vec2;
+use smallvec::SmallVec;
+
+use crate::event::WidgetEvent;
+
+/// Which part of its life a touch is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TouchPhase {
+    /// A finger touched the screen.
+    Started,
+
+    /// A finger moved while touching the screen.
+    Moved,
+
+    /// A finger was lifted from the screen.
+    Ended,
+
+    /// The system took over the touch, like for an edge swipe. Widgets should
+    /// undo anything the touch started instead of treating it like a release.
+    Cancelled,
+}
+
+/// A gesture recognized by [`Gestures`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// A single finger touched and was lifted quickly without moving.
+    Tap {
+        /// Where the finger was lifted, in logical pixels relative to the
+        /// viewport.
+        position: Vec2,
+    },
+
+    /// A single finger was held down without moving for a while. Sent while
+    /// the finger is still down.
+    LongPress {
+        /// Where the finger is, in logical pixels relative to the viewport.
+        position: Vec2,
+    },
+
+    /// Two fingers moved closer together or further apart.
+    Pinch {
+        /// The point halfway between the fingers, in logical pixels relative
+        /// to the viewport.
+        center: Vec2,
+
+        /// The distance between the fingers compared to when the second finger
+        /// touched down. Less than 1 when pinching in.
+        scale: f32,
+    },
+}
+
+/// How long a finger has to be held still to count as a long press.
+const LONG_PRESS_TIME: Duration = Duration::from_millis(500);
+
+/// How far a finger can move and still count as a tap or long press, in
+/// logical pixels. Fingers are less precise than mice, so this is much more
+/// forgiving than double click detection.
+const TOUCH_SLOP: f32 = 10.0;
+
+/// Recognizes taps, long presses and pinches from the touch events sent to a
+/// widget with [`EventInterest::TOUCH`][crate::event::EventInterest::TOUCH].
+///
+/// Widgets keep one of these in their state and pass it every event they
+/// receive. Long presses can happen without any new events, so
+/// [`Gestures::poll`] should also be called every frame while a finger is
+/// down, which [`Gestures::is_active`] tells.
+///
+/// Gestures are timed using [`Yakui::set_time`][crate::Yakui::set_time], so
+/// long presses are never recognized if the host doesn't call it.
+#[derive(Debug, Default)]
+pub struct Gestures {
+    touches: SmallVec<[Touch; 2]>,
+    start_time: Duration,
+    start_distance: Option<f32>,
+    moved: bool,
+    multi_touch: bool,
+    long_pressed: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Touch {
+    id: u64,
+    start: Vec2,
+    position: Vec2,
+}
+
+impl Gestures {
+    /// Create a recognizer with no touches in progress.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether any finger that touched down on the widget is still down.
+    pub fn is_active(&self) -> bool {
+        !self.touches.is_empty()
+    }
+
+    /// Updates the gesture with an event sent to the widget, returning a
+    /// gesture if one was recognized. Events other than
+    /// [`WidgetEvent::Touch`] are ignored.
+    pub fn handle(&mut self, event: &WidgetEvent, now: Duration) -> Option<Gesture> {
+        let &WidgetEvent::Touch {
+            id,
+            phase,
+            position,
+            ..
+        } = event
+        else {
+            return None;
+        };
+
+        match phase {
+            TouchPhase::Started => {
+                if self.touches.is_empty() {
+                    self.start_time = now;
+                    self.moved = false;
+                    self.multi_touch = false;
+                    self.long_pressed = false;
+                }
+
+                self.touches.push(Touch {
+                    id,
+                    start: position,
+                    position,
+                });
+
+                if self.touches.len() > 1 {
+                    self.multi_touch = true;
+                }
+
+                self.start_distance = self.pinch_distance();
+                None
+            }
+
+            TouchPhase::Moved => {
+                let touch = self.touches.iter_mut().find(|touch| touch.id == id)?;
+                touch.position = position;
+
+                if touch.start.distance(position) > TOUCH_SLOP {
+                    self.moved = true;
+                }
+
+                let start = self.start_distance?;
+                let distance = self.pinch_distance()?;
+
+                Some(Gesture::Pinch {
+                    center: (self.touches[0].position + self.touches[1].position) / 2.0,
+                    scale: distance / start,
+                })
+            }
+
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                let index = self.touches.iter().position(|touch| touch.id == id)?;
+                self.touches.remove(index);
+
+                // Pinches restart from the remaining fingers so that the scale
+                // doesn't jump when a finger is lifted.
+                self.start_distance = self.pinch_distance();
+
+                let tap = phase == TouchPhase::Ended
+                    && self.touches.is_empty()
+                    && !self.moved
+                    && !self.multi_touch
+                    && !self.long_pressed
+                    && now.saturating_sub(self.start_time) < LONG_PRESS_TIME;
+
+                tap.then_some(Gesture::Tap { position })
+            }
+        }
+    }
+
+    /// Checks whether the finger that's down has been held long enough to be
+    /// a long press. Only returns a gesture once per touch.
+    pub fn poll(&mut self, now: Duration) -> Option<Gesture> {
+        let [touch] = self.touches[..] else {
+            return None;
+        };
+
+        if self.moved
+            || self.multi_touch
+            || self.long_pressed
+            || now.saturating_sub(self.start_time) < LONG_PRESS_TIME
+        {
+            return None;
+        }
+
+        self.long_pressed = true;
+        Some(Gesture::LongPress {
+            position: touch.position,
+        })
+    }
+
+    fn pinch_distance(&self) -> Option<f32> {
+        match &self.touches[..] {
+            [a, b, ..] => Some(a.position.distance(b.position).max(f32::EPSILON)),
+            _ => None,
+        }
+    }
+}
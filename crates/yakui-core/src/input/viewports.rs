@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use crate::event::Event;
+use crate::input::TouchPhase;
+use crate::Yakui;
+
+/// Sends events from one window to several yakui states, each drawn into its
+/// own viewport of the window's surface, like the HUDs of a split-screen game.
+///
+/// Each state's viewport is set with [`Yakui::set_unscaled_viewport`] and its
+/// scale with [`Yakui::set_scale_factor`], while all of them share the size of
+/// the surface given to [`Yakui::set_surface_size`].
+///
+/// Mouse events go to the state whose viewport is under the cursor, or to the
+/// state that a button was pressed in until every button is released again.
+/// Each touch goes to the state whose viewport it started in. Keyboard events
+/// go to the focused state, which is the last one that was clicked or touched. Modifier changes go to every state so they all agree on which
+/// modifiers are held.
+///
+/// When several players share one state instead, their parts of the UI can be
+/// told apart with [`PlayerId`][crate::input::PlayerId] instead.
+#[derive(Debug, Default)]
+pub struct ViewportRouter {
+    hovered: Option<usize>,
+    focused: usize,
+    captured: Option<usize>,
+    buttons_down: usize,
+    touches: HashMap<u64, usize>,
+}
+
+impl ViewportRouter {
+    /// Create a router with the first state focused.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The index of the state that keyboard events are sent to.
+    pub fn focused(&self) -> usize {
+        self.focused
+    }
+
+    /// Change which state keyboard events are sent to, like when a game
+    /// switches which player is using the keyboard.
+    pub fn set_focused(&mut self, index: usize) {
+        self.focused = index;
+    }
+
+    /// The index of the state whose viewport the cursor is over, if any.
+    pub fn hovered(&self) -> Option<usize> {
+        self.hovered
+    }
+
+    /// Sends an event to the right state. Returns `true` if the event was sunk
+    /// by yakui and should not be processed by the application.
+    ///
+    /// [`Event::ViewportChanged`] is ignored, since each state has its own
+    /// viewport.
+    pub fn handle_event(&mut self, states: &mut [Yakui], event: Event) -> bool {
+        match event {
+            Event::ViewportChanged(_) => false,
+
+            Event::CursorMoved(Some(pos)) => {
+                let target = self.captured.or_else(|| {
+                    states.iter().position(|state| {
+                        state.layout_dom().unscaled_viewport().contains_point(pos)
+                    })
+                });
+
+                self.set_hovered(states, target);
+
+                match target.and_then(|index| states.get_mut(index)) {
+                    Some(state) => state.handle_event(Event::CursorMoved(Some(pos))),
+                    None => false,
+                }
+            }
+
+            Event::CursorMoved(None) => {
+                self.set_hovered(states, None);
+                false
+            }
+
+            Event::MouseButtonChanged { button, down } => {
+                let target = if down {
+                    self.captured.or(self.hovered)
+                } else {
+                    self.captured
+                };
+
+                if down {
+                    self.buttons_down += 1;
+
+                    if self.captured.is_none() {
+                        self.captured = target;
+
+                        if let Some(index) = target {
+                            self.focused = index;
+                        }
+                    }
+                } else {
+                    self.buttons_down = self.buttons_down.saturating_sub(1);
+
+                    if self.buttons_down == 0 {
+                        self.captured = None;
+                    }
+                }
+
+                match target.and_then(|index| states.get_mut(index)) {
+                    Some(state) => state.handle_event(Event::MouseButtonChanged { button, down }),
+                    None => false,
+                }
+            }
+
+            Event::Touch { id, phase, pos } => {
+                let target = match phase {
+                    TouchPhase::Started => {
+                        let target = states.iter().position(|state| {
+                            state.layout_dom().unscaled_viewport().contains_point(pos)
+                        });
+
+                        if let Some(index) = target {
+                            self.touches.insert(id, index);
+                            self.focused = index;
+                        }
+
+                        target
+                    }
+                    TouchPhase::Moved => self.touches.get(&id).copied(),
+                    TouchPhase::Ended | TouchPhase::Cancelled => self.touches.remove(&id),
+                };
+
+                match target.and_then(|index| states.get_mut(index)) {
+                    Some(state) => state.handle_event(Event::Touch { id, phase, pos }),
+                    None => false,
+                }
+            }
+
+            Event::MouseScroll { delta, unit } => {
+                match self.hovered.and_then(|i| states.get_mut(i)) {
+                    Some(state) => state.handle_event(Event::MouseScroll { delta, unit }),
+                    None => false,
+                }
+            }
+
+            Event::ModifiersChanged(modifiers) => {
+                let mut sunk = false;
+                for state in states.iter_mut() {
+                    sunk |= state.handle_event(Event::ModifiersChanged(modifiers));
+                }
+                sunk
+            }
+
+            event => match states.get_mut(self.focused) {
+                Some(state) => state.handle_event(event),
+                None => false,
+            },
+        }
+    }
+
+    /// Tells the previously hovered state that the cursor left it when the
+    /// cursor moves into another viewport.
+    fn set_hovered(&mut self, states: &mut [Yakui], hovered: Option<usize>) {
+        if self.hovered != hovered {
+            if let Some(state) = self.hovered.and_then(|index| states.get_mut(index)) {
+                state.handle_event(Event::CursorMoved(None));
+            }
+        }
+
+        self.hovered = hovered;
+    }
+}
@@ -0,0 +1,115 @@
+//! Walks the DOM along with its layout, for debugging tools and tests that
+//! need to see exactly what yakui built in a frame.
+//!
+//! Unlike a [`DomSnapshot`][crate::snapshot::DomSnapshot], an inspection keeps
+//! everything yakui knows about each widget's layout, like clipping and event
+//! interest, and isn't meant to be saved or shared. Its [`Debug`] output is a
+//! readable dump of the whole tree.
+
+use crate::dom::Dom;
+use crate::geometry::Rect;
+use crate::id::WidgetId;
+use crate::layout::{LayoutDom, LayoutDomNode};
+use crate::snapshot::short_type_name;
+
+/// The DOM and its layout at one point in time.
+///
+/// Returned by [`Yakui::inspect`][crate::Yakui::inspect].
+#[derive(Debug, Clone)]
+pub struct Inspection {
+    /// The viewport the DOM was laid out in, in logical pixels.
+    pub viewport: Rect,
+
+    /// The scale factor the DOM was laid out with.
+    pub scale_factor: f32,
+
+    /// The root of the DOM.
+    pub root: InspectedNode,
+}
+
+/// A widget recorded in an [`Inspection`].
+#[derive(Debug, Clone)]
+pub struct InspectedNode {
+    /// The ID of the widget, which can be used with [`Dom::get`] and
+    /// [`LayoutDom::get`] while the widget is still in the tree.
+    pub id: WidgetId,
+
+    /// The full type name of the widget, like
+    /// `yakui_widgets::widgets::list::ListWidget`.
+    pub type_name: &'static str,
+
+    /// How the widget was laid out, including its rect, clipping and event
+    /// interest. This is `None` for widgets that had no layout, like ones
+    /// that were culled.
+    pub layout: Option<LayoutDomNode>,
+
+    /// The widget's children, in order.
+    pub children: Vec<InspectedNode>,
+}
+
+impl Inspection {
+    /// Records the current structure of the DOM, along with the most recently
+    /// calculated layout.
+    pub fn capture(dom: &Dom, layout: &LayoutDom) -> Self {
+        Self {
+            viewport: layout.viewport(),
+            scale_factor: layout.scale_factor(),
+            root: InspectedNode::capture(dom, layout, dom.root()),
+        }
+    }
+
+    /// Visits every widget in the tree, parents before their children.
+    pub fn iter(&self) -> impl Iterator<Item = &InspectedNode> {
+        self.root.iter()
+    }
+
+    /// Finds the widget with the given ID, if it's in the tree.
+    pub fn get(&self, id: WidgetId) -> Option<&InspectedNode> {
+        self.iter().find(|node| node.id == id)
+    }
+
+    /// Finds every widget whose type name, without its module path, is
+    /// `name`, like `ButtonWidget`.
+    pub fn find_by_name<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a InspectedNode> {
+        self.iter().filter(move |node| node.short_name() == name)
+    }
+}
+
+impl InspectedNode {
+    fn capture(dom: &Dom, layout: &LayoutDom, id: WidgetId) -> Self {
+        let node = dom.get(id).unwrap();
+
+        Self {
+            id,
+            type_name: node.widget.type_name(),
+            layout: layout.get(id).cloned(),
+            children: node
+                .children
+                .iter()
+                .map(|&child| Self::capture(dom, layout, child))
+                .collect(),
+        }
+    }
+
+    /// A shorter version of the type name without its module path.
+    pub fn short_name(&self) -> String {
+        short_type_name(self.type_name)
+    }
+
+    /// Where the widget was laid out, in logical pixels.
+    pub fn rect(&self) -> Option<Rect> {
+        self.layout.as_ref().map(|layout| layout.rect)
+    }
+
+    /// Visits this widget and all of its descendants, parents before their
+    /// children.
+    pub fn iter(&self) -> impl Iterator<Item = &InspectedNode> {
+        let mut stack = vec![self];
+
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+}
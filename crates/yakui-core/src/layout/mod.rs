@@ -1,15 +1,16 @@
 //! Defines yakui's layout protocol and Layout DOM.
 
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 
-use glam::Vec2;
+use glam::{Affine2, Vec2};
 use thunderdome::Arena;
 
 use crate::dom::Dom;
 use crate::event::EventInterest;
 use crate::geometry::{Constraints, Rect};
 use crate::id::WidgetId;
-use crate::input::{InputState, MouseInterest};
+use crate::input::{InputState, MouseInterest, PlayerId};
+use crate::types::LayoutDirection;
 use crate::widget::LayoutContext;
 
 /// Contains information on how each widget in the DOM is laid out and what
@@ -18,19 +19,37 @@ use crate::widget::LayoutContext;
 pub struct LayoutDom {
     nodes: Arena<LayoutDomNode>,
     clip_stack: Vec<WidgetId>,
+    transform_stack: Vec<(WidgetId, Affine2)>,
+    baseline_stack: Vec<(WidgetId, f32)>,
+    player_stack: Vec<(WidgetId, PlayerId)>,
+
+    /// Widgets that called [`LayoutDom::detach`] and are still being laid out,
+    /// along with the clip stack of their ancestors to restore afterwards.
+    detach_stack: Vec<(WidgetId, Vec<WidgetId>)>,
+    has_transforms: bool,
+
+    /// Widgets whose entire subtree reported that it doesn't need to be laid
+    /// out again this frame.
+    clean: HashSet<WidgetId>,
+    last_scale_factor: f32,
+    last_layout_direction: LayoutDirection,
 
     unscaled_viewport: Rect,
     scale_factor: f32,
+    layout_direction: LayoutDirection,
 
     pub(crate) interest_mouse: MouseInterest,
 }
 
 /// A node in a [`LayoutDom`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LayoutDomNode {
     /// The bounding rectangle of the node in logical pixels.
     pub rect: Rect,
 
+    /// The constraints the node was laid out with.
+    pub constraints: Constraints,
+
     /// This node will clip its descendants to its bounding rectangle.
     pub clipping_enabled: bool,
 
@@ -41,8 +60,54 @@ pub struct LayoutDomNode {
     /// This node is clipped to the region defined by the given node.
     pub clipped_by: Option<WidgetId>,
 
+    /// This node is positioned at the top-left corner of the viewport instead
+    /// of relative to its parent, and isn't clipped or transformed by its
+    /// ancestors. Set with [`LayoutDom::detach`].
+    pub detached: bool,
+
     /// What events the widget reported interest in.
     pub event_interest: EventInterest,
+
+    /// The area the mouse has to be inside of to interact with the node,
+    /// relative to the top-left corner of `rect`. If `None`, `rect` is used.
+    pub interact_rect: Option<Rect>,
+
+    /// A transform applied to how this node's descendants are painted,
+    /// relative to the top-left corner of `rect`. Descendants are hit tested
+    /// where they're painted.
+    pub transform: Option<Affine2>,
+
+    /// The closest ancestor of this node that has a transform.
+    pub transformed_by: Option<WidgetId>,
+
+    /// The distance from the top of `rect` to the baseline of the first line
+    /// of text inside of the node, if it has any text.
+    pub baseline: Option<f32>,
+
+    /// The only player that this node accepts input from, as set by this
+    /// node or its closest ancestor that called [`LayoutDom::set_player`]. If
+    /// `None`, the node accepts input from everyone.
+    pub player: Option<PlayerId>,
+}
+
+impl LayoutDomNode {
+    /// The area the mouse has to be inside of to interact with the node, in
+    /// logical pixels.
+    pub fn hit_rect(&self) -> Rect {
+        match self.interact_rect {
+            Some(local) => Rect::from_pos_size(self.rect.pos() + local.pos(), local.size()),
+            None => self.rect,
+        }
+    }
+
+    /// The transform applied to this node's descendants, in logical pixels
+    /// relative to the viewport.
+    pub fn world_transform(&self) -> Option<Affine2> {
+        let pos = self.rect.pos();
+        self.transform.map(|transform| {
+            Affine2::from_translation(pos) * transform * Affine2::from_translation(-pos)
+        })
+    }
 }
 
 impl LayoutDom {
@@ -51,9 +116,19 @@ impl LayoutDom {
         Self {
             nodes: Arena::new(),
             clip_stack: Vec::new(),
+            transform_stack: Vec::new(),
+            baseline_stack: Vec::new(),
+            player_stack: Vec::new(),
+            detach_stack: Vec::new(),
+            has_transforms: false,
+
+            clean: HashSet::new(),
+            last_scale_factor: 1.0,
+            last_layout_direction: LayoutDirection::LeftToRight,
 
             unscaled_viewport: Rect::ONE,
             scale_factor: 1.0,
+            layout_direction: LayoutDirection::LeftToRight,
 
             interest_mouse: MouseInterest::new(),
         }
@@ -90,6 +165,16 @@ impl LayoutDom {
         self.scale_factor
     }
 
+    /// Set the reading direction that widgets should lay themselves out with.
+    pub fn set_layout_direction(&mut self, direction: LayoutDirection) {
+        self.layout_direction = direction;
+    }
+
+    /// Get the reading direction that widgets should lay themselves out with.
+    pub fn layout_direction(&self) -> LayoutDirection {
+        self.layout_direction
+    }
+
     /// Get the viewport in scaled units.
     pub fn viewport(&self) -> Rect {
         Rect::from_pos_size(
@@ -119,8 +204,25 @@ impl LayoutDom {
         log::debug!("LayoutDom::calculate_all()");
 
         self.clip_stack.clear();
+        self.transform_stack.clear();
+        self.baseline_stack.clear();
+        self.player_stack.clear();
+        self.detach_stack.clear();
+        self.has_transforms = false;
         self.interest_mouse.clear();
 
+        // Layouts can only be reused if nothing was added or removed, and
+        // nothing changed that affects every widget at once.
+        self.clean.clear();
+        if !dom.structure_changed()
+            && self.scale_factor == self.last_scale_factor
+            && self.layout_direction == self.last_layout_direction
+        {
+            self.find_clean(dom, dom.root());
+        }
+        self.last_scale_factor = self.scale_factor;
+        self.last_layout_direction = self.layout_direction;
+
         let constraints = Constraints::tight(self.viewport().size());
 
         self.calculate(dom, input, dom.root(), constraints);
@@ -139,6 +241,16 @@ impl LayoutDom {
         id: WidgetId,
         constraints: Constraints,
     ) -> Vec2 {
+        if self.clean.contains(&id) {
+            if let Some(node) = self.nodes.get(id.index()) {
+                if node.constraints == constraints {
+                    let size = node.rect.size();
+                    self.reuse(dom, id, None);
+                    return size;
+                }
+            }
+        }
+
         dom.enter(id);
         let dom_node = dom.get(id).unwrap();
 
@@ -154,10 +266,10 @@ impl LayoutDom {
         // the mouse interest layer stack.
         let new_layer = self.interest_mouse.current_layer_root() == Some(id);
 
-        // Mouse interest will be registered into the layout created by the
-        // widget if there is one.
+        // Mouse and touch interest will be registered into the layout created
+        // by the widget if there is one.
         let event_interest = dom_node.widget.event_interest();
-        if event_interest.intersects(EventInterest::MOUSE_ALL) {
+        if event_interest.intersects(EventInterest::MOUSE_ALL | EventInterest::TOUCH) {
             self.interest_mouse.insert(id, event_interest);
         }
 
@@ -171,29 +283,79 @@ impl LayoutDom {
         // top of the clip stack at this point.
         let clipping_enabled = self.clip_stack.last() == Some(&id);
 
+        // If the widget called detach() during layout, its descendants were
+        // laid out without its ancestors' clipping, which can come back now.
+        let outer_clip_stack = match self.detach_stack.last() {
+            Some(&(top, _)) if top == id => self.detach_stack.pop().map(|(_, stack)| stack),
+            _ => None,
+        };
+        let detached = outer_clip_stack.is_some();
+
+        // Likewise, a transform set with set_transform() will be on top of the
+        // transform stack.
+        let transform = match self.transform_stack.last() {
+            Some(&(top, transform)) if top == id => {
+                self.transform_stack.pop();
+                Some(transform)
+            }
+            _ => None,
+        };
+
+        // Widgets that don't report their own baseline take the baseline of
+        // their first child that has one.
+        let baseline = match self.baseline_stack.last() {
+            Some(&(top, baseline)) if top == id => {
+                self.baseline_stack.pop();
+                Some(baseline)
+            }
+            _ => dom_node.children.iter().find_map(|&child| {
+                let child = self.nodes.get(child.index())?;
+                Some(child.rect.pos().y + child.baseline?)
+            }),
+        };
+
         // If this node enabled clipping, the next node under that is the node
         // that clips this one.
-        let clipped_by = if clipping_enabled {
+        let clipped_by = if detached {
+            None
+        } else if clipping_enabled {
             self.clip_stack.iter().nth_back(2).copied()
         } else {
             self.clip_stack.last().copied()
         };
 
+        // Players are inherited from the closest ancestor that set one, which
+        // is still on the stack while its descendants are laid out.
+        let player = self.player_stack.last().map(|&(_, player)| player);
+
         self.nodes.insert_at(
             id.index(),
             LayoutDomNode {
                 rect: Rect::from_pos_size(Vec2::ZERO, size),
+                constraints,
                 clipping_enabled,
                 new_layer,
                 clipped_by,
+                detached,
                 event_interest,
+                interact_rect: None,
+                transform,
+                transformed_by: None,
+                baseline,
+                player,
             },
         );
 
-        if clipping_enabled {
+        if let Some(clip_stack) = outer_clip_stack {
+            self.clip_stack = clip_stack;
+        } else if clipping_enabled {
             self.clip_stack.pop();
         }
 
+        if self.player_stack.last().map(|&(top, _)| top) == Some(id) {
+            self.player_stack.pop();
+        }
+
         dom.exit(id);
         size
     }
@@ -203,11 +365,105 @@ impl LayoutDom {
         self.clip_stack.push(dom.current());
     }
 
+    /// Lays out the currently active widget and its descendants as if they
+    /// were children of the root widget. The widget is placed at the top-left
+    /// corner of the viewport, and it isn't clipped or transformed by any of
+    /// its ancestors.
+    ///
+    /// This must be called before the widget lays out its children. The widget
+    /// is still responsible for painting its descendants outside of its
+    /// ancestors' clipping, usually with
+    /// [`PaintDom::begin_overlay`][crate::paint::PaintDom::begin_overlay].
+    pub fn detach(&mut self, dom: &Dom) {
+        let id = dom.current();
+        let mut outer = std::mem::take(&mut self.clip_stack);
+
+        // The widget's own clipping still applies to its descendants.
+        if outer.last() == Some(&id) {
+            self.clip_stack.extend(outer.pop());
+        }
+
+        self.detach_stack.push((id, outer));
+    }
+
+    /// Tells yakui that the currently active widget paints its descendants
+    /// with the given transform, relative to the top-left corner of the
+    /// widget. Descendants will be hit tested where they're painted.
+    ///
+    /// The widget is still responsible for applying the transform when it
+    /// paints, usually with
+    /// [`PaintDom::push_transform`][crate::paint::PaintDom::push_transform]
+    /// and [`LayoutDomNode::world_transform`].
+    pub fn set_transform(&mut self, dom: &Dom, transform: Affine2) {
+        self.transform_stack.push((dom.current(), transform));
+        self.has_transforms = true;
+    }
+
+    /// Tells yakui where the baseline of the first line of text in the
+    /// currently active widget is, measured from the top of the widget.
+    ///
+    /// Widgets that don't call this get the baseline of their first child
+    /// that has one, which is right for most containers.
+    pub fn set_baseline(&mut self, dom: &Dom, baseline: f32) {
+        self.baseline_stack.push((dom.current(), baseline));
+    }
+
+    /// Limits the currently active widget and all of its descendants to only
+    /// accepting input from the given player. Events sent with
+    /// [`Yakui::handle_player_event`][crate::Yakui::handle_player_event] for
+    /// any other player are not delivered to them.
+    ///
+    /// This must be called before the widget lays out its children.
+    pub fn set_player(&mut self, dom: &Dom, player: PlayerId) {
+        self.player_stack.push((dom.current(), player));
+    }
+
+    /// Converts a point in logical pixels relative to the viewport into the
+    /// space the given widget was laid out in, undoing any transforms applied
+    /// by its ancestors.
+    pub fn untransform_point(&self, id: WidgetId, point: Vec2) -> Vec2 {
+        if !self.has_transforms {
+            return point;
+        }
+
+        let mut transforms = Vec::new();
+        let mut current = self.get(id).and_then(|node| node.transformed_by);
+        while let Some(ancestor) = current.and_then(|id| self.get(id)) {
+            transforms.extend(ancestor.world_transform());
+            current = ancestor.transformed_by;
+        }
+
+        // Outer transforms were applied last when painting, so they need to
+        // be undone first.
+        transforms.iter().rev().fold(point, |point, transform| {
+            transform.inverse().transform_point2(point)
+        })
+    }
+
     /// Put this widget and its children into a new layer.
     pub fn new_layer(&mut self, dom: &Dom) {
         self.interest_mouse.push_layer(dom.current());
     }
 
+    /// Skip laying out a widget this frame, discarding any layout information
+    /// left over for it and its descendants from previous frames.
+    ///
+    /// Culled widgets have no layout, receive no mouse events, and must not be
+    /// painted. This can be called from [`Widget::layout`][crate::widget::Widget::layout]
+    /// instead of [`LayoutDom::calculate`] for children that are known to be
+    /// out of view.
+    pub fn cull(&mut self, dom: &Dom, id: WidgetId) {
+        if self.nodes.remove(id.index()).is_none() {
+            return;
+        }
+
+        if let Some(node) = dom.get(id) {
+            for &child in &node.children {
+                self.cull(dom, child);
+            }
+        }
+    }
+
     /// Set the position of a widget.
     pub fn set_pos(&mut self, id: WidgetId, pos: Vec2) {
         if let Some(node) = self.nodes.get_mut(id.index()) {
@@ -215,19 +471,140 @@ impl LayoutDom {
         }
     }
 
+    /// Set the area the mouse has to be inside of to interact with a widget,
+    /// relative to the top-left corner of its layout rectangle. This can be
+    /// bigger than the widget to make small widgets easier to hit, or smaller
+    /// to ignore decorations around the widget.
+    ///
+    /// Like [`LayoutDom::set_pos`], this should be called by a widget's parent
+    /// after the widget has been laid out.
+    pub fn set_interact_rect(&mut self, id: WidgetId, rect: Option<Rect>) {
+        if let Some(node) = self.nodes.get_mut(id.index()) {
+            node.interact_rect = rect;
+        }
+    }
+
+    /// Finds every widget whose subtree can keep its layout from last frame,
+    /// returning whether the given widget's can.
+    fn find_clean(&mut self, dom: &Dom, id: WidgetId) -> bool {
+        let Some(node) = dom.get(id) else {
+            return false;
+        };
+
+        let mut clean = !node.widget.needs_layout() && self.nodes.contains(id.index());
+        for &child in &node.children {
+            // Every child is visited so that clean subtrees inside of changed
+            // widgets can still be reused.
+            clean &= self.find_clean(dom, child);
+        }
+
+        if clean {
+            self.clean.insert(id);
+        }
+
+        clean
+    }
+
+    /// Keeps a widget's layout from last frame, redoing everything that
+    /// [`LayoutDom::calculate`] would have for it and its descendants.
+    ///
+    /// Positions were made absolute by the end of last frame, so they're made
+    /// relative to their parent again. The position of the widget itself is
+    /// reset, since its parent will place it again.
+    fn reuse(&mut self, dom: &Dom, id: WidgetId, parent_pos: Option<Vec2>) {
+        let Some(node) = self.nodes.get_mut(id.index()) else {
+            return;
+        };
+
+        let old_pos = node.rect.pos();
+        let pos = parent_pos.map_or(Vec2::ZERO, |parent_pos| old_pos - parent_pos);
+        node.rect.set_pos(pos);
+
+        let new_layer = node.new_layer;
+        let clipping_enabled = node.clipping_enabled;
+        let detached = node.detached;
+        let event_interest = node.event_interest;
+        let own_player = node
+            .player
+            .filter(|&player| self.player_stack.last().map(|&(_, top)| top) != Some(player));
+        self.has_transforms |= node.transform.is_some();
+
+        if new_layer {
+            self.interest_mouse.push_layer(id);
+        }
+        let outer_clip_stack = detached.then(|| std::mem::take(&mut self.clip_stack));
+        if clipping_enabled {
+            self.clip_stack.push(id);
+        }
+        if let Some(player) = own_player {
+            self.player_stack.push((id, player));
+        }
+
+        if let Some(dom_node) = dom.get(id) {
+            for &child in &dom_node.children {
+                self.reuse(dom, child, Some(old_pos));
+            }
+        }
+
+        if event_interest.intersects(EventInterest::MOUSE_ALL | EventInterest::TOUCH) {
+            self.interest_mouse.insert(id, event_interest);
+        }
+        if new_layer {
+            self.interest_mouse.pop_layer();
+        }
+
+        let clipped_by = if detached {
+            None
+        } else if clipping_enabled {
+            self.clip_stack.iter().nth_back(2).copied()
+        } else {
+            self.clip_stack.last().copied()
+        };
+        let player = self.player_stack.last().map(|&(_, player)| player);
+
+        if let Some(node) = self.nodes.get_mut(id.index()) {
+            node.clipped_by = clipped_by;
+            node.player = player;
+        }
+
+        if let Some(clip_stack) = outer_clip_stack {
+            self.clip_stack = clip_stack;
+        } else if clipping_enabled {
+            self.clip_stack.pop();
+        }
+        if own_player.is_some() {
+            self.player_stack.pop();
+        }
+    }
+
     fn resolve_positions(&mut self, dom: &Dom) {
         let mut queue = VecDeque::new();
 
-        queue.push_back((dom.root(), Vec2::ZERO));
+        queue.push_back((dom.root(), Vec2::ZERO, None));
 
-        while let Some((id, parent_pos)) = queue.pop_front() {
+        while let Some((id, parent_pos, transformed_by)) = queue.pop_front() {
             if let Some(layout_node) = self.nodes.get_mut(id.index()) {
                 let node = dom.get(id).unwrap();
+
+                // Detached nodes start over from the root of the viewport.
+                let (parent_pos, transformed_by) = if layout_node.detached {
+                    layout_node.rect.set_pos(Vec2::ZERO);
+                    (Vec2::ZERO, None)
+                } else {
+                    (parent_pos, transformed_by)
+                };
+
                 layout_node
                     .rect
                     .set_pos(layout_node.rect.pos() + parent_pos);
-
-                queue.extend(node.children.iter().map(|&id| (id, layout_node.rect.pos())));
+                layout_node.transformed_by = transformed_by;
+
+                let pos = layout_node.rect.pos();
+                let transformed_by = match layout_node.transform {
+                    Some(_) => Some(id),
+                    None => transformed_by,
+                };
+                queue.extend(node.children.iter().map(|&id| (id, pos, transformed_by)));
             }
         }
     }
@@ -12,6 +12,7 @@ mod mopmopa;
 mod id;
 mod response;
 mod state;
+mod store;
 mod types;
 
 pub mod context;
@@ -19,11 +20,14 @@ pub mod dom;
 pub mod event;
 pub mod geometry;
 pub mod input;
+pub mod inspect;
 pub mod layout;
 pub mod paint;
+pub mod snapshot;
 pub mod widget;
 
 pub use self::id::*;
 pub use self::response::*;
 pub use self::state::*;
+pub use self::store::*;
 pub use self::types::*;
@@ -0,0 +1,192 @@
+use glam::UVec2;
+
+use crate::geometry::URect;
+use crate::id::ManagedTextureId;
+
+use super::texture::{AddressMode, Texture, TextureFilter, TextureFormat};
+
+/// Textures larger than this on either axis are never packed into an atlas.
+pub(crate) const MAX_PACKED_SIZE: u32 = 128;
+
+/// The size of each atlas page, unless the renderer's limits are smaller.
+pub(crate) const ATLAS_SIZE: u32 = 1024;
+
+/// Empty space left around each packed texture, filled with copies of its
+/// edge pixels so that linear filtering doesn't blend in its neighbors.
+const GUTTER: u32 = 1;
+
+/// A managed texture that small textures with the same format and filtering
+/// are packed into.
+#[derive(Debug)]
+pub(crate) struct TextureAtlas {
+    pub id: ManagedTextureId,
+    key: AtlasKey,
+    size: UVec2,
+
+    /// Rows of packed textures, filled from left to right.
+    shelves: Vec<Shelf>,
+
+    /// Slots left behind by removed textures, which are reused by textures
+    /// that fit inside them.
+    free: Vec<URect>,
+}
+
+/// Textures can only share an atlas if they'd be sampled the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct AtlasKey {
+    format: TextureFormat,
+    min_filter: TextureFilter,
+    mag_filter: TextureFilter,
+}
+
+#[derive(Debug)]
+struct Shelf {
+    y: u32,
+    height: u32,
+    used_width: u32,
+}
+
+/// Where a packed texture was placed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PackedTexture {
+    pub atlas: ManagedTextureId,
+
+    /// The slot reserved for the texture, including its gutter. This can be
+    /// bigger than the texture needs if it reused a freed slot.
+    pub slot: URect,
+
+    /// The part of the atlas holding the texture's pixels.
+    pub rect: URect,
+}
+
+impl PackedTexture {
+    pub fn new(atlas: ManagedTextureId, slot: URect, size: UVec2) -> Self {
+        Self {
+            atlas,
+            slot,
+            rect: URect::from_pos_size(slot.pos() + UVec2::splat(GUTTER), size),
+        }
+    }
+}
+
+impl AtlasKey {
+    pub fn new(texture: &Texture) -> Self {
+        Self {
+            format: texture.format(),
+            min_filter: texture.min_filter,
+            mag_filter: texture.mag_filter,
+        }
+    }
+}
+
+impl TextureAtlas {
+    /// Creates an empty atlas texture for textures matching the given key.
+    pub fn create_texture(key: AtlasKey, size: u32) -> Texture {
+        let len = (size * size) as usize * key.format.bytes_per_pixel();
+        let mut texture = Texture::new(key.format, UVec2::splat(size), vec![0; len]);
+        texture.min_filter = key.min_filter;
+        texture.mag_filter = key.mag_filter;
+        texture.allow_atlas = false;
+        texture
+    }
+
+    pub fn new(id: ManagedTextureId, key: AtlasKey, size: u32) -> Self {
+        Self {
+            id,
+            key,
+            size: UVec2::splat(size),
+            shelves: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    pub fn key(&self) -> AtlasKey {
+        self.key
+    }
+
+    /// Reserves space for a texture of the given size, returning a slot big
+    /// enough for it and its gutter.
+    pub fn allocate(&mut self, size: UVec2) -> Option<URect> {
+        let size = size + UVec2::splat(GUTTER * 2);
+
+        // Icons tend to come in a handful of sizes, so slots freed by removed
+        // textures are likely to fit new ones exactly.
+        let free = self
+            .free
+            .iter()
+            .position(|slot| slot.size().cmpge(size).all());
+        if let Some(index) = free {
+            return Some(self.free.swap_remove(index));
+        }
+
+        for shelf in &mut self.shelves {
+            if shelf.height >= size.y && shelf.used_width + size.x <= self.size.x {
+                let pos = UVec2::new(shelf.used_width, shelf.y);
+                shelf.used_width += size.x;
+                return Some(URect::from_pos_size(pos, size));
+            }
+        }
+
+        let y = self
+            .shelves
+            .last()
+            .map(|shelf| shelf.y + shelf.height)
+            .unwrap_or(0);
+
+        if y + size.y > self.size.y || size.x > self.size.x {
+            return None;
+        }
+
+        self.shelves.push(Shelf {
+            y,
+            height: size.y,
+            used_width: size.x,
+        });
+
+        Some(URect::from_pos_size(UVec2::new(0, y), size))
+    }
+
+    /// Gives back a slot from a texture that was removed.
+    pub fn free(&mut self, slot: URect) {
+        self.free.push(slot);
+    }
+}
+
+/// Tells whether a texture is small enough and sampled in a way that allows
+/// it to be drawn out of an atlas.
+pub(crate) fn can_pack(texture: &Texture) -> bool {
+    let size = texture.size();
+
+    texture.allow_atlas
+        && texture.address_mode == AddressMode::ClampToEdge
+        && size.x > 0
+        && size.y > 0
+        && size.x <= MAX_PACKED_SIZE
+        && size.y <= MAX_PACKED_SIZE
+}
+
+/// Copies a texture into its place in an atlas, repeating its edge pixels into
+/// the gutter around it.
+pub(crate) fn copy_into_atlas(atlas: &mut Texture, texture: &Texture, packed: &PackedTexture) {
+    let bpp = texture.format().bytes_per_pixel();
+    let atlas_width = atlas.size().x as usize;
+    let origin = packed.rect.pos() - UVec2::splat(GUTTER);
+    let size = texture.size();
+    let src = texture.data();
+    let dst = atlas.data_mut();
+
+    for y in 0..size.y + GUTTER * 2 {
+        // Pixels in the gutter repeat the nearest edge pixel of the texture.
+        let src_y = y.saturating_sub(GUTTER).min(size.y - 1) as usize;
+        let dst_y = (origin.y + y) as usize;
+
+        for x in 0..size.x + GUTTER * 2 {
+            let src_x = x.saturating_sub(GUTTER).min(size.x - 1) as usize;
+            let dst_x = (origin.x + x) as usize;
+
+            let from = (src_y * size.x as usize + src_x) * bpp;
+            let to = (dst_y * atlas_width + dst_x) * bpp;
+            dst[to..to + bpp].copy_from_slice(&src[from..from + bpp]);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt;
+
+use glam::Vec2;
+
+use crate::geometry::Color;
+use crate::id::WidgetId;
+
+use super::{PaintCall, PaintLayers, Vertex};
+
+bitflags::bitflags! {
+    /// Debugging aids that [`PaintDom`][super::PaintDom] can draw or report
+    /// while painting, set with
+    /// [`PaintDom::set_debug`][super::PaintDom::set_debug].
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy, Default)]
+    pub struct PaintDebug: u8 {
+        /// Outlines every triangle, in a different color for each paint call.
+        const WIREFRAME = 1;
+
+        /// Tints everything drawn by each paint call in a different color, so
+        /// areas that were drawn in one batch show up as one color.
+        const TINT_CALLS = 2;
+
+        /// Logs [`PaintStats`] at the info level every time the UI is painted.
+        const LOG_STATS = 4;
+    }
+}
+
+/// Why a paint call was started instead of adding to the one before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BatchBreak {
+    /// This is the first call in its layer.
+    NewLayer,
+
+    /// The previous call used a different texture.
+    Texture,
+
+    /// The previous call used a different pipeline.
+    Pipeline,
+
+    /// The previous call had a different clipping rect.
+    Clip,
+}
+
+/// Where a paint call came from. Returned by
+/// [`PaintLayer::origins`][super::PaintLayer::origins].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallOrigin {
+    /// The widget that was painting when the call was started.
+    pub widget: Option<WidgetId>,
+
+    /// The type name of that widget.
+    pub widget_type: Option<&'static str>,
+
+    /// Why the call couldn't be merged with the one before it.
+    pub reason: BatchBreak,
+}
+
+/// Counts how the most recent paint was split into draw calls, to help find
+/// which widgets are causing extra ones.
+///
+/// Returned by [`PaintDom::stats`][super::PaintDom::stats]. Its [`Display`]
+/// implementation prints a short report.
+///
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaintStats {
+    /// The number of layers that were painted.
+    pub layers: usize,
+
+    /// The number of draw calls across all layers.
+    pub calls: usize,
+
+    /// The number of vertices across all calls.
+    pub vertices: usize,
+
+    /// The number of indices across all calls.
+    pub indices: usize,
+
+    /// How many calls were started for each reason.
+    pub breaks: Vec<(BatchBreak, usize)>,
+
+    /// The widget types that started calls for any reason other than
+    /// [`BatchBreak::NewLayer`], and how many, most first.
+    pub breaking_widgets: Vec<(&'static str, usize)>,
+}
+
+impl PaintStats {
+    pub(crate) fn capture(layers: &PaintLayers) -> Self {
+        let mut stats = Self {
+            layers: layers.len(),
+            ..Self::default()
+        };
+
+        let mut breaks: HashMap<BatchBreak, usize> = HashMap::new();
+        let mut widgets: HashMap<&'static str, usize> = HashMap::new();
+
+        for layer in layers.iter() {
+            stats.calls += layer.calls.len();
+
+            for call in &layer.calls {
+                stats.vertices += call.vertices.len();
+                stats.indices += call.indices.len();
+            }
+
+            for origin in layer.origins() {
+                *breaks.entry(origin.reason).or_default() += 1;
+
+                if origin.reason != BatchBreak::NewLayer {
+                    let name = origin.widget_type.unwrap_or("<unknown>");
+                    *widgets.entry(name).or_default() += 1;
+                }
+            }
+        }
+
+        stats.breaks = breaks.into_iter().collect();
+        stats
+            .breaks
+            .sort_by_key(|&(reason, count)| (Reverse(count), reason as u8));
+
+        stats.breaking_widgets = widgets.into_iter().collect();
+        stats
+            .breaking_widgets
+            .sort_by_key(|&(name, count)| (Reverse(count), name));
+
+        stats
+    }
+}
+
+impl fmt::Display for PaintStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} calls in {} layers ({} vertices, {} indices)",
+            self.calls, self.layers, self.vertices, self.indices
+        )?;
+
+        for (reason, count) in &self.breaks {
+            writeln!(f, "  {count} started by {reason:?}")?;
+        }
+
+        for (name, count) in &self.breaking_widgets {
+            writeln!(f, "  {count} broken by {name}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Colors cycled through to tell neighboring calls apart.
+const CALL_COLORS: [Color; 6] = [
+    Color::RED,
+    Color::GREEN,
+    Color::BLUE,
+    Color::YELLOW,
+    Color::CYAN,
+    Color::FUCHSIA,
+];
+
+/// Adds an overlay layer that outlines or tints the calls in every existing
+/// layer. Positions in calls are already normalized to the surface, so the
+/// overlay is built directly instead of going through
+/// [`PaintDom::add_mesh`][super::PaintDom::add_mesh].
+pub(crate) fn add_overlay(layers: &mut PaintLayers, debug: PaintDebug, surface_size: Vec2) {
+    let mut overlay = Vec::new();
+
+    for (index, call) in layers.iter().flat_map(|layer| &layer.calls).enumerate() {
+        let color = CALL_COLORS[index % CALL_COLORS.len()];
+
+        if debug.contains(PaintDebug::TINT_CALLS) {
+            let mut tint = PaintCall::new();
+            tint.clip = call.clip;
+            tint.indices.extend_from_slice(&call.indices);
+            tint.vertices.extend(call.vertices.iter().map(|vertex| {
+                Vertex::new(
+                    vertex.position,
+                    Vec2::ZERO,
+                    color.with_alpha(0.3).to_linear(),
+                )
+            }));
+            overlay.push(tint);
+        }
+
+        if debug.contains(PaintDebug::WIREFRAME) {
+            let mut wireframe = PaintCall::new();
+            wireframe.clip = call.clip;
+
+            for triangle in call.indices.chunks_exact(3) {
+                let corners = [triangle[0], triangle[1], triangle[2]]
+                    .map(|index| call.vertices[index as usize].position);
+
+                for i in 0..3 {
+                    let (a, b) = (corners[i], corners[(i + 1) % 3]);
+                    add_line(&mut wireframe, a, b, surface_size, color);
+                }
+            }
+
+            overlay.push(wireframe);
+        }
+    }
+
+    layers.push_overlay();
+    layers.current_mut().unwrap().calls.extend(overlay);
+    layers.pop();
+}
+
+/// Adds a one pixel wide line between two points in normalized surface
+/// coordinates.
+fn add_line(call: &mut PaintCall, a: Vec2, b: Vec2, surface_size: Vec2, color: Color) {
+    let direction = ((b - a) * surface_size).normalize_or_zero();
+    let offset = direction.perp() * 0.5 / surface_size;
+    let color = color.to_linear();
+
+    let start = call.vertices.len() as u32;
+    call.vertices.extend(
+        [a + offset, b + offset, b - offset, a - offset]
+            .map(|position| Vertex::new(position, Vec2::ZERO, color)),
+    );
+    call.indices
+        .extend([0, 1, 2, 2, 3, 0].map(|index| start + index));
+}
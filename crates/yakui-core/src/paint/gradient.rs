@@ -0,0 +1,196 @@
+use glam::{Vec2, Vec4};
+
+use crate::geometry::Color;
+
+/// How many segments along each axis a shape is split into when painting a
+/// gradient that can't be represented exactly by interpolating between the
+/// shape's corners.
+const GRADIENT_SEGMENTS: u32 = 16;
+
+/// A color at a point along a [`Gradient`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    /// Where this stop is along the gradient, from 0.0 at the start to 1.0 at
+    /// the end.
+    pub offset: f32,
+
+    /// The color of the gradient at this stop.
+    pub color: Color,
+}
+
+/// The shape of a [`Gradient`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientKind {
+    /// The color changes along the line from `start` to `end`, and is
+    /// constant along lines perpendicular to it.
+    Linear {
+        /// The point where the gradient has the color of its first stop.
+        start: Vec2,
+
+        /// The point where the gradient has the color of its last stop.
+        end: Vec2,
+    },
+
+    /// The color changes with distance from `center`, reaching the color of
+    /// the last stop at `radius`.
+    Radial {
+        /// The point where the gradient has the color of its first stop.
+        center: Vec2,
+
+        /// The distance from `center` where the gradient has the color of its
+        /// last stop.
+        radius: f32,
+    },
+}
+
+/// A fill that blends between colors across a shape.
+///
+/// Positions are relative to the bounding rectangle of the shape being filled,
+/// where (0, 0) is the top-left corner and (1, 1) is the bottom-right corner.
+/// Colors are blended in linear space.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    /// The shape of the gradient.
+    pub kind: GradientKind,
+
+    /// The colors of the gradient, sorted by offset.
+    pub stops: Vec<GradientStop>,
+}
+
+impl Gradient {
+    /// Create a linear gradient that blends from `from` at `start` to `to` at
+    /// `end`.
+    pub fn linear(start: Vec2, end: Vec2, from: Color, to: Color) -> Self {
+        Self {
+            kind: GradientKind::Linear { start, end },
+            stops: vec![
+                GradientStop {
+                    offset: 0.0,
+                    color: from,
+                },
+                GradientStop {
+                    offset: 1.0,
+                    color: to,
+                },
+            ],
+        }
+    }
+
+    /// Create a linear gradient that blends from `top` to `bottom`.
+    pub fn vertical(top: Color, bottom: Color) -> Self {
+        Self::linear(Vec2::ZERO, Vec2::Y, top, bottom)
+    }
+
+    /// Create a linear gradient that blends from `left` to `right`.
+    pub fn horizontal(left: Color, right: Color) -> Self {
+        Self::linear(Vec2::ZERO, Vec2::X, left, right)
+    }
+
+    /// Create a radial gradient that blends from `inner` at `center` to
+    /// `outer` at `radius` away from it.
+    pub fn radial(center: Vec2, radius: f32, inner: Color, outer: Color) -> Self {
+        Self {
+            kind: GradientKind::Radial { center, radius },
+            stops: vec![
+                GradientStop {
+                    offset: 0.0,
+                    color: inner,
+                },
+                GradientStop {
+                    offset: 1.0,
+                    color: outer,
+                },
+            ],
+        }
+    }
+
+    /// Add another color stop to the gradient.
+    pub fn with_stop(mut self, offset: f32, color: Color) -> Self {
+        let index = self.stops.partition_point(|stop| stop.offset <= offset);
+        self.stops.insert(index, GradientStop { offset, color });
+        self
+    }
+
+    /// Sample the gradient at the given position, relative to the shape's
+    /// bounding rectangle. Returns a linear RGBA color.
+    pub fn sample(&self, position: Vec2) -> Vec4 {
+        let t = match self.kind {
+            GradientKind::Linear { start, end } => linear_offset(start, end, position),
+            GradientKind::Radial { center, radius } => {
+                if radius > 0.0 {
+                    position.distance(center) / radius
+                } else {
+                    1.0
+                }
+            }
+        };
+
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return Color::WHITE.to_linear();
+        };
+
+        if t <= first.offset {
+            return first.color.to_linear();
+        }
+
+        for pair in self.stops.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+
+            if t <= b.offset {
+                let span = b.offset - a.offset;
+                let ratio = if span > 0.0 {
+                    (t - a.offset) / span
+                } else {
+                    1.0
+                };
+
+                return a.color.to_linear().lerp(b.color.to_linear(), ratio);
+            }
+        }
+
+        last.color.to_linear()
+    }
+
+    /// The number of segments along each axis that a rectangle needs to be
+    /// split into for this gradient to look right when its colors are
+    /// interpolated between vertices.
+    pub fn segments(&self) -> u32 {
+        match (self.kind, self.stops.as_slice()) {
+            // Without at least two stops, the whole shape is one color.
+            (_, [] | [_]) => 1,
+
+            // A linear gradient with two stops varies linearly between them,
+            // which vertex interpolation reproduces exactly. That doesn't hold
+            // if any part of the shape is past either end, where the color
+            // stops changing.
+            (GradientKind::Linear { start, end }, [first, last]) => {
+                let corners = [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::ONE];
+                let covered = corners.into_iter().all(|corner| {
+                    let t = linear_offset(start, end, corner);
+                    t >= first.offset && t <= last.offset
+                });
+
+                if covered {
+                    1
+                } else {
+                    GRADIENT_SEGMENTS
+                }
+            }
+
+            _ => GRADIENT_SEGMENTS,
+        }
+    }
+}
+
+/// How far `position` is along a linear gradient from `start` to `end`, where
+/// 0.0 is at `start` and 1.0 is at `end`.
+fn linear_offset(start: Vec2, end: Vec2, position: Vec2) -> f32 {
+    let direction = end - start;
+    let length_squared = direction.length_squared();
+
+    if length_squared > 0.0 {
+        (position - start).dot(direction) / length_squared
+    } else {
+        0.0
+    }
+}
@@ -1,18 +1,41 @@
 use std::ops::Deref;
 
-use super::PaintCall;
+use crate::geometry::Rect;
+use crate::id::{TextureId, WidgetId};
+
+use super::{BatchBreak, CallOrigin, PaintCall, Pipeline};
 
 /// Contains all of the draw calls for a single layer of the UI.
 #[derive(Debug)]
 pub struct PaintLayer {
     /// The draw calls that can be used to paint this layer.
     pub calls: Vec<PaintCall>,
+
+    origins: Vec<CallOrigin>,
+    overlay: bool,
 }
 
 impl PaintLayer {
     /// Create a new, empty paint layer.
     pub fn new() -> Self {
-        Self { calls: Vec::new() }
+        Self {
+            calls: Vec::new(),
+            origins: Vec::new(),
+            overlay: false,
+        }
+    }
+
+    /// Tells which widget started each of the layer's calls, and why it
+    /// couldn't be merged into the call before it. Calls added to `calls`
+    /// directly don't have an origin.
+    pub fn origins(&self) -> &[CallOrigin] {
+        &self.origins
+    }
+
+    /// Tells whether this layer is an overlay, which is drawn on top of all
+    /// regular layers.
+    pub fn is_overlay(&self) -> bool {
+        self.overlay
     }
 }
 
@@ -22,6 +45,10 @@ impl PaintLayer {
 pub struct PaintLayers {
     layers: Vec<PaintLayer>,
     layer_stack: Vec<usize>,
+
+    /// Calls from previous frames, kept around so that their buffers can be
+    /// reused instead of being allocated again every frame.
+    spare_calls: Vec<PaintCall>,
 }
 
 impl PaintLayers {
@@ -30,15 +57,64 @@ impl PaintLayers {
         Self {
             layers: Vec::new(),
             layer_stack: Vec::new(),
+            spare_calls: Vec::new(),
         }
     }
 
     /// Clear all paint layers.
     pub fn clear(&mut self) {
-        self.layers.clear();
+        for layer in self.layers.drain(..) {
+            self.spare_calls.extend(layer.calls);
+        }
+
         self.layer_stack.clear();
     }
 
+    /// Returns the call in the current layer that geometry with the given
+    /// properties should be added to, starting a new call if the last one
+    /// doesn't match. New calls are attributed to `widget`.
+    pub(crate) fn current_call(
+        &mut self,
+        texture: Option<TextureId>,
+        pipeline: Pipeline,
+        clip: Option<Rect>,
+        widget: Option<(WidgetId, &'static str)>,
+    ) -> Option<&mut PaintCall> {
+        let index = *self.layer_stack.last()?;
+        let layer = self.layers.get_mut(index)?;
+
+        let reason = match layer.calls.last() {
+            None => Some(BatchBreak::NewLayer),
+            Some(call) if call.texture != texture => Some(BatchBreak::Texture),
+            Some(call) if call.pipeline != pipeline => Some(BatchBreak::Pipeline),
+            Some(call) if call.clip != clip => Some(BatchBreak::Clip),
+            Some(_) => None,
+        };
+
+        if let Some(reason) = reason {
+            layer.origins.push(CallOrigin {
+                widget: widget.map(|(id, _)| id),
+                widget_type: widget.map(|(_, name)| name),
+                reason,
+            });
+
+            let mut call = match self.spare_calls.pop() {
+                Some(mut call) => {
+                    call.clear();
+                    call
+                }
+                None => PaintCall::new(),
+            };
+
+            call.texture = texture;
+            call.pipeline = pipeline;
+            call.clip = clip;
+            layer.calls.push(call);
+        }
+
+        layer.calls.last_mut()
+    }
+
     /// Returns a reference to the currently active layer, if there is one.
     pub fn current(&self) -> Option<&PaintLayer> {
         self.layer_stack
@@ -61,6 +137,24 @@ impl PaintLayers {
         self.layer_stack.push(index);
     }
 
+    /// Push a new overlay layer into the set. Overlay layers are drawn on top of
+    /// all regular layers, no matter when they were pushed.
+    pub fn push_overlay(&mut self) {
+        let index = self.layers.len();
+        let mut layer = PaintLayer::new();
+        layer.overlay = true;
+        self.layers.push(layer);
+        self.layer_stack.push(index);
+    }
+
+    /// Moves all overlay layers after all regular layers, keeping the order of
+    /// layers otherwise the same. This should only be called once all layers
+    /// have been popped.
+    pub(crate) fn sort_overlays(&mut self) {
+        debug_assert!(self.layer_stack.is_empty());
+        self.layers.sort_by_key(|layer| layer.overlay);
+    }
+
     /// Pop the most recently pushed paint layer. This should always be paired
     /// with a call to `push`.
     pub fn pop(&mut self) {
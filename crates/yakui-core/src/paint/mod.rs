@@ -1,13 +1,22 @@
 //! Defines primitives for painting widgets, including the Paint DOM.
 
+mod atlas;
+mod debug;
+mod gradient;
 mod layers;
 mod paint_dom;
+mod path;
 mod primitives;
 mod rect;
+mod shadow;
 mod texture;
 
+pub use self::debug::*;
+pub use self::gradient::*;
 pub use self::layers::*;
 pub use self::paint_dom::*;
+pub use self::path::PaintPath;
 pub use self::primitives::*;
 pub use self::rect::PaintRect;
+pub use self::shadow::PaintShadow;
 pub use self::texture::*;
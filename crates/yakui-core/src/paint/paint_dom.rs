@@ -1,15 +1,17 @@
 use std::collections::HashMap;
 
-use glam::Vec2;
+use glam::{Affine2, Vec2};
 use thunderdome::Arena;
 
 use crate::dom::Dom;
-use crate::geometry::Rect;
-use crate::id::{ManagedTextureId, WidgetId};
+use crate::geometry::{Rect, URect};
+use crate::id::{ManagedTextureId, TextureId, WidgetId};
 use crate::layout::LayoutDom;
-use crate::paint::{PaintCall, Pipeline};
+use crate::paint::Pipeline;
 use crate::widget::PaintContext;
 
+use super::atlas::{self, AtlasKey, PackedTexture, TextureAtlas, ATLAS_SIZE};
+use super::debug::{self, PaintDebug, PaintStats};
 use super::layers::PaintLayers;
 use super::primitives::{PaintMesh, Vertex};
 use super::texture::{Texture, TextureChange};
@@ -30,6 +32,9 @@ pub struct PaintLimits {
 pub struct PaintDom {
     textures: Arena<Texture>,
     texture_edits: HashMap<ManagedTextureId, TextureChange>,
+    texture_edits_stale: bool,
+    atlases: Vec<TextureAtlas>,
+    packed: HashMap<ManagedTextureId, PackedTexture>,
     surface_size: Vec2,
     unscaled_viewport: Rect,
     scale_factor: f32,
@@ -37,6 +42,14 @@ pub struct PaintDom {
 
     layers: PaintLayers,
     clip_stack: Vec<Rect>,
+    transform_stack: Vec<Affine2>,
+    overlay_stack: Vec<(Vec<Rect>, Vec<Affine2>)>,
+
+    /// The widgets currently being painted, innermost last, which new paint
+    /// calls are attributed to.
+    widget_stack: Vec<(WidgetId, &'static str)>,
+    debug: PaintDebug,
+    stats: PaintStats,
 }
 
 impl PaintDom {
@@ -45,6 +58,9 @@ impl PaintDom {
         Self {
             textures: Arena::new(),
             texture_edits: HashMap::new(),
+            texture_edits_stale: false,
+            atlases: Vec::new(),
+            packed: HashMap::new(),
             surface_size: Vec2::ONE,
             unscaled_viewport: Rect::ONE,
             scale_factor: 1.0,
@@ -52,6 +68,12 @@ impl PaintDom {
 
             layers: PaintLayers::new(),
             clip_stack: Vec::new(),
+            transform_stack: Vec::new(),
+            overlay_stack: Vec::new(),
+
+            widget_stack: Vec::new(),
+            debug: PaintDebug::empty(),
+            stats: PaintStats::default(),
         }
     }
 
@@ -67,8 +89,10 @@ impl PaintDom {
 
     /// Prepares the PaintDom to be updated for the frame.
     pub fn start(&mut self) {
-        self.texture_edits.clear();
         self.clip_stack.clear();
+        self.transform_stack.clear();
+        self.overlay_stack.clear();
+        self.widget_stack.clear();
     }
 
     /// Returns the size of the surface that is being painted onto.
@@ -97,6 +121,19 @@ impl PaintDom {
         profiling::scope!("PaintDom::paint");
 
         let layout_node = layout.get(id).unwrap();
+
+        // Widgets that can't be seen don't need to be painted, and neither do
+        // their children. Nothing shows through a clipping rect with no area,
+        // like a collapsed scroll region, even if it paints outside its rect.
+        if self.clip_is_empty() {
+            return;
+        }
+
+        let paints_outside = dom.get(id).unwrap().widget.paints_outside_rect();
+        if !paints_outside && !self.is_visible(layout, layout_node.rect) {
+            return;
+        }
+
         if layout_node.clipping_enabled {
             self.push_clip(layout_node.rect);
         }
@@ -106,13 +143,16 @@ impl PaintDom {
 
         dom.enter(id);
 
+        let node = dom.get(id).unwrap();
+        self.widget_stack.push((id, node.widget.type_name()));
+
         let context = PaintContext {
             dom,
             layout,
             paint: self,
         };
-        let node = dom.get(id).unwrap();
         node.widget.paint(context);
+        self.widget_stack.pop();
 
         dom.exit(id);
 
@@ -129,24 +169,193 @@ impl PaintDom {
         profiling::scope!("PaintDom::paint_all");
         log::debug!("PaintDom:paint_all()");
 
+        self.clear_stale_texture_edits();
+
         self.layers.clear();
         self.paint(dom, layout, dom.root());
+        self.layers.sort_overlays();
+
+        self.stats = PaintStats::capture(&self.layers);
+        if self.debug.contains(PaintDebug::LOG_STATS) {
+            log::info!("{}", self.stats);
+        }
+        if self
+            .debug
+            .intersects(PaintDebug::WIREFRAME | PaintDebug::TINT_CALLS)
+        {
+            debug::add_overlay(&mut self.layers, self.debug, self.surface_size);
+        }
+
+        // Renderers read the edits after painting, so anything that changes
+        // after this point belongs to the next paint.
+        self.texture_edits_stale = true;
+    }
+
+    /// Turns debugging aids like wireframes on or off for future paints.
+    pub fn set_debug(&mut self, debug: PaintDebug) {
+        self.debug = debug;
+    }
+
+    /// Returns the debugging aids that are turned on.
+    pub fn debug(&self) -> PaintDebug {
+        self.debug
+    }
+
+    /// Returns statistics about how the most recent paint was split into
+    /// draw calls. Calls added by [`PaintDebug`] overlays aren't counted.
+    pub fn stats(&self) -> &PaintStats {
+        &self.stats
+    }
+
+    /// Start painting onto an overlay layer, which is drawn above every other
+    /// layer and isn't affected by any clipping from containing widgets.
+    ///
+    /// Must be paired with a call to [`PaintDom::end_overlay`].
+    pub fn begin_overlay(&mut self) {
+        self.layers.push_overlay();
+        self.overlay_stack.push((
+            std::mem::take(&mut self.clip_stack),
+            std::mem::take(&mut self.transform_stack),
+        ));
+    }
+
+    /// Finish painting onto the overlay layer started by
+    /// [`PaintDom::begin_overlay`].
+    pub fn end_overlay(&mut self) {
+        self.layers.pop();
+        (self.clip_stack, self.transform_stack) = self.overlay_stack.pop().unwrap_or_default();
+    }
+
+    /// Apply a transform to everything painted until the matching call to
+    /// [`PaintDom::pop_transform`], on top of any transforms that are already
+    /// active. Transforms work in logical pixels.
+    ///
+    /// Clipping rects pushed while a transform is active are transformed too.
+    /// Since clipping rects are always axis-aligned, rotated clipping rects
+    /// cover the bounding box of the rotated rect.
+    ///
+    /// Transforms only affect painting. For input to follow the transform,
+    /// widgets should also report it during layout with
+    /// [`LayoutDom::set_transform`][crate::layout::LayoutDom::set_transform].
+    pub fn push_transform(&mut self, transform: Affine2) {
+        let combined = self.transform() * transform;
+        self.transform_stack.push(combined);
+    }
+
+    /// Remove the most recent transform pushed with
+    /// [`PaintDom::push_transform`].
+    pub fn pop_transform(&mut self) {
+        let top = self.transform_stack.pop();
+        debug_assert!(
+            top.is_some(),
+            "cannot call pop_transform without a corresponding push_transform call"
+        );
+    }
+
+    /// Returns the transform currently applied to everything being painted.
+    pub fn transform(&self) -> Affine2 {
+        self.transform_stack
+            .last()
+            .copied()
+            .unwrap_or(Affine2::IDENTITY)
     }
 
     /// Add a texture to the Paint DOM, returning an ID that can be used to
     /// reference it later.
+    ///
+    /// Small textures are packed into a shared atlas unless
+    /// [`Texture::allow_atlas`] is turned off. They can still be drawn and
+    /// changed using the returned ID like any other texture.
     pub fn add_texture(&mut self, texture: Texture) -> ManagedTextureId {
+        self.clear_stale_texture_edits();
         let id = ManagedTextureId::new(self.textures.insert(texture));
-        self.texture_edits.insert(id, TextureChange::Added);
+
+        if !self.pack_texture(id) {
+            self.texture_edits.insert(id, TextureChange::Added);
+        }
+
         id
     }
 
     /// Remove a texture from the Paint DOM.
     pub fn remove_texture(&mut self, id: ManagedTextureId) {
+        self.clear_stale_texture_edits();
         self.textures.remove(id.index());
+
+        // Renderers never saw packed textures, so there's nothing for them to
+        // remove. Their space in the atlas is reused by later textures.
+        if let Some(packed) = self.packed.remove(&id) {
+            if let Some(atlas) = self.atlases.iter_mut().find(|a| a.id == packed.atlas) {
+                atlas.free(packed.slot);
+            }
+
+            return;
+        }
+
         self.texture_edits.insert(id, TextureChange::Removed);
     }
 
+    /// Tries to pack a newly added texture into an atlas, creating a new atlas
+    /// if the existing ones are full. Returns whether the texture was packed.
+    fn pack_texture(&mut self, id: ManagedTextureId) -> bool {
+        let texture = &self.textures[id.index()];
+        if !atlas::can_pack(texture) {
+            return false;
+        }
+
+        let key = AtlasKey::new(texture);
+        let size = texture.size();
+
+        let existing = self
+            .atlases
+            .iter_mut()
+            .filter(|atlas| atlas.key() == key)
+            .find_map(|atlas| Some((atlas.id, atlas.allocate(size)?)));
+
+        let (atlas_id, slot) = match existing {
+            Some(found) => found,
+            None => {
+                let page_size = self
+                    .limits
+                    .map(|limits| limits.max_texture_size_2d)
+                    .filter(|&max| max > 0)
+                    .map_or(ATLAS_SIZE, |max| max.min(ATLAS_SIZE));
+
+                let texture = TextureAtlas::create_texture(key, page_size);
+                let atlas_id = ManagedTextureId::new(self.textures.insert(texture));
+                let mut atlas = TextureAtlas::new(atlas_id, key, page_size);
+
+                // This only happens if the renderer can't make textures as big
+                // as the textures we're willing to pack.
+                let Some(slot) = atlas.allocate(size) else {
+                    self.textures.remove(atlas_id.index());
+                    return false;
+                };
+
+                self.texture_edits.insert(atlas_id, TextureChange::Added);
+                self.atlases.push(atlas);
+                (atlas_id, slot)
+            }
+        };
+
+        let packed = PackedTexture::new(atlas_id, slot, size);
+        self.packed.insert(id, packed);
+        self.copy_packed(id, packed);
+        true
+    }
+
+    /// Copies a packed texture's pixels into its atlas and tells renderers to
+    /// upload them.
+    fn copy_packed(&mut self, id: ManagedTextureId, packed: PackedTexture) {
+        if let (Some(texture), Some(atlas)) =
+            self.textures.get2_mut(id.index(), packed.atlas.index())
+        {
+            atlas::copy_into_atlas(atlas, texture, &packed);
+        }
+
+        self.mark_texture_region_modified(packed.atlas, packed.slot);
+    }
+
     /// Retrieve a texture by its ID, if it exists.
     pub fn texture(&self, id: ManagedTextureId) -> Option<&Texture> {
         self.textures.get(id.index())
@@ -159,14 +368,67 @@ impl PaintDom {
 
     /// Mark a texture as modified so that changes can be detected.
     pub fn mark_texture_modified(&mut self, id: ManagedTextureId) {
-        self.texture_edits.insert(id, TextureChange::Modified);
+        self.clear_stale_texture_edits();
+
+        if let Some(&packed) = self.packed.get(&id) {
+            self.copy_packed(id, packed);
+            return;
+        }
+
+        let edit = self
+            .texture_edits
+            .entry(id)
+            .or_insert(TextureChange::Modified);
+        if let TextureChange::ModifiedRegion(_) = edit {
+            *edit = TextureChange::Modified;
+        }
     }
 
-    /// Returns an iterator over all textures known to the Paint DOM.
+    /// Mark part of a texture as modified. This is cheaper than
+    /// [`PaintDom::mark_texture_modified`] for textures that are updated a
+    /// little bit at a time, since renderers only need to upload the pixels
+    /// that changed.
+    ///
+    /// Marking several regions in the same frame combines them into one region
+    /// that contains all of them.
+    pub fn mark_texture_region_modified(&mut self, id: ManagedTextureId, region: URect) {
+        self.clear_stale_texture_edits();
+
+        // Packed textures are small, so they're copied to their atlas whole.
+        if let Some(&packed) = self.packed.get(&id) {
+            self.copy_packed(id, packed);
+            return;
+        }
+
+        let edit = self
+            .texture_edits
+            .entry(id)
+            .or_insert(TextureChange::ModifiedRegion(region));
+
+        if let TextureChange::ModifiedRegion(existing) = edit {
+            *existing = existing.union(&region);
+        }
+    }
+
+    /// Texture edits are kept until the next paint after they were made, so
+    /// that textures changed between frames are still uploaded.
+    fn clear_stale_texture_edits(&mut self) {
+        if self.texture_edits_stale {
+            self.texture_edits.clear();
+            self.texture_edits_stale = false;
+        }
+    }
+
+    /// Returns an iterator over all textures that renderers need to upload.
+    ///
+    /// Textures that were packed into an atlas are left out, since they're
+    /// drawn from the atlas instead. They can still be looked up with
+    /// [`PaintDom::texture`].
     pub fn textures(&self) -> impl Iterator<Item = (ManagedTextureId, &Texture)> {
         self.textures
             .iter()
             .map(|(index, texture)| (ManagedTextureId::new(index), texture))
+            .filter(|(id, _)| !self.packed.contains_key(id))
     }
 
     /// Iterates over the list of changes that happened to yakui-managed
@@ -187,45 +449,45 @@ impl PaintDom {
     pub fn add_mesh<V, I>(&mut self, mesh: PaintMesh<V, I>)
     where
         V: IntoIterator<Item = Vertex>,
-        I: IntoIterator<Item = u16>,
+        I: IntoIterator<Item = u32>,
     {
         profiling::scope!("PaintDom::add_mesh");
 
-        let texture_id = mesh.texture.map(|(index, _rect)| index);
+        let mut texture_id = mesh.texture.map(|(index, _rect)| index);
+        let transform = self.transform();
 
-        let layer = self
-            .layers
-            .current_mut()
-            .expect("an active layer is required to call add_mesh");
+        // Meshes using a packed texture are drawn from its atlas instead, with
+        // their texture coordinates squeezed into the texture's spot in it.
+        let mut atlas_uv = None;
+        if let Some(TextureId::Managed(id)) = texture_id {
+            if let Some(packed) = self.packed.get(&id) {
+                let atlas_size = self.textures[packed.atlas.index()].size().as_vec2();
+                let rect = packed.rect.as_rect();
 
-        let current_clip = self.clip_stack.last().copied();
-        let call = match layer.calls.last_mut() {
-            Some(call)
-                if call.texture == texture_id
-                    && call.pipeline == mesh.pipeline
-                    && call.clip == current_clip =>
-            {
-                call
-            }
-            _ => {
-                let mut call = PaintCall::new();
-                call.texture = texture_id;
-                call.pipeline = mesh.pipeline;
-                call.clip = current_clip;
-
-                layer.calls.push(call);
-                layer.calls.last_mut().unwrap()
+                texture_id = Some(TextureId::Managed(packed.atlas));
+                atlas_uv = Some((rect.pos() / atlas_size, rect.size() / atlas_size));
             }
-        };
+        }
+
+        let current_clip = self.call_clip();
+        let call = self
+            .layers
+            .current_call(
+                texture_id,
+                mesh.pipeline,
+                current_clip,
+                self.widget_stack.last().copied(),
+            )
+            .expect("an active layer is required to call add_mesh");
 
         let indices = mesh
             .indices
             .into_iter()
-            .map(|index| index + call.vertices.len() as u16);
+            .map(|index| index + call.vertices.len() as u32);
         call.indices.extend(indices);
 
         let vertices = mesh.vertices.into_iter().map(|mut vertex| {
-            let mut pos = vertex.position * self.scale_factor;
+            let mut pos = transform.transform_point2(vertex.position) * self.scale_factor;
             pos += self.unscaled_viewport.pos();
 
             // Currently, we only round the vertices of geometry fed to the text
@@ -239,14 +501,69 @@ impl PaintDom {
 
             pos /= self.surface_size;
 
+            if let Some((offset, scale)) = atlas_uv {
+                vertex.texcoord = offset + vertex.texcoord * scale;
+            }
+
             vertex.position = pos;
             vertex
         });
         call.vertices.extend(vertices);
     }
 
+    /// Tells whether any part of the given rect, in logical pixels, is inside
+    /// the current clipping rect, or the viewport if there is none.
+    fn is_visible(&self, layout: &LayoutDom, rect: Rect) -> bool {
+        let rect = self.transform_rect(rect);
+        let unscaled = Rect::from_pos_size(
+            rect.pos() * self.scale_factor,
+            rect.size() * self.scale_factor,
+        );
+
+        let bounds = match self.clip_stack.last() {
+            Some(clip) => *clip,
+            None => Rect::from_pos_size(Vec2::ZERO, layout.unscaled_viewport().size()),
+        };
+
+        // Zero-sized widgets, like ones that only position their children,
+        // are still considered visible when they sit inside the bounds.
+        if unscaled.size().x == 0.0 || unscaled.size().y == 0.0 {
+            return bounds.contains_point(unscaled.pos());
+        }
+
+        unscaled.intersects(&bounds)
+    }
+
+    /// The clipping rect for new paint calls, in surface coordinates. Clip
+    /// rects are kept relative to the viewport, and calls are also clipped to
+    /// the viewport so that UI sharing a surface with other viewports can't
+    /// draw over them.
+    fn call_clip(&self) -> Option<Rect> {
+        let viewport = self.unscaled_viewport;
+
+        match self.clip_stack.last() {
+            Some(clip) => {
+                let mut clip = Rect::from_pos_size(clip.pos() + viewport.pos(), clip.size())
+                    .constrain(viewport);
+                clip.set_size(clip.size().max(Vec2::ZERO));
+                Some(clip)
+            }
+            None if viewport == Rect::from_pos_size(Vec2::ZERO, self.surface_size) => None,
+            None => Some(viewport),
+        }
+    }
+
+    /// Tells whether the current clipping rect has no area, which hides
+    /// everything painted inside it.
+    fn clip_is_empty(&self) -> bool {
+        self.clip_stack
+            .last()
+            .is_some_and(|clip| clip.size().x <= 0.0 || clip.size().y <= 0.0)
+    }
+
     /// Use the given region as the clipping rect for all following paint calls.
     fn push_clip(&mut self, region: Rect) {
+        let region = self.transform_rect(region);
         let mut unscaled = Rect::from_pos_size(
             region.pos() * self.scale_factor,
             region.size() * self.scale_factor,
@@ -254,11 +571,35 @@ impl PaintDom {
 
         if let Some(previous) = self.clip_stack.last() {
             unscaled = unscaled.constrain(*previous);
+
+            // Regions that don't overlap at all constrain to a negative size,
+            // which renderers can't use as a scissor rect.
+            unscaled.set_size(unscaled.size().max(Vec2::ZERO));
         }
 
         self.clip_stack.push(unscaled);
     }
 
+    /// Returns the bounding box of the given rect after the current transform
+    /// is applied to it.
+    fn transform_rect(&self, rect: Rect) -> Rect {
+        let Some(transform) = self.transform_stack.last() else {
+            return rect;
+        };
+
+        let corners = [
+            rect.pos(),
+            Vec2::new(rect.max().x, rect.pos().y),
+            rect.max(),
+            Vec2::new(rect.pos().x, rect.max().y),
+        ]
+        .map(|corner| transform.transform_point2(corner));
+
+        let min = corners.into_iter().reduce(Vec2::min).unwrap();
+        let max = corners.into_iter().reduce(Vec2::max).unwrap();
+        Rect::from_pos_size(min, max - min)
+    }
+
     /// Pop the most recent clip region, restoring the previous clipping rect.
     fn pop_clip(&mut self) {
         let top = self.clip_stack.pop();
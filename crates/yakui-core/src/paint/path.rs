@@ -0,0 +1,194 @@
+use glam::Vec2;
+use lyon_tessellation::geom::point;
+use lyon_tessellation::path::Path;
+use lyon_tessellation::{
+    BuffersBuilder, FillOptions, FillTessellator, FillVertex, StrokeOptions, StrokeTessellator,
+    StrokeVertex, VertexBuffers,
+};
+
+use crate::geometry::Color;
+
+use super::{PaintDom, PaintMesh, Vertex};
+
+/// The maximum distance, in logical pixels, between a curve and the line
+/// segments used to approximate it.
+const TOLERANCE: f32 = 0.1;
+
+#[derive(Debug, Clone, Copy)]
+enum Segment {
+    MoveTo(Vec2),
+    LineTo(Vec2),
+    QuadTo(Vec2, Vec2),
+    CubicTo(Vec2, Vec2, Vec2),
+    Close,
+}
+
+/// A shape made of lines and curves that can be filled or stroked.
+///
+/// Paths are built up like on an HTML canvas: `move_to` starts a new shape, and
+/// the other methods extend the current shape from the last point. After
+/// `close`, the next shape starts where the closed one did. Positions use the
+/// same coordinates as other paint primitives.
+///
+/// ```rust
+/// # use yakui_core::paint::PaintPath;
+/// # use yakui_core::geometry::Vec2;
+/// let mut path = PaintPath::new();
+/// path.move_to(Vec2::new(0.0, 0.0));
+/// path.line_to(Vec2::new(100.0, 0.0));
+/// path.quad_to(Vec2::new(100.0, 100.0), Vec2::new(0.0, 100.0));
+/// path.close();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PaintPath {
+    segments: Vec<Segment>,
+}
+
+impl PaintPath {
+    /// Create a new, empty path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new shape at the given point.
+    pub fn move_to(&mut self, to: Vec2) -> &mut Self {
+        self.segments.push(Segment::MoveTo(to));
+        self
+    }
+
+    /// Add a straight line from the last point to `to`.
+    pub fn line_to(&mut self, to: Vec2) -> &mut Self {
+        self.segments.push(Segment::LineTo(to));
+        self
+    }
+
+    /// Add a quadratic Bézier curve from the last point to `to`.
+    pub fn quad_to(&mut self, control: Vec2, to: Vec2) -> &mut Self {
+        self.segments.push(Segment::QuadTo(control, to));
+        self
+    }
+
+    /// Add a cubic Bézier curve from the last point to `to`.
+    pub fn cubic_to(&mut self, control1: Vec2, control2: Vec2, to: Vec2) -> &mut Self {
+        self.segments.push(Segment::CubicTo(control1, control2, to));
+        self
+    }
+
+    /// Close the current shape with a line back to its first point, which is
+    /// where the next shape starts unless `move_to` is called.
+    pub fn close(&mut self) -> &mut Self {
+        self.segments.push(Segment::Close);
+        self
+    }
+
+    /// Tells whether any segments have been added to this path.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Fill the inside of this path with the given color, using the non-zero
+    /// fill rule.
+    pub fn fill(&self, output: &mut PaintDom, color: Color) {
+        let color = color.to_linear();
+        let mut buffers: VertexBuffers<Vertex, u32> = VertexBuffers::new();
+
+        let result = FillTessellator::new().tessellate_path(
+            &self.to_lyon(),
+            &FillOptions::tolerance(TOLERANCE),
+            &mut BuffersBuilder::new(&mut buffers, |vertex: FillVertex<'_>| {
+                let pos = vertex.position();
+                Vertex::new([pos.x, pos.y], [0.0, 0.0], color)
+            }),
+        );
+
+        if let Err(err) = result {
+            log::warn!("Failed to fill path: {err:?}");
+            return;
+        }
+
+        output.add_mesh(PaintMesh::new(buffers.vertices, buffers.indices));
+    }
+
+    /// Draw a line of the given width along this path.
+    pub fn stroke(&self, output: &mut PaintDom, width: f32, color: Color) {
+        let color = color.to_linear();
+        let mut buffers: VertexBuffers<Vertex, u32> = VertexBuffers::new();
+
+        let result = StrokeTessellator::new().tessellate_path(
+            &self.to_lyon(),
+            &StrokeOptions::tolerance(TOLERANCE).with_line_width(width),
+            &mut BuffersBuilder::new(&mut buffers, |vertex: StrokeVertex<'_, '_>| {
+                let pos = vertex.position();
+                Vertex::new([pos.x, pos.y], [0.0, 0.0], color)
+            }),
+        );
+
+        if let Err(err) = result {
+            log::warn!("Failed to stroke path: {err:?}");
+            return;
+        }
+
+        output.add_mesh(PaintMesh::new(buffers.vertices, buffers.indices));
+    }
+
+    fn to_lyon(&self) -> Path {
+        let mut builder = Path::builder();
+        let mut open = false;
+        let mut last = Vec2::ZERO;
+        let mut start = Vec2::ZERO;
+
+        for &segment in &self.segments {
+            // Drawing without a call to move_to first continues from wherever
+            // the last shape left off.
+            if !open && !matches!(segment, Segment::MoveTo(_) | Segment::Close) {
+                builder.begin(point(last.x, last.y));
+                open = true;
+                start = last;
+            }
+
+            match segment {
+                Segment::MoveTo(to) => {
+                    if open {
+                        builder.end(false);
+                    }
+
+                    builder.begin(point(to.x, to.y));
+                    open = true;
+                    last = to;
+                    start = to;
+                }
+                Segment::LineTo(to) => {
+                    builder.line_to(point(to.x, to.y));
+                    last = to;
+                }
+                Segment::QuadTo(control, to) => {
+                    builder.quadratic_bezier_to(point(control.x, control.y), point(to.x, to.y));
+                    last = to;
+                }
+                Segment::CubicTo(control1, control2, to) => {
+                    builder.cubic_bezier_to(
+                        point(control1.x, control1.y),
+                        point(control2.x, control2.y),
+                        point(to.x, to.y),
+                    );
+                    last = to;
+                }
+                Segment::Close => {
+                    if open {
+                        builder.end(true);
+                        open = false;
+                    }
+
+                    // Closing goes back to the start of the shape.
+                    last = start;
+                }
+            }
+        }
+
+        if open {
+            builder.end(false);
+        }
+
+        builder.build()
+    }
+}
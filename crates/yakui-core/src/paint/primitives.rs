@@ -3,6 +3,10 @@ use glam::{Vec2, Vec4};
 use crate::geometry::Rect;
 use crate::id::TextureId;
 
+/// A mesh to be drawn with [`PaintDom::add_mesh`][crate::paint::PaintDom::add_mesh].
+///
+/// Indices can be either `u16` or `u32`. Small meshes can stick with `u16`,
+/// while large generated meshes can use `u32` to address more vertices.
 #[allow(missing_docs)]
 pub struct PaintMesh<V, I> {
     pub vertices: V,
@@ -14,7 +18,7 @@ pub struct PaintMesh<V, I> {
 impl<V, I> PaintMesh<V, I>
 where
     V: IntoIterator<Item = Vertex>,
-    I: IntoIterator<Item = u16>,
+    I: IntoIterator<Item = u32>,
 {
     /// Create a new `PaintMesh` with the default pipeline, no texture, and the
     /// given vertices and indices.
@@ -32,7 +36,7 @@ where
 #[allow(missing_docs)]
 pub struct PaintCall {
     pub vertices: Vec<Vertex>,
-    pub indices: Vec<u16>,
+    pub indices: Vec<u32>,
     pub texture: Option<TextureId>,
     pub pipeline: Pipeline,
     pub clip: Option<Rect>,
@@ -49,9 +53,23 @@ impl PaintCall {
             clip: None,
         }
     }
+
+    /// Removes the call's geometry so it can be reused, keeping its
+    /// allocations.
+    pub(crate) fn clear(&mut self) {
+        self.vertices.clear();
+        self.indices.clear();
+    }
 }
 
+/// A single vertex of a [`PaintCall`].
+///
+/// Vertices are laid out the same way as a `#[repr(C)]` struct of two `vec2`s
+/// followed by a `vec4`. With the `bytemuck` feature enabled, renderers can
+/// upload them without converting them first.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
 #[allow(missing_docs)]
 pub struct Vertex {
     pub position: Vec2,
@@ -85,4 +103,22 @@ pub enum Pipeline {
 
     /// Pipeline for drawing text: vertices and a coverage glyph texture.
     Text,
+
+    /// Pipeline for drawing text from a signed distance field glyph texture.
+    /// Glyphs drawn this way stay sharp when scaled far from the size they
+    /// were rasterized at.
+    TextSdf,
+
+    /// A pipeline registered with the renderer by the application, like a
+    /// custom shader effect. Renderers that don't know about the pipeline
+    /// will skip or approximate geometry drawn with it.
+    Custom {
+        /// Identifies the pipeline. Assigned by the renderer when the
+        /// pipeline is registered.
+        id: u32,
+
+        /// Identifies extra resources that the renderer should bind for this
+        /// draw call, like a uniform buffer holding shader parameters.
+        resources: Option<u64>,
+    },
 }
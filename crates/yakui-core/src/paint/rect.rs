@@ -3,7 +3,7 @@ use glam::Vec2;
 use crate::geometry::{Color, Rect};
 use crate::TextureId;
 
-use super::{PaintDom, PaintMesh, Pipeline, Vertex};
+use super::{Gradient, PaintDom, PaintMesh, Pipeline, Vertex};
 
 #[rustfmt::skip]
 const RECT_POS: [[f32; 2]; 4] = [
@@ -14,7 +14,7 @@ const RECT_POS: [[f32; 2]; 4] = [
 ];
 
 #[rustfmt::skip]
-const RECT_INDEX: [u16; 6] = [
+const RECT_INDEX: [u32; 6] = [
     0, 1, 2,
     3, 0, 2,
 ];
@@ -25,6 +25,9 @@ pub struct PaintRect {
     pub color: Color,
     pub texture: Option<(TextureId, Rect)>,
     pub pipeline: Pipeline,
+
+    /// If set, the rectangle is filled with this gradient instead of `color`.
+    pub gradient: Option<Gradient>,
 }
 
 impl PaintRect {
@@ -40,11 +43,16 @@ impl PaintRect {
             color: Color::WHITE,
             texture: None,
             pipeline: Pipeline::Main,
+            gradient: None,
         }
     }
 
     /// Add this rectangle to the PaintDom to be drawn this frame.
     pub fn add(&self, output: &mut PaintDom) {
+        if let Some(gradient) = &self.gradient {
+            return self.add_gradient(output, gradient);
+        }
+
         let size = self.rect.size();
         let pos = self.rect.pos();
         let color = self.color.to_linear();
@@ -67,4 +75,50 @@ impl PaintRect {
 
         output.add_mesh(mesh);
     }
+
+    fn add_gradient(&self, output: &mut PaintDom, gradient: &Gradient) {
+        let size = self.rect.size();
+        let pos = self.rect.pos();
+        let texture_rect = match self.texture {
+            Some((_index, rect)) => rect,
+            None => Rect::from_pos_size(Vec2::ZERO, Vec2::ONE),
+        };
+
+        let segments = gradient.segments();
+        let row_len = segments + 1;
+
+        let mut vertices = Vec::with_capacity((row_len * row_len) as usize);
+        for y in 0..row_len {
+            for x in 0..row_len {
+                let vert = Vec2::new(x as f32, y as f32) / segments as f32;
+
+                vertices.push(Vertex::new(
+                    vert * size + pos,
+                    vert * texture_rect.size() + texture_rect.pos(),
+                    gradient.sample(vert),
+                ));
+            }
+        }
+
+        let mut indices = Vec::with_capacity((segments * segments) as usize * 6);
+        for y in 0..segments {
+            for x in 0..segments {
+                let top_left = y * row_len + x;
+                let bottom_left = top_left + row_len;
+
+                indices.extend(RECT_INDEX.map(|index| match index {
+                    0 => top_left,
+                    1 => bottom_left,
+                    2 => bottom_left + 1,
+                    _ => top_left + 1,
+                }));
+            }
+        }
+
+        let mut mesh = PaintMesh::new(vertices, indices);
+        mesh.texture = self.texture;
+        mesh.pipeline = self.pipeline;
+
+        output.add_mesh(mesh);
+    }
 }
@@ -0,0 +1,114 @@
+use std::f32::consts::TAU;
+
+use glam::Vec2;
+
+use crate::geometry::{Color, Rect};
+
+use super::{PaintDom, PaintMesh, Vertex};
+
+/// How many points are used to approximate each corner of a shadow.
+const CORNER_SLICES: u32 = 8;
+
+/// A soft shadow cast by a rounded rectangle.
+///
+/// The shadow is drawn as a solid core that fades out to transparent over
+/// `blur_radius`, centered on the edge of the shape.
+#[derive(Debug, Clone)]
+pub struct PaintShadow {
+    /// The rectangle casting the shadow.
+    pub rect: Rect,
+
+    /// The corner radius of the rectangle casting the shadow.
+    pub radius: f32,
+
+    /// The color of the shadow at its most opaque.
+    pub color: Color,
+
+    /// How far the shadow is moved from the rectangle casting it.
+    pub offset: Vec2,
+
+    /// The distance over which the edge of the shadow fades out.
+    pub blur_radius: f32,
+
+    /// How far the shadow is grown past the rectangle casting it, before it
+    /// is blurred. Negative values shrink the shadow.
+    pub spread: f32,
+}
+
+impl PaintShadow {
+    /// Create a new `PaintShadow` for the given rectangle with a translucent
+    /// black color, no offset, and a small blur.
+    pub fn new(rect: Rect) -> Self {
+        Self {
+            rect,
+            radius: 0.0,
+            color: Color::BLACK.with_alpha(0.5),
+            offset: Vec2::ZERO,
+            blur_radius: 8.0,
+            spread: 0.0,
+        }
+    }
+
+    /// Add this shadow to the PaintDom to be drawn this frame.
+    pub fn add(&self, output: &mut PaintDom) {
+        let spread = Vec2::splat(self.spread);
+        let min = self.rect.pos() + self.offset - spread;
+        let max = self.rect.max() + self.offset + spread;
+        let half_size = ((max - min) / 2.0).max(Vec2::ZERO);
+
+        let half_blur = self.blur_radius.max(0.0) / 2.0;
+
+        // A blurred corner is always round, even when the shape casting it
+        // is not.
+        let corner_radius = self.radius.max(half_blur);
+        let inset = Vec2::splat(corner_radius).min(half_size);
+
+        let inner_radius = corner_radius - half_blur;
+        let outer_radius = corner_radius + half_blur;
+
+        let centers = [
+            (Vec2::new(max.x - inset.x, min.y + inset.y), 0.0),
+            (Vec2::new(min.x + inset.x, min.y + inset.y), TAU / 4.0),
+            (Vec2::new(min.x + inset.x, max.y - inset.y), TAU / 2.0),
+            (Vec2::new(max.x - inset.x, max.y - inset.y), 3.0 * TAU / 4.0),
+        ];
+
+        let directions: Vec<(Vec2, Vec2)> = centers
+            .into_iter()
+            .flat_map(|(center, start_angle)| {
+                (0..=CORNER_SLICES).map(move |i| {
+                    let angle = start_angle + (i as f32 / CORNER_SLICES as f32) * TAU / 4.0;
+                    (center, Vec2::new(angle.cos(), -angle.sin()))
+                })
+            })
+            .collect();
+
+        let solid = self.color.to_linear();
+        let clear = solid.truncate().extend(0.0);
+
+        let inner = directions
+            .iter()
+            .map(|&(center, dir)| Vertex::new(center + dir * inner_radius, [0.0, 0.0], solid));
+        let outer = directions
+            .iter()
+            .map(|&(center, dir)| Vertex::new(center + dir * outer_radius, [0.0, 0.0], clear));
+        let vertices: Vec<Vertex> = inner.chain(outer).collect();
+
+        let count = directions.len() as u32;
+        let mut indices = Vec::with_capacity(directions.len() * 9);
+
+        // The solid core of the shadow is convex, so it can be filled with a
+        // fan of triangles.
+        for i in 1..count - 1 {
+            indices.extend_from_slice(&[0, i, i + 1]);
+        }
+
+        // The blurred edge is a ring of quads fading from the core outward.
+        for i in 0..count {
+            let j = (i + 1) % count;
+            indices.extend_from_slice(&[i, count + i, count + j, count + j, j, i]);
+        }
+
+        output.add_mesh(PaintMesh::new(vertices, indices));
+    }
+}
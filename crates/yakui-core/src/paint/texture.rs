@@ -1,5 +1,7 @@
 use glam::UVec2;
 
+use crate::geometry::URect;
+
 /// A texture that is managed by yakui.
 #[derive(Clone)]
 pub struct Texture {
@@ -15,6 +17,15 @@ pub struct Texture {
 
     /// How to handle texture addressing
     pub address_mode: AddressMode,
+
+    /// Whether yakui may pack this texture into a shared atlas with other
+    /// small textures, which lets widgets that draw lots of icons do it in
+    /// fewer draw calls. Only small textures using
+    /// [`AddressMode::ClampToEdge`] are packed, and only when they're added.
+    ///
+    /// Texture coordinates outside of `[0, 1]` would sample neighboring
+    /// textures in the atlas, so textures drawn that way should turn this off.
+    pub allow_atlas: bool,
 }
 
 impl std::fmt::Debug for Texture {
@@ -25,6 +36,7 @@ impl std::fmt::Debug for Texture {
             .field("min_filter", &self.min_filter)
             .field("mag_filter", &self.mag_filter)
             .field("address_mode", &self.address_mode)
+            .field("allow_atlas", &self.allow_atlas)
             .finish_non_exhaustive()
     }
 }
@@ -44,6 +56,16 @@ pub enum TextureFormat {
     R8,
 }
 
+impl TextureFormat {
+    /// How many bytes each pixel takes up in this format.
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            TextureFormat::Rgba8Srgb | TextureFormat::Rgba8SrgbPremultiplied => 4,
+            TextureFormat::R8 => 1,
+        }
+    }
+}
+
 /// Which kind of filtering to use when scaling the texture.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TextureFilter {
@@ -74,6 +96,7 @@ impl Texture {
             min_filter: TextureFilter::Nearest,
             mag_filter: TextureFilter::Linear,
             address_mode: AddressMode::ClampToEdge,
+            allow_atlas: true,
         }
     }
 
@@ -109,4 +132,8 @@ pub enum TextureChange {
 
     /// The texture was modified since the last update.
     Modified,
+
+    /// Only part of the texture was modified since the last update, so
+    /// renderers only need to upload the pixels inside of the given region.
+    ModifiedRegion(URect),
 }
@@ -0,0 +1,233 @@
+//! Captures the structure and layout of the DOM as a compact text report.
+//!
+//! Snapshots only record each widget's type name and layout rect, never props
+//! or state, so they can be attached to crash and bug reports from players
+//! without leaking anything they typed. Maintainers can load a report back
+//! with [`DomSnapshot::parse`] to see how the UI was laid out.
+
+use std::fmt;
+
+use crate::dom::Dom;
+use crate::geometry::{Rect, Vec2};
+use crate::id::WidgetId;
+use crate::layout::LayoutDom;
+
+const HEADER: &str = "yakui-snapshot 1";
+
+/// The structure and layout of a DOM at one point in time.
+///
+/// Converting a snapshot to a string with [`ToString`] produces a report with
+/// one line per widget, which [`DomSnapshot::parse`] reads back.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomSnapshot {
+    /// The viewport the DOM was laid out in, in logical pixels.
+    pub viewport: Rect,
+
+    /// The scale factor the DOM was laid out with.
+    pub scale_factor: f32,
+
+    /// The root of the DOM.
+    pub root: SnapshotNode,
+}
+
+/// A widget recorded in a [`DomSnapshot`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotNode {
+    /// The full type name of the widget, like
+    /// `yakui_widgets::widgets::list::ListWidget`.
+    pub type_name: String,
+
+    /// Where the widget was laid out, in logical pixels. This is `None` for
+    /// widgets that had no layout, like ones that were culled.
+    pub rect: Option<Rect>,
+
+    /// The widget's children, in order.
+    pub children: Vec<SnapshotNode>,
+}
+
+impl DomSnapshot {
+    /// Records the current structure of the DOM, along with the most recently
+    /// calculated layout.
+    pub fn capture(dom: &Dom, layout: &LayoutDom) -> Self {
+        Self {
+            viewport: layout.viewport(),
+            scale_factor: layout.scale_factor(),
+            root: SnapshotNode::capture(dom, layout, dom.root()),
+        }
+    }
+
+    /// Reads a snapshot back from a report. Returns `None` if the report is
+    /// malformed or was written by an incompatible version of yakui.
+    pub fn parse(report: &str) -> Option<Self> {
+        let mut lines = report.lines().filter(|line| !line.trim().is_empty());
+
+        if lines.next()?.trim() != HEADER {
+            return None;
+        }
+
+        let (viewport, scale_factor) = {
+            let mut parts = lines.next()?.strip_prefix("viewport ")?.split(' ');
+            let rect = parse_rect(&mut parts)?;
+            let scale_factor = parts.next()?.parse().ok()?;
+            (rect, scale_factor)
+        };
+
+        // Nodes are written depth-first with two spaces of indentation per
+        // level, so each node's parent is the nearest shallower node before it.
+        let mut stack: Vec<SnapshotNode> = Vec::new();
+
+        for line in lines {
+            let content = line.trim_start_matches(' ');
+            let depth = (line.len() - content.len()) / 2;
+            let node = SnapshotNode::parse(content)?;
+
+            // There can only be one root, and nodes can't skip levels.
+            if depth > stack.len() || (depth == 0 && !stack.is_empty()) {
+                return None;
+            }
+
+            close_nodes(&mut stack, depth);
+            stack.push(node);
+        }
+
+        close_nodes(&mut stack, 1);
+        let root = stack.pop()?;
+
+        Some(Self {
+            viewport,
+            scale_factor,
+            root,
+        })
+    }
+}
+
+impl fmt::Display for DomSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+
+        let pos = self.viewport.pos();
+        let size = self.viewport.size();
+        writeln!(
+            f,
+            "viewport {} {} {} {} {}",
+            pos.x, pos.y, size.x, size.y, self.scale_factor
+        )?;
+
+        self.root.write(f, 0)
+    }
+}
+
+impl SnapshotNode {
+    fn capture(dom: &Dom, layout: &LayoutDom, id: WidgetId) -> Self {
+        let node = dom.get(id).unwrap();
+
+        Self {
+            type_name: node.widget.type_name().to_owned(),
+            rect: layout.get(id).map(|node| node.rect),
+            children: node
+                .children
+                .iter()
+                .map(|&child| Self::capture(dom, layout, child))
+                .collect(),
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        for _ in 0..depth {
+            f.write_str("  ")?;
+        }
+
+        f.write_str(&self.type_name)?;
+
+        match self.rect {
+            Some(rect) => {
+                let (pos, size) = (rect.pos(), rect.size());
+                writeln!(f, " {} {} {} {}", pos.x, pos.y, size.x, size.y)?;
+            }
+            None => f.write_str(" -\n")?,
+        }
+
+        for child in &self.children {
+            child.write(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Parses a line without its indentation. Type names can contain spaces,
+    /// like in generic parameters, so the rect is read from the end.
+    fn parse(line: &str) -> Option<Self> {
+        let (rest, last) = line.rsplit_once(' ')?;
+
+        let (type_name, rect) = if last == "-" {
+            (rest, None)
+        } else {
+            let parts: Vec<&str> = line.rsplitn(5, ' ').collect();
+            if parts.len() != 5 {
+                return None;
+            }
+
+            let rect = parse_rect(&mut parts[..4].iter().rev().copied())?;
+            (parts[4], Some(rect))
+        };
+
+        if type_name.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            type_name: type_name.to_owned(),
+            rect,
+            children: Vec::new(),
+        })
+    }
+
+    /// A shorter version of the type name without its module path, which is
+    /// easier to read in tools.
+    pub fn short_name(&self) -> String {
+        short_type_name(&self.type_name)
+    }
+}
+
+/// Strips the module path from each type in a type name, like
+/// `yakui_widgets::widgets::list::ListWidget` becoming `ListWidget`.
+pub(crate) fn short_type_name(type_name: &str) -> String {
+    let mut short = String::new();
+    let mut segment = String::new();
+
+    // Strip the path from each type in the name separately, which keeps
+    // generic parameters readable.
+    for c in type_name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            segment.push(c);
+        } else {
+            short.push_str(segment.rsplit("::").next().unwrap_or(""));
+            segment.clear();
+            short.push(c);
+        }
+    }
+
+    short.push_str(segment.rsplit("::").next().unwrap_or(""));
+    short
+}
+
+/// Pops nodes off the stack until it's the given length, adding each one to
+/// its parent.
+fn close_nodes(stack: &mut Vec<SnapshotNode>, len: usize) {
+    while stack.len() > len {
+        let child = stack.pop().unwrap();
+        stack.last_mut().unwrap().children.push(child);
+    }
+}
+
+fn parse_rect<'a>(parts: &mut impl Iterator<Item = &'a str>) -> Option<Rect> {
+    let mut fields = [0.0; 4];
+    for field in &mut fields {
+        *field = parts.next()?.parse().ok()?;
+    }
+
+    Some(Rect::from_pos_size(
+        Vec2::new(fields[0], fields[1]),
+        Vec2::new(fields[2], fields[3]),
+    ))
+}
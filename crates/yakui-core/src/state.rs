@@ -1,11 +1,16 @@
+use std::time::Duration;
+
 use crate::context;
 use crate::dom::Dom;
 use crate::event::{Event, EventResponse};
-use crate::geometry::{Rect, Vec2};
+use crate::geometry::{Rect, URect, Vec2};
 use crate::id::ManagedTextureId;
-use crate::input::InputState;
+use crate::input::{CursorIcon, InputState, PlayerId};
+use crate::inspect::Inspection;
 use crate::layout::LayoutDom;
-use crate::paint::{PaintDom, PaintLimits, Texture};
+use crate::paint::{PaintDebug, PaintDom, PaintLimits, Texture};
+use crate::snapshot::DomSnapshot;
+use crate::types::LayoutDirection;
 
 /// The entrypoint for yakui.
 #[derive(Debug)]
@@ -14,6 +19,128 @@ pub struct Yakui {
     layout: LayoutDom,
     paint: PaintDom,
     input: InputState,
+
+    frame: FrameInfo,
+    stats: FrameStats,
+    input_received: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    build_started: Option<std::time::Instant>,
+}
+
+/// Describes the most recently built frame, for hosts that want to skip
+/// building frames when nothing has changed, or schedule them around a
+/// variable refresh rate display.
+///
+/// Returned by [`Yakui::frame_info`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FrameInfo {
+    /// The time the frame was built at, as given to [`Yakui::set_time`].
+    pub time: Duration,
+
+    /// How much time passed since the previous frame, according to
+    /// [`Yakui::set_time`]. Zero for the first frame.
+    pub delta_time: Duration,
+
+    /// How long it took to build the frame and lay it out, from
+    /// [`Yakui::start`] to the end of [`Yakui::finish`]. This isn't measured
+    /// on the web, where there's no monotonic clock in the standard library.
+    pub build_time: Option<Duration>,
+
+    /// Whether any events were passed to [`Yakui::handle_event`] since the
+    /// previous frame.
+    pub input_received: bool,
+
+    /// Whether any widgets were created, replaced, or removed while building
+    /// the frame.
+    pub structure_changed: bool,
+
+    /// The earliest time a widget asked to be updated at, using
+    /// [`Dom::request_repaint`] or [`Dom::request_repaint_at`].
+    pub repaint_requested: Option<Duration>,
+
+    /// What the user was doing with the UI during the frame, like typing into
+    /// a text box. Games can use this to pause, duck audio, or stop treating
+    /// keys as game controls while the UI has the user's attention.
+    pub activity: UiActivity,
+}
+
+/// Measurements of the most recent frame, for graphing in a performance HUD.
+///
+/// Returned by [`Yakui::frame_stats`]. The DOM and layout fields are updated
+/// by [`Yakui::finish`], and the paint fields by [`Yakui::paint`]. Times
+/// aren't measured on the web, where there's no monotonic clock in the
+/// standard library.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameStats {
+    /// How many widgets are in the DOM, including the root.
+    pub widgets: usize,
+
+    /// How many widgets were created while building the frame, including ones
+    /// that replaced a widget of a different type.
+    pub widgets_created: usize,
+
+    /// How many widgets were removed because they weren't built this frame.
+    pub widgets_removed: usize,
+
+    /// How long it took to lay out the frame.
+    pub layout_time: Option<Duration>,
+
+    /// How long the most recent call to [`Yakui::paint`] took.
+    pub paint_time: Option<Duration>,
+
+    /// How many draw calls were painted.
+    pub paint_calls: usize,
+
+    /// How many vertices were painted, across all draw calls.
+    pub vertices: usize,
+
+    /// How many indices were painted, across all draw calls.
+    pub indices: usize,
+
+    /// How many bytes of texture data renderers need to keep uploaded for
+    /// the textures managed by yakui, like the font atlas.
+    pub texture_bytes: usize,
+}
+
+bitflags::bitflags! {
+    /// A summary of what the user is doing with the UI, built up each frame
+    /// by widgets calling [`Dom::report_activity`].
+    ///
+    /// Returned as part of [`FrameInfo`].
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy, Default)]
+    pub struct UiActivity: u8 {
+        /// Something is blocking interaction with the rest of the UI, like a
+        /// dialog. yakui doesn't have any modal widgets of its own, so
+        /// applications with their own dialogs should report this.
+        const MODAL = 1;
+
+        /// A widget that accepts typed text is focused, so key presses are
+        /// meant for it.
+        const TEXT_INPUT = 2;
+
+        /// A popup like a dropdown or context menu is open.
+        const POPUP = 4;
+
+        /// The user is moving focus between widgets with a keyboard or
+        /// gamepad.
+        const NAVIGATING = 8;
+    }
+}
+
+impl FrameInfo {
+    /// Suggests when the next frame should be built, using the same clock as
+    /// [`Yakui::set_time`]. If this returns `None`, nothing in the UI will
+    /// change until the host receives new input or changes its own state.
+    ///
+    /// Frames that received input or changed the DOM ask for another frame
+    /// right away, since widgets often respond to changes one frame later.
+    pub fn next_repaint(&self) -> Option<Duration> {
+        if self.input_received || self.structure_changed {
+            return Some(self.time);
+        }
+
+        self.repaint_requested
+    }
 }
 
 impl Yakui {
@@ -25,6 +152,12 @@ impl Yakui {
             layout: LayoutDom::new(),
             paint: PaintDom::new(),
             input: InputState::new(),
+
+            frame: FrameInfo::default(),
+            stats: FrameStats::default(),
+            input_received: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            build_started: None,
         }
     }
 
@@ -35,6 +168,7 @@ impl Yakui {
 
         context::bind_dom(&self.dom);
 
+        self.input_received = true;
         let response = self.input.handle_event(&self.dom, &self.layout, &event);
 
         if let Event::ViewportChanged(viewport) = event {
@@ -45,12 +179,55 @@ impl Yakui {
         response == EventResponse::Sink
     }
 
+    /// Handles an event sent by one of several players sharing the UI, like
+    /// from a specific gamepad in a split-screen game. Returns `true` if the
+    /// event was sunk by yakui and should not be processed by the application.
+    ///
+    /// Keyboard events go to the widget selected by that player instead of
+    /// the shared selection, and widgets limited to other players with
+    /// [`LayoutDom::set_player`] don't receive the event at all. Events sent
+    /// with [`Yakui::handle_event`] still go to every widget.
+    pub fn handle_player_event(&mut self, player: PlayerId, event: Event) -> bool {
+        log::debug!("State::handle_player_event({player:?}, {event:?})");
+
+        context::bind_dom(&self.dom);
+
+        self.input_received = true;
+        let response = self
+            .input
+            .handle_player_event(&self.dom, &self.layout, player, &event);
+
+        context::unbind_dom();
+        response == EventResponse::Sink
+    }
+
     /// Creates a texture for use within yakui.
     pub fn add_texture(&mut self, texture: Texture) -> ManagedTextureId {
         self.paint.add_texture(texture)
     }
 
-    /// Returns an iterator of all textures managed by yakui.
+    /// Returns a mutable reference to a texture managed by yakui. Changes
+    /// must be reported with [`Yakui::mark_texture_modified`] or
+    /// [`Yakui::mark_texture_region_modified`] for renderers to pick them up.
+    pub fn texture_mut(&mut self, id: ManagedTextureId) -> Option<&mut Texture> {
+        self.paint.texture_mut(id)
+    }
+
+    /// Tells renderers that a texture's contents changed and need to be
+    /// uploaded again.
+    pub fn mark_texture_modified(&mut self, id: ManagedTextureId) {
+        self.paint.mark_texture_modified(id);
+    }
+
+    /// Tells renderers that part of a texture's contents changed. For
+    /// textures that change every frame, like video, this avoids uploading the
+    /// parts that stayed the same.
+    pub fn mark_texture_region_modified(&mut self, id: ManagedTextureId, region: URect) {
+        self.paint.mark_texture_region_modified(id, region);
+    }
+
+    /// Returns an iterator of all textures managed by yakui that renderers need
+    /// to upload. Small textures packed into a shared atlas are left out.
     pub fn textures(&self) -> impl Iterator<Item = (ManagedTextureId, &Texture)> {
         self.paint.textures()
     }
@@ -76,6 +253,32 @@ impl Yakui {
         self.paint.set_scale_factor(factor);
     }
 
+    /// Sets the reading direction of the UI. When set to
+    /// [`LayoutDirection::RightToLeft`], layouts like lists and padding are
+    /// mirrored horizontally.
+    pub fn set_layout_direction(&mut self, direction: LayoutDirection) {
+        self.layout.set_layout_direction(direction);
+    }
+
+    /// Sets the current time, measured from any fixed point like the start of
+    /// the application. This should be called every frame before
+    /// [`Yakui::start`] so that widgets like timers can tell how much time has
+    /// passed.
+    ///
+    /// While building a frame, widgets can read the time with [`Dom::time`]
+    /// and the time since the previous frame with [`Dom::delta_time`].
+    pub fn set_time(&mut self, time: Duration) {
+        // Before the first call, there's no previous frame to measure from.
+        let previous = self.dom.time();
+        let delta_time = if previous.is_zero() {
+            Duration::ZERO
+        } else {
+            time.saturating_sub(previous)
+        };
+
+        self.dom.set_time(time, delta_time);
+    }
+
     /// Starts building the DOM on this thread.
     ///
     /// Once this method is called, widgets can be created on this thread and
@@ -83,6 +286,11 @@ impl Yakui {
     ///
     /// When finished, call [`Dom::finish`].
     pub fn start(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.build_started = Some(std::time::Instant::now());
+        }
+
         self.dom.start();
         self.input.start(&self.dom, &self.layout);
         self.paint.start();
@@ -98,19 +306,74 @@ impl Yakui {
         context::unbind_dom();
 
         self.dom.finish(&self.input);
+        if self.input.focus_visible() {
+            self.dom.report_activity(UiActivity::NAVIGATING);
+        }
+        let activity = self.dom.activity();
+
         self.layout.sync_removals(&self.dom.removed_nodes());
-        self.layout.calculate_all(&self.dom, &self.input);
+        let ((), layout_time) = timed(|| self.layout.calculate_all(&self.dom, &self.input));
         self.input.finish();
+
+        self.stats.widgets = self.dom.len();
+        self.stats.widgets_created = self.dom.widgets_created();
+        self.stats.widgets_removed = self.dom.widgets_removed();
+        self.stats.layout_time = layout_time;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let build_time = self.build_started.take().map(|start| start.elapsed());
+        #[cfg(target_arch = "wasm32")]
+        let build_time = None;
+
+        self.frame = FrameInfo {
+            time: self.dom.time(),
+            delta_time: self.dom.delta_time(),
+            build_time,
+            input_received: std::mem::take(&mut self.input_received),
+            structure_changed: self.dom.structure_changed(),
+            repaint_requested: self.dom.repaint_requested(),
+            activity,
+        };
+    }
+
+    /// Returns information about the most recently finished frame, which
+    /// hosts can use to decide when to build the next one.
+    pub fn frame_info(&self) -> &FrameInfo {
+        &self.frame
+    }
+
+    /// Returns the cursor icon that widgets want shown for where the mouse
+    /// currently is. Hosts should apply it to the window every frame.
+    pub fn cursor_icon(&self) -> CursorIcon {
+        self.input.cursor_icon()
     }
 
     /// Calculates the geometry needed to render the current state and gives
     /// access to the [`PaintDom`], which holds information about how to paint
     /// widgets.
     pub fn paint(&mut self) -> &PaintDom {
-        self.paint.paint_all(&self.dom, &self.layout);
+        let ((), paint_time) = timed(|| self.paint.paint_all(&self.dom, &self.layout));
+
+        let paint_stats = self.paint.stats();
+        self.stats.paint_time = paint_time;
+        self.stats.paint_calls = paint_stats.calls;
+        self.stats.vertices = paint_stats.vertices;
+        self.stats.indices = paint_stats.indices;
+        self.stats.texture_bytes = self
+            .paint
+            .textures()
+            .map(|(_, texture)| texture.data().len())
+            .sum();
+
         &self.paint
     }
 
+    /// Returns measurements of the most recent frame, like how many widgets
+    /// it had and how long it took to lay out and paint.
+    pub fn frame_stats(&self) -> &FrameStats {
+        &self.stats
+    }
+
     /// Returns access to the state's DOM.
     pub fn dom(&self) -> &Dom {
         &self.dom
@@ -121,8 +384,42 @@ impl Yakui {
         &self.layout
     }
 
+    /// Records the structure and layout of the most recently finished frame,
+    /// for attaching to bug reports. See [`DomSnapshot`] for details.
+    pub fn snapshot(&self) -> DomSnapshot {
+        DomSnapshot::capture(&self.dom, &self.layout)
+    }
+
+    /// Records everything about the most recently finished frame's widgets
+    /// and their layout, for debugging tools and tests. See [`Inspection`]
+    /// for details.
+    pub fn inspect(&self) -> Inspection {
+        Inspection::capture(&self.dom, &self.layout)
+    }
+
     /// Sets the paint limits, should be called once by rendering backends.
     pub fn set_paint_limit(&mut self, limits: PaintLimits) {
         self.paint.set_limit(limits)
     }
+
+    /// Turns paint debugging aids like wireframes and batching statistics on
+    /// or off. See [`PaintDebug`] for details.
+    pub fn set_paint_debug(&mut self, debug: PaintDebug) {
+        self.paint.set_debug(debug)
+    }
+}
+
+/// Runs the given function, measuring how long it took where that's possible.
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Option<Duration>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let start = std::time::Instant::now();
+        let output = f();
+        (output, Some(start.elapsed()))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        (f(), None)
+    }
 }
@@ -0,0 +1,151 @@
+use std::cell::{Ref, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::rc::Rc;
+
+use crate::id::WidgetId;
+
+/// A piece of application state that is shared with widgets.
+///
+/// Stores are registered with [`Dom::add_store`][crate::dom::Dom::add_store]
+/// and can be fetched from anywhere with access to the DOM. Widgets read from
+/// a store through named selectors using [`Store::select`], which records
+/// which widgets read which fields. Writes made with [`Store::update`] mark
+/// fields as changed so that the widgets reading them can be found with
+/// [`Store::take_invalidated`].
+pub struct Store<T> {
+    inner: Rc<StoreInner<T>>,
+}
+
+struct StoreInner<T> {
+    value: RefCell<T>,
+    subscriptions: RefCell<Subscriptions>,
+}
+
+#[derive(Default)]
+struct Subscriptions {
+    readers: HashMap<&'static str, HashSet<WidgetId>>,
+    changed: HashSet<&'static str>,
+}
+
+impl<T: 'static> Store<T> {
+    /// Creates a new store containing the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Rc::new(StoreInner {
+                value: RefCell::new(value),
+                subscriptions: RefCell::new(Subscriptions::default()),
+            }),
+        }
+    }
+
+    /// Reads part of the store's value. If called while a widget is being
+    /// updated or handling an event, that widget is recorded as a reader of
+    /// the given field.
+    pub fn select<R, F>(&self, field: &'static str, selector: F) -> R
+    where
+        F: FnOnce(&T) -> R,
+    {
+        if let Some(id) = crate::context::current_widget() {
+            let mut subscriptions = self.inner.subscriptions.borrow_mut();
+            subscriptions.readers.entry(field).or_default().insert(id);
+        }
+
+        selector(&self.inner.value.borrow())
+    }
+
+    /// Modifies the store's value and marks the given field as changed.
+    pub fn update<F>(&self, field: &'static str, update: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        update(&mut self.inner.value.borrow_mut());
+
+        let mut subscriptions = self.inner.subscriptions.borrow_mut();
+        subscriptions.changed.insert(field);
+    }
+
+    /// Borrows the store's whole value without recording any reads.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        self.inner.value.borrow()
+    }
+
+    /// Returns all of the widgets that read the given field during the most
+    /// recent update.
+    pub fn readers(&self, field: &str) -> Vec<WidgetId> {
+        let subscriptions = self.inner.subscriptions.borrow();
+        subscriptions
+            .readers
+            .get(field)
+            .map(|readers| readers.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns all of the fields that have been marked as changed since the
+    /// last call to [`Store::take_invalidated`].
+    pub fn changed_fields(&self) -> Vec<&'static str> {
+        let subscriptions = self.inner.subscriptions.borrow();
+        subscriptions.changed.iter().copied().collect()
+    }
+
+    /// Returns all of the widgets that read a field that has changed, then
+    /// clears the set of changed fields.
+    ///
+    /// Readers are recorded fresh each update, so this is best called between
+    /// updates, before [`Yakui::start`][crate::Yakui::start].
+    pub fn take_invalidated(&self) -> Vec<WidgetId> {
+        let mut subscriptions = self.inner.subscriptions.borrow_mut();
+        let changed = std::mem::take(&mut subscriptions.changed);
+        let mut invalidated = HashSet::new();
+
+        for field in changed {
+            if let Some(readers) = subscriptions.readers.get(field) {
+                invalidated.extend(readers.iter().copied());
+            }
+        }
+
+        invalidated.into_iter().collect()
+    }
+}
+
+impl<T> Clone for Store<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Store<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Store")
+            .field("value", &self.inner.value)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Type-erased access to a store's subscriptions, used by the DOM to keep
+/// them in sync with the tree.
+pub(crate) trait ErasedStore {
+    /// Called when the DOM starts an update. Readers are recorded fresh each
+    /// update.
+    fn start(&self);
+
+    /// Drops any readers that are no longer in the DOM.
+    fn retain_readers(&self, is_alive: &dyn Fn(WidgetId) -> bool);
+}
+
+impl<T> ErasedStore for Store<T> {
+    fn start(&self) {
+        let mut subscriptions = self.inner.subscriptions.borrow_mut();
+        subscriptions.readers.clear();
+    }
+
+    fn retain_readers(&self, is_alive: &dyn Fn(WidgetId) -> bool) {
+        let mut subscriptions = self.inner.subscriptions.borrow_mut();
+
+        for readers in subscriptions.readers.values_mut() {
+            readers.retain(|&id| is_alive(id));
+        }
+    }
+}
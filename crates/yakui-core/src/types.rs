@@ -100,7 +100,11 @@ pub enum MainAxisAlignItems {
 ///
 /// For example, a horizontal list's cross axis is vertical, and a vertical
 /// list's cross axis is horizontal.
+///
+/// More alignments may be added in the future, so matches on this enum outside
+/// of yakui need a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum CrossAxisAlignment {
     /// Align items to the beginning of the container's cross axis.
     ///
@@ -121,6 +125,25 @@ pub enum CrossAxisAlignment {
 
     /// Stretch items to fill the maximum size of the container's cross axis.
     Stretch,
+
+    /// Line up the baseline of the first line of text in each item. Items
+    /// without any text are aligned by their bottom edge.
+    ///
+    /// Only horizontal lists support baseline alignment. Other containers
+    /// treat this like [`CrossAxisAlignment::Start`].
+    Baseline,
+}
+
+/// Defines the reading direction of the UI. Layouts like lists and padding are
+/// mirrored horizontally when laid out right-to-left.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LayoutDirection {
+    /// Content starts on the left, like in English.
+    #[default]
+    LeftToRight,
+
+    /// Content starts on the right, like in Arabic or Hebrew.
+    RightToLeft,
 }
 
 /// Defines the direction that a container will lay out its children.
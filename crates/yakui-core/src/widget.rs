@@ -8,10 +8,10 @@ use glam::Vec2;
 use crate::dom::Dom;
 use crate::event::EventResponse;
 use crate::event::{EventInterest, WidgetEvent};
-use crate::geometry::{Constraints, FlexFit};
-use crate::input::{InputState, NavDirection};
+use crate::geometry::{Color, Constraints, FlexFit};
+use crate::input::{CursorIcon, InputState, NavDirection, TabIndex};
 use crate::layout::LayoutDom;
-use crate::paint::PaintDom;
+use crate::paint::{PaintDom, PaintPath};
 use crate::{Flow, WidgetId};
 
 /// Trait that's automatically implemented for all widget props.
@@ -52,6 +52,16 @@ impl<'dom> PaintContext<'dom> {
     pub fn paint(&mut self, widget: WidgetId) {
         self.paint.paint(self.dom, self.layout, widget);
     }
+
+    /// Fill the inside of the given path with a color.
+    pub fn fill_path(&mut self, path: &PaintPath, color: Color) {
+        path.fill(self.paint, color);
+    }
+
+    /// Draw a line of the given width along the given path.
+    pub fn stroke_path(&mut self, path: &PaintPath, width: f32, color: Color) {
+        path.stroke(self.paint, width, color);
+    }
 }
 
 /// Information available to a widget when it has received an event.
@@ -62,6 +72,43 @@ pub struct EventContext<'dom> {
     pub input: &'dom InputState,
 }
 
+impl<'dom> EventContext<'dom> {
+    /// Converts a position from an event, given in logical pixels relative to
+    /// the viewport, into the current widget's local space, where the top-left
+    /// corner of its layout rectangle is the origin. Transforms applied by
+    /// the widget's ancestors are undone.
+    pub fn to_local(&self, position: Vec2) -> Vec2 {
+        let id = self.dom.current();
+        let origin = self
+            .layout
+            .get(id)
+            .map(|node| node.rect.pos())
+            .unwrap_or(Vec2::ZERO);
+
+        self.layout.untransform_point(id, position) - origin
+    }
+
+    /// Returns the position of the mouse cursor in logical pixels relative to
+    /// the viewport, or `None` if the cursor is outside the window.
+    pub fn mouse_position(&self) -> Option<Vec2> {
+        self.input
+            .raw_mouse_position()
+            .map(|pos| pos / self.layout.scale_factor())
+    }
+
+    /// Returns the position of the mouse cursor in the current widget's local
+    /// space. See [`EventContext::to_local`].
+    pub fn local_mouse_position(&self) -> Option<Vec2> {
+        self.mouse_position().map(|pos| self.to_local(pos))
+    }
+
+    /// Sets the cursor icon to show while the mouse is over the current
+    /// widget. See [`InputState::set_cursor_icon`].
+    pub fn set_cursor_icon(&self, icon: CursorIcon) {
+        self.input.set_cursor_icon(self.dom.current(), icon);
+    }
+}
+
 /// Information available to a widget when it is being queried for navigation.
 #[allow(missing_docs)]
 pub struct NavigateContext<'dom> {
@@ -148,6 +195,34 @@ pub trait Widget: 'static + fmt::Debug {
         }
     }
 
+    /// Tells whether anything that affects this widget's layout or event
+    /// interest changed since it was last laid out, not counting its
+    /// children.
+    ///
+    /// If this returns `false` for a widget and all of its descendants, and
+    /// the widget is given the same constraints as last frame, yakui reuses
+    /// its previous layout instead of calling [`Widget::layout`] for any of
+    /// them. Widgets that return `false` must not have any other side effects
+    /// in `layout` that need to happen every frame.
+    ///
+    /// The default implementation returns `true`, so widgets are always laid
+    /// out unless they opt in.
+    fn needs_layout(&self) -> bool {
+        true
+    }
+
+    /// Tells whether this widget or its children may paint outside of this
+    /// widget's layout rectangle.
+    ///
+    /// Widgets whose rectangle is entirely clipped or off-screen are skipped
+    /// during painting, along with all of their children, unless this returns
+    /// `true`.
+    ///
+    /// The default implementation returns `false`.
+    fn paints_outside_rect(&self) -> bool {
+        false
+    }
+
     /// Tells which events the widget is interested in receiving.
     ///
     /// The default implementation will register interest in no events.
@@ -165,10 +240,23 @@ pub trait Widget: 'static + fmt::Debug {
 
     /// Tell which widget should be navigated to if the user navigates in a
     /// given direction.
+    ///
+    /// This is asked of the selected widget and then each of its ancestors,
+    /// so containers like grids or menus can override how the selection moves
+    /// through their children. Returning `None` falls back to picking the
+    /// nearest focusable widget in that direction.
     #[allow(unused)]
     fn navigate(&self, ctx: NavigateContext<'_>, dir: NavDirection) -> Option<WidgetId> {
         None
     }
+
+    /// Tell where this widget falls in the order that Tab moves the selection
+    /// through. Only matters for widgets with [`EventInterest::FOCUS`].
+    ///
+    /// The default implementation returns [`TabIndex::Auto`].
+    fn tab_index(&self) -> TabIndex {
+        TabIndex::Auto
+    }
 }
 
 /// A type-erased version of [`Widget`].
@@ -185,12 +273,24 @@ pub trait ErasedWidget: Any + fmt::Debug {
     /// See [`Widget::paint`].
     fn paint(&self, ctx: PaintContext<'_>);
 
+    /// See [`Widget::needs_layout`].
+    fn needs_layout(&self) -> bool;
+
+    /// See [`Widget::paints_outside_rect`].
+    fn paints_outside_rect(&self) -> bool;
+
     /// See [`Widget::event_interest`].
     fn event_interest(&self) -> EventInterest;
 
     /// See [`Widget::event`].
     fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse;
 
+    /// See [`Widget::navigate`].
+    fn navigate(&self, ctx: NavigateContext<'_>, dir: NavDirection) -> Option<WidgetId>;
+
+    /// See [`Widget::tab_index`].
+    fn tab_index(&self) -> TabIndex;
+
     /// Returns the type name of the widget, usable only for debugging.
     fn type_name(&self) -> &'static str;
 }
@@ -215,6 +315,14 @@ where
         <T as Widget>::paint(self, ctx)
     }
 
+    fn needs_layout(&self) -> bool {
+        <T as Widget>::needs_layout(self)
+    }
+
+    fn paints_outside_rect(&self) -> bool {
+        <T as Widget>::paints_outside_rect(self)
+    }
+
     fn event_interest(&self) -> EventInterest {
         <T as Widget>::event_interest(self)
     }
@@ -225,6 +333,14 @@ where
         <T as Widget>::event(self, ctx, event)
     }
 
+    fn navigate(&self, ctx: NavigateContext<'_>, dir: NavDirection) -> Option<WidgetId> {
+        <T as Widget>::navigate(self, ctx, dir)
+    }
+
+    fn tab_index(&self) -> TabIndex {
+        <T as Widget>::tab_index(self)
+    }
+
     fn type_name(&self) -> &'static str {
         type_name::<T>()
     }
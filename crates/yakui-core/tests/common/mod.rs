@@ -0,0 +1,318 @@
+//! Widgets and helpers shared between the test files. Each test file only
+//! uses some of them.
+
+#![allow(dead_code)]
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use glam::{Affine2, Vec2};
+use yakui_core::event::{Event, EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Constraints, Rect};
+use yakui_core::input::MouseButton;
+use yakui_core::paint::PaintRect;
+use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
+use yakui_core::Yakui;
+
+/// A count shared between a widget and the test that built it.
+#[derive(Debug, Clone, Default)]
+pub struct Counter(Rc<Cell<usize>>);
+
+impl Counter {
+    pub fn increment(&self) {
+        self.0.set(self.0.get() + 1);
+    }
+
+    pub fn get(&self) -> usize {
+        self.0.get()
+    }
+}
+
+#[derive(Debug)]
+pub struct TestWidget;
+
+impl Widget for TestWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+}
+
+/// Positions its children at a fixed spot, no matter where that is.
+#[derive(Debug)]
+pub struct PlaceWidget {
+    pos: Vec2,
+}
+
+impl Widget for PlaceWidget {
+    type Props<'a> = Vec2;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { pos: Vec2::ZERO }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.pos = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.calculate_layout(child, Constraints::none());
+            ctx.layout.set_pos(child, self.pos);
+        }
+
+        constraints.min
+    }
+}
+
+/// A zero-sized widget that clips its children if its props are true.
+#[derive(Debug)]
+pub struct EmptyClipWidget {
+    clip: bool,
+}
+
+impl Widget for EmptyClipWidget {
+    type Props<'a> = bool;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { clip: false }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.clip = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        if self.clip {
+            ctx.layout.enable_clipping(ctx.dom);
+        }
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.calculate_layout(child, Constraints::none());
+        }
+
+        Vec2::ZERO
+    }
+}
+
+pub type ButtonLog = Rc<RefCell<Vec<(bool, bool, bool)>>>;
+
+/// Records every mouse button event it receives as `(down, inside,
+/// pressed_elsewhere)`.
+#[derive(Debug)]
+pub struct ButtonLogWidget {
+    log: ButtonLog,
+}
+
+impl Widget for ButtonLogWidget {
+    type Props<'a> = ();
+    type Response = ButtonLog;
+
+    fn new() -> Self {
+        Self { log: Rc::default() }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        self.log.clone()
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        Vec2::new(50.0, 50.0)
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        if let WidgetEvent::MouseButtonChanged {
+            down,
+            inside,
+            pressed_elsewhere,
+            ..
+        } = *event
+        {
+            self.log
+                .borrow_mut()
+                .push((down, inside, pressed_elsewhere));
+        }
+
+        EventResponse::Sink
+    }
+}
+
+#[derive(Debug)]
+pub struct KeyboardWidget {
+    count: Counter,
+}
+
+impl Widget for KeyboardWidget {
+    type Props<'a> = ();
+    type Response = Counter;
+
+    fn new() -> Self {
+        Self {
+            count: Counter::default(),
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        self.count.clone()
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::FOCUSED_KEYBOARD
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        ctx.input.set_selection(Some(ctx.dom.current()));
+        Vec2::ZERO
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        if let WidgetEvent::TextInput(..) = event {
+            self.count.increment();
+        }
+
+        EventResponse::Bubble
+    }
+}
+
+/// A small widget that counts how many times it has been painted. Its props
+/// control whether it opts out of culling.
+#[derive(Debug)]
+pub struct PaintCountWidget {
+    count: Counter,
+    paints_outside: bool,
+}
+
+impl Widget for PaintCountWidget {
+    type Props<'a> = bool;
+    type Response = Counter;
+
+    fn new() -> Self {
+        Self {
+            count: Counter::default(),
+            paints_outside: false,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.paints_outside = props;
+        self.count.clone()
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        Vec2::new(10.0, 10.0)
+    }
+
+    fn paints_outside_rect(&self) -> bool {
+        self.paints_outside
+    }
+
+    fn paint(&self, _ctx: PaintContext<'_>) {
+        self.count.increment();
+    }
+}
+
+/// Paints a 10x10 rect at the origin, moved by the transform in its props.
+#[derive(Debug)]
+pub struct TransformedRectWidget {
+    transform: Affine2,
+}
+
+impl Widget for TransformedRectWidget {
+    type Props<'a> = Affine2;
+    type Response = ();
+
+    fn new() -> Self {
+        Self {
+            transform: Affine2::IDENTITY,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.transform = props;
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        ctx.paint.push_transform(self.transform);
+        PaintRect::new(Rect::from_pos_size(Vec2::ZERO, Vec2::splat(10.0))).add(ctx.paint);
+        ctx.paint.pop_transform();
+    }
+}
+
+/// A clickable box that places its children at (5, 5), counting how many
+/// times it's laid out and clicked. Its props tell whether it needs layout.
+#[derive(Debug)]
+pub struct CachedWidget {
+    dirty: bool,
+    layouts: Counter,
+    clicks: Counter,
+}
+
+impl Widget for CachedWidget {
+    type Props<'a> = bool;
+    type Response = (Counter, Counter);
+
+    fn new() -> Self {
+        Self {
+            dirty: false,
+            layouts: Counter::default(),
+            clicks: Counter::default(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.dirty = props;
+        (self.layouts.clone(), self.clicks.clone())
+    }
+
+    fn needs_layout(&self) -> bool {
+        self.dirty
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        self.layouts.increment();
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.calculate_layout(child, Constraints::none());
+            ctx.layout.set_pos(child, Vec2::splat(5.0));
+        }
+
+        Vec2::splat(20.0)
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        if let WidgetEvent::MouseButtonChanged { down: true, .. } = event {
+            self.clicks.increment();
+        }
+
+        EventResponse::Bubble
+    }
+}
+
+pub fn click_at(yak: &mut Yakui, pos: Vec2) {
+    yak.handle_event(Event::CursorMoved(Some(pos)));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: true,
+    });
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: false,
+    });
+}
@@ -0,0 +1,348 @@
+mod common;
+
+use std::time::Duration;
+
+use glam::Vec2;
+use yakui_core::event::{Event, EventInterest};
+use yakui_core::geometry::{Constraints, Rect};
+use yakui_core::snapshot::DomSnapshot;
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{UiActivity, WidgetId, Yakui};
+
+use self::common::{
+    ButtonLogWidget, CachedWidget, EmptyClipWidget, PaintCountWidget, PlaceWidget, TestWidget,
+};
+
+/// https://github.com/LPGhatguy/yakui/issues/69
+#[test]
+fn layout_nodes_leak() {
+    let mut yak = Yakui::new();
+
+    yak.start();
+    yak.dom().do_widget::<TestWidget>(());
+    yak.dom().do_widget::<TestWidget>(());
+    yak.finish();
+
+    let dom = yak.dom();
+    let layout = yak.layout_dom();
+    assert_eq!(dom.len(), 3); // Two of our nodes, plus the root node.
+    assert_eq!(layout.len(), 3);
+
+    yak.start();
+    yak.finish();
+
+    let dom = yak.dom();
+    let layout = yak.layout_dom();
+    assert_eq!(dom.len(), 1); // Just the root node now
+    assert_eq!(layout.len(), 1);
+}
+
+#[derive(Debug)]
+struct RepaintWidget;
+
+impl Widget for RepaintWidget {
+    type Props<'a> = Option<Duration>;
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        if let Some(time) = props {
+            yakui_core::context::dom().request_repaint_at(time);
+        }
+    }
+}
+
+#[test]
+fn frame_info_tracks_changes_and_repaints() {
+    let mut yak = Yakui::new();
+
+    yak.start();
+    yak.dom().do_widget::<RepaintWidget>(None);
+    yak.finish();
+
+    let info = yak.frame_info();
+    assert!(info.structure_changed);
+    assert_eq!(info.next_repaint(), Some(Duration::ZERO));
+
+    // Nothing changed and nothing asked for a repaint, so the host can wait.
+    yak.start();
+    yak.dom().do_widget::<RepaintWidget>(None);
+    yak.finish();
+
+    let info = yak.frame_info();
+    assert!(!info.structure_changed);
+    assert_eq!(info.next_repaint(), None);
+
+    yak.start();
+    yak.dom()
+        .do_widget::<RepaintWidget>(Some(Duration::from_secs(2)));
+    yak.dom()
+        .do_widget::<RepaintWidget>(Some(Duration::from_secs(1)));
+    yak.finish();
+
+    // The second widget is new, so the UI asks for another frame right away.
+    let info = yak.frame_info();
+    assert!(info.structure_changed);
+    assert_eq!(info.repaint_requested, Some(Duration::from_secs(1)));
+
+    yak.handle_event(Event::CursorMoved(None));
+    yak.start();
+    yak.dom()
+        .do_widget::<RepaintWidget>(Some(Duration::from_secs(2)));
+    yak.dom()
+        .do_widget::<RepaintWidget>(Some(Duration::from_secs(1)));
+    yak.finish();
+
+    let info = yak.frame_info();
+    assert!(info.input_received);
+    assert!(!info.structure_changed);
+    assert_eq!(info.next_repaint(), Some(Duration::ZERO));
+}
+
+#[test]
+fn activity_only_lasts_for_one_frame() {
+    let mut yak = Yakui::new();
+
+    yak.start();
+    yak.dom().report_activity(UiActivity::MODAL);
+    yak.dom().report_activity(UiActivity::TEXT_INPUT);
+    yak.finish();
+
+    assert_eq!(
+        yak.frame_info().activity,
+        UiActivity::MODAL | UiActivity::TEXT_INPUT
+    );
+
+    yak.start();
+    yak.finish();
+
+    assert_eq!(yak.frame_info().activity, UiActivity::empty());
+}
+
+#[test]
+fn snapshots_round_trip() {
+    let mut yak = Yakui::new();
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0)));
+    yak.set_scale_factor(2.0);
+
+    yak.start();
+    let place = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(12.5, 7.0));
+    yak.dom().do_widget::<PaintCountWidget>(false);
+    yak.dom().do_widget::<TestWidget>(());
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    yak.finish();
+
+    let snapshot = yak.snapshot();
+    assert_eq!(snapshot.viewport.size(), Vec2::new(100.0, 50.0));
+
+    let place = &snapshot.root.children[0];
+    assert_eq!(place.short_name(), "PlaceWidget");
+    assert_eq!(place.children.len(), 2);
+
+    let counter = &place.children[0];
+    assert_eq!(
+        counter.rect,
+        Some(Rect::from_pos_size(Vec2::new(12.5, 7.0), Vec2::splat(10.0)))
+    );
+
+    let report = snapshot.to_string();
+    assert_eq!(DomSnapshot::parse(&report), Some(snapshot));
+    assert_eq!(DomSnapshot::parse("not a snapshot"), None);
+}
+
+#[test]
+fn try_dom_only_succeeds_during_a_frame() {
+    let mut yak = Yakui::new();
+    assert!(yakui_core::context::try_dom().is_none());
+
+    yak.start();
+    assert_eq!(
+        yakui_core::context::try_dom().map(|dom| dom.root()),
+        Some(yak.dom().root())
+    );
+    yak.finish();
+
+    assert!(yakui_core::context::try_dom().is_none());
+}
+
+#[test]
+fn delta_time_measures_time_between_frames() {
+    let mut yak = Yakui::new();
+
+    let frame = |yak: &mut Yakui, time_ms: u64| {
+        yak.set_time(Duration::from_millis(time_ms));
+        yak.start();
+        let delta_time = yak.dom().delta_time();
+        yak.finish();
+
+        assert_eq!(yak.frame_info().delta_time, delta_time);
+        delta_time
+    };
+
+    assert_eq!(frame(&mut yak, 1000), Duration::ZERO);
+    assert_eq!(frame(&mut yak, 1016), Duration::from_millis(16));
+    assert_eq!(frame(&mut yak, 1050), Duration::from_millis(34));
+
+    // Time going backwards doesn't produce a huge delta.
+    assert_eq!(frame(&mut yak, 500), Duration::ZERO);
+}
+
+/// Counts how many times it has been updated.
+#[derive(Debug)]
+struct UpdateCountWidget {
+    count: u32,
+}
+
+impl Widget for UpdateCountWidget {
+    type Props<'a> = ();
+    type Response = u32;
+
+    fn new() -> Self {
+        Self { count: 0 }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        self.count += 1;
+        self.count
+    }
+}
+
+#[test]
+fn keyed_widgets_keep_state_when_siblings_are_inserted() {
+    let mut yak = Yakui::new();
+
+    let frame = |yak: &mut Yakui, keys: &[&str]| -> Vec<u32> {
+        yak.start();
+        let counts = keys
+            .iter()
+            .map(|key| {
+                let dom = yak.dom();
+                let response = dom.begin_keyed_widget::<UpdateCountWidget, _>(key, ());
+                dom.end_widget::<UpdateCountWidget>(response.id);
+                response.into_inner()
+            })
+            .collect();
+        yak.finish();
+        counts
+    };
+
+    assert_eq!(frame(&mut yak, &["b", "c"]), [1, 1]);
+    assert_eq!(frame(&mut yak, &["a", "b", "c"]), [1, 2, 2]);
+    assert_eq!(frame(&mut yak, &["c", "a"]), [3, 2]);
+    assert_eq!(frame(&mut yak, &["b", "c", "a"]), [1, 4, 3]);
+}
+
+/// Stacks its children from top to bottom. Like [`CachedWidget`], it never
+/// asks to be laid out again by itself.
+#[derive(Debug)]
+struct CachedColumnWidget;
+
+impl Widget for CachedColumnWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn needs_layout(&self) -> bool {
+        false
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let mut y = 0.0;
+        for &child in &node.children {
+            let size = ctx.calculate_layout(child, Constraints::none());
+            ctx.layout.set_pos(child, Vec2::new(0.0, y));
+            y += size.y;
+        }
+
+        Vec2::new(20.0, y)
+    }
+}
+
+#[test]
+fn reordering_cached_keyed_widgets_moves_them() {
+    let mut yak = Yakui::new();
+
+    let frame = |yak: &mut Yakui, keys: &[&str]| -> Vec<WidgetId> {
+        yak.start();
+        let dom = yak.dom();
+        let column = dom.begin_widget::<CachedColumnWidget>(());
+        let ids = keys
+            .iter()
+            .map(|key| {
+                let response = dom.begin_keyed_widget::<CachedWidget, _>(key, false);
+                dom.end_widget::<CachedWidget>(response.id);
+                response.id
+            })
+            .collect();
+        dom.end_widget::<CachedColumnWidget>(column.id);
+        yak.finish();
+        ids
+    };
+
+    let y = |yak: &Yakui, id: WidgetId| yak.layout_dom().get(id).unwrap().rect.pos().y;
+
+    let ids = frame(&mut yak, &["a", "b"]);
+    assert_eq!((y(&yak, ids[0]), y(&yak, ids[1])), (0.0, 20.0));
+
+    // Nothing asks to be laid out again, but swapping the widgets changes
+    // where they go.
+    let swapped = frame(&mut yak, &["b", "a"]);
+    assert_eq!(swapped, [ids[1], ids[0]]);
+    assert_eq!((y(&yak, ids[1]), y(&yak, ids[0])), (0.0, 20.0));
+}
+
+#[test]
+fn inspections_include_layout_details() {
+    let mut yak = Yakui::new();
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0)));
+
+    yak.start();
+    let place = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(20.0, 30.0));
+    let button = yak.dom().do_widget::<ButtonLogWidget>(());
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    let clip = yak.dom().begin_widget::<EmptyClipWidget>(true);
+    let test = yak.dom().begin_widget::<TestWidget>(());
+    yak.dom().end_widget::<TestWidget>(test.id);
+    yak.dom().end_widget::<EmptyClipWidget>(clip.id);
+    yak.finish();
+
+    let inspection = yak.inspect();
+
+    let names: Vec<String> = inspection.iter().map(|node| node.short_name()).collect();
+    assert_eq!(
+        names,
+        [
+            "RootWidget",
+            "PlaceWidget",
+            "ButtonLogWidget",
+            "EmptyClipWidget",
+            "TestWidget"
+        ]
+    );
+
+    let button_node = inspection.find_by_name("ButtonLogWidget").next().unwrap();
+    assert_eq!(button_node.id, button.id);
+    assert_eq!(
+        button_node.rect(),
+        Some(Rect::from_pos_size(
+            Vec2::new(20.0, 30.0),
+            Vec2::splat(50.0)
+        ))
+    );
+    let button_layout = button_node.layout.as_ref().unwrap();
+    assert_eq!(button_layout.event_interest, EventInterest::MOUSE_INSIDE);
+
+    let clip_node = inspection.get(clip.id).unwrap();
+    assert!(clip_node.layout.as_ref().unwrap().clipping_enabled);
+    let test_node = &clip_node.children[0];
+    assert_eq!(test_node.layout.as_ref().unwrap().clipped_by, Some(clip.id));
+}
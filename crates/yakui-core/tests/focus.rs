@@ -0,0 +1,247 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use glam::Vec2;
+use yakui_core::event::{Event, EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Constraints, Rect};
+use yakui_core::input::{
+    KeyCode, KeyLocation, LogicalKey, Modifiers, NavAction, NavDirection, TabIndex,
+};
+use yakui_core::widget::{EventContext, LayoutContext, Widget};
+use yakui_core::Yakui;
+
+use self::common::PlaceWidget;
+
+/// A focusable widget that logs its focus changes and the navigation actions
+/// sent to it. It only handles accepting, so cancels bubble to its ancestors.
+#[derive(Debug)]
+struct FocusWidget {
+    log: Rc<RefCell<Vec<WidgetEvent>>>,
+    tab_index: TabIndex,
+}
+
+impl Widget for FocusWidget {
+    type Props<'a> = TabIndex;
+    type Response = Rc<RefCell<Vec<WidgetEvent>>>;
+
+    fn new() -> Self {
+        Self {
+            log: Rc::default(),
+            tab_index: TabIndex::Auto,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.tab_index = props;
+        self.log.clone()
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        Vec2::new(20.0, 20.0)
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::FOCUS
+    }
+
+    fn tab_index(&self) -> TabIndex {
+        self.tab_index
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match event {
+            WidgetEvent::FocusChanged(focused) => {
+                self.log
+                    .borrow_mut()
+                    .push(WidgetEvent::FocusChanged(*focused));
+                EventResponse::Sink
+            }
+            WidgetEvent::NavAction(NavAction::Accept) => {
+                self.log
+                    .borrow_mut()
+                    .push(WidgetEvent::NavAction(NavAction::Accept));
+                EventResponse::Sink
+            }
+            _ => EventResponse::Bubble,
+        }
+    }
+}
+
+fn focus_at(yak: &Yakui, pos: Vec2, tab_index: TabIndex) -> Rc<RefCell<Vec<WidgetEvent>>> {
+    let place = yak.dom().begin_widget::<PlaceWidget>(pos);
+    let focus = yak.dom().do_widget::<FocusWidget>(tab_index).into_inner();
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    focus
+}
+
+fn focused(log: &Rc<RefCell<Vec<WidgetEvent>>>) -> bool {
+    matches!(log.borrow().last(), Some(WidgetEvent::FocusChanged(true)))
+}
+
+#[test]
+fn navigation_moves_selection_spatially() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 200.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 200.0)));
+
+    yak.start();
+    let top_left = focus_at(&yak, Vec2::new(0.0, 0.0), TabIndex::Auto);
+    let top_right = focus_at(&yak, Vec2::new(100.0, 0.0), TabIndex::Auto);
+    let bottom_left = focus_at(&yak, Vec2::new(0.0, 100.0), TabIndex::Auto);
+    yak.finish();
+
+    // With nothing selected, navigating selects the top left widget.
+    assert!(yak.handle_event(Event::Navigate(NavDirection::Right)));
+    assert!(focused(&top_left));
+
+    assert!(yak.handle_event(Event::Navigate(NavDirection::Right)));
+    assert!(focused(&top_right));
+    assert!(!focused(&top_left));
+
+    assert!(yak.handle_event(Event::Navigate(NavDirection::Down)));
+    assert!(focused(&bottom_left));
+
+    // There's nothing further down, so the event is left for the game.
+    assert!(!yak.handle_event(Event::Navigate(NavDirection::Down)));
+    assert!(focused(&bottom_left));
+
+    // Next and previous follow the order widgets were created in, wrapping
+    // around at the ends.
+    assert!(yak.handle_event(Event::Navigate(NavDirection::Next)));
+    assert!(focused(&top_left));
+    assert!(yak.handle_event(Event::Navigate(NavDirection::Previous)));
+    assert!(focused(&bottom_left));
+
+    assert!(yak.handle_event(Event::NavAction(NavAction::Accept)));
+    assert!(matches!(
+        bottom_left.borrow().last(),
+        Some(WidgetEvent::NavAction(NavAction::Accept))
+    ));
+    assert!(!yak.handle_event(Event::NavAction(NavAction::Cancel)));
+}
+
+fn press_tab(yak: &mut Yakui, shift: bool) -> bool {
+    let modifiers = if shift {
+        Modifiers::SHIFT
+    } else {
+        Modifiers::empty()
+    };
+    yak.handle_event(Event::ModifiersChanged(modifiers));
+
+    let sunk = yak.handle_event(Event::KeyChanged {
+        key: KeyCode::Tab,
+        logical_key: LogicalKey::Tab,
+        location: KeyLocation::Standard,
+        down: true,
+        repeat: false,
+    });
+    yak.handle_event(Event::KeyChanged {
+        key: KeyCode::Tab,
+        logical_key: LogicalKey::Tab,
+        location: KeyLocation::Standard,
+        down: false,
+        repeat: false,
+    });
+    sunk
+}
+
+#[test]
+fn tab_follows_tab_index_then_dom_order() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 200.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 200.0)));
+
+    yak.start();
+    let first = focus_at(&yak, Vec2::new(0.0, 0.0), TabIndex::Auto);
+    let skipped = focus_at(&yak, Vec2::new(50.0, 0.0), TabIndex::Skip);
+    let last = focus_at(&yak, Vec2::new(100.0, 0.0), TabIndex::Auto);
+    let ordered = focus_at(&yak, Vec2::new(150.0, 0.0), TabIndex::Order(1));
+    yak.finish();
+
+    assert!(press_tab(&mut yak, false));
+    assert!(focused(&ordered));
+    assert!(press_tab(&mut yak, false));
+    assert!(focused(&first));
+    assert!(press_tab(&mut yak, false));
+    assert!(focused(&last));
+    assert!(skipped.borrow().is_empty());
+
+    // Tab wraps around, and Shift+Tab goes backwards.
+    assert!(press_tab(&mut yak, false));
+    assert!(focused(&ordered));
+    assert!(press_tab(&mut yak, true));
+    assert!(focused(&last));
+
+    // Widgets that opt out of Tab can still be navigated to directionally.
+    assert!(yak.handle_event(Event::Navigate(NavDirection::Left)));
+    assert!(focused(&skipped));
+}
+
+/// Traps navigation inside of its children, like a modal would.
+#[derive(Debug)]
+struct ScopeWidget;
+
+impl Widget for ScopeWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        ctx.input.push_focus_scope(ctx.dom.current());
+        self.default_layout(ctx, constraints)
+    }
+}
+
+#[test]
+fn focus_scopes_trap_navigation_and_restore_selection() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 200.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 200.0)));
+
+    yak.start();
+    let outside = focus_at(&yak, Vec2::new(0.0, 0.0), TabIndex::Auto);
+    yak.finish();
+
+    assert!(press_tab(&mut yak, false));
+    assert!(focused(&outside));
+
+    let build_modal = |yak: &mut Yakui| {
+        yak.start();
+        focus_at(yak, Vec2::new(0.0, 0.0), TabIndex::Auto);
+        let scope = yak.dom().begin_widget::<ScopeWidget>(());
+        let first = focus_at(yak, Vec2::new(50.0, 50.0), TabIndex::Auto);
+        let second = focus_at(yak, Vec2::new(100.0, 50.0), TabIndex::Auto);
+        yak.dom().end_widget::<ScopeWidget>(scope.id);
+        yak.finish();
+        (first, second)
+    };
+
+    let (first, second) = build_modal(&mut yak);
+    assert!(press_tab(&mut yak, false));
+    assert!(focused(&first));
+    assert!(press_tab(&mut yak, false));
+    assert!(focused(&second));
+
+    // Tab wraps around inside of the scope instead of leaving it.
+    assert!(press_tab(&mut yak, false));
+    assert!(focused(&first));
+    assert!(!yak.handle_event(Event::Navigate(NavDirection::Up)));
+    assert!(focused(&first));
+
+    // Once the scope is gone, the widget selected before it comes back.
+    build_modal(&mut yak);
+    yak.start();
+    focus_at(&yak, Vec2::new(0.0, 0.0), TabIndex::Auto);
+    yak.finish();
+
+    yak.start();
+    assert!(focused(&outside));
+    yak.finish();
+}
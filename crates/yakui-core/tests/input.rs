@@ -0,0 +1,879 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use glam::{Affine2, Vec2};
+use yakui_core::event::{Event, EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Constraints, Rect};
+use yakui_core::input::{
+    ClipboardContent, CursorIcon, Gesture, Gestures, MouseButton, PlayerId, TouchPhase,
+    ViewportRouter,
+};
+use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
+use yakui_core::Yakui;
+
+use self::common::{
+    click_at, ButtonLog, ButtonLogWidget, Counter, KeyboardWidget, PlaceWidget,
+    TransformedRectWidget,
+};
+
+/// https://github.com/LPGhatguy/yakui/issues/38
+#[test]
+fn input_events_to_removed_widgets() {
+    let mut yak = Yakui::new();
+
+    yak.start();
+    let count = yak.dom().do_widget::<KeyboardWidget>(());
+    yak.finish();
+
+    assert_eq!(count.get(), 0);
+    yak.handle_event(yakui_core::event::Event::TextInput('h'));
+    assert_eq!(count.get(), 1);
+    yak.handle_event(yakui_core::event::Event::TextInput('e'));
+    assert_eq!(count.get(), 2);
+
+    yak.start();
+    yak.finish();
+
+    yak.handle_event(yakui_core::event::Event::TextInput('l'));
+    assert_eq!(count.get(), 2);
+}
+
+#[test]
+fn releases_go_to_pressed_widget() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0)));
+
+    yak.start();
+    let place = yak.dom().begin_widget::<PlaceWidget>(Vec2::ZERO);
+    let first = yak.dom().do_widget::<ButtonLogWidget>(());
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    let place = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(100.0, 0.0));
+    let second = yak.dom().do_widget::<ButtonLogWidget>(());
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    yak.finish();
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: true,
+    });
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(110.0, 10.0))));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: false,
+    });
+
+    assert_eq!(
+        *first.borrow(),
+        [(true, true, false), (false, false, false)]
+    );
+    assert_eq!(*second.borrow(), [(false, true, true)]);
+}
+
+#[test]
+fn extra_mouse_buttons_reach_widgets() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    yak.start();
+    let log = yak.dom().do_widget::<ButtonLogWidget>(());
+    yak.finish();
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    for button in [MouseButton::Back, MouseButton::Other(8)] {
+        assert!(yak.handle_event(Event::MouseButtonChanged { button, down: true }));
+        assert!(yak.handle_event(Event::MouseButtonChanged {
+            button,
+            down: false
+        }));
+    }
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            (true, true, false),
+            (false, true, false),
+            (true, true, false),
+            (false, true, false)
+        ]
+    );
+}
+
+fn build_split_screen(yak: &mut Yakui, viewport: Rect) -> ButtonLog {
+    yak.set_surface_size(Vec2::new(200.0, 100.0));
+    yak.set_unscaled_viewport(viewport);
+
+    yak.start();
+    let log = yak.dom().do_widget::<ButtonLogWidget>(());
+    yak.dom()
+        .do_widget::<TransformedRectWidget>(Affine2::IDENTITY);
+    yak.finish();
+
+    log.into_inner()
+}
+
+#[test]
+fn viewport_router_sends_clicks_to_hovered_viewport() {
+    let mut states = [Yakui::new(), Yakui::new()];
+    let left = build_split_screen(
+        &mut states[0],
+        Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)),
+    );
+    let right = build_split_screen(
+        &mut states[1],
+        Rect::from_pos_size(Vec2::new(100.0, 0.0), Vec2::new(100.0, 100.0)),
+    );
+
+    let mut router = ViewportRouter::new();
+    let press = |router: &mut ViewportRouter, states: &mut [Yakui], down| {
+        let button = MouseButton::One;
+        router.handle_event(states, Event::MouseButtonChanged { button, down });
+    };
+
+    router.handle_event(
+        &mut states,
+        Event::CursorMoved(Some(Vec2::new(110.0, 10.0))),
+    );
+    press(&mut router, &mut states, true);
+    press(&mut router, &mut states, false);
+
+    assert_eq!(router.focused(), 1);
+    assert!(left.borrow().is_empty());
+    assert_eq!(*right.borrow(), [(true, true, false), (false, true, false)]);
+
+    // Releases go to the viewport the press started in, even if the cursor
+    // has moved into another one.
+    router.handle_event(&mut states, Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    press(&mut router, &mut states, true);
+    router.handle_event(
+        &mut states,
+        Event::CursorMoved(Some(Vec2::new(150.0, 10.0))),
+    );
+    press(&mut router, &mut states, false);
+
+    assert_eq!(router.focused(), 0);
+    assert_eq!(*left.borrow(), [(true, true, false), (false, false, false)]);
+    assert_eq!(right.borrow().len(), 2);
+
+    // Paint calls are limited to their own viewport.
+    let paint = states[1].paint();
+    let clips: Vec<_> = paint.layers()[0]
+        .calls
+        .iter()
+        .map(|call| call.clip)
+        .collect();
+    assert!(clips.iter().all(|clip| *clip
+        == Some(Rect::from_pos_size(
+            Vec2::new(100.0, 0.0),
+            Vec2::new(100.0, 100.0)
+        ))));
+}
+
+/// Records the click count of every mouse press it receives.
+#[derive(Debug)]
+struct ClickCountWidget {
+    log: Rc<RefCell<Vec<u32>>>,
+}
+
+impl Widget for ClickCountWidget {
+    type Props<'a> = ();
+    type Response = Rc<RefCell<Vec<u32>>>;
+
+    fn new() -> Self {
+        Self { log: Rc::default() }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        self.log.clone()
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        Vec2::new(50.0, 50.0)
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        if let WidgetEvent::MouseButtonChanged {
+            down: true,
+            click_count,
+            ..
+        } = *event
+        {
+            self.log.borrow_mut().push(click_count);
+        }
+
+        EventResponse::Sink
+    }
+}
+
+#[test]
+fn quick_presses_count_as_multiple_clicks() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    let mut time = Duration::ZERO;
+    let mut click = |yak: &mut Yakui, delay_ms: u64| {
+        time += Duration::from_millis(delay_ms);
+        yak.set_time(time);
+        yak.start();
+        let log = yak.dom().do_widget::<ClickCountWidget>(());
+        yak.finish();
+
+        yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+        for down in [true, false] {
+            yak.handle_event(Event::MouseButtonChanged {
+                button: MouseButton::One,
+                down,
+            });
+        }
+
+        log
+    };
+
+    click(&mut yak, 100);
+    click(&mut yak, 100);
+    click(&mut yak, 100);
+    click(&mut yak, 1000);
+    let log = click(&mut yak, 100);
+
+    assert_eq!(*log.borrow(), [1, 2, 3, 1, 2]);
+}
+
+#[test]
+fn presses_without_time_between_them_count_as_multiple_clicks() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    // The time is never set, and every press happens in the same frame.
+    yak.start();
+    let log = yak.dom().do_widget::<ClickCountWidget>(());
+    yak.finish();
+
+    let click = |yak: &mut Yakui, pos: Vec2| {
+        yak.handle_event(Event::CursorMoved(Some(pos)));
+        for down in [true, false] {
+            yak.handle_event(Event::MouseButtonChanged {
+                button: MouseButton::One,
+                down,
+            });
+        }
+    };
+
+    click(&mut yak, Vec2::new(10.0, 10.0));
+    click(&mut yak, Vec2::new(10.0, 10.0));
+    click(&mut yak, Vec2::new(30.0, 30.0));
+    click(&mut yak, Vec2::new(30.0, 30.0));
+
+    // Moving the mouse between presses starts a new set of clicks.
+    assert_eq!(*log.borrow(), [1, 2, 1, 2]);
+}
+
+/// Lays out its children at the origin and grows their interact rects by 20
+/// pixels on every side.
+#[derive(Debug)]
+struct SlopWidget;
+
+impl Widget for SlopWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            let size = ctx.calculate_layout(child, Constraints::none());
+            let slop = Vec2::splat(20.0);
+            let rect = Rect::from_pos_size(-slop, size + slop * 2.0);
+            ctx.layout.set_interact_rect(child, Some(rect));
+        }
+
+        constraints.min
+    }
+}
+
+#[test]
+fn hit_testing_uses_interact_rect() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0)));
+
+    yak.start();
+    let slop = yak.dom().begin_widget::<SlopWidget>(());
+    let log = yak.dom().do_widget::<ButtonLogWidget>(());
+    yak.dom().end_widget::<SlopWidget>(slop.id);
+    yak.finish();
+
+    // The widget is 50x50, so this is outside of it but within its slop.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(60.0, 10.0))));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: true,
+    });
+
+    assert_eq!(*log.borrow(), [(true, true, false)]);
+}
+
+#[test]
+fn offset_children_are_hit_where_placed() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 200.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 200.0)));
+
+    // The inner widget is zero-sized, like a reflowed container, so its child
+    // is entirely outside of it.
+    yak.start();
+    let outer = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(50.0, 0.0));
+    let inner = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(0.0, 50.0));
+    let log = yak.dom().do_widget::<ButtonLogWidget>(());
+    yak.dom().end_widget::<PlaceWidget>(inner.id);
+    yak.dom().end_widget::<PlaceWidget>(outer.id);
+    yak.finish();
+
+    click_at(&mut yak, Vec2::new(10.0, 10.0));
+    assert!(log.borrow().is_empty());
+
+    click_at(&mut yak, Vec2::new(60.0, 60.0));
+    assert_eq!(*log.borrow(), [(true, true, false), (false, true, false)]);
+}
+
+/// Lays out its children at the origin and paints them with the transform in
+/// its props.
+#[derive(Debug)]
+struct HitTransformWidget {
+    transform: Affine2,
+}
+
+impl Widget for HitTransformWidget {
+    type Props<'a> = Affine2;
+    type Response = ();
+
+    fn new() -> Self {
+        Self {
+            transform: Affine2::IDENTITY,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.transform = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.calculate_layout(child, Constraints::none());
+        }
+
+        ctx.layout.set_transform(ctx.dom, self.transform);
+        constraints.min
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let node = ctx.dom.get_current();
+        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+
+        ctx.paint
+            .push_transform(layout_node.world_transform().unwrap_or_default());
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+        ctx.paint.pop_transform();
+    }
+}
+
+#[test]
+fn transformed_children_are_hit_where_painted() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 200.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 200.0)));
+
+    let transform =
+        Affine2::from_translation(Vec2::new(100.0, 0.0)) * Affine2::from_scale(Vec2::splat(2.0));
+
+    yak.start();
+    let parent = yak.dom().begin_widget::<HitTransformWidget>(transform);
+    let log = yak.dom().do_widget::<ButtonLogWidget>(());
+    yak.dom().end_widget::<HitTransformWidget>(parent.id);
+    yak.finish();
+
+    // The 50x50 child is painted over (100, 0) to (200, 100).
+    click_at(&mut yak, Vec2::new(10.0, 10.0));
+    assert!(log.borrow().is_empty());
+
+    click_at(&mut yak, Vec2::new(180.0, 80.0));
+    assert_eq!(*log.borrow(), [(true, true, false), (false, true, false)]);
+}
+
+/// Limits its children to the player in its props.
+#[derive(Debug)]
+struct PlayerWidget {
+    player: PlayerId,
+}
+
+impl Widget for PlayerWidget {
+    type Props<'a> = PlayerId;
+    type Response = ();
+
+    fn new() -> Self {
+        Self {
+            player: PlayerId(0),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.player = props;
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        ctx.layout.set_player(ctx.dom, self.player);
+        self.default_layout(ctx, constraints)
+    }
+}
+
+/// Selects itself for the player in its props and counts the text it's sent.
+#[derive(Debug)]
+struct PlayerKeyboardWidget {
+    player: PlayerId,
+    count: Counter,
+}
+
+impl Widget for PlayerKeyboardWidget {
+    type Props<'a> = PlayerId;
+    type Response = Counter;
+
+    fn new() -> Self {
+        Self {
+            player: PlayerId(0),
+            count: Counter::default(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.player = props;
+        self.count.clone()
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::FOCUSED_KEYBOARD
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        ctx.input
+            .set_player_selection(self.player, Some(ctx.dom.current()));
+        Vec2::ZERO
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        if let WidgetEvent::TextInput(..) = event {
+            self.count.increment();
+        }
+
+        EventResponse::Bubble
+    }
+}
+
+/// Builds an area for each of two players, owned by the given players, with a
+/// widget selected by that player inside of it.
+fn build_player_areas(yak: &mut Yakui, owners: [PlayerId; 2]) -> [Counter; 2] {
+    yak.start();
+    let counts = [PlayerId(0), PlayerId(1)].map(|player| {
+        let area = yak
+            .dom()
+            .begin_widget::<PlayerWidget>(owners[player.0 as usize]);
+        let count = yak.dom().do_widget::<PlayerKeyboardWidget>(player);
+        yak.dom().end_widget::<PlayerWidget>(area.id);
+        count.into_inner()
+    });
+    yak.finish();
+    counts
+}
+
+#[test]
+fn player_events_go_to_their_own_selection() {
+    let mut yak = Yakui::new();
+
+    let counts = build_player_areas(&mut yak, [PlayerId(0), PlayerId(1)]);
+    let count = |index: usize| counts[index].get();
+
+    yak.handle_player_event(PlayerId(0), Event::TextInput('a'));
+    assert_eq!((count(0), count(1)), (1, 0));
+
+    yak.handle_player_event(PlayerId(1), Event::TextInput('b'));
+    assert_eq!((count(0), count(1)), (1, 1));
+
+    // Events without a player go to the shared selection, which is empty.
+    yak.handle_event(Event::TextInput('c'));
+    assert_eq!((count(0), count(1)), (1, 1));
+
+    // Player 1's selection is now inside of player 0's area, so it can't
+    // receive anything from player 1.
+    let counts = build_player_areas(&mut yak, [PlayerId(0), PlayerId(0)]);
+    yak.handle_player_event(PlayerId(1), Event::TextInput('d'));
+    assert_eq!(counts[1].get(), 1);
+}
+
+/// A 10x10 widget that keeps track of text pasted into it.
+#[derive(Debug)]
+struct PasteWidget {
+    pasted: Rc<RefCell<Vec<String>>>,
+}
+
+impl Widget for PasteWidget {
+    type Props<'a> = ();
+    type Response = Rc<RefCell<Vec<String>>>;
+
+    fn new() -> Self {
+        Self {
+            pasted: Rc::default(),
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        self.pasted.clone()
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE | EventInterest::PASTE_TARGET
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.calculate_layout(child, constraints);
+        }
+
+        Vec2::new(10.0, 10.0)
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        if let WidgetEvent::Paste(ClipboardContent::Text(text)) = event {
+            self.pasted.borrow_mut().push(text.clone());
+            return EventResponse::Sink;
+        }
+
+        EventResponse::Bubble
+    }
+}
+
+fn paste(yak: &mut Yakui, text: &str) -> bool {
+    yak.handle_event(Event::Paste(ClipboardContent::Text(text.to_owned())))
+}
+
+#[test]
+fn pastes_go_to_selected_or_hovered_targets() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    yak.start();
+    let place = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(50.0, 50.0));
+    let pasted = yak.dom().do_widget::<PasteWidget>(());
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    yak.finish();
+
+    assert!(!paste(&mut yak, "away"));
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(55.0, 55.0))));
+    assert!(paste(&mut yak, "hovered"));
+    assert_eq!(*pasted.borrow(), ["hovered"]);
+
+    // A selected descendant routes pastes to its nearest paste target, even
+    // when the mouse is somewhere else.
+    yak.handle_event(Event::CursorMoved(None));
+    yak.start();
+    let place = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(50.0, 50.0));
+    let target = yak.dom().begin_widget::<PasteWidget>(());
+    yak.dom().do_widget::<KeyboardWidget>(());
+    yak.dom().end_widget::<PasteWidget>(target.id);
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    yak.finish();
+
+    assert!(paste(&mut yak, "selected"));
+    assert_eq!(*pasted.borrow(), ["hovered", "selected"]);
+}
+
+/// Recognizes gestures from every touch that starts inside of it.
+#[derive(Debug)]
+struct GestureWidget {
+    gestures: Gestures,
+    recognized: Rc<RefCell<Vec<Gesture>>>,
+}
+
+impl Widget for GestureWidget {
+    type Props<'a> = ();
+    type Response = Rc<RefCell<Vec<Gesture>>>;
+
+    fn new() -> Self {
+        Self {
+            gestures: Gestures::new(),
+            recognized: Rc::default(),
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        self.recognized.clone()
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        Vec2::new(100.0, 100.0)
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::TOUCH
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        if let Some(gesture) = self.gestures.handle(event, ctx.dom.time()) {
+            self.recognized.borrow_mut().push(gesture);
+        }
+
+        EventResponse::Sink
+    }
+}
+
+fn touch(yak: &mut Yakui, id: u64, phase: TouchPhase, x: f32) -> bool {
+    let pos = Vec2::new(x, 10.0);
+    yak.handle_event(Event::Touch { id, phase, pos })
+}
+
+#[test]
+fn touches_act_like_the_mouse_or_make_gestures() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 100.0)));
+
+    yak.start();
+    let buttons = yak.dom().do_widget::<ButtonLogWidget>(());
+    let place = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(100.0, 0.0));
+    let gestures = yak.dom().do_widget::<GestureWidget>(());
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    yak.finish();
+
+    // Widgets that only know about the mouse can still be tapped.
+    assert!(touch(&mut yak, 1, TouchPhase::Started, 10.0));
+    assert!(touch(&mut yak, 1, TouchPhase::Ended, 10.0));
+    assert_eq!(
+        *buttons.borrow(),
+        [(true, true, false), (false, true, false)]
+    );
+
+    // A cancelled touch doesn't click anything.
+    touch(&mut yak, 2, TouchPhase::Started, 10.0);
+    touch(&mut yak, 2, TouchPhase::Cancelled, 10.0);
+    assert_eq!(buttons.borrow()[3], (false, false, false));
+
+    touch(&mut yak, 3, TouchPhase::Started, 110.0);
+    touch(&mut yak, 3, TouchPhase::Ended, 110.0);
+    assert_eq!(
+        *gestures.borrow(),
+        [Gesture::Tap {
+            position: Vec2::new(110.0, 10.0)
+        }]
+    );
+
+    // Touches sunk by a widget don't act like the mouse.
+    assert_eq!(buttons.borrow().len(), 4);
+
+    gestures.borrow_mut().clear();
+    touch(&mut yak, 4, TouchPhase::Started, 110.0);
+    touch(&mut yak, 5, TouchPhase::Started, 130.0);
+    touch(&mut yak, 5, TouchPhase::Moved, 150.0);
+    touch(&mut yak, 4, TouchPhase::Ended, 110.0);
+    touch(&mut yak, 5, TouchPhase::Ended, 150.0);
+    assert_eq!(
+        *gestures.borrow(),
+        [Gesture::Pinch {
+            center: Vec2::new(130.0, 10.0),
+            scale: 2.0
+        }]
+    );
+
+    // Holding a finger still long enough is a long press instead of a tap.
+    let mut recognizer = Gestures::new();
+    let start = WidgetEvent::Touch {
+        id: 6,
+        phase: TouchPhase::Started,
+        position: Vec2::ZERO,
+        inside: true,
+    };
+    assert_eq!(recognizer.handle(&start, Duration::ZERO), None);
+    assert_eq!(recognizer.poll(Duration::from_millis(100)), None);
+    assert_eq!(
+        recognizer.poll(Duration::from_secs(1)),
+        Some(Gesture::LongPress {
+            position: Vec2::ZERO
+        })
+    );
+    assert_eq!(recognizer.poll(Duration::from_secs(2)), None);
+}
+
+/// Asks for a cursor icon when hovered and sinks presses, like a resize
+/// handle.
+#[derive(Debug)]
+struct CursorWidget {
+    icon: CursorIcon,
+}
+
+impl Widget for CursorWidget {
+    type Props<'a> = CursorIcon;
+    type Response = ();
+
+    fn new() -> Self {
+        Self {
+            icon: CursorIcon::Default,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.icon = props;
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        Vec2::new(50.0, 50.0)
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match event {
+            WidgetEvent::MouseEnter => {
+                ctx.set_cursor_icon(self.icon);
+                EventResponse::Sink
+            }
+            WidgetEvent::MouseButtonChanged { .. } => EventResponse::Sink,
+            _ => EventResponse::Bubble,
+        }
+    }
+}
+
+#[test]
+fn cursor_icon_follows_hovered_and_pressed_widgets() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 200.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 200.0)));
+
+    yak.start();
+    yak.dom().do_widget::<CursorWidget>(CursorIcon::Text);
+    let place = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(100.0, 0.0));
+    yak.dom()
+        .do_widget::<CursorWidget>(CursorIcon::ResizeHorizontal);
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    yak.finish();
+
+    assert_eq!(yak.cursor_icon(), CursorIcon::Default);
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    assert_eq!(yak.cursor_icon(), CursorIcon::Text);
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(75.0, 10.0))));
+    assert_eq!(yak.cursor_icon(), CursorIcon::Default);
+
+    // A widget that's being pressed keeps its cursor even when the mouse is
+    // dragged over something else.
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(110.0, 10.0))));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: true,
+    });
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+    assert_eq!(yak.cursor_icon(), CursorIcon::ResizeHorizontal);
+
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: false,
+    });
+    assert_eq!(yak.cursor_icon(), CursorIcon::Text);
+
+    yak.handle_event(Event::CursorMoved(None));
+    assert_eq!(yak.cursor_icon(), CursorIcon::Default);
+}
+
+/// A widget that logs every `u32` sent to it as a custom event, sinking them
+/// if its props are true.
+#[derive(Debug)]
+struct CustomWidget {
+    log: Rc<RefCell<Vec<u32>>>,
+    sink: bool,
+}
+
+impl Widget for CustomWidget {
+    type Props<'a> = bool;
+    type Response = Rc<RefCell<Vec<u32>>>;
+
+    fn new() -> Self {
+        Self {
+            log: Rc::default(),
+            sink: false,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.sink = props;
+        self.log.clone()
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::CUSTOM
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match event.custom::<u32>() {
+            Some(&value) => {
+                self.log.borrow_mut().push(value);
+
+                if self.sink {
+                    EventResponse::Sink
+                } else {
+                    EventResponse::Bubble
+                }
+            }
+            None => EventResponse::Bubble,
+        }
+    }
+}
+
+#[test]
+fn custom_events_reach_the_selection_first() {
+    let mut yak = Yakui::new();
+
+    yak.start();
+    let first = yak.dom().do_widget::<CustomWidget>(false).into_inner();
+    let second = yak.dom().do_widget::<CustomWidget>(true);
+    let second_id = second.id;
+    let second = second.into_inner();
+    yak.finish();
+
+    // With nothing selected, every interested widget gets a turn in DOM order.
+    assert!(yak.handle_event(Event::custom(1u32)));
+    assert_eq!(*first.borrow(), [1]);
+    assert_eq!(*second.borrow(), [1]);
+
+    // Widgets ignore events of types they don't know about.
+    assert!(!yak.handle_event(Event::custom("unknown")));
+
+    yak.start();
+    yak.dom().do_widget::<CustomWidget>(false);
+    yak.dom().do_widget::<CustomWidget>(true);
+    yak.dom().request_focus(second_id);
+    yak.finish();
+
+    assert!(yak.handle_event(Event::custom(2u32)));
+    assert_eq!(*first.borrow(), [1]);
+    assert_eq!(*second.borrow(), [1, 2]);
+}
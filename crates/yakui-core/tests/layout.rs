@@ -0,0 +1,231 @@
+mod common;
+
+use glam::Vec2;
+use yakui_core::event::Event;
+use yakui_core::geometry::{Constraints, Rect};
+use yakui_core::input::MouseButton;
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::Yakui;
+
+use self::common::{
+    click_at, ButtonLogWidget, CachedWidget, Counter, EmptyClipWidget, PlaceWidget, TestWidget,
+};
+
+/// Lays out its children normally, or culls all of them if its props are true.
+#[derive(Debug)]
+struct CullWidget {
+    cull: bool,
+}
+
+impl Widget for CullWidget {
+    type Props<'a> = bool;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { cull: false }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.cull = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            if self.cull {
+                ctx.layout.cull(ctx.dom, child);
+            } else {
+                ctx.calculate_layout(child, constraints);
+            }
+        }
+
+        constraints.min
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            if ctx.layout.get(child).is_some() {
+                ctx.paint(child);
+            }
+        }
+    }
+}
+
+#[test]
+fn culled_widgets_have_no_layout() {
+    let mut yak = Yakui::new();
+
+    for cull in [false, true, false] {
+        yak.start();
+        let parent = yak.dom().begin_widget::<CullWidget>(cull);
+        let child = yak.dom().begin_widget::<PlaceWidget>(Vec2::ZERO);
+        yak.dom().do_widget::<TestWidget>(());
+        yak.dom().end_widget::<PlaceWidget>(child.id);
+        yak.dom().end_widget::<CullWidget>(parent.id);
+        yak.finish();
+        yak.paint();
+
+        let layout = yak.layout_dom();
+        assert_eq!(layout.get(child.id).is_some(), !cull);
+        assert_eq!(layout.len(), if cull { 2 } else { 4 });
+    }
+}
+
+/// A 20x20 widget that reports the baseline given in its props.
+#[derive(Debug)]
+struct BaselineWidget {
+    baseline: f32,
+}
+
+impl Widget for BaselineWidget {
+    type Props<'a> = f32;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { baseline: 0.0 }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.baseline = props;
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        ctx.layout.set_baseline(ctx.dom, self.baseline);
+        Vec2::new(20.0, 20.0)
+    }
+}
+
+#[test]
+fn baselines_propagate_to_containers() {
+    let mut yak = Yakui::new();
+
+    yak.start();
+    let outer = yak.dom().begin_widget::<PlaceWidget>(Vec2::ZERO);
+    let place = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(0.0, 10.0));
+    yak.dom().do_widget::<TestWidget>(());
+    let text = yak.dom().do_widget::<BaselineWidget>(15.0);
+    yak.dom().do_widget::<BaselineWidget>(5.0);
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    yak.dom().end_widget::<PlaceWidget>(outer.id);
+    yak.finish();
+
+    // The first child with a baseline wins, and the offset of each container
+    // is added on the way up.
+    let layout = yak.layout_dom();
+    assert_eq!(layout.get(text.id).unwrap().baseline, Some(15.0));
+    assert_eq!(layout.get(place.id).unwrap().baseline, Some(25.0));
+    assert_eq!(layout.get(outer.id).unwrap().baseline, Some(25.0));
+}
+
+/// Builds two nested [`CachedWidget`]s at the given position, returning the
+/// counters of the outer and inner widget.
+fn build_cached_widgets(
+    yak: &mut Yakui,
+    pos: Vec2,
+    dirty: bool,
+) -> ((Counter, Counter), (Counter, Counter)) {
+    yak.start();
+    let place = yak.dom().begin_widget::<PlaceWidget>(pos);
+    let outer = yak.dom().begin_widget::<CachedWidget>(false);
+    let inner = yak.dom().do_widget::<CachedWidget>(dirty);
+    yak.dom().end_widget::<CachedWidget>(outer.id);
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    yak.finish();
+
+    (outer.into_inner(), inner.into_inner())
+}
+
+#[test]
+fn unchanged_subtrees_keep_their_layout() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(200.0, 200.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(200.0, 200.0)));
+
+    let ((outer_layouts, outer_clicks), (inner_layouts, inner_clicks)) =
+        build_cached_widgets(&mut yak, Vec2::splat(50.0), false);
+    assert_eq!((outer_layouts.get(), inner_layouts.get()), (1, 1));
+
+    build_cached_widgets(&mut yak, Vec2::splat(50.0), false);
+    assert_eq!((outer_layouts.get(), inner_layouts.get()), (1, 1));
+
+    click_at(&mut yak, Vec2::splat(72.0));
+    assert_eq!((outer_clicks.get(), inner_clicks.get()), (0, 1));
+
+    // Moving the subtree doesn't change its constraints, so it's reused and
+    // hit tested in its new position.
+    build_cached_widgets(&mut yak, Vec2::splat(100.0), false);
+    assert_eq!((outer_layouts.get(), inner_layouts.get()), (1, 1));
+
+    click_at(&mut yak, Vec2::splat(102.0));
+    click_at(&mut yak, Vec2::splat(122.0));
+    assert_eq!((outer_clicks.get(), inner_clicks.get()), (1, 2));
+
+    // A change deep in the tree lays out everything above it again.
+    build_cached_widgets(&mut yak, Vec2::splat(100.0), true);
+    assert_eq!((outer_layouts.get(), inner_layouts.get()), (2, 2));
+}
+
+/// Lays out its children as if they were children of the root.
+#[derive(Debug)]
+struct DetachWidget;
+
+impl Widget for DetachWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        ctx.layout.detach(ctx.dom);
+        self.default_layout(ctx, constraints)
+    }
+}
+
+#[test]
+fn detached_widgets_escape_their_ancestors() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    // The second frame reuses the layout from the first.
+    for _ in 0..2 {
+        yak.start();
+        let place = yak.dom().begin_widget::<PlaceWidget>(Vec2::new(50.0, 50.0));
+        let clip = yak.dom().begin_widget::<EmptyClipWidget>(true);
+        let detach = yak.dom().begin_widget::<DetachWidget>(());
+        let button = yak.dom().begin_widget::<ButtonLogWidget>(());
+        yak.dom().end_widget::<ButtonLogWidget>(button.id);
+        yak.dom().end_widget::<DetachWidget>(detach.id);
+        yak.dom().end_widget::<EmptyClipWidget>(clip.id);
+        yak.dom().end_widget::<PlaceWidget>(place.id);
+        yak.finish();
+
+        let layout = yak.layout_dom();
+        assert!(layout.get(detach.id).unwrap().detached);
+        assert_eq!(layout.get(detach.id).unwrap().clipped_by, None);
+
+        let node = layout.get(button.id).unwrap();
+        assert_eq!(node.rect.pos(), Vec2::ZERO);
+        assert_eq!(node.clipped_by, None);
+
+        yak.handle_event(Event::CursorMoved(Some(Vec2::new(10.0, 10.0))));
+        yak.handle_event(Event::MouseButtonChanged {
+            button: MouseButton::One,
+            down: true,
+        });
+        yak.handle_event(Event::MouseButtonChanged {
+            button: MouseButton::One,
+            down: false,
+        });
+
+        assert_eq!(
+            button.into_inner().borrow().last(),
+            Some(&(false, true, false))
+        );
+    }
+}
@@ -0,0 +1,489 @@
+mod common;
+
+use glam::{Affine2, UVec2, Vec2};
+use yakui_core::geometry::{Color, Rect, URect};
+use yakui_core::paint::{
+    BatchBreak, Gradient, PaintDebug, PaintPath, PaintRect, Texture, TextureChange, TextureFormat,
+};
+use yakui_core::widget::{PaintContext, Widget};
+use yakui_core::{ManagedTextureId, TextureId, Yakui};
+
+use self::common::{
+    EmptyClipWidget, PaintCountWidget, PlaceWidget, TestWidget, TransformedRectWidget,
+};
+
+fn paint_count_at(yak: &mut Yakui, pos: Vec2, paints_outside: bool) -> usize {
+    yak.start();
+    let place = yak.dom().begin_widget::<PlaceWidget>(pos);
+    let count = yak.dom().do_widget::<PaintCountWidget>(paints_outside);
+    yak.dom().end_widget::<PlaceWidget>(place.id);
+    yak.finish();
+
+    yak.paint();
+    count.get()
+}
+
+#[test]
+fn offscreen_widgets_are_not_painted() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    assert_eq!(paint_count_at(&mut yak, Vec2::new(20.0, 20.0), false), 1);
+    assert_eq!(paint_count_at(&mut yak, Vec2::new(500.0, 20.0), false), 1);
+    assert_eq!(paint_count_at(&mut yak, Vec2::new(500.0, 20.0), true), 2);
+}
+
+#[test]
+fn widgets_in_empty_clips_are_not_painted() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    for (clip, expected) in [(false, 1), (true, 1), (false, 2)] {
+        yak.start();
+        let parent = yak.dom().begin_widget::<EmptyClipWidget>(clip);
+        let count = yak.dom().do_widget::<PaintCountWidget>(true);
+        yak.dom().end_widget::<EmptyClipWidget>(parent.id);
+        yak.finish();
+
+        yak.paint();
+        assert_eq!(count.get(), expected);
+    }
+}
+
+#[test]
+fn paint_transforms_apply_to_vertices() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    yak.start();
+    yak.dom()
+        .do_widget::<TransformedRectWidget>(Affine2::from_translation(Vec2::new(50.0, 20.0)));
+    yak.finish();
+
+    let paint = yak.paint();
+    let call = &paint.layers()[0].calls[0];
+    let min = call
+        .vertices
+        .iter()
+        .map(|vertex| vertex.position)
+        .reduce(Vec2::min)
+        .unwrap();
+
+    assert_eq!(min, Vec2::new(0.5, 0.2));
+}
+
+#[derive(Debug)]
+struct ManyRectsWidget;
+
+impl Widget for ManyRectsWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        for i in 0..20_000 {
+            let pos = Vec2::new((i % 100) as f32, (i / 100) as f32 * 0.5);
+            PaintRect::new(Rect::from_pos_size(pos, Vec2::ONE)).add(ctx.paint);
+        }
+    }
+}
+
+#[test]
+fn large_calls_use_wide_indices() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    yak.start();
+    yak.dom().do_widget::<ManyRectsWidget>(());
+    yak.finish();
+
+    let paint = yak.paint();
+    let calls = &paint.layers()[0].calls;
+    assert_eq!(calls.len(), 1);
+
+    // The rects are batched into one call with more vertices than a u16 can
+    // address, so the last rect's indices must not have wrapped around.
+    let call = &calls[0];
+    assert!(call.vertices.len() > u16::MAX as usize);
+    let max_index = call.indices.iter().copied().max().unwrap();
+    assert_eq!(max_index as usize, call.vertices.len() - 1);
+}
+
+#[test]
+fn texture_edits_last_until_painted() {
+    let mut yak = Yakui::new();
+    let mut texture = Texture::new(TextureFormat::R8, UVec2::new(16, 16), vec![0; 256]);
+    texture.allow_atlas = false;
+    let id = yak.add_texture(texture);
+
+    let edits = |yak: &mut Yakui| yak.paint().texture_edits().collect::<Vec<_>>();
+
+    // Changes made before the frame starts are still seen by the renderer.
+    yak.start();
+    yak.finish();
+    assert_eq!(edits(&mut yak), [(id, TextureChange::Added)]);
+
+    yak.start();
+    yak.finish();
+    assert!(edits(&mut yak).is_empty());
+
+    yak.mark_texture_region_modified(id, URect::from_pos_size(UVec2::new(1, 2), UVec2::ONE));
+    yak.mark_texture_region_modified(id, URect::from_pos_size(UVec2::new(4, 4), UVec2::ONE));
+    yak.start();
+    yak.finish();
+    let region = URect::from_pos_size(UVec2::new(1, 2), UVec2::new(4, 3));
+    assert_eq!(
+        edits(&mut yak),
+        [(id, TextureChange::ModifiedRegion(region))]
+    );
+}
+
+/// Paints the texture in its props over a 10x10 rect.
+#[derive(Debug)]
+struct TexturedRectWidget {
+    texture: Option<ManagedTextureId>,
+}
+
+impl Widget for TexturedRectWidget {
+    type Props<'a> = ManagedTextureId;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { texture: None }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.texture = Some(props);
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        let mut rect = PaintRect::new(Rect::from_pos_size(Vec2::ZERO, Vec2::splat(10.0)));
+        rect.texture = Some((self.texture.unwrap().into(), Rect::ONE));
+        rect.add(ctx.paint);
+    }
+}
+
+#[test]
+fn small_textures_share_an_atlas() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    let icon = || Texture::new(TextureFormat::Rgba8Srgb, UVec2::new(8, 8), vec![255; 256]);
+    let first = yak.add_texture(icon());
+    let second = yak.add_texture(icon());
+
+    // Renderers only see the atlas, not the textures packed into it.
+    let uploaded: Vec<_> = yak.textures().map(|(id, _)| id).collect();
+    assert_eq!(uploaded.len(), 1);
+    let atlas = uploaded[0];
+    assert!(atlas != first && atlas != second);
+
+    yak.start();
+    yak.dom().do_widget::<TexturedRectWidget>(first);
+    yak.dom().do_widget::<TexturedRectWidget>(second);
+    yak.finish();
+
+    let paint = yak.paint();
+    let calls = &paint.layers()[0].calls;
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].texture, Some(TextureId::Managed(atlas)));
+
+    // Each rect samples only its own texture's pixels in the atlas.
+    let atlas_size = paint.texture(atlas).unwrap().size().as_vec2();
+    for quad in calls[0].vertices.chunks(4) {
+        let min = quad.iter().map(|v| v.texcoord).reduce(Vec2::min).unwrap();
+        let max = quad.iter().map(|v| v.texcoord).reduce(Vec2::max).unwrap();
+        assert_eq!((max - min) * atlas_size, Vec2::splat(8.0));
+    }
+}
+
+/// Paints a plain rect, then a rect with a user texture if its props are
+/// true.
+#[derive(Debug)]
+struct BatchBreakWidget {
+    textured: bool,
+}
+
+impl Widget for BatchBreakWidget {
+    type Props<'a> = bool;
+    type Response = ();
+
+    fn new() -> Self {
+        Self { textured: false }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.textured = props;
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        let rect = Rect::from_pos_size(Vec2::ZERO, Vec2::splat(10.0));
+        PaintRect::new(rect).add(ctx.paint);
+
+        if self.textured {
+            let mut textured = PaintRect::new(rect);
+            textured.texture = Some((TextureId::User(1), Rect::ONE));
+            textured.add(ctx.paint);
+        }
+    }
+}
+
+#[test]
+fn paint_stats_blame_widgets_that_break_batches() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+    yak.set_paint_debug(PaintDebug::WIREFRAME);
+
+    yak.start();
+    yak.dom().do_widget::<BatchBreakWidget>(false);
+    let textured = yak.dom().do_widget::<BatchBreakWidget>(true);
+    yak.finish();
+
+    let paint = yak.paint();
+    let stats = paint.stats().clone();
+    assert_eq!(stats.layers, 1);
+    assert_eq!(stats.calls, 2);
+    assert_eq!(
+        stats.breaks,
+        [(BatchBreak::NewLayer, 1), (BatchBreak::Texture, 1)]
+    );
+
+    assert_eq!(stats.breaking_widgets.len(), 1);
+    assert!(stats.breaking_widgets[0].0.ends_with("BatchBreakWidget"));
+
+    let origins = paint.layers()[0].origins();
+    assert_eq!(origins[1].widget, Some(textured.id));
+
+    // The wireframe is drawn on an extra overlay layer that isn't counted.
+    assert_eq!(paint.layers().len(), 2);
+    assert!(paint.layers()[1].is_overlay());
+    assert!(!paint.layers()[1].calls.is_empty());
+}
+
+#[test]
+fn frame_stats_count_widgets_and_paint_calls() {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    yak.start();
+    yak.dom().do_widget::<BatchBreakWidget>(true);
+    yak.dom().do_widget::<TestWidget>(());
+    yak.finish();
+    yak.paint();
+
+    let stats = *yak.frame_stats();
+    assert_eq!(stats.widgets, 3);
+    assert_eq!(stats.widgets_created, 2);
+    assert_eq!(stats.widgets_removed, 0);
+    assert_eq!(stats.paint_calls, 2);
+    assert_eq!(stats.vertices, 8);
+    assert_eq!(stats.indices, 12);
+
+    // Replacing a widget with one of a different type counts as creating one.
+    yak.start();
+    yak.dom().do_widget::<TestWidget>(());
+    yak.finish();
+
+    let stats = *yak.frame_stats();
+    assert_eq!(stats.widgets, 2);
+    assert_eq!(stats.widgets_created, 1);
+    assert_eq!(stats.widgets_removed, 1);
+}
+
+#[test]
+fn gradients_skip_segments_only_when_interpolation_is_exact() {
+    let (black, white) = (Color::BLACK, Color::WHITE);
+
+    assert_eq!(Gradient::horizontal(black, white).segments(), 1);
+    assert_eq!(
+        Gradient::linear(Vec2::ZERO, Vec2::ONE, black, white).segments(),
+        1
+    );
+
+    // These stop changing color partway across the shape, which vertex
+    // interpolation can't reproduce with only the corners.
+    let short = Gradient::linear(Vec2::ZERO, Vec2::new(0.5, 0.0), black, white);
+    assert!(short.segments() > 1);
+
+    let centered = Gradient::linear(Vec2::new(0.25, 0.0), Vec2::new(0.75, 0.0), black, white);
+    assert!(centered.segments() > 1);
+
+    let mut inset = Gradient::horizontal(black, white);
+    inset.stops[0].offset = 0.2;
+    inset.stops[1].offset = 0.8;
+    assert!(inset.segments() > 1);
+
+    assert!(
+        Gradient::horizontal(black, white)
+            .with_stop(0.5, Color::RED)
+            .segments()
+            > 1
+    );
+    assert!(Gradient::radial(Vec2::splat(0.5), 0.5, black, white).segments() > 1);
+
+    let mut single = Gradient::horizontal(black, white);
+    single.stops.truncate(1);
+    assert_eq!(single.segments(), 1);
+}
+
+#[test]
+fn gradients_sample_between_and_past_their_stops() {
+    let (black, white, red) = (Color::BLACK, Color::WHITE, Color::RED);
+
+    let gradient = Gradient::horizontal(black, white).with_stop(0.5, red);
+    assert_eq!(gradient.sample(Vec2::new(0.0, 0.3)), black.to_linear());
+    assert_eq!(gradient.sample(Vec2::new(0.5, 0.0)), red.to_linear());
+    assert_eq!(
+        gradient.sample(Vec2::new(0.25, 1.0)),
+        black.to_linear().lerp(red.to_linear(), 0.5)
+    );
+    assert_eq!(gradient.sample(Vec2::new(1.0, 0.0)), white.to_linear());
+
+    let short = Gradient::linear(Vec2::ZERO, Vec2::new(0.5, 0.0), black, white);
+    assert_eq!(short.sample(Vec2::new(1.0, 0.0)), white.to_linear());
+    assert_eq!(short.sample(Vec2::new(-1.0, 0.0)), black.to_linear());
+
+    let radial = Gradient::radial(Vec2::splat(0.5), 0.5, white, black);
+    assert_eq!(radial.sample(Vec2::splat(0.5)), white.to_linear());
+    assert_eq!(radial.sample(Vec2::ZERO), black.to_linear());
+}
+
+/// Fills the path in its props, or strokes it if it's given a width.
+#[derive(Debug)]
+struct PathWidget {
+    path: PaintPath,
+    stroke: Option<f32>,
+}
+
+impl Widget for PathWidget {
+    type Props<'a> = (PaintPath, Option<f32>);
+    type Response = ();
+
+    fn new() -> Self {
+        Self {
+            path: PaintPath::new(),
+            stroke: None,
+        }
+    }
+
+    fn update(&mut self, (path, stroke): Self::Props<'_>) -> Self::Response {
+        self.path = path;
+        self.stroke = stroke;
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        match self.stroke {
+            Some(width) => self.path.stroke(ctx.paint, width, Color::WHITE),
+            None => self.path.fill(ctx.paint, Color::WHITE),
+        }
+    }
+}
+
+/// Paints a path on a 100x100 surface, returning its triangles in pixels.
+fn path_triangles(path: &PaintPath, stroke: Option<f32>) -> Vec<[Vec2; 3]> {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(100.0, 100.0));
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+
+    yak.start();
+    yak.dom().do_widget::<PathWidget>((path.clone(), stroke));
+    yak.finish();
+
+    let paint = yak.paint();
+    let call = &paint.layers()[0].calls[0];
+    call.indices
+        .chunks(3)
+        .map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|i| call.vertices[triangle[i] as usize].position);
+            [a * 100.0, b * 100.0, c * 100.0]
+        })
+        .collect()
+}
+
+fn covers(triangles: &[[Vec2; 3]], point: Vec2) -> bool {
+    triangles.iter().any(|&[a, b, c]| {
+        let sides = [(a, b), (b, c), (c, a)].map(|(from, to)| (to - from).perp_dot(point - from));
+        sides.iter().all(|&side| side >= 0.0) || sides.iter().all(|&side| side <= 0.0)
+    })
+}
+
+#[test]
+fn paths_fill_their_inside() {
+    let mut path = PaintPath::new();
+    path.move_to(Vec2::new(10.0, 10.0))
+        .line_to(Vec2::new(90.0, 10.0))
+        .line_to(Vec2::new(90.0, 90.0))
+        .line_to(Vec2::new(10.0, 90.0))
+        .close();
+
+    let triangles = path_triangles(&path, None);
+    assert_eq!(triangles.len(), 2);
+    assert!(covers(&triangles, Vec2::new(50.0, 50.0)));
+    assert!(!covers(&triangles, Vec2::new(95.0, 50.0)));
+
+    // Curves are split into many small triangles.
+    let mut curve = PaintPath::new();
+    curve
+        .move_to(Vec2::new(10.0, 90.0))
+        .quad_to(Vec2::new(50.0, -70.0), Vec2::new(90.0, 90.0))
+        .close();
+
+    let triangles = path_triangles(&curve, None);
+    assert!(triangles.len() > 2);
+    assert!(covers(&triangles, Vec2::new(50.0, 20.0)));
+    assert!(!covers(&triangles, Vec2::new(15.0, 20.0)));
+}
+
+#[test]
+fn paths_are_stroked_along_their_segments() {
+    let mut line = PaintPath::new();
+    line.move_to(Vec2::new(10.0, 50.0))
+        .line_to(Vec2::new(90.0, 50.0));
+
+    let triangles = path_triangles(&line, Some(4.0));
+    assert!(covers(&triangles, Vec2::new(50.0, 51.5)));
+    assert!(!covers(&triangles, Vec2::new(50.0, 53.0)));
+
+    // An open shape isn't stroked from its end back to its start.
+    line.line_to(Vec2::new(90.0, 90.0));
+    let triangles = path_triangles(&line, Some(4.0));
+    assert!(covers(&triangles, Vec2::new(90.0, 70.0)));
+    assert!(!covers(&triangles, Vec2::new(50.0, 70.0)));
+
+    line.close();
+    let triangles = path_triangles(&line, Some(4.0));
+    assert!(covers(&triangles, Vec2::new(50.0, 70.0)));
+}
+
+#[test]
+fn paths_continue_from_the_start_of_a_closed_shape() {
+    // Without a move_to, the second shape starts at (10, 10), where the
+    // first one did, rather than at the last point that was added.
+    let mut path = PaintPath::new();
+    path.move_to(Vec2::new(10.0, 10.0))
+        .line_to(Vec2::new(50.0, 10.0))
+        .line_to(Vec2::new(50.0, 50.0))
+        .close()
+        .line_to(Vec2::new(10.0, 90.0))
+        .line_to(Vec2::new(50.0, 90.0));
+
+    let triangles = path_triangles(&path, None);
+    assert!(covers(&triangles, Vec2::new(20.0, 60.0)));
+    assert!(!covers(&triangles, Vec2::new(45.0, 60.0)));
+
+    let triangles = path_triangles(&path, Some(2.0));
+    assert!(covers(&triangles, Vec2::new(10.0, 50.0)));
+    assert!(!covers(&triangles, Vec2::new(30.0, 70.0)));
+}
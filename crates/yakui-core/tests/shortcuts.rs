@@ -0,0 +1,140 @@
+mod common;
+
+use yakui_core::event::{Event, EventInterest, EventResponse, WidgetEvent};
+use yakui_core::input::{primary_modifier, KeyCode, KeyLocation, LogicalKey, Modifiers, Shortcut};
+use yakui_core::widget::{EventContext, Widget};
+use yakui_core::Yakui;
+
+#[test]
+fn shortcuts_match_exact_modifiers() {
+    let copy = Shortcut::primary("c");
+    let c = LogicalKey::Character("c".to_owned());
+    let upper_c = LogicalKey::Character("C".to_owned());
+
+    assert!(copy.matches(&c, primary_modifier()));
+    assert!(copy.matches(&upper_c, primary_modifier() | Modifiers::CAPS_LOCK));
+    assert!(!copy.matches(&c, Modifiers::empty()));
+    assert!(!copy.matches(&c, primary_modifier() | Modifiers::SHIFT));
+    assert!(copy
+        .clone()
+        .shift()
+        .matches(&upper_c, primary_modifier() | Modifiers::SHIFT));
+}
+
+/// A widget that takes keyboard focus and uses every character key pressed
+/// while it's selected, like a text box.
+#[derive(Debug)]
+struct TypingWidget;
+
+impl Widget for TypingWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::FOCUS | EventInterest::FOCUSED_KEYBOARD
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match event {
+            WidgetEvent::KeyChanged {
+                logical_key: LogicalKey::Character(_),
+                ..
+            } => EventResponse::Sink,
+            _ => EventResponse::Bubble,
+        }
+    }
+}
+
+fn press_key(yak: &mut Yakui, key: KeyCode, logical_key: LogicalKey) -> bool {
+    let sunk = yak.handle_event(Event::KeyChanged {
+        key,
+        logical_key: logical_key.clone(),
+        location: KeyLocation::Standard,
+        down: true,
+        repeat: false,
+    });
+    yak.handle_event(Event::KeyChanged {
+        key,
+        logical_key,
+        location: KeyLocation::Standard,
+        down: false,
+        repeat: false,
+    });
+    sunk
+}
+
+#[test]
+fn shortcuts_fire_when_the_selection_ignores_them() {
+    let mut yak = Yakui::new();
+    let plain_s = Shortcut::new(Modifiers::empty(), LogicalKey::Character("s".to_owned()));
+    let f2 = Shortcut::new(Modifiers::empty(), LogicalKey::F2);
+
+    yak.start();
+    assert!(!yak.dom().shortcut(&plain_s));
+    assert!(!yak.dom().shortcut(&f2));
+    let typing = yak.dom().do_widget::<TypingWidget>(()).id;
+    yak.dom().request_focus(typing);
+    yak.finish();
+
+    // The selected widget uses the S key, but leaves F2 for the shortcut.
+    assert!(press_key(
+        &mut yak,
+        KeyCode::KeyS,
+        LogicalKey::Character("s".to_owned())
+    ));
+    assert!(press_key(&mut yak, KeyCode::F2, LogicalKey::F2));
+
+    yak.start();
+    assert!(!yak.dom().shortcut(&plain_s));
+    assert!(yak.dom().shortcut(&f2));
+    yak.dom().do_widget::<TypingWidget>(());
+    yak.finish();
+
+    // Presses only count for the frame after they happen.
+    yak.start();
+    assert!(!yak.dom().shortcut(&f2));
+    yak.finish();
+
+    // Shortcuts that stop being registered stop firing.
+    yak.start();
+    yak.finish();
+    assert!(!press_key(&mut yak, KeyCode::F2, LogicalKey::F2));
+}
+
+#[test]
+fn letter_shortcuts_use_the_physical_key_on_non_latin_layouts() {
+    let mut yak = Yakui::new();
+    let save = Shortcut::new(Modifiers::empty(), LogicalKey::Character("s".to_owned()));
+
+    let frame = |yak: &mut Yakui| {
+        yak.start();
+        let pressed = yak.dom().shortcut(&save);
+        yak.finish();
+        pressed
+    };
+
+    frame(&mut yak);
+
+    // On a Russian layout, the key where S is on QWERTY types "ы".
+    assert!(press_key(
+        &mut yak,
+        KeyCode::KeyS,
+        LogicalKey::Character("ы".to_owned())
+    ));
+    assert!(frame(&mut yak));
+
+    // Latin layouts still match on the character typed, so the key where S is
+    // on QWERTY doesn't trigger it on a layout where it types something else.
+    assert!(!press_key(
+        &mut yak,
+        KeyCode::KeyS,
+        LogicalKey::Character("o".to_owned())
+    ));
+    assert!(!frame(&mut yak));
+}
@@ -0,0 +1,102 @@
+use yakui_core::widget::Widget;
+use yakui_core::{Store, WidgetId, Yakui};
+
+#[derive(Debug, Default)]
+struct AppState {
+    count: u32,
+    name: String,
+}
+
+/// Reads one field of the `AppState` store each update.
+#[derive(Debug)]
+struct SelectWidget;
+
+impl Widget for SelectWidget {
+    type Props<'a> = &'static str;
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, field: Self::Props<'_>) -> Self::Response {
+        let store = yakui_core::context::dom().store::<AppState>().unwrap();
+        match field {
+            "count" => store.select(field, |state| state.count.to_string()),
+            _ => store.select(field, |state| state.name.clone()),
+        };
+    }
+}
+
+fn select_frame(yak: &mut Yakui, fields: &[&'static str]) -> Vec<WidgetId> {
+    yak.start();
+    let ids = fields
+        .iter()
+        .map(|&field| yak.dom().do_widget::<SelectWidget>(field).id)
+        .collect();
+    yak.finish();
+    ids
+}
+
+#[test]
+fn selectors_record_which_widgets_read_each_field() {
+    let mut yak = Yakui::new();
+    let store = yak.dom().add_store(AppState::default());
+
+    let ids = select_frame(&mut yak, &["count", "name", "count"]);
+
+    let mut count_readers = store.readers("count");
+    count_readers.sort();
+    let mut expected = vec![ids[0], ids[2]];
+    expected.sort();
+    assert_eq!(count_readers, expected);
+    assert_eq!(store.readers("name"), vec![ids[1]]);
+
+    // Reads outside of an update aren't attributed to any widget.
+    assert_eq!(store.select("other", |state| state.count), 0);
+    assert!(store.readers("other").is_empty());
+}
+
+#[test]
+fn updates_invalidate_only_the_readers_of_changed_fields() {
+    let mut yak = Yakui::new();
+    let store = yak.dom().add_store(AppState::default());
+    let ids = select_frame(&mut yak, &["count", "name"]);
+
+    assert!(store.take_invalidated().is_empty());
+
+    store.update("count", |state| state.count += 1);
+    assert_eq!(store.changed_fields(), vec!["count"]);
+    assert_eq!(store.borrow().count, 1);
+    assert_eq!(store.take_invalidated(), vec![ids[0]]);
+
+    // Taking the invalidated widgets clears the changed fields.
+    assert!(store.changed_fields().is_empty());
+    assert!(store.take_invalidated().is_empty());
+
+    // A field nobody reads changes without invalidating anything.
+    store.update("other", |state| state.count += 1);
+    assert!(store.take_invalidated().is_empty());
+
+    store.update("name", |state| state.name.push('a'));
+    assert_eq!(store.take_invalidated(), vec![ids[1]]);
+}
+
+#[test]
+fn readers_are_recorded_fresh_each_update() {
+    let mut yak = Yakui::new();
+    yak.dom().add_store(AppState::default());
+    select_frame(&mut yak, &["count", "name"]);
+
+    // The store registered with the DOM is shared with the one it returned.
+    let store: Store<AppState> = yak.dom().store().unwrap();
+    assert_eq!(store.readers("count").len(), 1);
+
+    // The count reader is gone, so it isn't invalidated by a later change.
+    let ids = select_frame(&mut yak, &["name"]);
+    assert!(store.readers("count").is_empty());
+    assert_eq!(store.readers("name"), vec![ids[0]]);
+
+    store.update("count", |state| state.count += 1);
+    assert!(store.take_invalidated().is_empty());
+}
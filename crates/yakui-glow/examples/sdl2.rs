@@ -0,0 +1,80 @@
+//! Draws a small UI into an SDL2 window with yakui-glow.
+//!
+//! Run with `cargo run -p yakui-glow --example sdl2 --features sdl2-example`.
+//! Requires the SDL2 library to be installed.
+
+use sdl2::event::Event;
+use sdl2::video::GLProfile;
+use yakui::{button, column, text, Color};
+use yakui_glow::YakuiGlow;
+use yakui_sdl2::YakuiSdl2;
+
+fn main() {
+    let sdl = sdl2::init().unwrap();
+    let video = sdl.video().unwrap();
+
+    let gl_attr = video.gl_attr();
+    gl_attr.set_context_profile(GLProfile::Core);
+    gl_attr.set_context_version(3, 3);
+
+    let window = video
+        .window("yakui-glow", 800, 600)
+        .opengl()
+        .resizable()
+        .build()
+        .unwrap();
+
+    let _context = window.gl_create_context().unwrap();
+    let gl = unsafe {
+        glow::Context::from_loader_function(|name| video.gl_get_proc_address(name) as *const _)
+    };
+
+    let mut yak = yakui::Yakui::new();
+    let mut platform = YakuiSdl2::new(&window);
+    let mut renderer = unsafe { YakuiGlow::new(&gl) };
+
+    let mut clicks = 0;
+    let mut event_pump = sdl.event_pump().unwrap();
+
+    'running: loop {
+        for event in event_pump.poll_iter() {
+            if platform.handle_event(&mut yak, &event) {
+                continue;
+            }
+
+            if let Event::Quit { .. } = event {
+                break 'running;
+            }
+        }
+
+        yak.start();
+        column(|| {
+            text(32.0, "Hello from yakui-glow!");
+
+            if button(format!("Clicked {clicks} times")).clicked {
+                clicks += 1;
+            }
+        });
+        yak.finish();
+
+        unsafe {
+            use glow::HasContext;
+
+            let (width, height) = window.drawable_size();
+            gl.viewport(0, 0, width as i32, height as i32);
+            gl.disable(glow::SCISSOR_TEST);
+
+            let [r, g, b, a] = Color::hex(0x1e1e2e).to_linear().to_array();
+            gl.clear_color(r, g, b, a);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+
+            renderer.paint(&mut yak, &gl);
+        }
+
+        window.gl_swap_window();
+    }
+
+    unsafe {
+        renderer.destroy(&gl);
+    }
+}
@@ -0,0 +1,8 @@
+uniform sampler2D u_texture;
+
+void main() {
+    vec4 color = texture(u_texture, v_texcoord);
+    color *= v_color.a;
+
+    out_color = encode_output(v_color * color);
+}
@@ -0,0 +1,17 @@
+layout(location = 0) in vec2 in_position;
+layout(location = 1) in vec2 in_texcoord;
+layout(location = 2) in vec4 in_color;
+
+out vec2 v_texcoord;
+out vec4 v_color;
+
+void main() {
+    // Transform from yakui coordinates to OpenGL:
+    // yakui uses (0, 0) in the top left and (1, 1) in the bottom right
+    // OpenGL uses (-1, 1) in the top left and (1, -1) in the bottom right
+    vec2 adjusted = in_position * vec2(2.0, -2.0) + vec2(-1.0, 1.0);
+
+    gl_Position = vec4(adjusted, 0.0, 1.0);
+    v_texcoord = in_texcoord;
+    v_color = in_color;
+}
@@ -0,0 +1,26 @@
+// Prepended to each of yakui's fragment shaders to convert their output for
+// the framebuffer.
+
+// Whether the framebuffer stores values as-is, so the shader has to apply the
+// sRGB transfer function itself. See `YakuiGlow::set_srgb_framebuffer`.
+uniform bool u_encode_srgb;
+
+in vec2 v_texcoord;
+in vec4 v_color;
+
+out vec4 out_color;
+
+vec3 srgb_encode(vec3 linear) {
+    vec3 low = linear * 12.92;
+    vec3 high = 1.055 * pow(max(linear, vec3(0.0)), vec3(1.0 / 2.4)) - 0.055;
+    return mix(high, low, vec3(lessThanEqual(linear, vec3(0.0031308))));
+}
+
+// Takes a premultiplied linear color and converts it for the framebuffer.
+vec4 encode_output(vec4 color) {
+    if (!u_encode_srgb || color.a <= 0.0) {
+        return color;
+    }
+
+    return vec4(srgb_encode(color.rgb / color.a) * color.a, color.a);
+}
@@ -0,0 +1,13 @@
+uniform sampler2D u_texture;
+
+void main() {
+    vec4 coverage = texture(u_texture, v_texcoord);
+
+    if (v_color.a > 0.0) {
+        float alpha = max(max(coverage.r, coverage.g), coverage.b) * v_color.a * coverage.a;
+
+        out_color = encode_output(vec4(v_color.rgb * alpha, alpha));
+    } else {
+        out_color = encode_output(coverage);
+    }
+}
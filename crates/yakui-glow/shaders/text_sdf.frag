@@ -0,0 +1,12 @@
+uniform sampler2D u_texture;
+
+void main() {
+    // The glyph's edge is at 0.5. Antialias over roughly one screen pixel,
+    // however far the glyph has been scaled from its rasterized size.
+    float dist = texture(u_texture, v_texcoord).r;
+    float width = max(fwidth(dist) * 0.7, 0.0001);
+    float coverage = smoothstep(0.5 - width, 0.5 + width, dist);
+
+    float alpha = coverage * v_color.a;
+    out_color = encode_output(vec4(v_color.rgb * alpha, alpha));
+}
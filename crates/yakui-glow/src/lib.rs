@@ -0,0 +1,380 @@
+#![doc = include_str!("../README.md")]
+
+mod program;
+mod texture;
+
+use std::collections::HashMap;
+use std::mem::size_of;
+
+use glam::UVec2;
+use glow::HasContext;
+use thunderdome::{Arena, Index};
+use yakui_core::geometry::Rect;
+use yakui_core::paint::{PaintDom, PaintLimits, Pipeline, TextureChange, Vertex};
+use yakui_core::{ManagedTextureId, TextureId};
+
+use self::program::Program;
+use self::texture::GlManagedTexture;
+
+/// Draws yakui's output with OpenGL.
+///
+/// All methods take the [`glow::Context`] that the renderer was created with.
+/// They're unsafe because they issue raw OpenGL calls, so that context has to
+/// be current on the calling thread.
+pub struct YakuiGlow {
+    limits: PaintLimits,
+    main_program: Program,
+    text_program: Program,
+    text_sdf_program: Program,
+    vertex_array: glow::VertexArray,
+    vertices: glow::Buffer,
+    indices: glow::Buffer,
+    default_texture: glow::Texture,
+    textures: Arena<glow::Texture>,
+    managed_textures: HashMap<ManagedTextureId, GlManagedTexture>,
+    srgb_framebuffer: bool,
+
+    vertex_data: Vec<Vertex>,
+    index_data: Vec<u32>,
+    commands: Vec<DrawCommand>,
+}
+
+impl YakuiGlow {
+    /// Create a renderer, compiling its shaders for the OpenGL or OpenGL ES
+    /// version of `gl`.
+    ///
+    /// # Safety
+    /// `gl` must be current on this thread.
+    pub unsafe fn new(gl: &glow::Context) -> Self {
+        let max_texture_size = gl.get_parameter_i32(glow::MAX_TEXTURE_SIZE) as u32;
+        let max_3d_texture_size = gl.get_parameter_i32(glow::MAX_3D_TEXTURE_SIZE) as u32;
+
+        // OpenGL ES has no 1D textures, so they're assumed to be emulated with
+        // 2D textures.
+        let limits = PaintLimits {
+            max_texture_size_1d: max_texture_size,
+            max_texture_size_2d: max_texture_size,
+            max_texture_size_3d: max_3d_texture_size,
+        };
+
+        let main_program = Program::new(gl, include_str!("../shaders/main.frag"));
+        let text_program = Program::new(gl, include_str!("../shaders/text.frag"));
+        let text_sdf_program = Program::new(gl, include_str!("../shaders/text_sdf.frag"));
+
+        let vertex_array = gl
+            .create_vertex_array()
+            .expect("failed to create vertex array");
+        let vertices = gl.create_buffer().expect("failed to create vertex buffer");
+        let indices = gl.create_buffer().expect("failed to create index buffer");
+
+        // yakui's vertices are uploaded as-is, so these attributes have to
+        // match the layout of `Vertex`.
+        gl.bind_vertex_array(Some(vertex_array));
+        gl.bind_buffer(glow::ARRAY_BUFFER, Some(vertices));
+        gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, Some(indices));
+
+        let stride = size_of::<Vertex>() as i32;
+        gl.enable_vertex_attrib_array(0);
+        gl.vertex_attrib_pointer_f32(0, 2, glow::FLOAT, false, stride, 0);
+        gl.enable_vertex_attrib_array(1);
+        gl.vertex_attrib_pointer_f32(1, 2, glow::FLOAT, false, stride, 8);
+        gl.enable_vertex_attrib_array(2);
+        gl.vertex_attrib_pointer_f32(2, 4, glow::FLOAT, false, stride, 16);
+
+        gl.bind_vertex_array(None);
+
+        let default_texture = gl.create_texture().expect("failed to create texture");
+        gl.bind_texture(glow::TEXTURE_2D, Some(default_texture));
+        gl.tex_image_2d(
+            glow::TEXTURE_2D,
+            0,
+            glow::RGBA8 as i32,
+            1,
+            1,
+            0,
+            glow::RGBA,
+            glow::UNSIGNED_BYTE,
+            glow::PixelUnpackData::Slice(Some(&[255, 255, 255, 255][..])),
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MIN_FILTER,
+            glow::NEAREST as i32,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MAG_FILTER,
+            glow::NEAREST as i32,
+        );
+
+        Self {
+            limits,
+            main_program,
+            text_program,
+            text_sdf_program,
+            vertex_array,
+            vertices,
+            indices,
+            default_texture,
+            textures: Arena::new(),
+            managed_textures: HashMap::new(),
+            srgb_framebuffer: false,
+
+            vertex_data: Vec::new(),
+            index_data: Vec::new(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Frees all of the OpenGL objects owned by the renderer. Textures added
+    /// with [`YakuiGlow::add_texture`] are owned by the application and are
+    /// left alone.
+    ///
+    /// # Safety
+    /// `gl` must be the context that the renderer was created with, and the
+    /// renderer must not be used afterwards.
+    pub unsafe fn destroy(&mut self, gl: &glow::Context) {
+        self.main_program.delete(gl);
+        self.text_program.delete(gl);
+        self.text_sdf_program.delete(gl);
+
+        gl.delete_vertex_array(self.vertex_array);
+        gl.delete_buffer(self.vertices);
+        gl.delete_buffer(self.indices);
+        gl.delete_texture(self.default_texture);
+
+        for (_, texture) in self.managed_textures.drain() {
+            texture.delete(gl);
+        }
+    }
+
+    /// Whether the framebuffer that yakui draws into encodes colors as sRGB by
+    /// itself, like an `SRGB8_ALPHA8` framebuffer with `GL_FRAMEBUFFER_SRGB`
+    /// enabled. Defaults to `false`.
+    pub fn srgb_framebuffer(&self) -> bool {
+        self.srgb_framebuffer
+    }
+
+    /// Tell the renderer whether the framebuffer encodes colors as sRGB by
+    /// itself. When it doesn't, which includes the default framebuffer of most
+    /// contexts and all of WebGL, yakui's shaders encode their output instead.
+    pub fn set_srgb_framebuffer(&mut self, srgb_framebuffer: bool) {
+        self.srgb_framebuffer = srgb_framebuffer;
+    }
+
+    /// Make an OpenGL texture available to yakui widgets. The texture's
+    /// filtering and wrapping parameters are used as-is.
+    pub fn add_texture(&mut self, texture: glow::Texture) -> TextureId {
+        let index = self.textures.insert(texture);
+        TextureId::User(index.to_bits())
+    }
+
+    /// Stop making a texture available to yakui widgets. The texture itself is
+    /// not deleted.
+    pub fn remove_texture(&mut self, id: TextureId) -> Option<glow::Texture> {
+        let TextureId::User(bits) = id else {
+            return None;
+        };
+
+        self.textures.remove(Index::from_bits(bits)?)
+    }
+
+    /// Draws yakui's output into the currently bound framebuffer.
+    ///
+    /// This changes the bound program, vertex array, textures, viewport,
+    /// scissor and blending state without restoring them afterwards.
+    ///
+    /// # Safety
+    /// `gl` must be the context that the renderer was created with.
+    pub unsafe fn paint(&mut self, state: &mut yakui_core::Yakui, gl: &glow::Context) {
+        profiling::scope!("yakui-glow paint");
+
+        state.set_paint_limit(self.limits);
+        let paint = state.paint();
+
+        self.update_textures(gl, paint);
+
+        let surface_size = paint.surface_size().as_uvec2();
+        let layers = paint.layers();
+        if layers.iter().all(|layer| layer.calls.is_empty()) || surface_size == UVec2::ZERO {
+            return;
+        }
+
+        self.update_buffers(paint);
+
+        gl.bind_vertex_array(Some(self.vertex_array));
+        gl.bind_buffer(glow::ARRAY_BUFFER, Some(self.vertices));
+        gl.buffer_data_u8_slice(
+            glow::ARRAY_BUFFER,
+            bytemuck::cast_slice(&self.vertex_data),
+            glow::STREAM_DRAW,
+        );
+        gl.buffer_data_u8_slice(
+            glow::ELEMENT_ARRAY_BUFFER,
+            bytemuck::cast_slice(&self.index_data),
+            glow::STREAM_DRAW,
+        );
+
+        gl.viewport(0, 0, surface_size.x as i32, surface_size.y as i32);
+        gl.disable(glow::DEPTH_TEST);
+        gl.disable(glow::CULL_FACE);
+        gl.enable(glow::SCISSOR_TEST);
+        gl.enable(glow::BLEND);
+        gl.blend_equation(glow::FUNC_ADD);
+        gl.blend_func(glow::ONE, glow::ONE_MINUS_SRC_ALPHA);
+        gl.active_texture(glow::TEXTURE0);
+
+        let encode_srgb = !self.srgb_framebuffer;
+        let mut last_program = None;
+        let mut last_clip: Option<Option<Rect>> = None;
+
+        for command in &self.commands {
+            let program = match command.pipeline {
+                Pipeline::Main => &self.main_program,
+                Pipeline::Text => &self.text_program,
+                Pipeline::TextSdf => &self.text_sdf_program,
+
+                // There's no way to register custom pipelines with this
+                // renderer, so their geometry is skipped.
+                Pipeline::Custom { .. } => continue,
+            };
+
+            if last_program != Some(program.program) {
+                last_program = Some(program.program);
+                program.bind(gl, encode_srgb);
+            }
+
+            // The scissor rect is left over from whatever drew before yakui,
+            // so it's always set for the first command.
+            if last_clip != Some(command.clip) {
+                match command.clip {
+                    Some(rect) => {
+                        let pos = rect.pos().as_uvec2();
+                        let size = rect.size().as_uvec2();
+
+                        let max = (pos + size).min(surface_size);
+                        let size =
+                            UVec2::new(max.x.saturating_sub(pos.x), max.y.saturating_sub(pos.y));
+
+                        // If the scissor rect isn't valid, we can skip this
+                        // entire draw call.
+                        if pos.x > surface_size.x
+                            || pos.y > surface_size.y
+                            || size.x == 0
+                            || size.y == 0
+                        {
+                            continue;
+                        }
+
+                        // OpenGL's scissor rect starts from the bottom left.
+                        let y = surface_size.y - (pos.y + size.y);
+                        gl.scissor(pos.x as i32, y as i32, size.x as i32, size.y as i32);
+                    }
+                    None => {
+                        gl.scissor(0, 0, surface_size.x as i32, surface_size.y as i32);
+                    }
+                }
+
+                last_clip = Some(command.clip);
+            }
+
+            let texture = command
+                .texture
+                .and_then(|id| match id {
+                    TextureId::Managed(managed) => {
+                        Some(self.managed_textures.get(&managed)?.texture)
+                    }
+                    TextureId::User(bits) => self.textures.get(Index::from_bits(bits)?).copied(),
+                })
+                .unwrap_or(self.default_texture);
+
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+
+            let count = command.index_count as i32;
+            let offset = (command.index_start * size_of::<u32>()) as i32;
+            gl.draw_elements(glow::TRIANGLES, count, glow::UNSIGNED_INT, offset);
+        }
+
+        gl.bind_vertex_array(None);
+    }
+
+    fn update_buffers(&mut self, paint: &PaintDom) {
+        profiling::scope!("update_buffers");
+
+        self.vertex_data.clear();
+        self.index_data.clear();
+        self.commands.clear();
+
+        for call in paint.layers().iter().flat_map(|layer| &layer.calls) {
+            // OpenGL ES 3.0 can't draw with a base vertex, so each call's
+            // indices are offset by the vertices that come before it.
+            let base = self.vertex_data.len() as u32;
+            let index_start = self.index_data.len();
+
+            self.vertex_data.extend_from_slice(&call.vertices);
+            self.index_data
+                .extend(call.indices.iter().map(|index| index + base));
+
+            self.commands.push(DrawCommand {
+                index_start,
+                index_count: call.indices.len(),
+                texture: call.texture,
+                pipeline: call.pipeline,
+                clip: call.clip,
+            });
+        }
+    }
+
+    unsafe fn update_textures(&mut self, gl: &glow::Context, paint: &PaintDom) {
+        profiling::scope!("update_textures");
+
+        for (id, change) in paint.texture_edits() {
+            match change {
+                TextureChange::Added => {
+                    let texture = paint.texture(id).unwrap();
+                    let new = GlManagedTexture::new(gl, texture);
+
+                    if let Some(old) = self.managed_textures.insert(id, new) {
+                        old.delete(gl);
+                    }
+                }
+
+                TextureChange::Removed => {
+                    if let Some(old) = self.managed_textures.remove(&id) {
+                        old.delete(gl);
+                    }
+                }
+
+                TextureChange::Modified => {
+                    if let Some(existing) = self.managed_textures.get_mut(&id) {
+                        let texture = paint.texture(id).unwrap();
+                        existing.update(gl, texture);
+                    }
+                }
+
+                TextureChange::ModifiedRegion(region) => {
+                    if let Some(existing) = self.managed_textures.get_mut(&id) {
+                        let texture = paint.texture(id).unwrap();
+                        existing.update_region(gl, texture, region);
+                    }
+                }
+            }
+        }
+
+        // Textures added before this renderer was created never show up as
+        // edits, so they're created here instead.
+        for (id, texture) in paint.textures() {
+            self.managed_textures
+                .entry(id)
+                .or_insert_with(|| GlManagedTexture::new(gl, texture));
+        }
+    }
+}
+
+struct DrawCommand {
+    index_start: usize,
+    index_count: usize,
+    texture: Option<TextureId>,
+    pipeline: Pipeline,
+    clip: Option<Rect>,
+}
@@ -0,0 +1,82 @@
+use glow::HasContext;
+
+const VERTEX_SHADER: &str = include_str!("../shaders/main.vert");
+const OUTPUT_SHADER: &str = include_str!("../shaders/output.glsl");
+
+/// One of yakui's shader programs, linked from the shared vertex shader and a
+/// fragment shader.
+pub(crate) struct Program {
+    pub program: glow::Program,
+    encode_srgb: Option<glow::UniformLocation>,
+}
+
+impl Program {
+    pub unsafe fn new(gl: &glow::Context, fragment_source: &str) -> Self {
+        // The same GLSL is shared between desktop OpenGL and OpenGL ES, so only
+        // the version header differs between them.
+        let header = if gl.version().is_embedded {
+            "#version 300 es\nprecision highp float;\n"
+        } else {
+            "#version 330 core\n"
+        };
+
+        let vertex_source = format!("{header}{VERTEX_SHADER}");
+        let fragment_source = format!("{header}{OUTPUT_SHADER}\n{fragment_source}");
+
+        let program = gl.create_program().expect("failed to create program");
+
+        let shaders = [
+            (glow::VERTEX_SHADER, vertex_source),
+            (glow::FRAGMENT_SHADER, fragment_source),
+        ]
+        .map(|(kind, source)| {
+            let shader = gl.create_shader(kind).expect("failed to create shader");
+            gl.shader_source(shader, &source);
+            gl.compile_shader(shader);
+
+            if !gl.get_shader_compile_status(shader) {
+                panic!(
+                    "failed to compile yakui shader: {}",
+                    gl.get_shader_info_log(shader)
+                );
+            }
+
+            gl.attach_shader(program, shader);
+            shader
+        });
+
+        gl.link_program(program);
+        if !gl.get_program_link_status(program) {
+            panic!(
+                "failed to link yakui shader: {}",
+                gl.get_program_info_log(program)
+            );
+        }
+
+        for shader in shaders {
+            gl.detach_shader(program, shader);
+            gl.delete_shader(shader);
+        }
+
+        // Every program samples from texture unit 0.
+        gl.use_program(Some(program));
+        let texture = gl.get_uniform_location(program, "u_texture");
+        gl.uniform_1_i32(texture.as_ref(), 0);
+
+        let encode_srgb = gl.get_uniform_location(program, "u_encode_srgb");
+
+        Self {
+            program,
+            encode_srgb,
+        }
+    }
+
+    pub unsafe fn bind(&self, gl: &glow::Context, encode_srgb: bool) {
+        gl.use_program(Some(self.program));
+        gl.uniform_1_i32(self.encode_srgb.as_ref(), encode_srgb as i32);
+    }
+
+    pub unsafe fn delete(&self, gl: &glow::Context) {
+        gl.delete_program(self.program);
+    }
+}
@@ -0,0 +1,160 @@
+use std::borrow::Cow;
+
+use glam::UVec2;
+use glow::HasContext;
+use yakui_core::geometry::URect;
+use yakui_core::paint::{AddressMode, Texture, TextureFilter, TextureFormat};
+
+pub(crate) struct GlManagedTexture {
+    size: UVec2,
+    format: TextureFormat,
+    pub texture: glow::Texture,
+}
+
+impl GlManagedTexture {
+    pub unsafe fn new(gl: &glow::Context, texture: &Texture) -> Self {
+        let data = premultiply_alpha(texture.format(), texture.data());
+        let size = texture.size();
+        let (internal_format, format) = gl_format(texture.format());
+
+        let gl_texture = gl.create_texture().expect("failed to create texture");
+        gl.bind_texture(glow::TEXTURE_2D, Some(gl_texture));
+
+        gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, 1);
+        gl.tex_image_2d(
+            glow::TEXTURE_2D,
+            0,
+            internal_format as i32,
+            size.x as i32,
+            size.y as i32,
+            0,
+            format,
+            glow::UNSIGNED_BYTE,
+            glow::PixelUnpackData::Slice(Some(&*data)),
+        );
+
+        let min_filter = gl_filter(texture.min_filter);
+        let mag_filter = gl_filter(texture.mag_filter);
+        let address_mode = gl_address_mode(texture.address_mode);
+
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MIN_FILTER,
+            min_filter as i32,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MAG_FILTER,
+            mag_filter as i32,
+        );
+        gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_WRAP_S, address_mode as i32);
+        gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_WRAP_T, address_mode as i32);
+
+        Self {
+            size,
+            format: texture.format(),
+            texture: gl_texture,
+        }
+    }
+
+    /// Update the GlManagedTexture from a yakui Texture.
+    pub unsafe fn update(&mut self, gl: &glow::Context, texture: &Texture) {
+        let region = URect::from_pos_size(UVec2::ZERO, texture.size());
+        self.update_region(gl, texture, region);
+    }
+
+    /// Update only the given region of the GlManagedTexture from a yakui
+    /// Texture.
+    pub unsafe fn update_region(&mut self, gl: &glow::Context, texture: &Texture, region: URect) {
+        if self.size != texture.size() || self.format != texture.format() {
+            self.delete(gl);
+            *self = Self::new(gl, texture);
+            return;
+        }
+
+        let min = region.pos().min(self.size);
+        let max = region.max().min(self.size);
+        let extent = max - min;
+        if extent.x == 0 || extent.y == 0 {
+            return;
+        }
+
+        // Copy the rows of the region into their own buffer so that the rest
+        // of the texture doesn't need to be touched.
+        let bpp = self.format.bytes_per_pixel();
+        let row_len = extent.x as usize * bpp;
+        let mut data = Vec::with_capacity(row_len * extent.y as usize);
+        for y in min.y..max.y {
+            let start = (y * self.size.x + min.x) as usize * bpp;
+            data.extend_from_slice(&texture.data()[start..start + row_len]);
+        }
+
+        let data = premultiply_alpha(self.format, &data);
+        let (_, format) = gl_format(self.format);
+
+        gl.bind_texture(glow::TEXTURE_2D, Some(self.texture));
+        gl.pixel_store_i32(glow::UNPACK_ALIGNMENT, 1);
+        gl.tex_sub_image_2d(
+            glow::TEXTURE_2D,
+            0,
+            min.x as i32,
+            min.y as i32,
+            extent.x as i32,
+            extent.y as i32,
+            format,
+            glow::UNSIGNED_BYTE,
+            glow::PixelUnpackData::Slice(Some(&*data)),
+        );
+    }
+
+    pub unsafe fn delete(&self, gl: &glow::Context) {
+        gl.delete_texture(self.texture);
+    }
+}
+
+/// Returns the internal format and pixel format used to upload a texture.
+fn gl_format(format: TextureFormat) -> (u32, u32) {
+    match format {
+        TextureFormat::Rgba8Srgb => (glow::SRGB8_ALPHA8, glow::RGBA),
+        TextureFormat::Rgba8SrgbPremultiplied => (glow::SRGB8_ALPHA8, glow::RGBA),
+        TextureFormat::R8 => (glow::R8, glow::RED),
+    }
+}
+
+fn gl_filter(filter: TextureFilter) -> u32 {
+    match filter {
+        TextureFilter::Linear => glow::LINEAR,
+        TextureFilter::Nearest => glow::NEAREST,
+    }
+}
+
+fn gl_address_mode(address_mode: AddressMode) -> u32 {
+    match address_mode {
+        AddressMode::ClampToEdge => glow::CLAMP_TO_EDGE,
+        AddressMode::Repeat => glow::REPEAT,
+    }
+}
+
+fn premultiply_alpha(format: TextureFormat, data: &[u8]) -> Cow<'_, [u8]> {
+    match format {
+        TextureFormat::Rgba8Srgb => {
+            let mut data = data.to_vec();
+            premultiply_pixels(&mut data);
+            Cow::Owned(data)
+        }
+        TextureFormat::Rgba8SrgbPremultiplied => Cow::Borrowed(data),
+        TextureFormat::R8 => Cow::Borrowed(data),
+    }
+}
+
+fn premultiply_pixels(data: &mut [u8]) {
+    fn premul(a: u8, b: u8) -> u8 {
+        (((a as u32) * (b as u32) + 255) >> 8) as u8
+    }
+
+    for pixel in data.chunks_exact_mut(4) {
+        pixel[0] = premul(pixel[0], pixel[3]);
+        pixel[1] = premul(pixel[1], pixel[3]);
+        pixel[2] = premul(pixel[2], pixel[3]);
+    }
+}
@@ -1,5 +1,5 @@
-use sdl2::keyboard::{Mod, Scancode};
-use yakui_core::input::{KeyCode, Modifiers};
+use sdl2::keyboard::{Keycode, Mod, Scancode};
+use yakui_core::input::{KeyCode, KeyLocation, LogicalKey, Modifiers};
 
 pub fn from_sdl_scancode(key: Scancode) -> Option<KeyCode> {
     Some(match key {
@@ -84,6 +84,65 @@ pub fn from_sdl_scancode(key: Scancode) -> Option<KeyCode> {
     })
 }
 
+pub fn from_sdl_keycode(key: Keycode) -> LogicalKey {
+    match key {
+        Keycode::BACKSPACE => LogicalKey::Backspace,
+        Keycode::TAB => LogicalKey::Tab,
+        Keycode::RETURN | Keycode::KP_ENTER => LogicalKey::Enter,
+        Keycode::ESCAPE => LogicalKey::Escape,
+        Keycode::DELETE => LogicalKey::Delete,
+        Keycode::INSERT => LogicalKey::Insert,
+        Keycode::CAPSLOCK => LogicalKey::CapsLock,
+        Keycode::LEFT => LogicalKey::ArrowLeft,
+        Keycode::RIGHT => LogicalKey::ArrowRight,
+        Keycode::UP => LogicalKey::ArrowUp,
+        Keycode::DOWN => LogicalKey::ArrowDown,
+        Keycode::HOME => LogicalKey::Home,
+        Keycode::END => LogicalKey::End,
+        Keycode::PAGEUP => LogicalKey::PageUp,
+        Keycode::PAGEDOWN => LogicalKey::PageDown,
+        Keycode::LSHIFT | Keycode::RSHIFT => LogicalKey::Shift,
+        Keycode::LCTRL | Keycode::RCTRL => LogicalKey::Control,
+        Keycode::LALT | Keycode::RALT => LogicalKey::Alt,
+        Keycode::LGUI | Keycode::RGUI => LogicalKey::Meta,
+        Keycode::F1 => LogicalKey::F1,
+        Keycode::F2 => LogicalKey::F2,
+        Keycode::F3 => LogicalKey::F3,
+        Keycode::F4 => LogicalKey::F4,
+        Keycode::F5 => LogicalKey::F5,
+        Keycode::F6 => LogicalKey::F6,
+        Keycode::F7 => LogicalKey::F7,
+        Keycode::F8 => LogicalKey::F8,
+        Keycode::F9 => LogicalKey::F9,
+        Keycode::F10 => LogicalKey::F10,
+        Keycode::F11 => LogicalKey::F11,
+        Keycode::F12 => LogicalKey::F12,
+
+        // Keycodes for keys that type a character are that character.
+        _ => match char::from_u32(key.into_i32() as u32) {
+            Some(c) if !c.is_control() => LogicalKey::Character(c.to_string()),
+            _ => LogicalKey::Unidentified,
+        },
+    }
+}
+
+pub fn sdl_key_location(key: Scancode) -> KeyLocation {
+    let code = key as i32;
+    let numpad = (Scancode::KpDivide as i32..=Scancode::KpPeriod as i32).contains(&code)
+        || (Scancode::Kp00 as i32..=Scancode::KpHexadecimal as i32).contains(&code)
+        || matches!(
+            key,
+            Scancode::KpEquals | Scancode::KpComma | Scancode::KpEqualsAS400
+        );
+
+    match key {
+        _ if numpad => KeyLocation::Numpad,
+        Scancode::LShift | Scancode::LCtrl | Scancode::LAlt | Scancode::LGui => KeyLocation::Left,
+        Scancode::RShift | Scancode::RCtrl | Scancode::RAlt | Scancode::RGui => KeyLocation::Right,
+        _ => KeyLocation::Standard,
+    }
+}
+
 pub fn from_sdl_modifiers(sdl_mods: Mod) -> Modifiers {
     let mut mods = Modifiers::default();
 
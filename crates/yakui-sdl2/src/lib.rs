@@ -5,9 +5,9 @@ use sdl2::mouse::MouseButton as SdlMouseButton;
 use sdl2::video::Window;
 use yakui_core::event::Event;
 use yakui_core::geometry::{Rect, UVec2, Vec2};
-use yakui_core::input::MouseButton;
+use yakui_core::input::{LogicalKey, MouseButton, ScrollUnit};
 
-use self::keys::from_sdl_scancode;
+use self::keys::{from_sdl_keycode, from_sdl_modifiers, from_sdl_scancode, sdl_key_location};
 
 pub struct YakuiSdl2 {
     init: Option<InitState>,
@@ -69,6 +69,8 @@ impl YakuiSdl2 {
                     SdlMouseButton::Left => MouseButton::One,
                     SdlMouseButton::Right => MouseButton::Two,
                     SdlMouseButton::Middle => MouseButton::Three,
+                    SdlMouseButton::X1 => MouseButton::Back,
+                    SdlMouseButton::X2 => MouseButton::Forward,
                     _ => return false,
                 };
 
@@ -80,6 +82,8 @@ impl YakuiSdl2 {
                     SdlMouseButton::Left => MouseButton::One,
                     SdlMouseButton::Right => MouseButton::Two,
                     SdlMouseButton::Middle => MouseButton::Three,
+                    SdlMouseButton::X1 => MouseButton::Back,
+                    SdlMouseButton::X2 => MouseButton::Forward,
                     _ => return false,
                 };
 
@@ -93,14 +97,10 @@ impl YakuiSdl2 {
                 precise_x,
                 precise_y,
                 ..
-            } => {
-                // Observed logical pixels per scroll wheel increment in Windows on Chrome
-                const LINE_HEIGHT: f32 = 100.0 / 3.0;
-
-                state.handle_event(Event::MouseScroll {
-                    delta: Vec2::new(*precise_x, -*precise_y) * LINE_HEIGHT,
-                })
-            }
+            } => state.handle_event(Event::MouseScroll {
+                delta: Vec2::new(*precise_x, -*precise_y),
+                unit: ScrollUnit::Lines,
+            }),
 
             SdlEvent::TextInput { text, .. } => {
                 for c in text.chars() {
@@ -110,17 +110,45 @@ impl YakuiSdl2 {
                 false
             }
 
-            SdlEvent::KeyDown { scancode, .. } => {
-                if let Some(key) = scancode.and_then(from_sdl_scancode) {
-                    state.handle_event(Event::KeyChanged { key, down: true })
+            SdlEvent::KeyDown {
+                keycode,
+                scancode: Some(scancode),
+                keymod,
+                repeat,
+                ..
+            } => {
+                state.handle_event(Event::ModifiersChanged(from_sdl_modifiers(*keymod)));
+
+                if let Some(key) = from_sdl_scancode(*scancode) {
+                    state.handle_event(Event::KeyChanged {
+                        key,
+                        logical_key: keycode.map_or(LogicalKey::Unidentified, from_sdl_keycode),
+                        location: sdl_key_location(*scancode),
+                        down: true,
+                        repeat: *repeat,
+                    })
                 } else {
                     false
                 }
             }
 
-            SdlEvent::KeyUp { scancode, .. } => {
-                if let Some(key) = scancode.and_then(from_sdl_scancode) {
-                    state.handle_event(Event::KeyChanged { key, down: false })
+            SdlEvent::KeyUp {
+                keycode,
+                scancode: Some(scancode),
+                keymod,
+                repeat,
+                ..
+            } => {
+                state.handle_event(Event::ModifiersChanged(from_sdl_modifiers(*keymod)));
+
+                if let Some(key) = from_sdl_scancode(*scancode) {
+                    state.handle_event(Event::KeyChanged {
+                        key,
+                        logical_key: keycode.map_or(LogicalKey::Unidentified, from_sdl_keycode),
+                        location: sdl_key_location(*scancode),
+                        down: false,
+                        repeat: *repeat,
+                    })
                 } else {
                     false
                 }
@@ -0,0 +1,89 @@
+use glam::{UVec2, Vec4};
+use yakui_core::geometry::Color;
+
+/// An image that yakui can be painted into.
+///
+/// Pixels are stored as premultiplied linear colors so that blending behaves
+/// the same as it does in yakui's GPU renderers.
+#[derive(Debug, Clone)]
+pub struct Framebuffer {
+    size: UVec2,
+    pixels: Vec<Vec4>,
+}
+
+impl Framebuffer {
+    /// Create a transparent framebuffer of the given size in pixels.
+    pub fn new(size: UVec2) -> Self {
+        Self {
+            size,
+            pixels: vec![Vec4::ZERO; (size.x * size.y) as usize],
+        }
+    }
+
+    /// The size of the framebuffer in pixels.
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Change the size of the framebuffer, clearing it to transparent.
+    pub fn resize(&mut self, size: UVec2) {
+        self.size = size;
+        self.pixels.clear();
+        self.pixels.resize((size.x * size.y) as usize, Vec4::ZERO);
+    }
+
+    /// Fill the whole framebuffer with a color.
+    pub fn clear(&mut self, color: Color) {
+        let color = color.to_linear();
+        let color = Vec4::new(
+            color.x * color.w,
+            color.y * color.w,
+            color.z * color.w,
+            color.w,
+        );
+        self.pixels.fill(color);
+    }
+
+    /// The premultiplied linear color of every pixel, row by row from the top
+    /// left.
+    pub fn pixels(&self) -> &[Vec4] {
+        &self.pixels
+    }
+
+    /// The color of one pixel, converted to a straight alpha sRGB [`Color`].
+    pub fn get(&self, pos: UVec2) -> Option<Color> {
+        if pos.x >= self.size.x || pos.y >= self.size.y {
+            return None;
+        }
+
+        Some(encode_pixel(
+            self.pixels[(pos.y * self.size.x + pos.x) as usize],
+        ))
+    }
+
+    /// Converts the framebuffer to straight alpha, sRGB encoded RGBA8 pixels,
+    /// which is what most image formats expect.
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&pixel| {
+                let color = encode_pixel(pixel);
+                [color.r, color.g, color.b, color.a]
+            })
+            .collect()
+    }
+
+    pub(crate) fn blend(&mut self, x: u32, y: u32, color: Vec4) {
+        let pixel = &mut self.pixels[(y * self.size.x + x) as usize];
+        *pixel = color + *pixel * (1.0 - color.w);
+    }
+}
+
+fn encode_pixel(pixel: Vec4) -> Color {
+    let alpha = pixel.w.clamp(0.0, 1.0);
+    if alpha <= 0.0 {
+        return Color::CLEAR;
+    }
+
+    Color::from_linear((pixel.truncate() / alpha).extend(alpha))
+}
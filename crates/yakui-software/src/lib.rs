@@ -0,0 +1,168 @@
+#![doc = include_str!("../README.md")]
+
+mod framebuffer;
+mod raster;
+mod texture;
+
+use std::collections::HashMap;
+
+use glam::UVec2;
+use thunderdome::{Arena, Index};
+use yakui_core::paint::{PaintDom, PaintLimits, Pipeline, Texture, TextureChange};
+use yakui_core::{ManagedTextureId, TextureId};
+
+pub use self::framebuffer::Framebuffer;
+
+use self::raster::{draw_triangle, Shader};
+use self::texture::SoftwareTexture;
+
+/// Draws yakui's output on the CPU.
+pub struct YakuiSoftware {
+    textures: Arena<SoftwareTexture>,
+    managed_textures: HashMap<ManagedTextureId, SoftwareTexture>,
+}
+
+/// There's no hardware limit on texture sizes, so this is only meant to keep
+/// atlases from growing unreasonably large.
+const MAX_TEXTURE_SIZE: u32 = 8192;
+
+impl Default for YakuiSoftware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl YakuiSoftware {
+    /// Create a new software renderer.
+    pub fn new() -> Self {
+        Self {
+            textures: Arena::new(),
+            managed_textures: HashMap::new(),
+        }
+    }
+
+    /// Creates a `TextureId` from a texture owned by the application that can
+    /// then be used by any yakui widgets.
+    pub fn add_texture(&mut self, texture: &Texture) -> TextureId {
+        let index = self.textures.insert(SoftwareTexture::new(texture));
+        TextureId::User(index.to_bits())
+    }
+
+    /// Update an existing texture with new contents.
+    ///
+    /// ## Panics
+    ///
+    /// Will panic if `TextureId` was not created from a previous call to
+    /// `add_texture`.
+    pub fn update_texture(&mut self, id: TextureId, texture: &Texture) {
+        let index = match id {
+            TextureId::User(bits) => Index::from_bits(bits).expect("invalid user texture"),
+            _ => panic!("invalid user texture"),
+        };
+
+        let existing = self
+            .textures
+            .get_mut(index)
+            .expect("user texture does not exist");
+        existing.update(texture);
+    }
+
+    /// Stop making a texture available to yakui widgets.
+    pub fn remove_texture(&mut self, id: TextureId) {
+        if let TextureId::User(bits) = id {
+            if let Some(index) = Index::from_bits(bits) {
+                self.textures.remove(index);
+            }
+        }
+    }
+
+    /// Paints yakui's output into a new framebuffer the size of yakui's
+    /// surface.
+    pub fn paint(&mut self, state: &mut yakui_core::Yakui) -> Framebuffer {
+        let size = state.paint().surface_size().as_uvec2();
+        let mut target = Framebuffer::new(size);
+        self.paint_into(state, &mut target);
+        target
+    }
+
+    /// Paints yakui's output over the existing contents of a framebuffer,
+    /// which should be the same size as yakui's surface.
+    pub fn paint_into(&mut self, state: &mut yakui_core::Yakui, target: &mut Framebuffer) {
+        profiling::scope!("yakui-software paint");
+
+        state.set_paint_limit(PaintLimits {
+            max_texture_size_1d: MAX_TEXTURE_SIZE,
+            max_texture_size_2d: MAX_TEXTURE_SIZE,
+            max_texture_size_3d: MAX_TEXTURE_SIZE,
+        });
+
+        let paint = state.paint();
+        self.update_textures(paint);
+
+        for call in paint.layers().iter().flat_map(|layer| &layer.calls) {
+            // Custom pipelines run shaders that only exist on the GPU, so
+            // their geometry is skipped.
+            if let Pipeline::Custom { .. } = call.pipeline {
+                continue;
+            }
+
+            let texture = call.texture.and_then(|id| match id {
+                TextureId::Managed(managed) => self.managed_textures.get(&managed),
+                TextureId::User(bits) => self.textures.get(Index::from_bits(bits)?),
+            });
+
+            let shader = Shader {
+                pipeline: call.pipeline,
+                texture,
+            };
+
+            let clip = match call.clip {
+                Some(rect) => (rect.pos().as_uvec2(), rect.max().as_uvec2()),
+                None => (UVec2::ZERO, target.size()),
+            };
+
+            for triangle in call.indices.chunks_exact(3) {
+                let vertices = [0, 1, 2].map(|i| &call.vertices[triangle[i] as usize]);
+                draw_triangle(target, clip, vertices, &shader);
+            }
+        }
+    }
+
+    fn update_textures(&mut self, paint: &PaintDom) {
+        profiling::scope!("update_textures");
+
+        for (id, texture) in paint.textures() {
+            self.managed_textures
+                .entry(id)
+                .or_insert_with(|| SoftwareTexture::new(texture));
+        }
+
+        for (id, change) in paint.texture_edits() {
+            match change {
+                TextureChange::Added => {
+                    let texture = paint.texture(id).unwrap();
+                    self.managed_textures
+                        .insert(id, SoftwareTexture::new(texture));
+                }
+
+                TextureChange::Removed => {
+                    self.managed_textures.remove(&id);
+                }
+
+                TextureChange::Modified => {
+                    if let Some(existing) = self.managed_textures.get_mut(&id) {
+                        let texture = paint.texture(id).unwrap();
+                        existing.update(texture);
+                    }
+                }
+
+                TextureChange::ModifiedRegion(region) => {
+                    if let Some(existing) = self.managed_textures.get_mut(&id) {
+                        let texture = paint.texture(id).unwrap();
+                        existing.update_region(texture, region);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use glam::{UVec2, Vec2, Vec4};
+use yakui_core::paint::{Pipeline, Vertex};
+
+use crate::framebuffer::Framebuffer;
+use crate::texture::SoftwareTexture;
+
+/// Everything needed to shade the triangles of one paint call.
+pub(crate) struct Shader<'a> {
+    pub pipeline: Pipeline,
+    pub texture: Option<&'a SoftwareTexture>,
+}
+
+impl Shader<'_> {
+    /// The CPU equivalent of yakui's fragment shaders. `dx` and `dy` are how
+    /// far the texture coordinates move over one pixel.
+    fn shade(&self, texcoord: Vec2, color: Vec4, dx: Vec2, dy: Vec2) -> Vec4 {
+        let sample = |texcoord: Vec2| match self.texture {
+            Some(texture) => {
+                let size = texture.size().as_vec2();
+                let texels = (dx * size).abs().max((dy * size).abs()).max_element();
+                texture.sample(texcoord, texels > 1.0)
+            }
+            None => Vec4::ONE,
+        };
+
+        match self.pipeline {
+            Pipeline::Text => {
+                let coverage = sample(texcoord);
+
+                if color.w > 0.0 {
+                    let alpha = coverage.truncate().max_element() * color.w * coverage.w;
+                    (color.truncate() * alpha).extend(alpha)
+                } else {
+                    coverage
+                }
+            }
+
+            Pipeline::TextSdf => {
+                // Without hardware derivatives, fwidth is approximated with
+                // the neighboring pixels' samples.
+                let distance = sample(texcoord).x;
+                let fwidth = (sample(texcoord + dx).x - distance).abs()
+                    + (sample(texcoord + dy).x - distance).abs();
+                let width = (fwidth * 0.7).max(0.0001);
+                let coverage = smoothstep(0.5 - width, 0.5 + width, distance);
+
+                let alpha = coverage * color.w;
+                (color.truncate() * alpha).extend(alpha)
+            }
+
+            _ => color * sample(texcoord) * color.w,
+        }
+    }
+}
+
+/// Fills a triangle into the framebuffer, only touching pixels within `clip`.
+///
+/// Pixels are covered when their center is inside of the triangle, with ties
+/// on a shared edge going to exactly one of the triangles so that they don't
+/// blend twice.
+pub(crate) fn draw_triangle(
+    target: &mut Framebuffer,
+    clip: (UVec2, UVec2),
+    vertices: [&Vertex; 3],
+    shader: &Shader<'_>,
+) {
+    let surface = target.size().as_vec2();
+    let points = vertices.map(|vertex| vertex.position * surface);
+
+    let area = edge(points[0], points[1], points[2]);
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+
+    // Barycentric weights of any point, which are affine over the triangle.
+    let weights = |point: Vec2| {
+        [
+            edge(points[1], points[2], point) / area,
+            edge(points[2], points[0], point) / area,
+            edge(points[0], points[1], point) / area,
+        ]
+    };
+
+    let interpolate = |weights: [f32; 3]| {
+        let mut texcoord = Vec2::ZERO;
+        let mut color = Vec4::ZERO;
+        for (vertex, weight) in vertices.iter().zip(weights) {
+            texcoord += vertex.texcoord * weight;
+            color += vertex.color * weight;
+        }
+        (texcoord, color)
+    };
+
+    let (origin, _) = interpolate(weights(Vec2::ZERO));
+    let (right, _) = interpolate(weights(Vec2::X));
+    let (down, _) = interpolate(weights(Vec2::Y));
+    let dx = right - origin;
+    let dy = down - origin;
+
+    let min = points[0]
+        .min(points[1])
+        .min(points[2])
+        .floor()
+        .max(Vec2::ZERO);
+    let max = points[0]
+        .max(points[1])
+        .max(points[2])
+        .ceil()
+        .max(Vec2::ZERO);
+    let min = min.as_uvec2().max(clip.0);
+    let max = max.as_uvec2().min(clip.1).min(target.size());
+
+    let edges = [(1, 2), (2, 0), (0, 1)];
+    let owned = edges.map(|(a, b)| {
+        let dir = (points[b] - points[a]) * area.signum();
+        dir.y > 0.0 || (dir.y == 0.0 && dir.x < 0.0)
+    });
+
+    for y in min.y..max.y {
+        for x in min.x..max.x {
+            let center = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+            let weights = weights(center);
+
+            let inside = weights
+                .iter()
+                .zip(owned)
+                .all(|(&weight, owned)| weight > 0.0 || (weight == 0.0 && owned));
+
+            if !inside {
+                continue;
+            }
+
+            let (texcoord, color) = interpolate(weights);
+            target.blend(x, y, shader.shade(texcoord, color, dx, dy));
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `c`.
+///
+/// The edge's endpoints are always used in the same order so that triangles
+/// on either side of a shared edge get exactly opposite results.
+fn edge(a: Vec2, b: Vec2, c: Vec2) -> f32 {
+    if (a.x, a.y) > (b.x, b.y) {
+        return -edge(b, a, c);
+    }
+
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+fn smoothstep(low: f32, high: f32, value: f32) -> f32 {
+    let t = ((value - low) / (high - low)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
@@ -0,0 +1,120 @@
+use glam::{UVec2, Vec2, Vec4};
+use yakui_core::geometry::{Color, URect};
+use yakui_core::paint::{AddressMode, Texture, TextureFilter, TextureFormat};
+
+/// A texture decoded into premultiplied linear colors, ready to be sampled.
+pub(crate) struct SoftwareTexture {
+    size: UVec2,
+    format: TextureFormat,
+    min_filter: TextureFilter,
+    mag_filter: TextureFilter,
+    address_mode: AddressMode,
+    pixels: Vec<Vec4>,
+}
+
+impl SoftwareTexture {
+    pub fn new(texture: &Texture) -> Self {
+        let size = texture.size();
+        let mut new = Self {
+            size,
+            format: texture.format(),
+            min_filter: texture.min_filter,
+            mag_filter: texture.mag_filter,
+            address_mode: texture.address_mode,
+            pixels: vec![Vec4::ZERO; (size.x * size.y) as usize],
+        };
+
+        new.decode(texture, URect::from_pos_size(UVec2::ZERO, size));
+        new
+    }
+
+    /// Update the SoftwareTexture from a yakui Texture.
+    pub fn update(&mut self, texture: &Texture) {
+        *self = Self::new(texture);
+    }
+
+    /// Update only the given region of the SoftwareTexture from a yakui
+    /// Texture.
+    pub fn update_region(&mut self, texture: &Texture, region: URect) {
+        if self.size != texture.size() || self.format != texture.format() {
+            self.update(texture);
+            return;
+        }
+
+        self.decode(texture, region);
+    }
+
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Samples the texture at normalized coordinates. `minified` tells whether
+    /// more than one texel covers each pixel.
+    pub fn sample(&self, texcoord: Vec2, minified: bool) -> Vec4 {
+        let filter = if minified {
+            self.min_filter
+        } else {
+            self.mag_filter
+        };
+
+        let pos = texcoord * self.size.as_vec2();
+
+        match filter {
+            TextureFilter::Nearest => self.texel(pos.floor().as_ivec2().to_array()),
+            TextureFilter::Linear => {
+                let pos = pos - 0.5;
+                let base = pos.floor();
+                let t = pos - base;
+                let [x, y] = base.as_ivec2().to_array();
+
+                let top = self.texel([x, y]).lerp(self.texel([x + 1, y]), t.x);
+                let bottom = self.texel([x, y + 1]).lerp(self.texel([x + 1, y + 1]), t.x);
+                top.lerp(bottom, t.y)
+            }
+        }
+    }
+
+    fn texel(&self, [x, y]: [i32; 2]) -> Vec4 {
+        if self.size.x == 0 || self.size.y == 0 {
+            return Vec4::ZERO;
+        }
+
+        let x = self.address(x, self.size.x);
+        let y = self.address(y, self.size.y);
+        self.pixels[(y * self.size.x + x) as usize]
+    }
+
+    fn address(&self, value: i32, size: u32) -> u32 {
+        match self.address_mode {
+            AddressMode::ClampToEdge => value.clamp(0, size as i32 - 1) as u32,
+            AddressMode::Repeat => value.rem_euclid(size as i32) as u32,
+        }
+    }
+
+    fn decode(&mut self, texture: &Texture, region: URect) {
+        let min = region.pos().min(self.size);
+        let max = region.max().min(self.size);
+        let data = texture.data();
+
+        for y in min.y..max.y {
+            for x in min.x..max.x {
+                let index = (y * self.size.x + x) as usize;
+                let bytes = &data[index * self.format.bytes_per_pixel()..];
+
+                // These match what a GPU would read out of the texture formats
+                // that yakui's GPU renderers upload these textures as.
+                self.pixels[index] = match self.format {
+                    TextureFormat::Rgba8Srgb => {
+                        let color = Color::rgba(bytes[0], bytes[1], bytes[2], bytes[3]);
+                        let linear = color.to_linear();
+                        (linear.truncate() * linear.w).extend(linear.w)
+                    }
+                    TextureFormat::Rgba8SrgbPremultiplied => {
+                        Color::rgba(bytes[0], bytes[1], bytes[2], bytes[3]).to_linear()
+                    }
+                    TextureFormat::R8 => Vec4::new(bytes[0] as f32 / 255.0, 0.0, 0.0, 1.0),
+                };
+            }
+        }
+    }
+}
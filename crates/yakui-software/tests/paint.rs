@@ -0,0 +1,67 @@
+use yakui::geometry::{Color, Rect, UVec2, Vec2};
+use yakui::paint::{Texture, TextureFormat};
+use yakui::{align, colored_box, image, offset, Alignment, Yakui};
+use yakui_software::{Framebuffer, YakuiSoftware};
+
+/// Builds a frame with the children placed at the top left of the surface.
+fn build(yak: &mut Yakui, size: Vec2, children: impl FnOnce()) {
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+    yak.start();
+    align(Alignment::TOP_LEFT, children);
+    yak.finish();
+}
+
+#[test]
+fn colored_box_is_painted_inside_its_rect() {
+    let mut yak = Yakui::new();
+    build(&mut yak, Vec2::new(20.0, 20.0), || {
+        offset(Vec2::new(5.0, 5.0), || {
+            colored_box(Color::RED, [10.0, 10.0]);
+        });
+    });
+
+    let framebuffer = YakuiSoftware::default().paint(&mut yak);
+    assert_eq!(framebuffer.size(), UVec2::new(20, 20));
+
+    assert_eq!(framebuffer.get(UVec2::new(5, 5)), Some(Color::RED));
+    assert_eq!(framebuffer.get(UVec2::new(14, 14)), Some(Color::RED));
+    assert_eq!(framebuffer.get(UVec2::new(4, 4)), Some(Color::CLEAR));
+    assert_eq!(framebuffer.get(UVec2::new(15, 15)), Some(Color::CLEAR));
+    assert_eq!(framebuffer.get(UVec2::new(20, 0)), None);
+}
+
+#[test]
+fn paint_into_keeps_pixels_the_ui_does_not_cover() {
+    let mut yak = Yakui::new();
+    build(&mut yak, Vec2::new(20.0, 20.0), || {
+        colored_box(Color::RED, [10.0, 10.0]);
+    });
+
+    let mut framebuffer = Framebuffer::new(UVec2::new(20, 20));
+    framebuffer.clear(Color::BLUE);
+    YakuiSoftware::new().paint_into(&mut yak, &mut framebuffer);
+
+    assert_eq!(framebuffer.get(UVec2::new(5, 5)), Some(Color::RED));
+    assert_eq!(framebuffer.get(UVec2::new(15, 15)), Some(Color::BLUE));
+}
+
+#[test]
+fn user_textures_are_sampled() {
+    let mut renderer = YakuiSoftware::new();
+    let texture = Texture::new(
+        TextureFormat::Rgba8Srgb,
+        UVec2::new(1, 1),
+        vec![0, 255, 0, 255],
+    );
+    let id = renderer.add_texture(&texture);
+
+    let mut yak = Yakui::new();
+    build(&mut yak, Vec2::new(20.0, 20.0), || {
+        image(id, [10.0, 10.0]);
+    });
+
+    let framebuffer = renderer.paint(&mut yak);
+    assert_eq!(framebuffer.get(UVec2::new(5, 5)), Some(Color::GREEN));
+    assert_eq!(framebuffer.get(UVec2::new(15, 15)), Some(Color::CLEAR));
+}
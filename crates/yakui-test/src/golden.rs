@@ -0,0 +1,226 @@
+//! Compares rendered frames against stored golden images, drawing them with
+//! the CPU renderer from `yakui-software` so that no GPU is needed.
+
+use std::fmt;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+use image::{Rgba, RgbaImage};
+use yakui_core::Yakui;
+use yakui_software::YakuiSoftware;
+
+/// Setting this environment variable to `1` makes [`assert_golden`] overwrite
+/// golden images with the current output instead of comparing against them.
+pub const UPDATE_GOLDEN_VAR: &str = "YAKUI_UPDATE_GOLDEN";
+
+/// Asserts that the current frame of a [`Yakui`] matches a golden image
+/// stored in the `tests/golden` folder of the crate the test is in.
+///
+/// ```ignore
+/// yakui_test::assert_golden!(yak, "button_hovered");
+/// yakui_test::assert_golden!(yak, "button_hovered", Tolerance::exact());
+/// ```
+#[macro_export]
+macro_rules! assert_golden {
+    ($state:expr, $name:expr) => {
+        ::yakui_test::assert_golden!($state, $name, ::yakui_test::Tolerance::default())
+    };
+
+    ($state:expr, $name:expr, $tolerance:expr) => {
+        ::yakui_test::assert_golden(
+            &mut $state,
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("golden")
+                .join(format!("{}.png", $name)),
+            $tolerance,
+        )
+    };
+}
+
+/// How different a rendered image can be from its golden image and still
+/// match. The CPU renderer isn't bit-for-bit stable across changes to
+/// rasterization, so small differences are allowed by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tolerance {
+    /// How far apart any channel of two pixels can be before the pixels are
+    /// counted as different.
+    pub channel: u8,
+
+    /// How many pixels can be different before the images don't match.
+    pub pixels: usize,
+}
+
+impl Tolerance {
+    /// Only allows images that are identical.
+    pub const fn exact() -> Self {
+        Self {
+            channel: 0,
+            pixels: 0,
+        }
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            channel: 2,
+            pixels: 0,
+        }
+    }
+}
+
+/// How a rendered image differs from its golden image.
+#[derive(Debug)]
+pub enum Mismatch {
+    /// The images aren't the same size, so pixels weren't compared.
+    Size {
+        /// The width and height of the golden image.
+        expected: (u32, u32),
+
+        /// The width and height of the rendered image.
+        actual: (u32, u32),
+    },
+
+    /// More pixels were different than the tolerance allows.
+    Pixels {
+        /// How many pixels were different.
+        count: usize,
+
+        /// The largest difference of any channel of any pixel.
+        max_difference: u8,
+
+        /// An image with differing pixels in red and matching ones dimmed.
+        diff: RgbaImage,
+    },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mismatch::Size { expected, actual } => write!(
+                f,
+                "expected an image of size {}x{}, but got {}x{}",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+            Mismatch::Pixels {
+                count,
+                max_difference,
+                ..
+            } => write!(
+                f,
+                "{count} pixels were different, by up to {max_difference} in one channel"
+            ),
+        }
+    }
+}
+
+/// Paints the current frame of a [`Yakui`] with the CPU renderer.
+pub fn render(state: &mut Yakui) -> RgbaImage {
+    let framebuffer = YakuiSoftware::new().paint(state);
+    let size = framebuffer.size();
+
+    RgbaImage::from_raw(size.x, size.y, framebuffer.to_rgba8())
+        .expect("framebuffer size didn't match its pixels")
+}
+
+/// Compares two images, allowing them to differ within `tolerance`.
+pub fn compare(
+    expected: &RgbaImage,
+    actual: &RgbaImage,
+    tolerance: Tolerance,
+) -> Result<(), Mismatch> {
+    if expected.dimensions() != actual.dimensions() {
+        return Err(Mismatch::Size {
+            expected: expected.dimensions(),
+            actual: actual.dimensions(),
+        });
+    }
+
+    let mut count = 0;
+    let mut max_difference = 0;
+    let mut diff = RgbaImage::new(actual.width(), actual.height());
+
+    for ((expected, actual), out) in expected
+        .pixels()
+        .zip(actual.pixels())
+        .zip(diff.pixels_mut())
+    {
+        let difference = expected
+            .0
+            .iter()
+            .zip(actual.0)
+            .map(|(&a, b)| a.abs_diff(b))
+            .max()
+            .unwrap_or(0);
+
+        max_difference = max_difference.max(difference);
+
+        if difference > tolerance.channel {
+            count += 1;
+            *out = Rgba([255, 0, 0, 255]);
+        } else {
+            let [r, g, b, _] = actual.0;
+            *out = Rgba([r / 4, g / 4, b / 4, 255]);
+        }
+    }
+
+    if count > tolerance.pixels {
+        Err(Mismatch::Pixels {
+            count,
+            max_difference,
+            diff,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Renders the current frame of a [`Yakui`] and compares it against the PNG
+/// at `path`. Usually called through the [`assert_golden!`] macro.
+///
+/// If the golden image doesn't exist yet, or [`UPDATE_GOLDEN_VAR`] is set,
+/// the golden image is written instead. When the images don't match, the
+/// rendered image and a diff are saved next to the golden image for review.
+///
+/// # Panics
+///
+/// Panics if the images don't match or if reading or writing images fails.
+pub fn assert_golden<P: AsRef<Path>>(state: &mut Yakui, path: P, tolerance: Tolerance) {
+    let path = path.as_ref();
+    let actual = render(state);
+
+    let update = matches!(std::env::var(UPDATE_GOLDEN_VAR).as_deref(), Ok("1"));
+    if update || !path.exists() {
+        create_dir_all(path.parent().unwrap()).unwrap();
+        actual.save(path).unwrap();
+        return;
+    }
+
+    let expected = image::open(path)
+        .unwrap_or_else(|err| panic!("could not read golden image {}: {err}", path.display()))
+        .to_rgba8();
+
+    if let Err(mismatch) = compare(&expected, &actual, tolerance) {
+        let actual_path = sibling(path, "actual");
+        actual.save(&actual_path).unwrap();
+
+        if let Mismatch::Pixels { diff, .. } = &mismatch {
+            diff.save(sibling(path, "diff")).unwrap();
+        }
+
+        panic!(
+            "rendered image didn't match golden image {}: {mismatch}\n\
+             The rendered image was saved to {}. If the change is intended, \
+             run again with {UPDATE_GOLDEN_VAR}=1 to update the golden image.",
+            path.display(),
+            actual_path.display(),
+        );
+    }
+}
+
+/// Turns `golden/name.png` into `golden/name.suffix.png`.
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{stem}.{suffix}.png"))
+}
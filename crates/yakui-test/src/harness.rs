@@ -0,0 +1,174 @@
+//! Drives a [`Yakui`] without a window so tests can click on widgets, type
+//! into them, and check how they respond.
+
+use std::time::Duration;
+
+use yakui_core::event::Event;
+use yakui_core::geometry::{Rect, Vec2};
+use yakui_core::input::{KeyCode, KeyLocation, LogicalKey, MouseButton};
+use yakui_core::inspect::Inspection;
+use yakui_core::{FrameStats, WidgetId, Yakui};
+
+/// A headless yakui that builds frames on demand and accepts synthetic input.
+///
+/// Events are handled against the layout of the most recent frame, just like
+/// in a real application, so widgets usually see input on the frame after it
+/// happened. Build a frame, send input, then build another frame to see the
+/// widgets' responses.
+pub struct Harness {
+    yak: Yakui,
+    time: Duration,
+}
+
+impl Harness {
+    /// Creates a harness with a 1000x1000 surface, matching [`Test`][crate::Test].
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self::with_size(Vec2::new(1000.0, 1000.0))
+    }
+
+    /// Creates a harness with a surface and viewport of the given size.
+    pub fn with_size(size: Vec2) -> Self {
+        let mut yak = Yakui::new();
+        yak.set_surface_size(size);
+        yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+        Self {
+            yak,
+            time: Duration::ZERO,
+        }
+    }
+
+    /// Builds a frame with `build` and lays it out, returning whatever
+    /// `build` returned. Widget responses and IDs can be passed out this way.
+    pub fn frame<F, R>(&mut self, build: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.yak.set_time(self.time);
+        self.yak.start();
+        let output = build();
+        self.yak.finish();
+        output
+    }
+
+    /// Moves the clock forward. The next frame sees the new time, which lets
+    /// animations and timers be stepped deterministically.
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.time += duration;
+    }
+
+    /// The time the next frame will be built at.
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Sends an event to yakui, returning whether it was sunk.
+    pub fn send(&mut self, event: Event) -> bool {
+        self.yak.handle_event(event)
+    }
+
+    /// Moves the mouse to a position in logical pixels.
+    pub fn move_mouse(&mut self, pos: Vec2) {
+        let pos = self.to_physical(pos);
+        self.send(Event::CursorMoved(Some(pos)));
+    }
+
+    /// Moves the mouse outside of the window.
+    pub fn leave_window(&mut self) {
+        self.send(Event::CursorMoved(None));
+    }
+
+    /// Moves the mouse to a position in logical pixels and clicks the primary
+    /// button there.
+    pub fn click(&mut self, pos: Vec2) {
+        self.move_mouse(pos);
+        self.press_mouse(MouseButton::One);
+    }
+
+    /// Presses and releases a mouse button wherever the mouse currently is.
+    pub fn press_mouse(&mut self, button: MouseButton) {
+        self.send(Event::MouseButtonChanged { button, down: true });
+        self.send(Event::MouseButtonChanged {
+            button,
+            down: false,
+        });
+    }
+
+    /// Clicks the center of a widget from the most recent frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the widget wasn't laid out in the most recent frame.
+    pub fn click_widget(&mut self, id: WidgetId) {
+        let rect = self.rect(id);
+        self.click(rect.pos() + rect.size() / 2.0);
+    }
+
+    /// Presses and releases a key.
+    pub fn press_key(&mut self, key: KeyCode, logical_key: LogicalKey) -> bool {
+        let sunk = self.send(Event::KeyChanged {
+            key,
+            logical_key: logical_key.clone(),
+            location: KeyLocation::Standard,
+            down: true,
+            repeat: false,
+        });
+
+        self.send(Event::KeyChanged {
+            key,
+            logical_key,
+            location: KeyLocation::Standard,
+            down: false,
+            repeat: false,
+        });
+
+        sunk
+    }
+
+    /// Types each character of `text` as if it came from the keyboard.
+    pub fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            self.send(Event::TextInput(c));
+        }
+    }
+
+    /// Returns where a widget was laid out in the most recent frame, in
+    /// logical pixels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the widget wasn't laid out in the most recent frame.
+    pub fn rect(&self, id: WidgetId) -> Rect {
+        match self.yak.layout_dom().get(id) {
+            Some(node) => node.rect,
+            None => panic!("widget {id:?} has no layout"),
+        }
+    }
+
+    /// Captures the widget tree of the most recent frame along with its
+    /// layout.
+    pub fn inspect(&self) -> Inspection {
+        self.yak.inspect()
+    }
+
+    /// Returns counters and timings for the most recent frame.
+    pub fn frame_stats(&self) -> &FrameStats {
+        self.yak.frame_stats()
+    }
+
+    /// The yakui being driven, for anything the harness doesn't cover.
+    pub fn yak(&self) -> &Yakui {
+        &self.yak
+    }
+
+    /// Mutable access to the yakui being driven, like to change its scale
+    /// factor or paint a frame.
+    pub fn yak_mut(&mut self) -> &mut Yakui {
+        &mut self.yak
+    }
+
+    fn to_physical(&self, pos: Vec2) -> Vec2 {
+        pos * self.yak.layout_dom().scale_factor()
+    }
+}
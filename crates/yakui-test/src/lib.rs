@@ -4,7 +4,15 @@ use std::fmt::Write as _;
 use yakui_core::dom::Dom;
 use yakui_core::geometry::{Rect, Vec2};
 use yakui_core::layout::LayoutDom;
+use yakui_core::LayoutDirection;
 
+mod golden;
+mod harness;
+
+pub use golden::{assert_golden, compare, render, Mismatch, Tolerance, UPDATE_GOLDEN_VAR};
+pub use harness::Harness;
+
+pub extern crate image;
 pub extern crate insta;
 pub extern crate yakui_core;
 
@@ -27,6 +35,7 @@ macro_rules! run {
         let mut state = ::yakui_test::yakui_core::Yakui::new();
         state.set_surface_size(test.surface_size);
         state.set_unscaled_viewport(test.viewport);
+        state.set_layout_direction(test.layout_direction);
         state.start();
         $body
         state.finish();
@@ -65,6 +74,7 @@ macro_rules! literally_snapshot {
 pub struct Test {
     pub surface_size: Vec2,
     pub viewport: Rect,
+    pub layout_direction: LayoutDirection,
 }
 
 impl Test {
@@ -73,6 +83,7 @@ impl Test {
         Self {
             surface_size: Vec2::new(1000.0, 1000.0),
             viewport: Rect::from_pos_size(Vec2::ZERO, Vec2::new(1000.0, 1000.0)),
+            layout_direction: LayoutDirection::LeftToRight,
         }
     }
 }
@@ -2,6 +2,7 @@
 #define NO_TEXTURE 4294967295
 #define WORKFLOW_MAIN 0
 #define WORKFLOW_TEXT 1
+#define WORKFLOW_TEXT_SDF 2
 
 layout (location = 0) in vec4 in_color;
 layout (location = 1) in vec2 in_uv;
@@ -29,6 +30,14 @@ void main() {
         } else {
             out_color = coverage;
         }
+    } else if (workflow == WORKFLOW_TEXT_SDF) {
+        // The glyph's edge is at 0.5. Antialias over roughly one screen pixel,
+        // however far the glyph has been scaled from its rasterized size.
+        float dist = texture(textures[texture_id], in_uv).r;
+        float width = max(fwidth(dist) * 0.7, 0.0001);
+        float alpha = smoothstep(0.5 - width, 0.5 + width, dist) * in_color.a;
+
+        out_color = vec4(in_color.rgb * alpha, alpha);
     } else {
         vec4 user_texture = texture(textures[texture_id], in_uv);
         out_color = in_color * user_texture;
@@ -12,7 +12,7 @@ pub use ash::vk;
 use buffer::Buffer;
 use bytemuck::{bytes_of, Pod, Zeroable};
 pub use descriptors::Descriptors;
-use std::{collections::HashMap, ffi::CStr, io::Cursor};
+use std::{collections::HashMap, io::Cursor};
 pub use vulkan_context::VulkanContext;
 use vulkan_texture::{UploadQueue, NO_TEXTURE_ID};
 pub use vulkan_texture::{VulkanTexture, VulkanTextureCreateInfo};
@@ -47,6 +47,8 @@ pub struct YakuiVulkan {
     /// A wrapper around descriptor set functionality
     descriptors: Descriptors,
     uploads: UploadQueue,
+    /// Brightness of white UI elements in nits, if drawing to an scRGB surface
+    hdr_paper_white: Option<f32>,
 }
 
 /// Vulkan configuration
@@ -96,6 +98,7 @@ impl PushConstant {
 enum Workflow {
     Main,
     Text,
+    TextSdf,
 }
 
 unsafe impl bytemuck::Zeroable for Workflow {}
@@ -106,6 +109,11 @@ impl From<yakui_core::paint::Pipeline> for Workflow {
         match p {
             yakui_core::paint::Pipeline::Main => Workflow::Main,
             yakui_core::paint::Pipeline::Text => Workflow::Text,
+            yakui_core::paint::Pipeline::TextSdf => Workflow::TextSdf,
+
+            // Custom pipelines can only be registered with yakui-wgpu, so
+            // geometry drawn with them is treated like any other geometry.
+            yakui_core::paint::Pipeline::Custom { .. } => Workflow::Main,
         }
     }
 }
@@ -189,7 +197,7 @@ impl YakuiVulkan {
                 .unwrap()
         };
 
-        let shader_entry_name = unsafe { CStr::from_bytes_with_nul_unchecked(b"main\0") };
+        let shader_entry_name = c"main";
         let shader_stage_create_infos = [
             vk::PipelineShaderStageCreateInfo {
                 module: vertex_shader_module,
@@ -346,6 +354,7 @@ impl YakuiVulkan {
             yakui_managed_textures: Default::default(),
             initial_textures_synced: false,
             uploads: UploadQueue::new(),
+            hdr_paper_white: None,
         }
     }
 
@@ -442,8 +451,6 @@ impl YakuiVulkan {
             let mut last_clip = None;
             for draw_call in draw_calls {
                 if draw_call.clip != last_clip {
-                    last_clip = draw_call.clip;
-
                     // TODO - do this when processing draw calls
                     match draw_call.clip {
                         Some(rect) => {
@@ -484,6 +491,8 @@ impl YakuiVulkan {
                             device.cmd_set_scissor(command_buffer, 0, &default_scissor);
                         }
                     }
+
+                    last_clip = draw_call.clip;
                 }
 
                 // Instead of using different pipelines for text and non-text rendering, we just
@@ -561,6 +570,21 @@ impl YakuiVulkan {
         self.uploads.cleanup(device);
     }
 
+    /// The brightness of white UI elements in nits when drawing to an HDR surface, or `None` when
+    /// drawing to an SDR surface, which is the default.
+    pub fn hdr_paper_white(&self) -> Option<f32> {
+        self.hdr_paper_white
+    }
+
+    /// Sets how bright white UI elements should be, in nits, when drawing to an scRGB surface, like
+    /// an `R16G16B16A16_SFLOAT` swapchain using `EXTENDED_SRGB_LINEAR_EXT`. In scRGB, 1.0 is 80
+    /// nits, so without this the UI looks dim next to HDR content. Pass `None` for SDR surfaces.
+    ///
+    /// Colors are scaled before they're uploaded, so this takes effect on the next paint.
+    pub fn set_hdr_paper_white(&mut self, paper_white: Option<f32>) {
+        self.hdr_paper_white = paper_white;
+    }
+
     /// Provides access to the descriptors used by `YakuiVulkan` to manage textures.
     /// Only useful for creating a [`crate::VulkanTexture`] from a pre-existing [`ash::vk::Image`]
     pub fn descriptors(&mut self) -> &mut Descriptors {
@@ -609,23 +633,52 @@ impl YakuiVulkan {
                     }
                 }
 
-                TextureChange::Modified => {
-                    if let Some(old) = self.yakui_managed_textures.remove(&id) {
-                        unsafe {
-                            self.uploads.dispose(old);
-                        }
+                TextureChange::ModifiedRegion(region) => {
+                    let texture = paint.texture(id).unwrap();
+                    let updated = self
+                        .yakui_managed_textures
+                        .get(&id)
+                        .is_some_and(|existing| {
+                            existing.update_region(
+                                vulkan_context,
+                                texture,
+                                region,
+                                &mut self.uploads,
+                            )
+                        });
+
+                    if !updated {
+                        self.replace_managed_texture(vulkan_context, paint, id);
                     }
-                    let new = paint.texture(id).unwrap();
-                    let texture = VulkanTexture::from_yakui_texture(
-                        vulkan_context,
-                        &mut self.descriptors,
-                        new,
-                        &mut self.uploads,
-                    );
-                    self.yakui_managed_textures.insert(id, texture);
                 }
+
+                TextureChange::Modified => {
+                    self.replace_managed_texture(vulkan_context, paint, id);
+                }
+            }
+        }
+    }
+
+    /// Recreates a managed texture from scratch, like when it's been resized.
+    fn replace_managed_texture(
+        &mut self,
+        vulkan_context: &VulkanContext,
+        paint: &yakui_core::paint::PaintDom,
+        id: ManagedTextureId,
+    ) {
+        if let Some(old) = self.yakui_managed_textures.remove(&id) {
+            unsafe {
+                self.uploads.dispose(old);
             }
         }
+        let new = paint.texture(id).unwrap();
+        let texture = VulkanTexture::from_yakui_texture(
+            vulkan_context,
+            &mut self.descriptors,
+            new,
+            &mut self.uploads,
+        );
+        self.yakui_managed_textures.insert(id, texture);
     }
 
     fn build_draw_calls(
@@ -639,16 +692,23 @@ impl YakuiVulkan {
 
         let calls = paint.layers().iter().flat_map(|layer| &layer.calls);
 
+        // The shader's output is linear in the vertex color, so scRGB can be
+        // handled by scaling colors here instead of in the shader.
+        let scale = self.hdr_paper_white.map_or(1.0, |nits| nits / 80.0);
+        let color_scale = yakui_core::geometry::Vec4::new(scale, scale, scale, 1.0);
+
         for call in calls {
             let base = vertices.len() as u32;
             let index_offset = indices.len() as u32;
             let index_count = call.indices.len() as u32;
 
             for index in &call.indices {
-                indices.push(*index as u32 + base);
+                indices.push(*index + base);
             }
             for vertex in &call.vertices {
-                vertices.push(vertex.into())
+                let mut vertex = Vertex::from(vertex);
+                vertex.color *= color_scale;
+                vertices.push(vertex)
             }
 
             let texture_id = call
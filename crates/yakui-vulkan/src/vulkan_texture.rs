@@ -2,6 +2,7 @@ use std::{collections::VecDeque, mem};
 
 use ash::vk;
 use yakui_core as yakui;
+use yakui_core::geometry::{URect, UVec2};
 
 use crate::{buffer::Buffer, descriptors::Descriptors, vulkan_context::VulkanContext};
 
@@ -14,6 +15,9 @@ pub struct VulkanTexture {
     pub(crate) sampler: vk::Sampler,
     pub(crate) view: vk::ImageView,
     pub(crate) id: u32,
+    /// Size of the image, if it was created by this crate and can be updated
+    size: Option<UVec2>,
+    format: vk::Format,
 }
 
 /// A container for information about a texture.
@@ -87,6 +91,8 @@ impl VulkanTexture {
             sampler,
             view,
             id,
+            size: None,
+            format: vk::Format::UNDEFINED,
         }
     }
 
@@ -106,8 +112,18 @@ impl VulkanTexture {
         } = create_info;
 
         let (image, memory) = unsafe { vulkan_context.create_image(resolution, format) };
+        let region = vk::Rect2D {
+            offset: vk::Offset2D::default(),
+            extent: resolution,
+        };
         unsafe {
-            queue.push(vulkan_context, image, resolution, image_data.as_ref());
+            queue.push(
+                vulkan_context,
+                image,
+                vk::ImageLayout::UNDEFINED,
+                region,
+                image_data.as_ref(),
+            );
         }
         let view = unsafe { vulkan_context.create_image_view(image, format) };
 
@@ -135,6 +151,8 @@ impl VulkanTexture {
             view,
             sampler,
             id,
+            size: Some(UVec2::new(resolution.width, resolution.height)),
+            format,
         }
     }
 
@@ -170,6 +188,63 @@ impl VulkanTexture {
         )
     }
 
+    /// Uploads the pixels inside `region` of a yakui texture that this texture
+    /// was created from, keeping the rest of the image as it is. Returns
+    /// `false` if the texture's size or format has changed since, in which
+    /// case it needs to be recreated instead.
+    pub(crate) fn update_region(
+        &self,
+        vulkan_context: &VulkanContext,
+        texture: &yakui::paint::Texture,
+        region: URect,
+        queue: &mut UploadQueue,
+    ) -> bool {
+        if self.size != Some(texture.size()) || self.format != get_format(texture.format()) {
+            return false;
+        }
+
+        let size = texture.size();
+        let min = region.pos().min(size);
+        let max = region.max().min(size);
+        let extent = max - min;
+        if extent.x == 0 || extent.y == 0 {
+            return true;
+        }
+
+        // Only the rows of the region are staged, so the upload is about as
+        // big as the region instead of the whole texture.
+        let bpp = texture.format().bytes_per_pixel() as u32;
+        let row_len = (extent.x * bpp) as usize;
+        let mut data = Vec::with_capacity(row_len * extent.y as usize);
+        for y in min.y..max.y {
+            let start = ((y * size.x + min.x) * bpp) as usize;
+            data.extend_from_slice(&texture.data()[start..start + row_len]);
+        }
+
+        let region = vk::Rect2D {
+            offset: vk::Offset2D {
+                x: min.x as i32,
+                y: min.y as i32,
+            },
+            extent: vk::Extent2D {
+                width: extent.x,
+                height: extent.y,
+            },
+        };
+
+        unsafe {
+            queue.push(
+                vulkan_context,
+                self.image,
+                vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL,
+                region,
+                &data,
+            );
+        }
+
+        true
+    }
+
     pub(crate) unsafe fn cleanup(&self, device: &ash::Device) {
         device.destroy_sampler(self.sampler, None);
         device.destroy_image_view(self.view, None);
@@ -204,7 +279,7 @@ fn get_address_mode(yakui_address_mode: yakui::paint::AddressMode) -> vk::Sample
 pub(crate) struct UploadQueue {
     phase: UploadPhase,
     in_flight: VecDeque<UploadPhase>,
-    textures: Vec<(vk::Image, vk::Extent2D, vk::Buffer, usize)>,
+    textures: Vec<(vk::Image, vk::Rect2D, vk::Buffer, usize)>,
     pre_barriers: Vec<vk::ImageMemoryBarrier<'static>>,
     post_barriers: Vec<vk::ImageMemoryBarrier<'static>>,
 }
@@ -233,15 +308,27 @@ impl UploadQueue {
         phase.graveyard.push(texture);
     }
 
+    /// Schedules `data` to be copied into `region` of `image`. Images that are
+    /// already in use should give their current layout as `old_layout` so that
+    /// the rest of their contents are kept.
     unsafe fn push(
         &mut self,
         vulkan_context: &VulkanContext,
         image: vk::Image,
-        extent: vk::Extent2D,
+        old_layout: vk::ImageLayout,
+        region: vk::Rect2D,
         data: &[u8],
     ) {
+        let src_access_mask = if old_layout == vk::ImageLayout::UNDEFINED {
+            vk::AccessFlags::empty()
+        } else {
+            vk::AccessFlags::SHADER_READ
+        };
+
         self.pre_barriers.push(vk::ImageMemoryBarrier {
+            src_access_mask,
             dst_access_mask: vk::AccessFlags::TRANSFER_WRITE,
+            old_layout,
             new_layout: vk::ImageLayout::TRANSFER_DST_OPTIMAL,
             image,
             subresource_range: vk::ImageSubresourceRange {
@@ -253,7 +340,7 @@ impl UploadQueue {
             ..Default::default()
         });
         let (buffer, offset) = self.phase.push(vulkan_context, data);
-        self.textures.push((image, extent, buffer, offset));
+        self.textures.push((image, region, buffer, offset));
         self.post_barriers.push(vk::ImageMemoryBarrier {
             src_access_mask: vk::AccessFlags::TRANSFER_WRITE,
             dst_access_mask: vk::AccessFlags::SHADER_READ,
@@ -272,9 +359,10 @@ impl UploadQueue {
 
     pub unsafe fn record(&mut self, vulkan_context: &VulkanContext, cmd: vk::CommandBuffer) {
         let device = vulkan_context.device;
+        // Images being updated in place may still be read by earlier draws.
         device.cmd_pipeline_barrier(
             cmd,
-            vk::PipelineStageFlags::TOP_OF_PIPE,
+            vk::PipelineStageFlags::FRAGMENT_SHADER,
             vk::PipelineStageFlags::TRANSFER,
             vk::DependencyFlags::empty(),
             &[],
@@ -283,7 +371,7 @@ impl UploadQueue {
         );
         self.pre_barriers.clear();
 
-        for (image, extent, buffer, offset) in self.textures.drain(..) {
+        for (image, region, buffer, offset) in self.textures.drain(..) {
             device.cmd_copy_buffer_to_image(
                 cmd,
                 buffer,
@@ -295,7 +383,12 @@ impl UploadQueue {
                         layer_count: 1,
                         ..Default::default()
                     },
-                    image_extent: extent.into(),
+                    image_offset: vk::Offset3D {
+                        x: region.offset.x,
+                        y: region.offset.y,
+                        z: 0,
+                    },
+                    image_extent: region.extent.into(),
                     buffer_offset: offset as vk::DeviceSize,
                     ..Default::default()
                 }],
@@ -342,7 +435,7 @@ impl UploadPhase {
         if self
             .buffers
             .last()
-            .map_or(true, |(buffer, fill)| fill + data.len() > buffer.capacity())
+            .is_none_or(|(buffer, fill)| fill + data.len() > buffer.capacity())
         {
             self.buffers.push((
                 Buffer::with_capacity(
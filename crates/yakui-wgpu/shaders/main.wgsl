@@ -28,7 +28,7 @@ fn vs_main(
 
     out.position = vec4<f32>(adjusted, 0.0, 1.0);
     out.texcoord = in.texcoord;
-    out.color = in.color;
+    out.color = decode_vertex_color(in.color);
     return out;
 }
 
@@ -37,5 +37,5 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     var color = textureSample(color_texture, color_sampler, in.texcoord);
     color *= in.color.a;
 
-    return in.color * color;
+    return encode_output(in.color * color);
 }
\ No newline at end of file
@@ -0,0 +1,77 @@
+// Appended to each of yakui's shaders to convert their output for the surface.
+// See `ColorOutput` for what each mode means.
+
+// 0: SDR, 1: scRGB, 2: HDR10, 3: sRGB-encoded SDR
+override OUTPUT_MODE: u32 = 0u;
+
+// The brightness of white UI elements in nits.
+override PAPER_WHITE: f32 = 80.0;
+
+// Whether vertex colors are sRGB-encoded instead of linear. See `VertexColors`.
+override SRGB_VERTEX_COLORS: bool = false;
+
+fn srgb_encode(linear: vec3<f32>) -> vec3<f32> {
+    let low = linear * 12.92;
+    let high = 1.055 * pow(max(linear, vec3(0.0)), vec3(1.0 / 2.4)) - 0.055;
+    return select(high, low, linear <= vec3(0.0031308));
+}
+
+fn srgb_decode(encoded: vec3<f32>) -> vec3<f32> {
+    let low = encoded / 12.92;
+    let high = pow(max((encoded + 0.055) / 1.055, vec3(0.0)), vec3(2.4));
+    return select(high, low, encoded <= vec3(0.04045));
+}
+
+// Converts a vertex color to a straight linear color.
+fn decode_vertex_color(color: vec4<f32>) -> vec4<f32> {
+    if SRGB_VERTEX_COLORS {
+        return vec4(srgb_decode(color.rgb), color.a);
+    }
+
+    return color;
+}
+
+fn pq_encode(luminance: vec3<f32>) -> vec3<f32> {
+    let m1 = 0.1593017578125;
+    let m2 = 78.84375;
+    let c1 = 0.8359375;
+    let c2 = 18.8515625;
+    let c3 = 18.6875;
+
+    let y = pow(max(luminance, vec3(0.0)), vec3(m1));
+    return pow((c1 + c2 * y) / (1.0 + c3 * y), vec3(m2));
+}
+
+// Takes a premultiplied linear color and converts it for the surface.
+fn encode_output(color: vec4<f32>) -> vec4<f32> {
+    if OUTPUT_MODE == 1u {
+        return vec4(color.rgb * (PAPER_WHITE / 80.0), color.a);
+    }
+
+    if OUTPUT_MODE == 2u {
+        if color.a <= 0.0 {
+            return color;
+        }
+
+        let to_bt2020 = mat3x3<f32>(
+            vec3(0.6274, 0.0691, 0.0164),
+            vec3(0.3293, 0.9195, 0.0880),
+            vec3(0.0433, 0.0114, 0.8956),
+        );
+
+        let straight = color.rgb / color.a;
+        let encoded = pq_encode(to_bt2020 * straight * (PAPER_WHITE / 10000.0));
+        return vec4(encoded * color.a, color.a);
+    }
+
+    if OUTPUT_MODE == 3u {
+        if color.a <= 0.0 {
+            return color;
+        }
+
+        let encoded = srgb_encode(color.rgb / color.a);
+        return vec4(encoded * color.a, color.a);
+    }
+
+    return color;
+}
@@ -25,7 +25,7 @@ fn vs_main(
 
     out.position = vec4<f32>(adjusted, 0.0, 1.0);
     out.texcoord = in.texcoord;
-    out.color = in.color;
+    out.color = decode_vertex_color(in.color);
     return out;
 }
 
@@ -36,8 +36,8 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     if in.color.a > 0.0 {
         let alpha = max(max(coverage.r, coverage.g), coverage.b) * in.color.a * coverage.a;
 
-        return vec4(in.color.rgb * alpha, alpha);
+        return encode_output(vec4(in.color.rgb * alpha, alpha));
     } else {
-        return coverage;
+        return encode_output(coverage);
     }
 }
\ No newline at end of file
@@ -0,0 +1,42 @@
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) texcoord: vec2<f32>,
+    @location(2) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) texcoord: vec2<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+@group(0) @binding(0) var distance_texture: texture_2d<f32>;
+@group(0) @binding(1) var distance_sampler: sampler;
+
+@vertex
+fn vs_main(
+    in: VertexInput,
+) -> VertexOutput {
+    var out: VertexOutput;
+
+    var adjusted: vec2<f32> = in.position;
+    adjusted *= vec2(2.0, -2.0);
+    adjusted += vec2(-1.0, 1.0);
+
+    out.position = vec4<f32>(adjusted, 0.0, 1.0);
+    out.texcoord = in.texcoord;
+    out.color = decode_vertex_color(in.color);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    // The glyph's edge is at 0.5. Antialias over roughly one screen pixel,
+    // however far the glyph has been scaled from its rasterized size.
+    let distance = textureSample(distance_texture, distance_sampler, in.texcoord).r;
+    let width = max(fwidth(distance) * 0.7, 0.0001);
+    let coverage = smoothstep(0.5 - width, 0.5 + width, distance);
+
+    let alpha = coverage * in.color.a;
+    return encode_output(vec4(in.color.rgb * alpha, alpha));
+}
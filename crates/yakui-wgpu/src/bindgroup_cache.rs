@@ -26,6 +26,10 @@ impl TextureBindgroupCache {
         }
     }
 
+    pub fn layout(&self) -> &wgpu::BindGroupLayout {
+        &self.layout
+    }
+
     pub fn clear(&mut self) {
         self.cache.clear();
     }
@@ -38,16 +42,14 @@ impl TextureBindgroupCache {
         samplers: &Samplers,
     ) {
         self.cache.entry(entry).or_insert_with(|| {
-            bindgroup(
-                device,
-                &self.layout,
-                samplers,
-                view,
+            let sampler = samplers.get(
                 entry.min_filter,
                 entry.mag_filter,
                 entry.mipmap_filter,
                 entry.address_mode,
-            )
+            );
+
+            bindgroup(device, &self.layout, view, sampler)
         });
     }
 
@@ -59,12 +61,8 @@ impl TextureBindgroupCache {
 pub fn bindgroup(
     device: &wgpu::Device,
     layout: &wgpu::BindGroupLayout,
-    samplers: &Samplers,
     view: &wgpu::TextureView,
-    min_filter: wgpu::FilterMode,
-    mag_filter: wgpu::FilterMode,
-    mipmap_filter: wgpu::FilterMode,
-    address_mode: wgpu::AddressMode,
+    sampler: &wgpu::Sampler,
 ) -> wgpu::BindGroup {
     device.create_bind_group(&wgpu::BindGroupDescriptor {
         label: Some("yakui Bind Group"),
@@ -76,12 +74,7 @@ pub fn bindgroup(
             },
             wgpu::BindGroupEntry {
                 binding: 1,
-                resource: wgpu::BindingResource::Sampler(samplers.get(
-                    min_filter,
-                    mag_filter,
-                    mipmap_filter,
-                    address_mode,
-                )),
+                resource: wgpu::BindingResource::Sampler(sampler),
             },
         ],
     })
@@ -1,68 +1,61 @@
-use std::mem::size_of;
+use std::num::NonZeroU64;
 
-use bytemuck::{bytes_of, NoUninit};
+use wgpu::util::StagingBelt;
 
+/// A GPU buffer that grows as needed and is refilled every frame.
+///
+/// The buffer is kept around between frames and only recreated when it needs
+/// to grow, at which point its size is rounded up to the next power of two.
 pub struct Buffer {
-    len: usize,
-    cpu_buffer: Vec<u8>,
+    label: &'static str,
     gpu_buffer: Option<wgpu::Buffer>,
     gpu_buffer_len: usize,
     usage: wgpu::BufferUsages,
 }
 
 impl Buffer {
-    pub fn new(usage: wgpu::BufferUsages) -> Self {
+    pub fn new(label: &'static str, usage: wgpu::BufferUsages) -> Self {
         Self {
-            len: 0,
-            cpu_buffer: Vec::new(),
+            label,
             gpu_buffer: None,
             gpu_buffer_len: 0,
             usage: usage | wgpu::BufferUsages::COPY_DST,
         }
     }
 
-    pub fn len(&self) -> usize {
-        self.len
-    }
-
-    pub fn clear(&mut self) {
-        self.len = 0;
-        self.cpu_buffer.clear();
-    }
-
-    #[allow(unused)]
-    pub fn push(&mut self, value: &impl NoUninit) {
-        self.len += 1;
-        self.cpu_buffer.extend(bytes_of(value));
-    }
-
-    pub fn extend<I, T>(&mut self, iter: I)
+    /// Uploads `len` bytes to the buffer, which are written by `fill` directly
+    /// into a chunk of `belt`. The copy into the buffer is recorded into
+    /// `encoder`, so `belt` must be finished before that encoder is submitted.
+    ///
+    /// `fill` must not read from the slice it's given, since reading from
+    /// staging memory can be very slow.
+    pub fn upload_with<F>(
+        &mut self,
+        device: &wgpu::Device,
+        belt: &mut StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        len: usize,
+        fill: F,
+    ) -> &wgpu::Buffer
     where
-        I: IntoIterator<Item = T>,
-        I::IntoIter: ExactSizeIterator,
-        T: NoUninit,
+        F: FnOnce(&mut [u8]),
     {
-        profiling::scope!("Buffer::extend");
+        profiling::scope!("Buffer::upload_with");
 
-        let iter = iter.into_iter();
-        self.len += iter.len();
-        self.cpu_buffer.reserve(iter.len() * size_of::<T>());
+        // Writes must be a non-zero multiple of four bytes.
+        let padded_len = len
+            .max(1)
+            .next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT as usize);
 
-        for v in iter {
-            self.cpu_buffer.extend(bytes_of(&v));
-        }
-    }
-
-    pub fn upload(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) -> &wgpu::Buffer {
-        let buffer = if self.gpu_buffer_len >= self.cpu_buffer.len() {
+        let buffer = if self.gpu_buffer_len >= padded_len {
             self.gpu_buffer.as_ref().unwrap()
         } else {
             // Buffer needs to grow or be created
-            let size = self.cpu_buffer.len().next_power_of_two();
+            let size = padded_len.next_power_of_two();
             self.gpu_buffer_len = size;
 
             let desc = wgpu::BufferDescriptor {
-                label: Some("yakui vertices"),
+                label: Some(self.label),
                 size: size as u64,
                 usage: self.usage,
                 mapped_at_creation: false,
@@ -72,7 +65,15 @@ impl Buffer {
             self.gpu_buffer.insert(buffer)
         };
 
-        queue.write_buffer(buffer, 0, &self.cpu_buffer);
+        let size = NonZeroU64::new(padded_len as u64).unwrap();
+        let mut view = belt.write_buffer(encoder, buffer, 0, size, device);
+        fill(&mut view[..len]);
+
         buffer
     }
+
+    /// The buffer written by the last upload, if there has been one.
+    pub fn gpu_buffer(&self) -> Option<&wgpu::Buffer> {
+        self.gpu_buffer.as_ref()
+    }
 }
@@ -3,41 +3,62 @@
 
 mod bindgroup_cache;
 mod buffer;
+mod multisampled;
+mod offscreen;
+mod output;
 mod pipeline_cache;
 mod samplers;
 mod texture;
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::mem::size_of;
 use std::ops::Range;
 use std::sync::Arc;
 
 use buffer::Buffer;
-use bytemuck::{Pod, Zeroable};
 use glam::UVec2;
 use thunderdome::{Arena, Index};
-use yakui_core::geometry::{Rect, Vec2, Vec4};
-use yakui_core::paint::{PaintDom, PaintLimits, Pipeline, Texture, TextureChange, TextureFormat};
+use wgpu::util::StagingBelt;
+use yakui_core::geometry::Rect;
+use yakui_core::paint::{
+    PaintDom, PaintLimits, Pipeline, Texture, TextureChange, TextureFormat, Vertex,
+};
 use yakui_core::{ManagedTextureId, TextureId};
 
+pub use self::multisampled::MultisampledTarget;
+pub use self::offscreen::OffscreenTarget;
+pub use self::output::{ColorOutput, VertexColors};
+
 use self::bindgroup_cache::TextureBindgroupCache;
 use self::bindgroup_cache::TextureBindgroupCacheEntry;
-use self::pipeline_cache::PipelineCache;
+use self::output::ShaderColors;
+use self::pipeline_cache::{CustomPipelineCache, PipelineCache};
 use self::samplers::Samplers;
 use self::texture::{GpuManagedTexture, GpuTexture};
 
 pub struct YakuiWgpu {
     limits: PaintLimits,
+    colors: ShaderColors,
     main_pipeline: PipelineCache,
     text_pipeline: PipelineCache,
+    text_sdf_pipeline: PipelineCache,
+    custom_pipelines: Vec<CustomPipelineCache>,
+    custom_bind_groups: Arena<wgpu::BindGroup>,
     samplers: Samplers,
     textures: Arena<GpuTexture>,
     managed_textures: HashMap<ManagedTextureId, GpuManagedTexture>,
     texture_bindgroup_cache: TextureBindgroupCache,
 
+    staging_belt: StagingBelt,
     vertices: Buffer,
     indices: Buffer,
     commands: Vec<DrawCommand>,
+    surface_size: UVec2,
+
+    /// Whether draw calls can be given a base vertex. Without one, indices are
+    /// rebased onto the shared vertex buffer while they're uploaded.
+    base_vertex: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -48,25 +69,21 @@ pub struct SurfaceInfo<'a> {
     pub resolve_target: Option<&'a wgpu::TextureView>,
 }
 
-#[derive(Debug, Clone, Copy, Zeroable, Pod)]
-#[repr(C)]
-struct Vertex {
-    pos: Vec2,
-    texcoord: Vec2,
-    color: Vec4,
-}
-
-impl Vertex {
-    const DESCRIPTOR: wgpu::VertexBufferLayout<'static> = wgpu::VertexBufferLayout {
-        array_stride: size_of::<Self>() as u64,
-        step_mode: wgpu::VertexStepMode::Vertex,
-        attributes: &wgpu::vertex_attr_array![
-            0 => Float32x2,
-            1 => Float32x2,
-            2 => Float32x4,
-        ],
-    };
-}
+/// Size of each chunk of the staging belt used to upload geometry. A typical
+/// UI fits both its vertices and indices into a single chunk.
+const STAGING_CHUNK_SIZE: wgpu::BufferAddress = 1 << 20;
+
+/// yakui's vertices are uploaded as-is, so this layout has to match
+/// [`Vertex`].
+const VERTEX_LAYOUT: wgpu::VertexBufferLayout<'static> = wgpu::VertexBufferLayout {
+    array_stride: size_of::<Vertex>() as u64,
+    step_mode: wgpu::VertexStepMode::Vertex,
+    attributes: &wgpu::vertex_attr_array![
+        0 => Float32x2,
+        1 => Float32x2,
+        2 => Float32x4,
+    ],
+};
 
 impl YakuiWgpu {
     pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
@@ -114,37 +131,58 @@ impl YakuiWgpu {
 
         let text_pipeline = PipelineCache::new(pipeline_layout);
 
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("yakui Text SDF Pipeline Layout"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+
+        let text_sdf_pipeline = PipelineCache::new(pipeline_layout);
+
         let samplers = Samplers::new(device);
 
         let default_texture_data =
             Texture::new(TextureFormat::Rgba8Srgb, UVec2::new(1, 1), vec![255; 4]);
         let default_texture = GpuManagedTexture::new(device, queue, &default_texture_data);
-        let default_bindgroup = bindgroup_cache::bindgroup(
-            device,
-            &layout,
-            &samplers,
-            &default_texture.view,
+        let default_sampler = samplers.get(
             default_texture.min_filter,
             default_texture.mag_filter,
             wgpu::FilterMode::Nearest,
             wgpu::AddressMode::ClampToEdge,
         );
+        let default_bindgroup =
+            bindgroup_cache::bindgroup(device, &layout, &default_texture.view, default_sampler);
 
         Self {
             limits,
+            colors: ShaderColors::default(),
             main_pipeline,
             text_pipeline,
+            text_sdf_pipeline,
+            custom_pipelines: Vec::new(),
+            custom_bind_groups: Arena::new(),
             samplers,
             textures: Arena::new(),
             managed_textures: HashMap::new(),
 
             texture_bindgroup_cache: TextureBindgroupCache::new(layout, default_bindgroup),
-            vertices: Buffer::new(wgpu::BufferUsages::VERTEX),
-            indices: Buffer::new(wgpu::BufferUsages::INDEX),
+            staging_belt: StagingBelt::new(STAGING_CHUNK_SIZE),
+            vertices: Buffer::new("yakui Vertices", wgpu::BufferUsages::VERTEX),
+            indices: Buffer::new("yakui Indices", wgpu::BufferUsages::INDEX),
             commands: Vec::new(),
+            surface_size: UVec2::ZERO,
+            base_vertex: true,
         }
     }
 
+    /// Tells the renderer which downlevel features the adapter supports, as
+    /// returned by [`wgpu::Adapter::get_downlevel_capabilities`]. This should
+    /// be called on downlevel backends like WebGL2, which can't draw with a
+    /// base vertex.
+    pub fn set_downlevel_flags(&mut self, flags: wgpu::DownlevelFlags) {
+        self.base_vertex = flags.contains(wgpu::DownlevelFlags::BASE_VERTEX);
+    }
+
     /// Creates a `TextureId` from an existing wgpu texture that then be used by
     /// any yakui widgets.
     pub fn add_texture(
@@ -184,6 +222,90 @@ impl YakuiWgpu {
         existing.view = view.into();
     }
 
+    /// How colors are encoded for the surface being drawn to. Defaults to
+    /// [`ColorOutput::Sdr`].
+    pub fn color_output(&self) -> ColorOutput {
+        self.colors.output
+    }
+
+    /// Sets how colors should be encoded for the surface being drawn to. This
+    /// should be changed whenever the surface switches between HDR and SDR
+    /// formats, and rebuilds yakui's pipelines on the next paint.
+    pub fn set_color_output(&mut self, output: ColorOutput) {
+        self.colors.output = output;
+    }
+
+    /// The color space of vertex colors. Defaults to [`VertexColors::Linear`].
+    pub fn vertex_colors(&self) -> VertexColors {
+        self.colors.vertex_colors
+    }
+
+    /// Sets the color space of vertex colors, which rebuilds yakui's pipelines
+    /// on the next paint.
+    pub fn set_vertex_colors(&mut self, vertex_colors: VertexColors) {
+        self.colors.vertex_colors = vertex_colors;
+    }
+
+    /// The vertex buffer layout that custom pipelines must accept. Positions
+    /// are given in normalized surface coordinates, where (0, 0) is the
+    /// top-left corner and (1, 1) is the bottom-right corner.
+    pub fn vertex_layout() -> wgpu::VertexBufferLayout<'static> {
+        VERTEX_LAYOUT
+    }
+
+    /// The layout of bind group 0, which holds the texture and sampler of each
+    /// draw call. Custom pipelines must use this layout for their first bind
+    /// group.
+    pub fn texture_bind_group_layout(&self) -> &wgpu::BindGroupLayout {
+        self.texture_bindgroup_cache.layout()
+    }
+
+    /// Registers a custom render pipeline that widgets can draw with by using
+    /// [`Pipeline::Custom`] with the returned ID.
+    ///
+    /// `create` is called with the format and sample count of the surface
+    /// being drawn to, and again whenever either of them changes. Pipelines
+    /// must use [`YakuiWgpu::vertex_layout`] for their only vertex buffer and
+    /// [`YakuiWgpu::texture_bind_group_layout`] for bind group 0. If a draw
+    /// call has resources attached, they are bound to bind group 1.
+    pub fn register_pipeline<F>(&mut self, create: F) -> u32
+    where
+        F: FnMut(&wgpu::Device, wgpu::TextureFormat, u32) -> wgpu::RenderPipeline + 'static,
+    {
+        let id = self.custom_pipelines.len() as u32;
+        self.custom_pipelines
+            .push(CustomPipelineCache::new(Box::new(create)));
+        id
+    }
+
+    /// Adds a bind group that draw calls using custom pipelines can refer to
+    /// with the `resources` field of [`Pipeline::Custom`].
+    pub fn add_bind_group(&mut self, bind_group: wgpu::BindGroup) -> u64 {
+        self.custom_bind_groups.insert(bind_group).to_bits()
+    }
+
+    /// Replaces a bind group previously created with
+    /// [`YakuiWgpu::add_bind_group`].
+    ///
+    /// ## Panics
+    ///
+    /// Will panic if the bind group does not exist.
+    pub fn update_bind_group(&mut self, id: u64, bind_group: wgpu::BindGroup) {
+        let existing = Index::from_bits(id)
+            .and_then(|index| self.custom_bind_groups.get_mut(index))
+            .expect("bind group does not exist");
+        *existing = bind_group;
+    }
+
+    /// Removes a bind group previously created with
+    /// [`YakuiWgpu::add_bind_group`]. Draw calls that still refer to it will be
+    /// skipped.
+    pub fn remove_bind_group(&mut self, id: u64) {
+        if let Some(index) = Index::from_bits(id) {
+            self.custom_bind_groups.remove(index);
+        }
+    }
+
     #[must_use = "YakuiWgpu::paint returns a command buffer which MUST be submitted to wgpu."]
     pub fn paint(
         &mut self,
@@ -201,6 +323,43 @@ impl YakuiWgpu {
         encoder.finish()
     }
 
+    /// Draws the UI into an [`OffscreenTarget`] instead of a window surface.
+    /// If `clear` is set, the target is cleared to that color first.
+    /// Otherwise, the UI is drawn on top of what's already in the target.
+    #[must_use = "YakuiWgpu::paint_offscreen returns a command buffer which MUST be submitted to wgpu."]
+    pub fn paint_offscreen(
+        &mut self,
+        state: &mut yakui_core::Yakui,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        target: &OffscreenTarget,
+        clear: Option<wgpu::Color>,
+    ) -> wgpu::CommandBuffer {
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("yakui Offscreen Encoder"),
+        });
+
+        if let Some(color) = clear {
+            // This pass only clears the target, so it can end right away.
+            drop(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("yakui Offscreen Clear Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target.view(),
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(color),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                ..Default::default()
+            }));
+        }
+
+        self.paint_with_encoder(state, device, queue, &mut encoder, target.surface_info());
+
+        encoder.finish()
+    }
+
     pub fn paint_with_encoder(
         &mut self,
         state: &mut yakui_core::Yakui,
@@ -211,112 +370,241 @@ impl YakuiWgpu {
     ) {
         profiling::scope!("yakui-wgpu paint_with_encoder");
 
+        self.prepare(
+            state,
+            device,
+            queue,
+            encoder,
+            surface.format,
+            surface.sample_count,
+        );
+
+        if self.commands.is_empty() {
+            return;
+        }
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("yakui Render Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: surface.color_attachment,
+                resolve_target: surface.resolve_target,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            ..Default::default()
+        });
+
+        self.render(&mut render_pass);
+    }
+
+    /// Uploads the UI's textures and geometry and sets up its pipelines for
+    /// the given target format and sample count, without drawing anything.
+    ///
+    /// Together with [`YakuiWgpu::render`], this lets the UI be drawn inside a
+    /// render pass owned by the application, like one targeting the same
+    /// multisampled texture as the rest of the game. That pass must not have a
+    /// depth or stencil attachment.
+    ///
+    /// The uploads recorded into `encoder` have to run before the UI is drawn,
+    /// so the render pass should either be recorded into the same encoder
+    /// afterwards or be submitted after it.
+    pub fn prepare(
+        &mut self,
+        state: &mut yakui_core::Yakui,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+    ) {
+        profiling::scope!("yakui-wgpu prepare");
+
         state.set_paint_limit(self.limits);
         let paint = state.paint();
 
         self.update_textures(device, paint, queue);
+        self.surface_size = paint.surface_size().as_uvec2();
 
         let layers = paint.layers();
-        if layers.iter().all(|layer| layer.calls.is_empty()) {
+        if layers.iter().all(|layer| layer.calls.is_empty()) || self.surface_size == UVec2::ZERO {
+            self.commands.clear();
             return;
         }
 
-        self.update_buffers(device, paint);
+        let (vertex_count, index_count) = self.update_buffers(device, paint);
+        let calls = || paint.layers().iter().flat_map(|layer| &layer.calls);
+
+        // The previous frame's command buffer has been submitted by now, so
+        // its staging chunks can be reclaimed for this frame.
+        self.staging_belt.recall();
+
+        // Geometry is copied straight from yakui's paint calls into the
+        // staging belt, in the same order as the draw commands.
+        self.vertices.upload_with(
+            device,
+            &mut self.staging_belt,
+            encoder,
+            vertex_count * size_of::<Vertex>(),
+            |bytes| {
+                write_slices(
+                    bytes,
+                    calls().map(|call| bytemuck::cast_slice(&call.vertices)),
+                )
+            },
+        );
+
+        let base_vertex = self.base_vertex;
+        self.indices.upload_with(
+            device,
+            &mut self.staging_belt,
+            encoder,
+            index_count * size_of::<u32>(),
+            |bytes| {
+                if base_vertex {
+                    write_slices(
+                        bytes,
+                        calls().map(|call| bytemuck::cast_slice(&call.indices)),
+                    )
+                } else {
+                    write_rebased_indices(
+                        bytes,
+                        calls().map(|call| (&call.indices[..], call.vertices.len())),
+                    )
+                }
+            },
+        );
+
+        self.staging_belt.finish();
 
-        let vertices = self.vertices.upload(device, queue);
-        let indices = self.indices.upload(device, queue);
-        let commands = &self.commands;
+        let colors = self.colors;
+        self.main_pipeline
+            .get(device, format, sample_count, colors, make_main_pipeline);
+        self.text_pipeline
+            .get(device, format, sample_count, colors, make_text_pipeline);
+        self.text_sdf_pipeline
+            .get(device, format, sample_count, colors, make_text_sdf_pipeline);
 
-        if paint.surface_size() == Vec2::ZERO {
+        for cache in &mut self.custom_pipelines {
+            cache.get(device, format, sample_count);
+        }
+    }
+
+    /// Draws the UI set up by the last call to [`YakuiWgpu::prepare`] into a
+    /// render pass owned by the application. The pass's color attachment must
+    /// match the format and sample count given to `prepare`.
+    pub fn render(&self, render_pass: &mut wgpu::RenderPass<'_>) {
+        profiling::scope!("yakui-wgpu render");
+
+        if self.commands.is_empty() {
             return;
         }
 
-        {
-            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("yakui Render Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: surface.color_attachment,
-                    resolve_target: surface.resolve_target,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Load,
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                ..Default::default()
-            });
+        let (Some(vertices), Some(indices)) =
+            (self.vertices.gpu_buffer(), self.indices.gpu_buffer())
+        else {
+            return;
+        };
 
-            render_pass.set_vertex_buffer(0, vertices.slice(..));
-            render_pass.set_index_buffer(indices.slice(..), wgpu::IndexFormat::Uint32);
-
-            let mut last_clip = None;
-
-            let main_pipeline = self.main_pipeline.get(
-                device,
-                surface.format,
-                surface.sample_count,
-                make_main_pipeline,
-            );
-
-            let text_pipeline = self.text_pipeline.get(
-                device,
-                surface.format,
-                surface.sample_count,
-                make_text_pipeline,
-            );
-
-            for command in commands {
-                match command.pipeline {
-                    Pipeline::Main => render_pass.set_pipeline(main_pipeline),
-                    Pipeline::Text => render_pass.set_pipeline(text_pipeline),
-                }
+        let (Some(main_pipeline), Some(text_pipeline), Some(text_sdf_pipeline)) = (
+            self.main_pipeline.current(),
+            self.text_pipeline.current(),
+            self.text_sdf_pipeline.current(),
+        ) else {
+            return;
+        };
 
-                if command.clip != last_clip {
-                    last_clip = command.clip;
+        render_pass.set_vertex_buffer(0, vertices.slice(..));
+        render_pass.set_index_buffer(indices.slice(..), wgpu::IndexFormat::Uint32);
+
+        let mut last_clip = None;
+
+        for command in &self.commands {
+            let mut resources = None;
+
+            match command.pipeline {
+                Pipeline::Main => render_pass.set_pipeline(main_pipeline),
+                Pipeline::Text => render_pass.set_pipeline(text_pipeline),
+                Pipeline::TextSdf => render_pass.set_pipeline(text_sdf_pipeline),
+                Pipeline::Custom {
+                    id,
+                    resources: resource_id,
+                } => {
+                    let pipeline = self
+                        .custom_pipelines
+                        .get(id as usize)
+                        .and_then(|cache| cache.current());
+
+                    let Some(pipeline) = pipeline else {
+                        continue;
+                    };
+
+                    if let Some(resource_id) = resource_id {
+                        let bind_group = Index::from_bits(resource_id)
+                            .and_then(|index| self.custom_bind_groups.get(index));
+
+                        match bind_group {
+                            Some(bind_group) => resources = Some(bind_group),
+                            None => continue,
+                        }
+                    }
 
-                    let surface = paint.surface_size().as_uvec2();
+                    render_pass.set_pipeline(pipeline);
+                }
+            }
 
-                    match command.clip {
-                        Some(rect) => {
-                            let pos = rect.pos().as_uvec2();
-                            let size = rect.size().as_uvec2();
+            if command.clip != last_clip {
+                let surface = self.surface_size;
 
-                            let max = (pos + size).min(surface);
-                            let size = UVec2::new(
-                                max.x.saturating_sub(pos.x),
-                                max.y.saturating_sub(pos.y),
-                            );
+                match command.clip {
+                    Some(rect) => {
+                        let pos = rect.pos().as_uvec2();
+                        let size = rect.size().as_uvec2();
 
-                            // If the scissor rect isn't valid, we can skip this
-                            // entire draw call.
-                            if pos.x > surface.x || pos.y > surface.y || size.x == 0 || size.y == 0
-                            {
-                                continue;
-                            }
+                        let max = (pos + size).min(surface);
+                        let size =
+                            UVec2::new(max.x.saturating_sub(pos.x), max.y.saturating_sub(pos.y));
 
-                            render_pass.set_scissor_rect(pos.x, pos.y, size.x, size.y);
-                        }
-                        None => {
-                            render_pass.set_scissor_rect(0, 0, surface.x, surface.y);
+                        // If the scissor rect isn't valid, we can skip this
+                        // entire draw call.
+                        if pos.x > surface.x || pos.y > surface.y || size.x == 0 || size.y == 0 {
+                            continue;
                         }
+
+                        render_pass.set_scissor_rect(pos.x, pos.y, size.x, size.y);
+                    }
+                    None => {
+                        render_pass.set_scissor_rect(0, 0, surface.x, surface.y);
                     }
                 }
 
-                let bindgroup = command
-                    .bind_group_entry
-                    .map(|entry| self.texture_bindgroup_cache.get(&entry))
-                    .unwrap_or(&self.texture_bindgroup_cache.default);
+                last_clip = command.clip;
+            }
+
+            let bindgroup = command
+                .bind_group_entry
+                .map(|entry| self.texture_bindgroup_cache.get(&entry))
+                .unwrap_or(&self.texture_bindgroup_cache.default);
+
+            render_pass.set_bind_group(0, bindgroup, &[]);
 
-                render_pass.set_bind_group(0, bindgroup, &[]);
-                render_pass.draw_indexed(command.index_range.clone(), 0, 0..1);
+            if let Some(resources) = resources {
+                render_pass.set_bind_group(1, resources, &[]);
             }
+
+            render_pass.draw_indexed(command.index_range.clone(), command.base_vertex, 0..1);
         }
     }
 
-    fn update_buffers(&mut self, device: &wgpu::Device, paint: &PaintDom) {
+    /// Builds the draw commands for the frame, returning how many vertices and
+    /// indices need to be uploaded for them.
+    fn update_buffers(&mut self, device: &wgpu::Device, paint: &PaintDom) -> (usize, usize) {
         profiling::scope!("update_buffers");
 
-        self.vertices.clear();
-        self.indices.clear();
+        let mut vertex_count = 0;
+        let mut index_count = 0;
+
         self.commands.clear();
         self.texture_bindgroup_cache.clear();
 
@@ -325,20 +613,19 @@ impl YakuiWgpu {
             .iter()
             .flat_map(|layer| &layer.calls)
             .map(|call| {
-                let vertices = call.vertices.iter().map(|vertex| Vertex {
-                    pos: vertex.position,
-                    texcoord: vertex.texcoord,
-                    color: vertex.color,
-                });
-
-                let base = self.vertices.len() as u32;
-                let indices = call.indices.iter().map(|&index| base + index as u32);
-
-                let start = self.indices.len() as u32;
-                let end = start + indices.len() as u32;
-
-                self.vertices.extend(vertices);
-                self.indices.extend(indices);
+                // Indices are relative to the start of each call, so each call
+                // is drawn with its own base vertex, unless they were rebased
+                // when they were uploaded.
+                let base_vertex = if self.base_vertex {
+                    vertex_count as i32
+                } else {
+                    0
+                };
+                let start = index_count as u32;
+                let end = start + call.indices.len() as u32;
+
+                vertex_count += call.vertices.len();
+                index_count += call.indices.len();
 
                 let bind_group_entry = call
                     .texture
@@ -388,6 +675,7 @@ impl YakuiWgpu {
 
                 DrawCommand {
                     index_range: start..end,
+                    base_vertex,
                     bind_group_entry,
                     pipeline: call.pipeline,
                     clip: call.clip,
@@ -395,6 +683,8 @@ impl YakuiWgpu {
             });
 
         self.commands.extend(commands);
+
+        (vertex_count, index_count)
     }
 
     fn update_textures(&mut self, device: &wgpu::Device, paint: &PaintDom, queue: &wgpu::Queue) {
@@ -424,6 +714,13 @@ impl YakuiWgpu {
                         existing.update(device, queue, texture);
                     }
                 }
+
+                TextureChange::ModifiedRegion(region) => {
+                    if let Some(existing) = self.managed_textures.get_mut(&id) {
+                        let texture = paint.texture(id).unwrap();
+                        existing.update_region(device, queue, texture, region);
+                    }
+                }
             }
         }
     }
@@ -431,6 +728,7 @@ impl YakuiWgpu {
 
 struct DrawCommand {
     index_range: Range<u32>,
+    base_vertex: i32,
     bind_group_entry: Option<TextureBindgroupCacheEntry>,
     pipeline: Pipeline,
     clip: Option<Rect>,
@@ -441,10 +739,11 @@ fn make_main_pipeline(
     layout: &wgpu::PipelineLayout,
     format: wgpu::TextureFormat,
     samples: u32,
+    colors: ShaderColors,
 ) -> wgpu::RenderPipeline {
     let main_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
         label: Some("Main Shader"),
-        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/main.wgsl").into()),
+        source: shader_source(include_str!("../shaders/main.wgsl")),
     });
 
     device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
@@ -454,12 +753,15 @@ fn make_main_pipeline(
             module: &main_shader,
             entry_point: None,
             compilation_options: Default::default(),
-            buffers: &[Vertex::DESCRIPTOR],
+            buffers: &[VERTEX_LAYOUT],
         },
         fragment: Some(wgpu::FragmentState {
             module: &main_shader,
             entry_point: None,
-            compilation_options: Default::default(),
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &output_constants(colors),
+                ..Default::default()
+            },
             targets: &[Some(wgpu::ColorTargetState {
                 format,
                 blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
@@ -490,25 +792,72 @@ fn make_text_pipeline(
     layout: &wgpu::PipelineLayout,
     format: wgpu::TextureFormat,
     samples: u32,
+    colors: ShaderColors,
 ) -> wgpu::RenderPipeline {
     let text_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
         label: Some("Text Shader"),
-        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/text.wgsl").into()),
+        source: shader_source(include_str!("../shaders/text.wgsl")),
+    });
+
+    make_glyph_pipeline(
+        device,
+        layout,
+        format,
+        samples,
+        colors,
+        "yakui Text Pipeline",
+        &text_shader,
+    )
+}
+
+fn make_text_sdf_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    format: wgpu::TextureFormat,
+    samples: u32,
+    colors: ShaderColors,
+) -> wgpu::RenderPipeline {
+    let text_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("Text SDF Shader"),
+        source: shader_source(include_str!("../shaders/text_sdf.wgsl")),
     });
 
+    make_glyph_pipeline(
+        device,
+        layout,
+        format,
+        samples,
+        colors,
+        "yakui Text SDF Pipeline",
+        &text_shader,
+    )
+}
+
+fn make_glyph_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    format: wgpu::TextureFormat,
+    samples: u32,
+    colors: ShaderColors,
+    label: &str,
+    text_shader: &wgpu::ShaderModule,
+) -> wgpu::RenderPipeline {
     device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-        label: Some("yakui Text Pipeline"),
+        label: Some(label),
         layout: Some(layout),
         vertex: wgpu::VertexState {
-            module: &text_shader,
+            module: text_shader,
             entry_point: None,
             compilation_options: Default::default(),
-            buffers: &[Vertex::DESCRIPTOR],
+            buffers: &[VERTEX_LAYOUT],
         },
         fragment: Some(wgpu::FragmentState {
-            module: &text_shader,
+            module: text_shader,
             entry_point: None,
-            compilation_options: Default::default(),
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &output_constants(colors),
+                ..Default::default()
+            },
             targets: &[Some(wgpu::ColorTargetState {
                 format,
                 blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
@@ -533,3 +882,40 @@ fn make_text_pipeline(
         cache: None,
     })
 }
+
+/// Appends the output encoding functions shared by all of yakui's shaders.
+fn shader_source(source: &str) -> wgpu::ShaderSource<'static> {
+    let output = include_str!("../shaders/output.wgsl");
+    wgpu::ShaderSource::Wgsl(Cow::Owned(format!("{source}\n{output}")))
+}
+
+fn output_constants(colors: ShaderColors) -> HashMap<String, f64> {
+    colors
+        .constants()
+        .into_iter()
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect()
+}
+
+/// Copies each slice into `bytes`, one after another.
+/// Writes the indices of each call offset by the number of vertices in the
+/// calls before it, so that they can be drawn without a base vertex.
+fn write_rebased_indices<'a>(bytes: &mut [u8], calls: impl Iterator<Item = (&'a [u32], usize)>) {
+    let mut offset = 0;
+    let mut base = 0;
+    for (indices, vertex_count) in calls {
+        for &index in indices {
+            bytes[offset..offset + 4].copy_from_slice(&(index + base).to_ne_bytes());
+            offset += 4;
+        }
+        base += vertex_count as u32;
+    }
+}
+
+fn write_slices<'a>(bytes: &mut [u8], slices: impl Iterator<Item = &'a [u8]>) {
+    let mut offset = 0;
+    for slice in slices {
+        bytes[offset..offset + slice.len()].copy_from_slice(slice);
+        offset += slice.len();
+    }
+}
@@ -0,0 +1,108 @@
+use glam::UVec2;
+
+use crate::SurfaceInfo;
+
+/// A multisampled texture that yakui can be drawn into before being resolved
+/// into a single-sampled texture, like a window's surface.
+///
+/// The texture is created when it's first used, and recreated whenever the
+/// size, format, or sample count of the target changes. With a sample count of
+/// one, no texture is created and the UI is drawn straight into the resolve
+/// target.
+pub struct MultisampledTarget {
+    sample_count: u32,
+    format: Option<wgpu::TextureFormat>,
+    size: UVec2,
+    view: Option<wgpu::TextureView>,
+}
+
+impl MultisampledTarget {
+    pub fn new(sample_count: u32) -> Self {
+        Self {
+            sample_count: sample_count.max(1),
+            format: None,
+            size: UVec2::ZERO,
+            view: None,
+        }
+    }
+
+    /// The number of samples per pixel.
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    /// Sets the number of samples per pixel. This has to be a count that the
+    /// format of the target supports, which can be checked with
+    /// [`wgpu::TextureFormatFeatureFlags::sample_count_supported`].
+    pub fn set_sample_count(&mut self, sample_count: u32) {
+        let sample_count = sample_count.max(1);
+        if sample_count != self.sample_count {
+            self.sample_count = sample_count;
+            self.view = None;
+        }
+    }
+
+    /// Describes the target for [`YakuiWgpu::paint`][crate::YakuiWgpu::paint]
+    /// or a render pass owned by the application. Passes that use it draw into
+    /// the multisampled texture and resolve into `resolve_target`, which has to
+    /// have the given size and format.
+    pub fn surface_info<'a>(
+        &'a mut self,
+        device: &wgpu::Device,
+        resolve_target: &'a wgpu::TextureView,
+        size: UVec2,
+        format: wgpu::TextureFormat,
+    ) -> SurfaceInfo<'a> {
+        if self.sample_count == 1 {
+            return SurfaceInfo {
+                format,
+                sample_count: 1,
+                color_attachment: resolve_target,
+                resolve_target: None,
+            };
+        }
+
+        if size != self.size || Some(format) != self.format {
+            self.view = None;
+        }
+
+        self.size = size;
+        self.format = Some(format);
+
+        let sample_count = self.sample_count;
+        let view = self
+            .view
+            .get_or_insert_with(|| create_view(device, size, format, sample_count));
+
+        SurfaceInfo {
+            format,
+            sample_count,
+            color_attachment: view,
+            resolve_target: Some(resolve_target),
+        }
+    }
+}
+
+fn create_view(
+    device: &wgpu::Device,
+    size: UVec2,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+) -> wgpu::TextureView {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("yakui Multisampled Target"),
+        size: wgpu::Extent3d {
+            width: size.x.max(1),
+            height: size.y.max(1),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        view_formats: &[],
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+    });
+
+    texture.create_view(&Default::default())
+}
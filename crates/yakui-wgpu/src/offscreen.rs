@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use glam::{UVec2, Vec2};
+use yakui_core::geometry::Rect;
+
+use crate::SurfaceInfo;
+
+/// A texture that a yakui UI can be drawn into instead of a window surface,
+/// for UI shown in the world like in-game computer screens or VR menus.
+///
+/// Each target should be used with its own [`yakui_core::Yakui`] state and
+/// [`YakuiWgpu`][crate::YakuiWgpu] renderer, since textures managed by yakui
+/// belong to a single state. Call [`OffscreenTarget::configure`] every frame
+/// before starting the state, then draw with
+/// [`YakuiWgpu::paint_offscreen`][crate::YakuiWgpu::paint_offscreen].
+pub struct OffscreenTarget {
+    texture: wgpu::Texture,
+    view: Arc<wgpu::TextureView>,
+    size: UVec2,
+    format: wgpu::TextureFormat,
+}
+
+impl OffscreenTarget {
+    /// Create a new target with the given size in pixels. The texture can be
+    /// sampled from as well as drawn into.
+    pub fn new(device: &wgpu::Device, size: UVec2, format: wgpu::TextureFormat) -> Self {
+        let (texture, view) = create_texture(device, size, format);
+
+        Self {
+            texture,
+            view,
+            size,
+            format,
+        }
+    }
+
+    /// Change the size of the target, recreating its texture if the size is
+    /// different. Views of the old texture will no longer be drawn to.
+    pub fn resize(&mut self, device: &wgpu::Device, size: UVec2) {
+        if size == self.size {
+            return;
+        }
+
+        let (texture, view) = create_texture(device, size, self.format);
+        self.texture = texture;
+        self.view = view;
+        self.size = size;
+    }
+
+    /// The size of the target in pixels.
+    pub fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// The format of the target's texture.
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.format
+    }
+
+    /// The texture being drawn into.
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// A view of the texture being drawn into, which can be given to
+    /// [`YakuiWgpu::add_texture`][crate::YakuiWgpu::add_texture] or bound in
+    /// the application's own shaders.
+    pub fn view(&self) -> &Arc<wgpu::TextureView> {
+        &self.view
+    }
+
+    /// Sets up a yakui state to lay out and paint for this target instead of a
+    /// window, with the given scale factor.
+    pub fn configure(&self, state: &mut yakui_core::Yakui, scale_factor: f32) {
+        let size = self.size.as_vec2();
+
+        state.set_surface_size(size);
+        state.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+        state.set_scale_factor(scale_factor);
+    }
+
+    /// Converts texture coordinates on the target, like ones found by casting
+    /// a ray against a quad in the world, into a cursor position that can be
+    /// sent to yakui with [`Event::CursorMoved`][yakui_core::event::Event::CursorMoved].
+    pub fn cursor_position(&self, uv: Vec2) -> Option<Vec2> {
+        if uv.cmplt(Vec2::ZERO).any() || uv.cmpgt(Vec2::ONE).any() {
+            return None;
+        }
+
+        Some(uv * self.size.as_vec2())
+    }
+
+    pub(crate) fn surface_info(&self) -> SurfaceInfo<'_> {
+        SurfaceInfo {
+            format: self.format,
+            sample_count: 1,
+            color_attachment: &self.view,
+            resolve_target: None,
+        }
+    }
+}
+
+fn create_texture(
+    device: &wgpu::Device,
+    size: UVec2,
+    format: wgpu::TextureFormat,
+) -> (wgpu::Texture, Arc<wgpu::TextureView>) {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("yakui Offscreen Target"),
+        size: wgpu::Extent3d {
+            width: size.x.max(1),
+            height: size.y.max(1),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        view_formats: &[],
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+    });
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    (texture, Arc::new(view))
+}
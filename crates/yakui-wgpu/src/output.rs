@@ -0,0 +1,156 @@
+/// Describes how the surface being drawn to interprets color values, so that
+/// UI colors can be adjusted to look the same on HDR and SDR displays.
+///
+/// yakui colors are specified in sRGB and its shaders output linear values.
+/// This is correct for sRGB surfaces, but on an HDR surface a linear value of
+/// 1.0 may be much dimmer or brighter than the user expects.
+///
+/// wgpu does not expose the color space of a surface, so picking the right
+/// output for a format is up to the application. For SDR surfaces,
+/// [`ColorOutput::sdr_for_format`] picks the output that keeps colors the same
+/// regardless of whether the format is sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ColorOutput {
+    /// Linear BT.709 values, with 1.0 as the brightest white. This is the
+    /// default, and is correct for `*Srgb` surface formats, which encode
+    /// values after blending so that blending is gamma-correct.
+    #[default]
+    Sdr,
+
+    /// sRGB-encoded BT.709 values, for SDR surfaces that store whatever is
+    /// written to them, like `Bgra8Unorm`. Blending happens in the encoded
+    /// space, which matches most other UI toolkits and web browsers but makes
+    /// translucent elements look darker than with [`ColorOutput::Sdr`].
+    SrgbEncoded,
+
+    /// Extended linear BT.709 values where 1.0 is 80 nits, as used by
+    /// `Rgba16Float` swapchains on Windows.
+    ScRgb {
+        /// How bright white UI elements should be, in nits.
+        paper_white: f32,
+    },
+
+    /// BT.2020 values encoded with the PQ transfer function, as used by HDR10
+    /// swapchains. Blending happens in the encoded space, so translucent
+    /// elements will blend slightly differently than on other outputs.
+    Hdr10 {
+        /// How bright white UI elements should be, in nits.
+        paper_white: f32,
+    },
+}
+
+impl ColorOutput {
+    /// The paper white used when picking an HDR output automatically, a common
+    /// default for UI in HDR games.
+    pub const DEFAULT_PAPER_WHITE: f32 = 203.0;
+
+    /// Picks the SDR output for a surface format: [`ColorOutput::Sdr`] if the
+    /// format applies sRGB encoding itself and [`ColorOutput::SrgbEncoded`]
+    /// otherwise.
+    pub fn sdr_for_format(format: wgpu::TextureFormat) -> Self {
+        if format.is_srgb() {
+            Self::Sdr
+        } else {
+            Self::SrgbEncoded
+        }
+    }
+
+    /// Picks the HDR output that's most likely to match a surface format, or
+    /// `None` if the format is not usually used for HDR.
+    pub fn hdr_for_format(format: wgpu::TextureFormat) -> Option<Self> {
+        let paper_white = Self::DEFAULT_PAPER_WHITE;
+
+        match format {
+            wgpu::TextureFormat::Rgba16Float => Some(Self::ScRgb { paper_white }),
+            wgpu::TextureFormat::Rgb10a2Unorm => Some(Self::Hdr10 { paper_white }),
+            _ => None,
+        }
+    }
+
+    /// Converts a linear color to the values that should be written to the
+    /// surface, like the UI shaders do. Useful for picking clear colors.
+    pub fn encode(&self, linear: [f32; 3]) -> [f32; 3] {
+        match *self {
+            Self::Sdr => linear,
+
+            Self::SrgbEncoded => linear.map(srgb_encode),
+
+            Self::ScRgb { paper_white } => linear.map(|c| c * paper_white / 80.0),
+
+            Self::Hdr10 { paper_white } => {
+                let [r, g, b] = linear;
+                let bt2020 = [
+                    0.6274 * r + 0.3293 * g + 0.0433 * b,
+                    0.0691 * r + 0.9195 * g + 0.0114 * b,
+                    0.0164 * r + 0.0880 * g + 0.8956 * b,
+                ];
+
+                bt2020.map(|c| pq_encode(c * paper_white / 10000.0))
+            }
+        }
+    }
+}
+
+/// The color space of the vertex colors given to yakui-wgpu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VertexColors {
+    /// Vertex colors are linear. This is the default, and is what yakui's own
+    /// painting code produces, since it converts colors with
+    /// [`Color::to_linear`][yakui_core::geometry::Color::to_linear].
+    #[default]
+    Linear,
+
+    /// Vertex colors are sRGB-encoded and are converted to linear by the
+    /// shaders, for meshes that store [`Color`][yakui_core::geometry::Color]
+    /// values as-is.
+    Srgb,
+}
+
+/// Everything about color handling that yakui's shaders are specialized for.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct ShaderColors {
+    pub output: ColorOutput,
+    pub vertex_colors: VertexColors,
+}
+
+impl ShaderColors {
+    /// The values of the overridable constants declared in `output.wgsl`.
+    pub fn constants(&self) -> [(&'static str, f64); 3] {
+        let (mode, paper_white) = match self.output {
+            ColorOutput::Sdr => (0.0, 80.0),
+            ColorOutput::ScRgb { paper_white } => (1.0, paper_white),
+            ColorOutput::Hdr10 { paper_white } => (2.0, paper_white),
+            ColorOutput::SrgbEncoded => (3.0, 80.0),
+        };
+
+        let srgb_vertex_colors = match self.vertex_colors {
+            VertexColors::Linear => 0.0,
+            VertexColors::Srgb => 1.0,
+        };
+
+        [
+            ("OUTPUT_MODE", mode),
+            ("PAPER_WHITE", f64::from(paper_white)),
+            ("SRGB_VERTEX_COLORS", srgb_vertex_colors),
+        ]
+    }
+}
+
+fn srgb_encode(linear: f32) -> f32 {
+    if linear <= 0.0031308 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn pq_encode(luminance: f32) -> f32 {
+    const M1: f32 = 2610.0 / 16384.0;
+    const M2: f32 = 2523.0 / 4096.0 * 128.0;
+    const C1: f32 = 3424.0 / 4096.0;
+    const C2: f32 = 2413.0 / 4096.0 * 32.0;
+    const C3: f32 = 2392.0 / 4096.0 * 32.0;
+
+    let y = luminance.max(0.0).powf(M1);
+    ((C1 + C2 * y) / (1.0 + C3 * y)).powf(M2)
+}
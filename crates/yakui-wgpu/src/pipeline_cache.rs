@@ -1,3 +1,5 @@
+use crate::output::ShaderColors;
+
 pub struct PipelineCache {
     layout: wgpu::PipelineLayout,
     current: Option<Cached>,
@@ -7,6 +9,7 @@ struct Cached {
     pipeline: wgpu::RenderPipeline,
     format: wgpu::TextureFormat,
     samples: u32,
+    colors: ShaderColors,
 }
 
 impl PipelineCache {
@@ -22,6 +25,7 @@ impl PipelineCache {
         device: &wgpu::Device,
         format: wgpu::TextureFormat,
         samples: u32,
+        colors: ShaderColors,
         init: F,
     ) -> &'a wgpu::RenderPipeline
     where
@@ -30,21 +34,80 @@ impl PipelineCache {
             &wgpu::PipelineLayout,
             wgpu::TextureFormat,
             u32,
+            ShaderColors,
         ) -> wgpu::RenderPipeline,
     {
+        match &mut self.current {
+            Some(existing)
+                if existing.format == format
+                    && existing.samples == samples
+                    && existing.colors == colors => {}
+            _ => {
+                let pipeline = init(device, &self.layout, format, samples, colors);
+
+                self.current = Some(Cached {
+                    pipeline,
+                    format,
+                    samples,
+                    colors,
+                });
+            }
+        }
+
+        &self.current.as_ref().unwrap().pipeline
+    }
+
+    /// The pipeline created by the last call to `get`, if any.
+    pub fn current(&self) -> Option<&wgpu::RenderPipeline> {
+        self.current.as_ref().map(|cached| &cached.pipeline)
+    }
+}
+
+type CreatePipeline =
+    Box<dyn FnMut(&wgpu::Device, wgpu::TextureFormat, u32) -> wgpu::RenderPipeline>;
+
+/// A pipeline registered by the user, recreated whenever the surface format or
+/// sample count changes.
+pub struct CustomPipelineCache {
+    create: CreatePipeline,
+    current: Option<Cached>,
+}
+
+impl CustomPipelineCache {
+    pub fn new(create: CreatePipeline) -> Self {
+        Self {
+            create,
+            current: None,
+        }
+    }
+
+    pub fn get(
+        &mut self,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        samples: u32,
+    ) -> &wgpu::RenderPipeline {
         match &mut self.current {
             Some(existing) if existing.format == format && existing.samples == samples => (),
             _ => {
-                let pipeline = init(device, &self.layout, format, samples);
+                let pipeline = (self.create)(device, format, samples);
 
+                // Custom pipelines are responsible for their own output
+                // encoding, so they don't need to be rebuilt when it changes.
                 self.current = Some(Cached {
                     pipeline,
                     format,
                     samples,
+                    colors: ShaderColors::default(),
                 });
             }
         }
 
         &self.current.as_ref().unwrap().pipeline
     }
+
+    /// The pipeline created by the last call to `get`, if any.
+    pub fn current(&self) -> Option<&wgpu::RenderPipeline> {
+        self.current.as_ref().map(|cached| &cached.pipeline)
+    }
 }
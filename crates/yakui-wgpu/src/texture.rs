@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use {std::sync::Arc, yakui_core::paint::AddressMode};
 
 use glam::UVec2;
+use yakui_core::geometry::URect;
 use yakui_core::paint::{Texture, TextureFilter, TextureFormat};
 
 pub(crate) struct GpuManagedTexture {
@@ -100,6 +101,68 @@ impl GpuManagedTexture {
             size,
         );
     }
+
+    /// Update only the given region of the GpuTexture from a yakui Texture.
+    pub fn update_region(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        texture: &Texture,
+        region: URect,
+    ) {
+        if self.size != texture.size() || self.format != texture.format() {
+            *self = Self::new(device, queue, texture);
+            return;
+        }
+
+        let min = region.pos().min(self.size);
+        let max = region.max().min(self.size);
+        let extent = max - min;
+        if extent.x == 0 || extent.y == 0 {
+            return;
+        }
+
+        // Copy the rows of the region into their own buffer so that the rest
+        // of the texture doesn't need to be touched.
+        let bpp = bytes_per_pixel(self.format);
+        let row_len = (extent.x * bpp) as usize;
+        let mut data = Vec::with_capacity(row_len * extent.y as usize);
+        for y in min.y..max.y {
+            let start = ((y * self.size.x + min.x) * bpp) as usize;
+            data.extend_from_slice(&texture.data()[start..start + row_len]);
+        }
+
+        if self.format == TextureFormat::Rgba8Srgb {
+            premultiply_pixels(&mut data);
+        }
+
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &self.gpu_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: min.x,
+                    y: min.y,
+                    z: 0,
+                },
+                aspect: wgpu::TextureAspect::All,
+            },
+            &data,
+            data_layout(self.format, extent),
+            wgpu::Extent3d {
+                width: extent.x,
+                height: extent.y,
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+}
+
+fn bytes_per_pixel(format: TextureFormat) -> u32 {
+    match format {
+        TextureFormat::Rgba8Srgb | TextureFormat::Rgba8SrgbPremultiplied => 4,
+        TextureFormat::R8 => 1,
+    }
 }
 
 fn data_layout(format: TextureFormat, size: UVec2) -> wgpu::ImageDataLayout {
@@ -145,23 +208,25 @@ fn wgpu_address_mode(address_mode: AddressMode) -> wgpu::AddressMode {
 }
 
 fn premultiply_alpha(texture: &Texture) -> Cow<'_, Texture> {
-    fn premul(a: u8, b: u8) -> u8 {
-        (((a as u32) * (b as u32) + 255) >> 8) as u8
-    }
-
     match texture.format() {
         TextureFormat::Rgba8Srgb => {
             let mut texture = texture.clone();
-
-            for pixel in texture.data_mut().chunks_exact_mut(4) {
-                pixel[0] = premul(pixel[0], pixel[3]);
-                pixel[1] = premul(pixel[1], pixel[3]);
-                pixel[2] = premul(pixel[2], pixel[3]);
-            }
-
+            premultiply_pixels(texture.data_mut());
             Cow::Owned(texture)
         }
         TextureFormat::Rgba8SrgbPremultiplied => Cow::Borrowed(texture),
         TextureFormat::R8 => Cow::Borrowed(texture),
     }
 }
+
+fn premultiply_pixels(data: &mut [u8]) {
+    fn premul(a: u8, b: u8) -> u8 {
+        (((a as u32) * (b as u32) + 255) >> 8) as u8
+    }
+
+    for pixel in data.chunks_exact_mut(4) {
+        pixel[0] = premul(pixel[0], pixel[3]);
+        pixel[1] = premul(pixel[1], pixel[3]);
+        pixel[2] = premul(pixel[2], pixel[3]);
+    }
+}
@@ -0,0 +1,318 @@
+//! Building blocks for animating values over time, used by widgets like
+//! [`Tween`][crate::widgets::Tween] and [`SpringAnimation`][crate::widgets::SpringAnimation].
+
+use std::fmt::Debug;
+use std::ops::{Add, Mul, Sub};
+use std::time::Duration;
+
+use yakui_core::geometry::{Color, Vec2};
+
+/// A curve that shapes how an animation moves between its start and end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Moves at a constant speed.
+    Linear,
+
+    /// Starts slow and speeds up.
+    EaseIn,
+
+    /// Starts fast and slows down. Most UI motion looks best with this, since
+    /// it responds to the user right away.
+    #[default]
+    EaseOut,
+
+    /// Starts slow, speeds up, then slows down again.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Maps how far along an animation is in time, from 0 to 1, to how far
+    /// along it should be drawn. Values outside of that range are clamped.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// A value that can be animated by blending between two of them.
+pub trait Animatable: Debug + Copy + PartialEq + 'static {
+    /// Blends from `self` to `other`, where `t` is 0 at `self` and 1 at
+    /// `other`.
+    fn lerp(self, other: Self, t: f32) -> Self;
+}
+
+impl Animatable for f32 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Animatable for Vec2 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Vec2::lerp(self, other, t)
+    }
+}
+
+impl Animatable for Color {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Color::lerp(&self, &other, t)
+    }
+}
+
+/// Tracks a value that moves toward a target over a fixed amount of time,
+/// picking up from wherever it is when the target changes. Widgets keep one of
+/// these and call [`Transition::update`] every frame.
+#[derive(Debug, Clone, Copy)]
+pub struct Transition<T> {
+    /// The value the current animation started from, the target it's headed
+    /// to, and when it started.
+    animation: Option<(T, T, Duration)>,
+    value: Option<T>,
+}
+
+impl<T: Animatable> Transition<T> {
+    pub fn new() -> Self {
+        Self {
+            animation: None,
+            value: None,
+        }
+    }
+
+    /// Returns the value at time `now`, starting a new animation from the
+    /// current value if `target` changed. The first update starts at the
+    /// target.
+    ///
+    /// If `now` is zero, like when the host doesn't call
+    /// [`Yakui::set_time`][yakui_core::Yakui::set_time], the value jumps
+    /// straight to the target.
+    pub fn update(&mut self, target: T, duration: Duration, easing: Easing, now: Duration) -> T {
+        let current = self.value.unwrap_or(target);
+
+        let (from, to, start) = match self.animation {
+            Some(animation @ (_, to, _)) if to == target => animation,
+            _ => {
+                let animation = (current, target, now);
+                self.animation = Some(animation);
+                animation
+            }
+        };
+        let elapsed = now.saturating_sub(start);
+
+        let value = if now.is_zero() || elapsed >= duration || from == to {
+            to
+        } else {
+            let t = elapsed.as_secs_f32() / duration.as_secs_f32();
+            from.lerp(to, easing.apply(t))
+        };
+
+        self.value = Some(value);
+        value
+    }
+
+    /// Tells whether the value hasn't reached its target yet.
+    pub fn is_animating(&self) -> bool {
+        match (self.animation, self.value) {
+            (Some((_, to, _)), Some(value)) => value != to,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Animatable> Default for Transition<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A value that can be moved by a [`Spring`]. Unlike [`Animatable`], springs
+/// need to do arithmetic on values to track their velocity.
+pub trait SpringValue:
+    Animatable + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self>
+{
+    /// The zero value, used as the velocity of a spring at rest.
+    const ZERO: Self;
+
+    /// How far this value is from zero.
+    fn magnitude(self) -> f32;
+}
+
+impl SpringValue for f32 {
+    const ZERO: Self = 0.0;
+
+    fn magnitude(self) -> f32 {
+        self.abs()
+    }
+}
+
+impl SpringValue for Vec2 {
+    const ZERO: Self = Vec2::ZERO;
+
+    fn magnitude(self) -> f32 {
+        self.length()
+    }
+}
+
+/// How a spring pulls a value toward its target.
+///
+/// Springs don't have a fixed duration. When the target changes mid-flight,
+/// the value keeps its momentum and curves toward the new target, which makes
+/// them a good fit for things like snapping a dragged panel into place.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// How strongly the spring pulls toward the target. Stiffer springs move
+    /// faster.
+    pub stiffness: f32,
+
+    /// How much the spring resists motion. Less damping means more bouncing
+    /// around the target before settling.
+    pub damping: f32,
+}
+
+impl Spring {
+    /// Settles quickly with no visible bounce.
+    pub const DEFAULT: Self = Self::new(170.0, 26.0);
+
+    /// Moves slowly and smoothly.
+    pub const GENTLE: Self = Self::new(120.0, 14.0);
+
+    /// Overshoots and bounces a few times before settling.
+    pub const WOBBLY: Self = Self::new(180.0, 12.0);
+
+    /// Moves fast and settles almost right away.
+    pub const STIFF: Self = Self::new(210.0, 20.0);
+
+    /// Creates a spring with the given [stiffness][Self::stiffness] and
+    /// [damping][Self::damping]. Both are usually positive.
+    pub const fn new(stiffness: f32, damping: f32) -> Self {
+        Self { stiffness, damping }
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Tracks a value being pulled toward a target by a [`Spring`]. Widgets keep
+/// one of these and call [`SpringState::update`] every frame.
+#[derive(Debug, Clone, Copy)]
+pub struct SpringState<T> {
+    value: Option<T>,
+    velocity: T,
+    last_update: Option<Duration>,
+}
+
+/// The spring is simulated in steps no longer than this so that stiff springs
+/// stay stable at low frame rates.
+const SPRING_STEP: f32 = 1.0 / 240.0;
+
+/// Frames longer than this, like after the app was paused, are treated as if
+/// they were this long so the spring doesn't jump.
+const SPRING_MAX_FRAME: f32 = 0.1;
+
+/// Once the value is this close to the target and moving this slowly, it snaps
+/// to the target and stops.
+const SPRING_REST_THRESHOLD: f32 = 0.001;
+
+impl<T: SpringValue> SpringState<T> {
+    pub fn new() -> Self {
+        Self {
+            value: None,
+            velocity: T::ZERO,
+            last_update: None,
+        }
+    }
+
+    /// The current value, if the spring has been updated before.
+    pub fn value(&self) -> Option<T> {
+        self.value
+    }
+
+    /// How fast the value is moving, in units per second.
+    pub fn velocity(&self) -> T {
+        self.velocity
+    }
+
+    /// Moves the value somewhere without animating, keeping its velocity.
+    /// While dragging something, call this with the drag position every frame.
+    pub fn set_value(&mut self, value: T) {
+        self.value = Some(value);
+    }
+
+    /// Replaces how fast the value is moving, in units per second. Setting this
+    /// when letting go of a drag lets the value carry on with the momentum it
+    /// was released with.
+    pub fn set_velocity(&mut self, velocity: T) {
+        self.velocity = velocity;
+    }
+
+    /// Steps the spring forward to time `now` and returns the value. The first
+    /// update starts at the target.
+    ///
+    /// If `now` is zero, like when the host doesn't call
+    /// [`Yakui::set_time`][yakui_core::Yakui::set_time], the value jumps
+    /// straight to the target.
+    pub fn update(&mut self, target: T, spring: Spring, now: Duration) -> T {
+        let last_update = self.last_update.replace(now);
+
+        let Some(mut value) = self.value else {
+            self.value = Some(target);
+            return target;
+        };
+
+        if now.is_zero() {
+            self.value = Some(target);
+            self.velocity = T::ZERO;
+            return target;
+        }
+
+        let elapsed = now.saturating_sub(last_update.unwrap_or(now));
+        let mut remaining = elapsed.as_secs_f32().min(SPRING_MAX_FRAME);
+        let mut velocity = self.velocity;
+
+        while remaining > 0.0 {
+            let dt = remaining.min(SPRING_STEP);
+            remaining -= dt;
+
+            let force = (target - value) * spring.stiffness - velocity * spring.damping;
+            velocity = velocity + force * dt;
+            value = value + velocity * dt;
+        }
+
+        if (target - value).magnitude() < SPRING_REST_THRESHOLD
+            && velocity.magnitude() < SPRING_REST_THRESHOLD
+        {
+            value = target;
+            velocity = T::ZERO;
+        }
+
+        self.value = Some(value);
+        self.velocity = velocity;
+        value
+    }
+
+    /// Tells whether the value is still moving or hasn't reached the target it
+    /// was last updated with.
+    pub fn is_moving(&self, target: T) -> bool {
+        self.value != Some(target) || self.velocity != T::ZERO
+    }
+}
+
+impl<T: SpringValue> Default for SpringState<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -7,8 +8,19 @@ pub struct Fonts {
     inner: Rc<RefCell<FontsInner>>,
 }
 
+/// A font family that has been loaded, as listed by [`Fonts::families`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontFamily {
+    pub name: String,
+
+    /// The weights this family has faces for, from lightest to heaviest.
+    pub weights: Vec<cosmic_text::Weight>,
+}
+
 struct FontsInner {
     font_system: cosmic_text::FontSystem,
+    fallback: Vec<String>,
+    generation: u64,
 }
 
 impl Fonts {
@@ -38,10 +50,61 @@ impl Fonts {
                 )));
         }
 
-        let inner = Rc::new(RefCell::new(FontsInner { font_system }));
+        let inner = Rc::new(RefCell::new(FontsInner {
+            font_system,
+            fallback: Vec::new(),
+            generation: 0,
+        }));
         Self { inner }
     }
 
+    /// A number that changes whenever fonts are loaded or any setting that
+    /// affects which font text uses is changed. Text widgets use this to know
+    /// when they need to be shaped again.
+    pub fn generation(&self) -> u64 {
+        self.inner.borrow().generation
+    }
+
+    fn changed(&self) {
+        self.inner.borrow_mut().generation += 1;
+    }
+
+    /// Lists every font family that text can use, sorted by name. A font
+    /// registered with [`Fonts::add`] is listed under both the name it was
+    /// registered with and the names contained in the font itself.
+    ///
+    /// This is useful for letting users pick a font. Any of these names can be
+    /// passed to [`TextStyle::with_family`][crate::style::TextStyle::with_family].
+    pub fn families(&self) -> Vec<FontFamily> {
+        self.with_system(|font_system| {
+            let mut families: BTreeMap<&str, Vec<cosmic_text::Weight>> = BTreeMap::new();
+
+            for face in font_system.db().faces() {
+                for (name, _language) in &face.families {
+                    families.entry(name.as_str()).or_default().push(face.weight);
+                }
+            }
+
+            families
+                .into_iter()
+                .map(|(name, mut weights)| {
+                    weights.sort();
+                    weights.dedup();
+
+                    FontFamily {
+                        name: name.to_owned(),
+                        weights,
+                    }
+                })
+                .collect()
+        })
+    }
+
+    /// Tells whether a font family with the given name has been loaded.
+    pub fn has_family(&self, name: &str) -> bool {
+        self.with_system(|font_system| has_family(font_system, cosmic_text::Family::Name(name)))
+    }
+
     pub fn with_system<T>(&self, f: impl FnOnce(&mut cosmic_text::FontSystem) -> T) -> T {
         let mut inner = (*self.inner).borrow_mut();
 
@@ -52,33 +115,109 @@ impl Fonts {
         &self,
         source: cosmic_text::fontdb::Source,
     ) -> Vec<cosmic_text::fontdb::ID> {
-        self.with_system(|font_system| font_system.db_mut().load_font_source(source))
-            .to_vec()
+        let ids = self
+            .with_system(|font_system| font_system.db_mut().load_font_source(source))
+            .to_vec();
+
+        self.changed();
+        ids
+    }
+
+    /// Loads a font from memory and registers it under the given family name.
+    ///
+    /// The font can then be selected by name, like with
+    /// [`TextStyle::with_family`][crate::style::TextStyle::with_family]. It is
+    /// also still available under the family names contained in the font
+    /// itself.
+    pub fn add<S, D>(&self, name: S, data: D) -> Vec<cosmic_text::fontdb::ID>
+    where
+        S: Into<String>,
+        D: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        let name = name.into();
+        self.changed();
+
+        self.with_system(|font_system| {
+            let db = font_system.db_mut();
+            let source = cosmic_text::fontdb::Source::Binary(Arc::new(data));
+
+            db.load_font_source(source)
+                .into_iter()
+                .filter_map(|id| {
+                    let mut info = db.face(id)?.clone();
+                    db.remove_face(id);
+
+                    if !info.families.iter().any(|(family, _)| *family == name) {
+                        info.families.insert(
+                            0,
+                            (
+                                name.clone(),
+                                cosmic_text::fontdb::Language::English_UnitedStates,
+                            ),
+                        );
+                    }
+
+                    // fontdb picks a new ID when pushing a face without
+                    // returning it, so find the face that wasn't there before.
+                    let existing: HashSet<_> = db.faces().map(|face| face.id).collect();
+                    db.push_face_info(info);
+                    db.faces()
+                        .map(|face| face.id)
+                        .find(|id| !existing.contains(id))
+                })
+                .collect()
+        })
+    }
+
+    /// Sets the list of families that text falls back to, in order, when its
+    /// own font family is missing a glyph. This is useful for covering
+    /// characters like CJK, symbols, or emoji with dedicated fonts.
+    ///
+    /// Fonts containing color glyphs, like color emoji fonts, are supported.
+    pub fn set_fallback_families<I, S>(&self, families: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut inner = (*self.inner).borrow_mut();
+        inner.fallback = families.into_iter().map(Into::into).collect();
+        inner.generation += 1;
+    }
+
+    /// Returns the list of fallback families set with
+    /// [`Fonts::set_fallback_families`].
+    pub fn fallback_families(&self) -> Vec<String> {
+        self.inner.borrow().fallback.clone()
     }
 
     /// Sets the family that will be used by `Family::Serif`.
     pub fn set_serif_family<S: Into<String>>(&self, family: S) {
         self.with_system(|font_system| font_system.db_mut().set_serif_family(family));
+        self.changed();
     }
 
     /// Sets the family that will be used by `Family::SansSerif`.
     pub fn set_sans_serif_family<S: Into<String>>(&self, family: S) {
         self.with_system(|font_system| font_system.db_mut().set_sans_serif_family(family));
+        self.changed();
     }
 
     /// Sets the family that will be used by `Family::Cursive`.
     pub fn set_cursive_family<S: Into<String>>(&self, family: S) {
         self.with_system(|font_system| font_system.db_mut().set_cursive_family(family));
+        self.changed();
     }
 
     /// Sets the family that will be used by `Family::Fantasy`.
     pub fn set_fantasy_family<S: Into<String>>(&self, family: S) {
         self.with_system(|font_system| font_system.db_mut().set_fantasy_family(family));
+        self.changed();
     }
 
     /// Sets the family that will be used by `Family::Monospace`.
     pub fn set_monospace_family<S: Into<String>>(&self, family: S) {
         self.with_system(|font_system| font_system.db_mut().set_monospace_family(family));
+        self.changed();
     }
 }
 
@@ -87,3 +226,94 @@ impl Default for Fonts {
         Self::new()
     }
 }
+
+fn has_family(font_system: &cosmic_text::FontSystem, family: cosmic_text::Family<'_>) -> bool {
+    let query = cosmic_text::fontdb::Query {
+        families: &[family],
+        weight: cosmic_text::Weight::NORMAL,
+        stretch: cosmic_text::Stretch::Normal,
+        style: cosmic_text::Style::Normal,
+    };
+
+    font_system.db().query(&query).is_some()
+}
+
+/// Sets the text of a buffer, using the first family from `fallback` that can
+/// display a character whenever the requested family can't.
+///
+/// If the requested family hasn't been loaded, like when a font picked in a
+/// settings menu is missing, the default sans-serif family is used instead.
+pub(crate) fn set_text_with_fallback<'a>(
+    buffer: &mut cosmic_text::Buffer,
+    font_system: &mut cosmic_text::FontSystem,
+    text: &str,
+    attrs: cosmic_text::Attrs<'a>,
+    fallback: &'a [String],
+) {
+    let attrs = match attrs.family {
+        cosmic_text::Family::Name(_) if !has_family(font_system, attrs.family) => {
+            attrs.family(cosmic_text::Family::SansSerif)
+        }
+        _ => attrs,
+    };
+
+    if fallback.is_empty() {
+        buffer.set_text(font_system, text, attrs, cosmic_text::Shaping::Advanced);
+        return;
+    }
+
+    let families: Vec<cosmic_text::Family<'a>> = std::iter::once(attrs.family)
+        .chain(fallback.iter().map(|name| cosmic_text::Family::Name(name)))
+        .collect();
+
+    let fonts: Vec<_> = families
+        .iter()
+        .map(|&family| {
+            let query = cosmic_text::fontdb::Query {
+                families: &[family],
+                weight: attrs.weight,
+                stretch: attrs.stretch,
+                style: attrs.style,
+            };
+
+            let id = font_system.db().query(&query)?;
+            font_system.get_font(id)
+        })
+        .collect();
+
+    let has_glyph = |index: usize, c: char| {
+        fonts[index]
+            .as_ref()
+            .is_some_and(|font| font.as_swash().charmap().map(c) != 0)
+    };
+
+    let mut spans = Vec::new();
+    let mut span_start = 0;
+    let mut span_family = 0;
+
+    for (index, c) in text.char_indices() {
+        // Whitespace and characters that no font can display stay with the
+        // surrounding text.
+        if c.is_whitespace() || has_glyph(span_family, c) {
+            continue;
+        }
+
+        let Some(family) = (0..families.len()).find(|&family| has_glyph(family, c)) else {
+            continue;
+        };
+
+        if index > span_start {
+            spans.push((
+                &text[span_start..index],
+                attrs.family(families[span_family]),
+            ));
+        }
+
+        span_start = index;
+        span_family = family;
+    }
+
+    spans.push((&text[span_start..], attrs.family(families[span_family])));
+
+    buffer.set_rich_text(font_system, spans, attrs, cosmic_text::Shaping::Advanced);
+}
@@ -7,12 +7,14 @@
 
 mod ignore_debug;
 
+pub mod animation;
 pub mod colors;
 pub mod font;
 pub mod shapes;
 pub mod shorthand;
 pub mod style;
 pub mod text_renderer;
+pub mod theme;
 pub mod util;
 pub mod widgets;
 
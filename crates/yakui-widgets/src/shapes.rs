@@ -1,7 +1,7 @@
 use std::f32::consts::TAU;
 
 use yakui_core::geometry::{Color, Rect, Vec2};
-use yakui_core::paint::{PaintDom, PaintMesh, PaintRect, Vertex};
+use yakui_core::paint::{Gradient, PaintDom, PaintMesh, PaintRect, Vertex};
 use yakui_core::TextureId;
 
 pub fn cross(output: &mut PaintDom, rect: Rect, color: Color) {
@@ -26,7 +26,7 @@ pub fn cross(output: &mut PaintDom, rect: Rect, color: Color) {
     ];
 
     #[rustfmt::skip]
-    static INDICES: [u16; 18] = [
+    static INDICES: [u32; 18] = [
         // '\' part of the X
         0, 2, 5,
         5, 7, 0,
@@ -54,53 +54,200 @@ pub fn selection_halo(output: &mut PaintDom, rect: Rect, color: Color) {
 }
 
 pub fn outline(output: &mut PaintDom, rect: Rect, w: f32, color: Color) {
-    let vw = rect.size().x;
-    let vh = rect.size().y;
+    let mut outline = Outline::new(rect, w);
+    outline.color = color;
+    outline.add(output);
+}
 
-    #[rustfmt::skip]
-    let positions: [[f32; 2]; 12] = [
-        // Main rectangle
-        [0.0, 0.0], // 0
-        [0.0, vh], // 1
-        [ vw, vh], // 2
-        [ vw, 0.0], // 3
-        // Left border
-        [0.0,      w], // 4
-        [0.0, vh - w], // 5
-        [  w, vh - w], // 6
-        [  w,      w], // 7
-        // Right border
-        [vw - w,      w], // 8
-        [vw - w, vh - w], // 9
-        [    vw, vh - w], // 10
-        [    vw,      w], // 11
-    ];
+/// The shortest distance from the start of one dash to the start of the next.
+const MIN_DASH_PERIOD: f32 = 0.5;
 
-    #[rustfmt::skip]
-    static INDICES: [u16; 24] = [
-        // Top
-        0, 4, 11,
-        11, 3, 0,
-        // Bottom
-        5, 1, 2,
-        2, 10, 5,
-        // Left
-        4, 5, 6,
-        6, 7, 4,
-        // Right
-        8, 9, 10,
-        10, 11, 8,
-    ];
+/// The pattern used to draw the sides of an [`Outline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StrokeStyle {
+    /// A continuous line.
+    Solid,
 
-    let color = color.to_linear();
+    /// Dashes of length `dash` separated by gaps of length `gap`.
+    Dashed { dash: f32, gap: f32 },
 
-    let vertices = positions
-        .into_iter()
-        .map(Vec2::from)
-        .map(|pos| Vertex::new(rect.pos() + pos, [0.0, 0.0], color));
+    /// Square dots as wide as the line, separated by gaps of the same size.
+    Dotted,
+}
 
-    let mesh = PaintMesh::new(vertices, INDICES);
-    output.add_mesh(mesh);
+/// How the corners of an [`Outline`] are filled in where two sides meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrokeJoin {
+    /// The corner is filled in completely, giving a sharp corner.
+    Miter,
+
+    /// The outer point of the corner is cut off diagonally.
+    Bevel,
+}
+
+/// The width of each side of an [`Outline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutlineWidths {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl OutlineWidths {
+    pub fn all(width: f32) -> Self {
+        Self {
+            left: width,
+            right: width,
+            top: width,
+            bottom: width,
+        }
+    }
+}
+
+/// The border of a rectangle, drawn on the inside of its edges.
+pub struct Outline {
+    pub rect: Rect,
+    pub widths: OutlineWidths,
+    pub color: Color,
+    pub style: StrokeStyle,
+    pub join: StrokeJoin,
+}
+
+impl Outline {
+    pub fn new(rect: Rect, width: f32) -> Self {
+        Self {
+            rect,
+            widths: OutlineWidths::all(width),
+            color: Color::WHITE,
+            style: StrokeStyle::Solid,
+            join: StrokeJoin::Miter,
+        }
+    }
+
+    pub fn add(&self, output: &mut PaintDom) {
+        let outer_min = self.rect.pos();
+        let outer_max = self.rect.max();
+        let size = self.rect.size();
+
+        // Sides can't be wider than the rectangle they're drawn inside of.
+        let left = self.widths.left.clamp(0.0, size.x);
+        let right = self.widths.right.clamp(0.0, size.x - left);
+        let top = self.widths.top.clamp(0.0, size.y);
+        let bottom = self.widths.bottom.clamp(0.0, size.y - top);
+
+        let inner_min = outer_min + Vec2::new(left, top);
+        let inner_max = outer_max - Vec2::new(right, bottom);
+
+        let color = self.color.to_linear();
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        let mut polygon = |points: &[Vec2]| {
+            let base = vertices.len() as u32;
+            vertices.extend(
+                points
+                    .iter()
+                    .map(|&pos| Vertex::new(pos, [0.0, 0.0], color)),
+            );
+
+            for i in 1..points.len() as u32 - 1 {
+                indices.extend_from_slice(&[base, base + i, base + i + 1]);
+            }
+        };
+
+        // Each corner is given as its outer point and its inner point.
+        let corners = [
+            (outer_min, inner_min),
+            (
+                Vec2::new(outer_max.x, outer_min.y),
+                Vec2::new(inner_max.x, inner_min.y),
+            ),
+            (outer_max, inner_max),
+            (
+                Vec2::new(outer_min.x, outer_max.y),
+                Vec2::new(inner_min.x, inner_max.y),
+            ),
+        ];
+
+        for (outer, inner) in corners {
+            if outer.x == inner.x || outer.y == inner.y {
+                continue;
+            }
+
+            let horizontal = Vec2::new(inner.x, outer.y);
+            let vertical = Vec2::new(outer.x, inner.y);
+
+            match self.join {
+                StrokeJoin::Miter => polygon(&[outer, horizontal, inner, vertical]),
+                StrokeJoin::Bevel => polygon(&[horizontal, inner, vertical]),
+            }
+        }
+
+        // Each side is given as the two corners of the rectangle it covers,
+        // and the axis it runs along.
+        let sides = [
+            (
+                Vec2::new(inner_min.x, outer_min.y),
+                Vec2::new(inner_max.x, inner_min.y),
+                Vec2::X,
+            ),
+            (
+                Vec2::new(inner_min.x, inner_max.y),
+                Vec2::new(inner_max.x, outer_max.y),
+                Vec2::X,
+            ),
+            (
+                Vec2::new(outer_min.x, inner_min.y),
+                Vec2::new(inner_min.x, inner_max.y),
+                Vec2::Y,
+            ),
+            (
+                Vec2::new(inner_max.x, inner_min.y),
+                Vec2::new(outer_max.x, inner_max.y),
+                Vec2::Y,
+            ),
+        ];
+
+        for (min, max, axis) in sides {
+            let side_size = max - min;
+            let cross_axis = Vec2::new(axis.y, axis.x);
+            let length = side_size.dot(axis);
+            let thickness = side_size.dot(cross_axis);
+
+            if length <= 0.0 || thickness <= 0.0 {
+                continue;
+            }
+
+            let (dash, gap) = match self.style {
+                StrokeStyle::Solid => (length, 0.0),
+                StrokeStyle::Dashed { dash, gap } => (dash.max(0.0), gap.max(0.0)),
+                StrokeStyle::Dotted => (thickness, thickness),
+            };
+
+            if dash <= 0.0 {
+                continue;
+            }
+
+            // Patterns shorter than this look like a solid line anyway, and
+            // would add a quad for every fraction of a pixel along the side.
+            let period = (dash + gap).max(MIN_DASH_PERIOD);
+
+            let across = side_size * cross_axis;
+            let mut start = 0.0;
+
+            while start < length {
+                let end = (start + dash).min(length);
+                let a = min + axis * start;
+                let b = min + axis * end;
+                polygon(&[a, b, b + across, a + across]);
+
+                start += period;
+            }
+        }
+
+        output.add_mesh(PaintMesh::new(vertices, indices));
+    }
 }
 
 pub struct Circle {
@@ -132,14 +279,14 @@ impl Circle {
         }
 
         vertices.push(Vertex::new(self.center, [0.0, 0.0], color));
-        let middle_vertex = (vertices.len() - 1) as u16;
+        let middle_vertex = (vertices.len() - 1) as u32;
 
         let mut indices = Vec::new();
         let segments = segments as i16;
 
         for i in 0i16..segments {
-            indices.push(i as u16);
-            indices.push((i - 1).rem_euclid(segments) as u16);
+            indices.push(i as u32);
+            indices.push((i - 1).rem_euclid(segments) as u32);
             indices.push(middle_vertex);
         }
 
@@ -157,7 +304,7 @@ const RECT_POS: [[f32; 2]; 4] = [
 ];
 
 #[rustfmt::skip]
-const RECT_INDEX: [u16; 6] = [
+const RECT_INDEX: [u32; 6] = [
     0, 1, 2,
     3, 0, 2,
 ];
@@ -167,6 +314,9 @@ pub struct RoundedRectangle {
     pub radius: f32,
     pub color: Color,
     pub texture: Option<(TextureId, Rect)>,
+
+    /// If set, the rectangle is filled with this gradient instead of `color`.
+    pub gradient: Option<Gradient>,
 }
 
 impl RoundedRectangle {
@@ -176,6 +326,7 @@ impl RoundedRectangle {
             radius,
             color: Color::WHITE,
             texture: None,
+            gradient: None,
         }
     }
 
@@ -194,6 +345,7 @@ impl RoundedRectangle {
             let mut p = PaintRect::new(rect);
             p.texture = self.texture;
             p.color = self.color;
+            p.gradient = self.gradient.clone();
             return p.add(output);
         }
 
@@ -217,20 +369,33 @@ impl RoundedRectangle {
             (position - rect.pos()) * uv_factor + uv_offset
         };
 
-        let create_vertex = |pos| Vertex::new(pos, calc_uv(pos), color);
+        let calc_color = |position: Vec2| match &self.gradient {
+            Some(gradient) => gradient.sample((position - rect.pos()) / rect.size()),
+            None => color,
+        };
+
+        let create_vertex = |pos| Vertex::new(pos, calc_uv(pos), calc_color(pos));
+
+        let segments = self.gradient.as_ref().map_or(1, Gradient::segments);
 
         let mut rectangle = |min: Vec2, max: Vec2| {
-            let base_vertex = vertices.len();
+            let size = (max - min) / segments as f32;
 
-            let size = max - min;
-            let rect_vertices = RECT_POS
-                .map(Vec2::from)
-                .map(|vert| create_vertex(vert * size + min));
+            for y in 0..segments {
+                for x in 0..segments {
+                    let base_vertex = vertices.len();
+                    let min = min + Vec2::new(x as f32, y as f32) * size;
 
-            let rect_indices = RECT_INDEX.map(|index| index + base_vertex as u16);
+                    let rect_vertices = RECT_POS
+                        .map(Vec2::from)
+                        .map(|vert| create_vertex(vert * size + min));
 
-            vertices.extend(rect_vertices);
-            indices.extend(rect_indices);
+                    let rect_indices = RECT_INDEX.map(|index| index + base_vertex as u32);
+
+                    vertices.extend(rect_vertices);
+                    indices.extend(rect_indices);
+                }
+            }
         };
 
         rectangle(
@@ -261,9 +426,9 @@ impl RoundedRectangle {
                 vertices.push(create_vertex(center + offset));
 
                 indices.extend_from_slice(&[
-                    center_vertex as u16,
-                    (index - 1) as u16,
-                    index as u16,
+                    center_vertex as u32,
+                    (index - 1) as u32,
+                    index as u32,
                 ]);
             }
         };
@@ -4,19 +4,34 @@
 //! widget if its defaults don't work for you.
 
 use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
 
 use yakui_core::geometry::{Color, Constraints, Dim2, Vec2};
+use yakui_core::input::{PlayerId, Shortcut};
 use yakui_core::widget::PaintContext;
 use yakui_core::{Alignment, ManagedTextureId, Pivot, Response, TextureId};
 
+use crate::animation::{Animatable, Spring, SpringValue};
+use crate::theme::Theme;
 use crate::widgets::{
-    Align, AlignResponse, Button, ButtonResponse, Canvas, CanvasResponse, Checkbox,
-    CheckboxResponse, Circle, CircleResponse, ColoredBox, ColoredBoxResponse, ConstrainedBox,
-    ConstrainedBoxResponse, CountGrid, Divider, DividerResponse, Draggable, DraggableResponse,
-    Flexible, FlexibleResponse, Image, ImageResponse, List, ListResponse, MaxWidth,
-    MaxWidthResponse, NineSlice, Offset, OffsetResponse, Opaque, OpaqueResponse, Pad, PadResponse,
-    Reflow, ReflowResponse, Scrollable, ScrollableResponse, Slider, SliderResponse, Spacer, Stack,
-    StackResponse, State, StateResponse, Text, TextBox, TextBoxResponse, TextResponse,
+    Absolute, AbsoluteResponse, Align, AlignResponse, Button, ButtonResponse, Canvas,
+    CanvasResponse, Checkbox, CheckboxResponse, Circle, CircleResponse, ColoredBox,
+    ColoredBoxResponse, ConstrainedBox, ConstrainedBoxResponse, ContextProvider,
+    ContextProviderResponse, CountGrid, DismissOnOutsideClick, DismissOnOutsideClickResponse,
+    Divider, DividerResponse, DragSource, DragSourceResponse, Draggable, DraggableResponse,
+    DropTarget, DropTargetResponse, DropdownButton, DropdownButtonResponse, Effect, EffectResponse,
+    Flexible, FlexibleResponse, FocusScope, FocusScopeResponse, FpsCounter, FpsCounterResponse,
+    FractionalSize, FractionalSizeResponse, Grid, GridResponse, GridTrack, HitSlop,
+    HitSlopResponse, Hotkey, HotkeyResponse, Image, ImageResponse, KeyHint, KeyHintResponse, Keyed,
+    List, ListResponse, MaxWidth, MaxWidthResponse, MenuItem, MinSize, MinSizeResponse, NineSlice,
+    Offset, OffsetResponse, Opaque, OpaqueResponse, Pad, PadResponse, PasteTarget,
+    PasteTargetResponse, PlayerInput, PlayerInputResponse, Portal, PortalResponse, Reflow,
+    ReflowResponse, Scrollable, ScrollableResponse, Slider, SliderResponse, Spacer, SplitButton,
+    SplitButtonResponse, SpringAnimation, SpringAnimationResponse, Stack, StackResponse, State,
+    StateHandle, StateResponse, Table, TableColumn, TableResponse, Text, TextBox, TextBoxResponse,
+    TextResponse, Themed, Timer, TimerResponse, Tween, TweenResponse, Wrap, WrapResponse,
 };
 
 /// See [List].
@@ -29,6 +44,37 @@ pub fn row<F: FnOnce()>(children: F) -> Response<ListResponse> {
     List::row().show(children)
 }
 
+/// See [List::show_items]. Each item is used as its own key.
+pub fn column_items<I, F>(items: I, children: F) -> Response<ListResponse>
+where
+    I: IntoIterator,
+    I::Item: Hash,
+    F: FnMut(I::Item),
+{
+    List::column().show_items(items, hash_key, children)
+}
+
+/// See [List::show_items]. Each item is used as its own key.
+pub fn row_items<I, F>(items: I, children: F) -> Response<ListResponse>
+where
+    I: IntoIterator,
+    I::Item: Hash,
+    F: FnMut(I::Item),
+{
+    List::row().show_items(items, hash_key, children)
+}
+
+fn hash_key<T: Hash>(item: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    item.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// See [Keyed].
+pub fn keyed<K: Hash, R, F: FnOnce() -> R>(key: K, children: F) -> R {
+    Keyed::new(key).show(children)
+}
+
 /// See [CountGrid].
 pub fn countgrid_column<F: FnOnce()>(n_columns: usize, children: F) -> Response<ListResponse> {
     CountGrid::col(n_columns).show(children)
@@ -39,6 +85,24 @@ pub fn countgrid_row<F: FnOnce()>(n_rows: usize, children: F) -> Response<ListRe
     CountGrid::row(n_rows).show(children)
 }
 
+/// See [Grid].
+pub fn grid<F: FnOnce()>(columns: Vec<GridTrack>, children: F) -> Response<GridResponse> {
+    Grid::new(columns).show(children)
+}
+
+/// See [Table].
+pub fn table<F: FnOnce(&TableResponse)>(
+    columns: Vec<TableColumn>,
+    rows: F,
+) -> Response<TableResponse> {
+    Table::new(columns).show(rows)
+}
+
+/// See [Wrap].
+pub fn wrap<F: FnOnce()>(children: F) -> Response<WrapResponse> {
+    Wrap::row().show(children)
+}
+
 /// See [Align].
 pub fn center<F: FnOnce()>(children: F) -> Response<AlignResponse> {
     Align::center().show(children)
@@ -54,6 +118,26 @@ pub fn button<S: Into<Cow<'static, str>>>(text: S) -> Response<ButtonResponse> {
     Button::styled(text.into()).show()
 }
 
+/// See [DropdownButton].
+pub fn dropdown_button<S, I, T>(text: S, items: I) -> Response<DropdownButtonResponse>
+where
+    S: Into<Cow<'static, str>>,
+    I: IntoIterator<Item = T>,
+    T: Into<MenuItem>,
+{
+    DropdownButton::new(text, items).show()
+}
+
+/// See [SplitButton].
+pub fn split_button<S, I, T>(text: S, items: I) -> Response<SplitButtonResponse>
+where
+    S: Into<Cow<'static, str>>,
+    I: IntoIterator<Item = T>,
+    T: Into<MenuItem>,
+{
+    SplitButton::new(text, items).show()
+}
+
 /// See [Circle].
 pub fn colored_circle<S: Into<f32>>(color: Color, size: S) -> Response<CircleResponse> {
     let mut circle = Circle::new();
@@ -84,6 +168,16 @@ where
     Image::new(image.into(), size.into()).show()
 }
 
+/// See [FpsCounter].
+pub fn fps_counter() -> Response<FpsCounterResponse> {
+    FpsCounter::new().show()
+}
+
+/// See [HitSlop].
+pub fn hit_slop<F: FnOnce()>(slop: Pad, children: F) -> Response<HitSlopResponse> {
+    HitSlop::new(slop).show(children)
+}
+
 /// See [Pad].
 pub fn pad<F: FnOnce()>(padding: Pad, children: F) -> Response<PadResponse> {
     padding.show(children)
@@ -137,6 +231,22 @@ pub fn draggable<F: FnOnce()>(children: F) -> Response<DraggableResponse> {
     Draggable::new().show(children)
 }
 
+/// See [DragSource].
+pub fn drag_source<T>(payload: T, children: impl FnOnce()) -> Response<DragSourceResponse>
+where
+    T: Clone + std::fmt::Debug + 'static,
+{
+    DragSource::new(payload).show(children)
+}
+
+/// See [DropTarget].
+pub fn drop_target<T>(children: impl FnOnce()) -> Response<DropTargetResponse<T>>
+where
+    T: Clone + std::fmt::Debug + 'static,
+{
+    DropTarget::new().show(children)
+}
+
 /// See [NineSlice].
 pub fn nineslice(
     texture: ManagedTextureId,
@@ -167,6 +277,13 @@ pub fn slider(value: f64, min: f64, max: f64) -> Response<SliderResponse> {
     Slider::new(value, min, max).show()
 }
 
+/// See [DismissOnOutsideClick].
+pub fn dismiss_on_outside_click(
+    children: impl FnOnce(),
+) -> Response<DismissOnOutsideClickResponse> {
+    DismissOnOutsideClick::new().show(children)
+}
+
 /// See [Reflow].
 pub fn reflow(
     anchor: Alignment,
@@ -182,6 +299,46 @@ pub fn opaque(children: impl FnOnce()) -> Response<OpaqueResponse> {
     Opaque::new().show(children)
 }
 
+/// See [FractionalSize].
+pub fn fractional_width(
+    width_factor: f32,
+    children: impl FnOnce(),
+) -> Response<FractionalSizeResponse> {
+    FractionalSize::width(width_factor).show(children)
+}
+
+/// See [FractionalSize].
+pub fn fractional_height(
+    height_factor: f32,
+    children: impl FnOnce(),
+) -> Response<FractionalSizeResponse> {
+    FractionalSize::height(height_factor).show(children)
+}
+
+/// See [FractionalSize].
+pub fn fractional_size(
+    width_factor: f32,
+    height_factor: f32,
+    children: impl FnOnce(),
+) -> Response<FractionalSizeResponse> {
+    FractionalSize::new(width_factor, height_factor).show(children)
+}
+
+/// See [PlayerInput].
+pub fn player_input(player: PlayerId, children: impl FnOnce()) -> Response<PlayerInputResponse> {
+    PlayerInput::new(player).show(children)
+}
+
+/// See [FocusScope].
+pub fn focus_scope(children: impl FnOnce()) -> Response<FocusScopeResponse> {
+    FocusScope::new().show(children)
+}
+
+/// See [PasteTarget].
+pub fn paste_target(children: impl FnOnce()) -> Response<PasteTargetResponse> {
+    PasteTarget::new().show(children)
+}
+
 /// See [Canvas].
 pub fn canvas(paint: impl Fn(&mut PaintContext<'_>) + 'static) -> Response<CanvasResponse> {
     Canvas::new(paint).show()
@@ -192,6 +349,36 @@ pub fn max_width(max_width: f32, children: impl FnOnce()) -> Response<MaxWidthRe
     MaxWidth::new(max_width).show(children)
 }
 
+/// See [KeyHint].
+pub fn key_hint(shortcut: Shortcut) -> Response<KeyHintResponse> {
+    KeyHint::new(shortcut).show()
+}
+
+/// See [MinSize].
+pub fn min_width(min_width: f32, children: impl FnOnce()) -> Response<MinSizeResponse> {
+    MinSize::width(min_width).show(children)
+}
+
+/// See [MinSize].
+pub fn min_height(min_height: f32, children: impl FnOnce()) -> Response<MinSizeResponse> {
+    MinSize::height(min_height).show(children)
+}
+
+/// See [MinSize].
+pub fn min_size<S: Into<Vec2>>(min_size: S, children: impl FnOnce()) -> Response<MinSizeResponse> {
+    MinSize::new(min_size.into()).show(children)
+}
+
+/// See [Absolute].
+pub fn absolute(children: impl FnOnce()) -> Response<AbsoluteResponse> {
+    Absolute::new().show(children)
+}
+
+/// See [Portal].
+pub fn portal(children: impl FnOnce()) -> Response<PortalResponse> {
+    Portal::new().show(children)
+}
+
 /// See [Stack].
 pub fn stack(children: impl FnOnce()) -> Response<StackResponse> {
     Stack::new().show(children)
@@ -203,3 +390,81 @@ where
 {
     State::new(default).show()
 }
+
+/// See [State::key].
+pub fn use_keyed_state<S, F, T: 'static>(key: S, default: F) -> Response<StateResponse<T>>
+where
+    S: Into<Cow<'static, str>>,
+    F: FnOnce() -> T + 'static,
+{
+    State::new(default).key(key).show()
+}
+
+/// See [Effect]. Runs `effect` the first time this is called and whenever
+/// `deps` changes afterwards.
+pub fn use_effect<D, F, C>(deps: D, effect: F) -> Response<EffectResponse>
+where
+    D: PartialEq + std::fmt::Debug + 'static,
+    F: FnOnce(&D) -> C + 'static,
+    C: 'static,
+{
+    Effect::new(deps, effect).show()
+}
+
+/// See [Timer]. Fires once, `duration` after this is first called.
+pub fn use_timer(duration: Duration) -> Response<TimerResponse> {
+    Timer::once(duration).show()
+}
+
+/// See [Timer]. Fires every `period` for as long as this keeps being called.
+pub fn use_interval(period: Duration) -> Response<TimerResponse> {
+    Timer::interval(period).show()
+}
+
+/// See [Hotkey]. Fires when `shortcut` is pressed while this keeps being
+/// called.
+pub fn use_shortcut(shortcut: Shortcut) -> Response<HotkeyResponse> {
+    Hotkey::new(shortcut).show()
+}
+
+/// See [Themed].
+pub fn themed(theme: Theme, children: impl FnOnce()) -> Response<ContextProviderResponse> {
+    Themed::new(theme).show(children)
+}
+
+/// See [ContextProvider].
+pub fn provide_context<T>(value: T, children: impl FnOnce()) -> Response<ContextProviderResponse>
+where
+    T: Clone + std::fmt::Debug + 'static,
+{
+    ContextProvider::new(value).show(children)
+}
+
+/// See [ContextProvider]. Returns the value from the nearest provider of type
+/// `T` that the current widget is inside of.
+pub fn use_context<T>() -> Option<T>
+where
+    T: Clone + std::fmt::Debug + 'static,
+{
+    ContextProvider::<T>::current()
+}
+
+/// See [Tween]. Moves toward `target` over `duration` whenever it changes.
+pub fn use_tween<T: Animatable>(target: T, duration: Duration) -> Response<TweenResponse<T>> {
+    Tween::new(target, duration).show()
+}
+
+/// See [SpringAnimation]. Pulls the value toward `target` with `spring`.
+pub fn use_spring<T: SpringValue>(
+    target: T,
+    spring: Spring,
+) -> Response<SpringAnimationResponse<T>> {
+    SpringAnimation::new(target, spring).show()
+}
+
+/// See [StateHandle].
+pub fn use_state_handle<T: 'static, S: Into<Cow<'static, str>>>(
+    key: S,
+) -> Option<StateResponse<T>> {
+    StateHandle::new(key).get()
+}
@@ -1,14 +1,51 @@
-use yakui_core::geometry::Color;
+use yakui_core::geometry::{Color, Vec2};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TextStyle {
     pub font_size: f32,
     pub line_height_override: Option<f32>,
     pub color: Color,
     pub align: TextAlignment,
+    pub overflow: TextOverflow,
+    pub wrap: TextWrap,
+
+    /// If set, only this many lines of text are shown.
+    pub max_lines: Option<usize>,
+
+    pub rendering: TextRendering,
+
+    /// A shadow drawn behind the text.
+    pub shadow: Option<TextShadow>,
+
+    /// An outline drawn around each glyph, which keeps text readable on top
+    /// of busy backgrounds.
+    pub outline: Option<TextOutline>,
+
     pub attrs: cosmic_text::AttrsOwned,
 }
 
+/// A drop shadow for text. See [`TextStyle::shadow`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextShadow {
+    /// How far the shadow is moved from the text, in logical pixels.
+    pub offset: Vec2,
+
+    /// How far the shadow is spread out, in logical pixels. Zero gives the
+    /// shadow hard edges.
+    pub blur: f32,
+
+    pub color: Color,
+}
+
+/// An outline around text. See [`TextStyle::outline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextOutline {
+    /// How thick the outline is, in logical pixels.
+    pub thickness: f32,
+
+    pub color: Color,
+}
+
 impl Default for TextStyle {
     fn default() -> Self {
         Self {
@@ -16,6 +53,12 @@ impl Default for TextStyle {
             line_height_override: None,
             color: Color::WHITE,
             align: TextAlignment::Start,
+            overflow: TextOverflow::Visible,
+            wrap: TextWrap::Word,
+            max_lines: None,
+            rendering: TextRendering::Raster,
+            shadow: None,
+            outline: None,
             attrs: cosmic_text::AttrsOwned {
                 family_owned: cosmic_text::FamilyOwned::SansSerif,
                 ..cosmic_text::AttrsOwned::new(cosmic_text::Attrs::new())
@@ -31,6 +74,17 @@ impl TextStyle {
         }
     }
 
+    /// Selects a font family by name, like one registered with
+    /// [`Fonts::add`][crate::font::Fonts::add]. If no family with that name
+    /// has been loaded, the default sans-serif family is used instead.
+    ///
+    /// [`Fonts::families`][crate::font::Fonts::families] lists the names that
+    /// can be used here.
+    pub fn with_family<S: Into<String>>(mut self, family: S) -> Self {
+        self.attrs.family_owned = cosmic_text::FamilyOwned::Name(family.into());
+        self
+    }
+
     pub fn line_height(&self) -> f32 {
         self.line_height_override.unwrap_or(self.font_size * 1.175)
     }
@@ -50,6 +104,20 @@ pub enum TextAlignment {
     End,
 }
 
+impl TextAlignment {
+    /// Converts this alignment into the one used by cosmic-text, taking the
+    /// direction of each line into account. `Start` and `End` refer to the
+    /// left and right of left-to-right text, and the right and left of
+    /// right-to-left text.
+    pub fn to_cosmic(self) -> Option<cosmic_text::Align> {
+        match self {
+            TextAlignment::Start => None,
+            TextAlignment::Center => Some(cosmic_text::Align::Center),
+            TextAlignment::End => Some(cosmic_text::Align::End),
+        }
+    }
+}
+
 impl From<TextAlignment> for cosmic_text::Align {
     fn from(value: TextAlignment) -> Self {
         match value {
@@ -59,3 +127,52 @@ impl From<TextAlignment> for cosmic_text::Align {
         }
     }
 }
+
+/// Describes where text can be broken onto new lines when it is wider than the
+/// space available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextWrap {
+    /// Text is never wrapped. Lines only break at explicit newlines.
+    None,
+
+    /// Text wraps between words. Words that are too long to fit on their own
+    /// line are broken between characters.
+    Word,
+
+    /// Text wraps between any two characters.
+    Anywhere,
+}
+
+impl From<TextWrap> for cosmic_text::Wrap {
+    fn from(value: TextWrap) -> Self {
+        match value {
+            TextWrap::None => cosmic_text::Wrap::None,
+            TextWrap::Word => cosmic_text::Wrap::WordOrGlyph,
+            TextWrap::Anywhere => cosmic_text::Wrap::Glyph,
+        }
+    }
+}
+
+/// Describes what happens to text that is wider than the space available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextOverflow {
+    /// Text wraps onto new lines and may overflow its container.
+    Visible,
+
+    /// Each line is kept to a single line and is truncated with a trailing
+    /// "…" if it doesn't fit. This overrides the style's [`TextWrap`].
+    Ellipsis,
+}
+
+/// Describes how glyphs are rasterized and drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextRendering {
+    /// Glyphs are rasterized at the exact size they are drawn at. This gives
+    /// the best quality for static text, but every new size needs new glyphs.
+    Raster,
+
+    /// Glyphs are rasterized once into a signed distance field and scaled to
+    /// the size they are drawn at. Useful for very large text or text whose
+    /// size is animated.
+    Sdf,
+}
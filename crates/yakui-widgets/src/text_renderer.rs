@@ -10,67 +10,179 @@ use yakui_core::ManagedTextureId;
 pub(crate) enum Kind {
     Mask,
     Color,
+    Sdf,
 }
 
 impl Kind {
     fn num_channels(self) -> usize {
         match self {
-            Kind::Mask => 1,
+            Kind::Mask | Kind::Sdf => 1,
             Kind::Color => 4,
         }
     }
 
     fn texture_format(self) -> TextureFormat {
         match self {
-            Kind::Mask => TextureFormat::R8,
+            Kind::Mask | Kind::Sdf => TextureFormat::R8,
             Kind::Color => TextureFormat::Rgba8SrgbPremultiplied,
         }
     }
 }
 
+/// The font size, in physical pixels, that glyphs in the SDF atlas are
+/// rasterized at before being scaled to their final size.
+const SDF_BASE_SIZE: f32 = 64.0;
+
+/// How far, in pixels of the SDF atlas, the distance field extends past the
+/// edge of each glyph.
+const SDF_SPREAD: u32 = 8;
+
 pub struct GlyphRender {
     pub(crate) kind: Kind,
     pub rect: URect,
     pub offset: Vec2,
     pub tex_rect: Rect,
     pub texture: ManagedTextureId,
+
+    /// How much `rect` and `offset` need to be scaled by to get the size of
+    /// the glyph in physical pixels. This is only not 1.0 for SDF glyphs.
+    pub scale: f32,
 }
 
+/// A change made to a glyph's coverage mask so that a text effect can be
+/// painted with one copy of the glyph. Sizes are in quarters of a physical
+/// pixel, which lets them be part of the key the glyph is cached under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum GlyphEffect {
+    /// Grows the glyph outward, for outlines.
+    Dilate(u32),
+
+    /// Blurs the glyph, for soft shadows.
+    Blur(u32),
+}
+
+impl GlyphEffect {
+    /// An outline of the given thickness in physical pixels, or `None` if it's
+    /// too thin to see.
+    pub(crate) fn dilate(thickness: f32) -> Option<Self> {
+        quarters(thickness).map(Self::Dilate)
+    }
+
+    /// A blur with the given radius in physical pixels, or `None` if it's too
+    /// small to see.
+    pub(crate) fn blur(radius: f32) -> Option<Self> {
+        quarters(radius).map(Self::Blur)
+    }
+
+    fn radius(self) -> f32 {
+        match self {
+            Self::Dilate(quarters) | Self::Blur(quarters) => quarters as f32 / 4.0,
+        }
+    }
+}
+
+fn quarters(size: f32) -> Option<u32> {
+    let quarters = (size * 4.0).round();
+    (quarters >= 1.0).then_some(quarters as u32)
+}
+
+/// The size of each glyph atlas page, unless the renderer's limits are smaller.
+const PAGE_SIZE: u32 = 1024;
+
 #[derive(Debug)]
 pub struct InnerAtlas {
     pub(crate) kind: Kind,
-    pub texture: Option<ManagedTextureId>,
-    pub glyph_rects: HashMap<cosmic_text::CacheKey, (URect, Vec2)>,
+
+    /// Textures that glyphs are packed into. New glyphs always go in the last
+    /// page, and a new page is added whenever it fills up.
+    pages: Vec<AtlasPage>,
+
+    /// Which page each glyph is in, where it is, and how it's offset.
+    pub glyph_rects: HashMap<cosmic_text::CacheKey, (usize, URect, Vec2)>,
+
+    /// Like `glyph_rects`, but for glyphs that have had an effect applied.
+    effect_rects: HashMap<(cosmic_text::CacheKey, GlyphEffect), (usize, URect, Vec2)>,
+}
+
+#[derive(Debug)]
+struct AtlasPage {
+    texture: ManagedTextureId,
+    size: UVec2,
     next_pos: UVec2,
     max_height: u32,
 }
 
+impl AtlasPage {
+    fn new(paint: &mut PaintDom, kind: Kind, size: u32) -> Self {
+        let mut texture = Texture::new(
+            kind.texture_format(),
+            UVec2::new(size, size),
+            vec![0; (size * size) as usize * kind.num_channels()],
+        );
+        texture.mag_filter = TextureFilter::Linear;
+        texture.min_filter = TextureFilter::Linear;
+        texture.allow_atlas = false;
+
+        Self {
+            texture: paint.add_texture(texture),
+            size: UVec2::new(size, size),
+            next_pos: UVec2::ZERO,
+            max_height: 0,
+        }
+    }
+
+    /// Finds room for a glyph of the given size, returning `None` if the page
+    /// is full.
+    fn allocate(&mut self, glyph_size: UVec2) -> Option<UVec2> {
+        let pos = if (self.next_pos + glyph_size).x < self.size.x {
+            self.next_pos
+        } else {
+            UVec2::new(0, self.max_height)
+        };
+
+        let glyph_max = pos + glyph_size;
+        if glyph_max.x >= self.size.x || glyph_max.y >= self.size.y {
+            return None;
+        }
+
+        self.max_height = self.max_height.max(pos.y + glyph_size.y + 1);
+        self.next_pos = pos + UVec2::new(glyph_size.x + 1, 0);
+
+        Some(pos)
+    }
+}
+
 impl InnerAtlas {
     fn new(kind: Kind) -> Self {
         Self {
             kind,
-            texture: None,
+            pages: Vec::new(),
             glyph_rects: HashMap::new(),
-            next_pos: UVec2::ZERO,
-            max_height: 0,
+            effect_rects: HashMap::new(),
         }
     }
 
-    fn ensure_texture(&mut self, paint: &mut PaintDom) -> Option<ManagedTextureId> {
-        let texture_size = paint.limits()?.max_texture_size_2d;
-
-        if self.texture.is_none() {
-            let mut texture = Texture::new(
-                self.kind.texture_format(),
-                UVec2::new(texture_size, texture_size),
-                vec![0; (texture_size * texture_size) as usize * self.kind.num_channels()],
-            );
-            texture.mag_filter = TextureFilter::Linear;
-            texture.min_filter = TextureFilter::Linear;
-            self.texture = Some(paint.add_texture(texture))
-        }
+    /// Returns the textures used by this atlas, in the order they were added.
+    pub fn textures(&self) -> impl Iterator<Item = ManagedTextureId> + '_ {
+        self.pages.iter().map(|page| page.texture)
+    }
 
-        self.texture
+    fn cached(&self, cache_key: &cosmic_text::CacheKey, scale: f32) -> Option<GlyphRender> {
+        let &(page, rect, offset) = self.glyph_rects.get(cache_key)?;
+        Some(self.render(page, rect, offset, scale))
+    }
+
+    fn render(&self, page: usize, rect: URect, offset: Vec2, scale: f32) -> GlyphRender {
+        let page = &self.pages[page];
+
+        GlyphRender {
+            kind: self.kind,
+            rect,
+            offset,
+            tex_rect: rect.as_rect().div_vec2(page.size.as_vec2()),
+            texture: page.texture,
+            scale,
+        }
     }
 
     fn get_or_insert(
@@ -81,21 +193,15 @@ impl InnerAtlas {
         glyph: &cosmic_text::LayoutGlyph,
         image: Option<cosmic_text::SwashImage>,
     ) -> Result<Option<GlyphRender>, Option<cosmic_text::SwashImage>> {
-        let Some(texture_id) = self.ensure_texture(paint) else {
+        // Glyphs can't be rasterized until the renderer has told us how big
+        // our textures can be.
+        if paint.limits().is_none() {
             return Ok(None);
-        };
-
-        let texture_size = paint.texture_mut(texture_id).unwrap().size();
+        }
 
         let physical_glyph = glyph.physical((0.0, 0.0), 1.0);
-        if let Some((rect, offset)) = self.glyph_rects.get(&physical_glyph.cache_key).cloned() {
-            return Ok(Some(GlyphRender {
-                kind: self.kind,
-                rect,
-                offset,
-                tex_rect: rect.as_rect().div_vec2(texture_size.as_vec2()),
-                texture: self.texture.unwrap(),
-            }));
+        if let Some(render) = self.cached(&physical_glyph.cache_key, 1.0) {
+            return Ok(Some(render));
         }
 
         if glyph.color_opt.is_some() {
@@ -125,55 +231,160 @@ impl InnerAtlas {
         }
 
         let glyph_size = UVec2::new(image.placement.width, image.placement.height);
+        let offset = Vec2::new(image.placement.left as f32, image.placement.top as f32);
 
-        let pos = if (self.next_pos + glyph_size).x < texture_size.x {
-            self.next_pos
-        } else {
-            UVec2::new(0, self.max_height)
-        };
+        Ok(self.insert(
+            paint,
+            physical_glyph.cache_key,
+            glyph_size,
+            &image.data,
+            offset,
+            1.0,
+        ))
+    }
 
-        let glyph_max = pos + glyph_size;
-        if glyph_max.x >= texture_size.x || glyph_max.y >= texture_size.y {
-            panic!("Overflowed glyph cache!");
+    fn get_or_insert_sdf(
+        &mut self,
+        paint: &mut PaintDom,
+        font_system: &mut cosmic_text::FontSystem,
+        cache: &mut cosmic_text::SwashCache,
+        glyph: &cosmic_text::LayoutGlyph,
+    ) -> Result<Option<GlyphRender>, ()> {
+        if paint.limits().is_none() {
+            return Ok(None);
         }
 
-        self.max_height = self.max_height.max(pos.y + glyph_size.y + 1);
-        self.next_pos = pos + UVec2::new(glyph_size.x + 1, 0);
+        let scale = glyph.font_size / SDF_BASE_SIZE;
 
-        let num_channels = self.kind.num_channels() as u32;
-        let scale = UVec2::new(num_channels, 1);
-        blit(
-            pos * scale,
-            glyph_size * scale,
-            &image.data,
-            texture_size * scale,
-            paint.texture_mut(self.texture.unwrap()).unwrap().data_mut(),
+        // Every size of a glyph shares a single entry in the SDF atlas, so the
+        // key is built at the base size with no subpixel offset.
+        let (cache_key, _, _) = cosmic_text::CacheKey::new(
+            glyph.font_id,
+            glyph.glyph_id,
+            SDF_BASE_SIZE,
+            (0.0, 0.0),
+            glyph.cache_key_flags,
         );
-        paint.mark_texture_modified(self.texture.unwrap());
 
-        let rect = URect::from_pos_size(pos, glyph_size);
-        let offset = Vec2::new(image.placement.left as f32, image.placement.top as f32);
+        if let Some(render) = self.cached(&cache_key, scale) {
+            return Ok(Some(render));
+        }
 
-        self.glyph_rects
-            .insert(physical_glyph.cache_key, (rect, offset));
+        let Some(image) = cache.get_image_uncached(font_system, cache_key) else {
+            return Err(());
+        };
 
-        Ok(Some(GlyphRender {
-            kind: self.kind,
-            rect,
-            offset,
-            tex_rect: rect.as_rect().div_vec2(texture_size.as_vec2()),
-            texture: self.texture.unwrap(),
-        }))
+        if image.content != cosmic_text::SwashContent::Mask {
+            return Err(());
+        }
+
+        let size = UVec2::new(image.placement.width, image.placement.height);
+        let (glyph_size, data) = distance_field(size, &image.data, SDF_SPREAD);
+        let offset = Vec2::new(
+            image.placement.left as f32 - SDF_SPREAD as f32,
+            image.placement.top as f32 + SDF_SPREAD as f32,
+        );
+
+        Ok(self.insert(paint, cache_key, glyph_size, &data, offset, scale))
     }
 
-    fn clear(&mut self, paint: &mut PaintDom) {
-        self.glyph_rects.clear();
-        self.next_pos = UVec2::ZERO;
-        self.max_height = 0;
+    /// Like [`InnerAtlas::get_or_insert`], but with an effect applied to the
+    /// glyph. Only glyphs that are coverage masks can have effects, since
+    /// color glyphs can't be tinted.
+    fn get_or_insert_effect(
+        &mut self,
+        paint: &mut PaintDom,
+        font_system: &mut cosmic_text::FontSystem,
+        cache: &mut cosmic_text::SwashCache,
+        glyph: &cosmic_text::LayoutGlyph,
+        effect: GlyphEffect,
+    ) -> Option<GlyphRender> {
+        // Glyphs can't be rasterized until the renderer has told us how big
+        // our textures can be.
+        paint.limits()?;
+
+        let key = (glyph.physical((0.0, 0.0), 1.0).cache_key, effect);
+        if let Some(&(page, rect, offset)) = self.effect_rects.get(&key) {
+            return Some(self.render(page, rect, offset, 1.0));
+        }
 
-        if let Some(id) = self.texture.take() {
-            paint.remove_texture(id);
+        let image = cache.get_image_uncached(font_system, key.0)?;
+        if image.content != cosmic_text::SwashContent::Mask {
+            return None;
         }
+
+        let size = UVec2::new(image.placement.width, image.placement.height);
+        let (glyph_size, padding, data) = apply_effect(size, &image.data, effect);
+        let offset = Vec2::new(
+            image.placement.left as f32 - padding as f32,
+            image.placement.top as f32 + padding as f32,
+        );
+
+        let (page, rect) = self.pack(paint, glyph_size, &data)?;
+        self.effect_rects.insert(key, (page, rect, offset));
+
+        Some(self.render(page, rect, offset, 1.0))
+    }
+
+    fn insert(
+        &mut self,
+        paint: &mut PaintDom,
+        cache_key: cosmic_text::CacheKey,
+        glyph_size: UVec2,
+        data: &[u8],
+        offset: Vec2,
+        scale: f32,
+    ) -> Option<GlyphRender> {
+        let (page, rect) = self.pack(paint, glyph_size, data)?;
+        self.glyph_rects.insert(cache_key, (page, rect, offset));
+
+        Some(self.render(page, rect, offset, scale))
+    }
+
+    /// Packs a rasterized glyph into the atlas, adding a new page if the
+    /// current one is full. Returns `None` if the glyph is too big to fit in a
+    /// page at all.
+    fn pack(
+        &mut self,
+        paint: &mut PaintDom,
+        glyph_size: UVec2,
+        data: &[u8],
+    ) -> Option<(usize, URect)> {
+        let allocated = self
+            .pages
+            .last_mut()
+            .and_then(|page| page.allocate(glyph_size));
+
+        let pos = match allocated {
+            Some(pos) => pos,
+            None => {
+                let page_size = paint.limits()?.max_texture_size_2d.min(PAGE_SIZE);
+                if glyph_size.x >= page_size || glyph_size.y >= page_size {
+                    return None;
+                }
+
+                let mut page = AtlasPage::new(paint, self.kind, page_size);
+                let pos = page.allocate(glyph_size)?;
+                self.pages.push(page);
+                pos
+            }
+        };
+
+        let page_index = self.pages.len() - 1;
+        let page = &self.pages[page_index];
+
+        let num_channels = self.kind.num_channels() as u32;
+        let channel_scale = UVec2::new(num_channels, 1);
+        blit(
+            pos * channel_scale,
+            glyph_size * channel_scale,
+            data,
+            page.size * channel_scale,
+            paint.texture_mut(page.texture).unwrap().data_mut(),
+        );
+        paint.mark_texture_region_modified(page.texture, URect::from_pos_size(pos, glyph_size));
+
+        Some((page_index, URect::from_pos_size(pos, glyph_size)))
     }
 }
 
@@ -194,11 +405,234 @@ fn blit(pos: UVec2, src_size: UVec2, src: &[u8], dst_size: UVec2, dst: &mut [u8]
     }
 }
 
+/// Converts a coverage mask into a signed distance field, padded by `spread`
+/// pixels on every side. Values above 0.5 are inside the glyph, and the field
+/// falls off linearly to 0.0 or 1.0 at `spread` pixels from the edge.
+fn distance_field(size: UVec2, mask: &[u8], spread: u32) -> (UVec2, Vec<u8>) {
+    let padded = size + UVec2::splat(spread * 2);
+
+    let inside = |x: u32, y: u32| {
+        if x < spread || y < spread || x - spread >= size.x || y - spread >= size.y {
+            return false;
+        }
+
+        mask[((y - spread) * size.x + x - spread) as usize] >= 128
+    };
+
+    let to_inside = squared_distances(padded, inside);
+    let to_outside = squared_distances(padded, |x, y| !inside(x, y));
+
+    let mut output = Vec::with_capacity((padded.x * padded.y) as usize);
+
+    for y in 0..padded.y {
+        for x in 0..padded.x {
+            let index = (y * padded.x + x) as usize;
+            let here = inside(x, y);
+
+            let squared = if here {
+                to_outside[index]
+            } else {
+                to_inside[index]
+            };
+            let nearest = (squared.sqrt() as f32).min(spread as f32);
+
+            // The edge lies halfway between this pixel and the nearest pixel
+            // on the other side of it.
+            let edge = (nearest - 0.5).max(0.0);
+            let signed = if here { edge } else { -edge };
+            let value = 0.5 + signed / (2.0 * spread as f32);
+
+            output.push((value.clamp(0.0, 1.0) * 255.0).round() as u8);
+        }
+    }
+
+    (padded, output)
+}
+
+/// Applies an effect to a coverage mask, first padding it on every side so that
+/// nothing is cut off. Returns the padded size, the padding, and the new mask.
+fn apply_effect(size: UVec2, mask: &[u8], effect: GlyphEffect) -> (UVec2, u32, Vec<u8>) {
+    let radius = effect.radius();
+    let padding = radius.ceil() as u32 + 1;
+    let padded_size = size + UVec2::splat(padding * 2);
+
+    let mut padded = vec![0; (padded_size.x * padded_size.y) as usize];
+    blit(UVec2::splat(padding), size, mask, padded_size, &mut padded);
+
+    let output = match effect {
+        GlyphEffect::Dilate(_) => dilate(padded_size, &padded, radius),
+        GlyphEffect::Blur(_) => blur(padded_size, &padded, radius),
+    };
+
+    (padded_size, padding, output)
+}
+
+/// Grows a coverage mask outward by `radius` pixels, with an antialiased edge.
+fn dilate(size: UVec2, mask: &[u8], radius: f32) -> Vec<u8> {
+    let to_inside = squared_distances(size, |x, y| mask[(y * size.x + x) as usize] >= 128);
+
+    mask.iter()
+        .zip(to_inside)
+        .map(|(&coverage, squared)| {
+            // The glyph's edge is about half a pixel past the nearest pixel
+            // inside of it, so the new edge is half a pixel past `radius`.
+            let grown = (radius + 1.0 - squared.sqrt() as f32).clamp(0.0, 1.0);
+            coverage.max((grown * 255.0).round() as u8)
+        })
+        .collect()
+}
+
+/// Blurs a coverage mask with a Gaussian whose standard deviation is half of
+/// `radius`, like a CSS `text-shadow`.
+fn blur(size: UVec2, mask: &[u8], radius: f32) -> Vec<u8> {
+    let (width, height) = (size.x as i32, size.y as i32);
+    let reach = radius.ceil() as i32;
+    let sigma = radius / 2.0;
+
+    let mut weights: Vec<f32> = (-reach..=reach)
+        .map(|offset| (-(offset * offset) as f32 / (2.0 * sigma * sigma)).exp())
+        .collect();
+    let total: f32 = weights.iter().sum();
+    weights.iter_mut().for_each(|weight| *weight /= total);
+
+    // The Gaussian is separable, so it's applied along each row and then
+    // along each column.
+    let pass = |input: &[f32], step: (i32, i32)| -> Vec<f32> {
+        let sample = |x: i32, y: i32| {
+            if x < 0 || y < 0 || x >= width || y >= height {
+                0.0
+            } else {
+                input[(y * width + x) as usize]
+            }
+        };
+
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                (-reach..=reach)
+                    .zip(&weights)
+                    .map(|(offset, weight)| {
+                        weight * sample(x + offset * step.0, y + offset * step.1)
+                    })
+                    .sum()
+            })
+            .collect()
+    };
+
+    let input: Vec<f32> = mask.iter().map(|&coverage| coverage as f32).collect();
+    let rows = pass(&input, (1, 0));
+    let blurred = pass(&rows, (0, 1));
+
+    blurred
+        .into_iter()
+        .map(|coverage| coverage.round().clamp(0.0, 255.0) as u8)
+        .collect()
+}
+
+/// Finds the squared distance from every pixel to the nearest pixel that
+/// `seed` is true for, using the exact transform from Felzenszwalb and
+/// Huttenlocher's "Distance Transforms of Sampled Functions". It runs in time
+/// linear in the number of pixels.
+fn squared_distances(size: UVec2, seed: impl Fn(u32, u32) -> bool) -> Vec<f64> {
+    let (width, height) = (size.x as usize, size.y as usize);
+
+    // Further than any two pixels can be apart, but small enough to keep the
+    // math exact.
+    let far = ((width + height) as f64).powi(2);
+
+    let mut grid: Vec<f64> = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| if seed(x as u32, y as u32) { 0.0 } else { far })
+        .collect();
+
+    let longest = width.max(height);
+    let mut line = vec![0.0; longest];
+    let mut output = vec![0.0; longest];
+    let mut scratch = Scratch::new(longest);
+
+    for x in 0..width {
+        for y in 0..height {
+            line[y] = grid[y * width + x];
+        }
+        scratch.transform(&line[..height], &mut output[..height]);
+        for y in 0..height {
+            grid[y * width + x] = output[y];
+        }
+    }
+
+    for row in grid.chunks_exact_mut(width.max(1)) {
+        scratch.transform(row, &mut output[..row.len()]);
+        row.copy_from_slice(&output[..row.len()]);
+    }
+
+    grid
+}
+
+/// Buffers for the one-dimensional distance transform, which finds the lower
+/// envelope of the parabolas rooted at each sample.
+struct Scratch {
+    /// The samples whose parabolas make up the lower envelope.
+    roots: Vec<usize>,
+
+    /// Where each parabola in the envelope starts being the lowest.
+    bounds: Vec<f64>,
+}
+
+impl Scratch {
+    fn new(len: usize) -> Self {
+        Self {
+            roots: vec![0; len],
+            bounds: vec![0.0; len + 1],
+        }
+    }
+
+    fn transform(&mut self, f: &[f64], output: &mut [f64]) {
+        if f.is_empty() {
+            return;
+        }
+
+        let intersection = |q: usize, p: usize| {
+            let (q, p) = (q as f64, p as f64);
+            ((f[q as usize] + q * q) - (f[p as usize] + p * p)) / (2.0 * q - 2.0 * p)
+        };
+
+        let mut k = 0;
+        self.roots[0] = 0;
+        self.bounds[0] = f64::NEG_INFINITY;
+        self.bounds[1] = f64::INFINITY;
+
+        for q in 1..f.len() {
+            let mut s = intersection(q, self.roots[k]);
+            while s <= self.bounds[k] {
+                k -= 1;
+                s = intersection(q, self.roots[k]);
+            }
+
+            k += 1;
+            self.roots[k] = q;
+            self.bounds[k] = s;
+            self.bounds[k + 1] = f64::INFINITY;
+        }
+
+        k = 0;
+        for (q, out) in output.iter_mut().enumerate() {
+            while self.bounds[k + 1] < q as f64 {
+                k += 1;
+            }
+
+            let root = self.roots[k];
+            let offset = q as f64 - root as f64;
+            *out = offset * offset + f[root];
+        }
+    }
+}
+
 /// An atlas containing a cache of rasterized glyphs that can be rendered.
 #[derive(Debug)]
 pub struct TextAtlas {
     pub(crate) color_atlas: InnerAtlas,
     pub(crate) mask_atlas: InnerAtlas,
+    pub(crate) sdf_atlas: InnerAtlas,
 }
 
 impl TextAtlas {
@@ -206,10 +640,12 @@ impl TextAtlas {
     pub fn new() -> Self {
         let color_atlas = InnerAtlas::new(Kind::Color);
         let mask_atlas = InnerAtlas::new(Kind::Mask);
+        let sdf_atlas = InnerAtlas::new(Kind::Sdf);
 
         Self {
             color_atlas,
             mask_atlas,
+            sdf_atlas,
         }
     }
 }
@@ -247,6 +683,45 @@ impl InnerState {
             }
         }
     }
+
+    /// Like [`InnerState::get_or_insert`], but with an effect applied to the
+    /// glyph. Returns `None` for glyphs that can't have effects, like color
+    /// emoji.
+    pub(crate) fn get_or_insert_effect(
+        &mut self,
+        paint: &mut PaintDom,
+        font_system: &mut cosmic_text::FontSystem,
+        glyph: &cosmic_text::LayoutGlyph,
+        effect: GlyphEffect,
+    ) -> Option<GlyphRender> {
+        self.atlas.mask_atlas.get_or_insert_effect(
+            paint,
+            font_system,
+            &mut self.swash,
+            glyph,
+            effect,
+        )
+    }
+
+    /// Like [`InnerState::get_or_insert`], but places the glyph in the SDF
+    /// atlas. Glyphs that can't be represented as a distance field, like color
+    /// emoji, fall back to the regular atlases.
+    pub fn get_or_insert_sdf(
+        &mut self,
+        paint: &mut PaintDom,
+        font_system: &mut cosmic_text::FontSystem,
+        glyph: &cosmic_text::LayoutGlyph,
+    ) -> Option<GlyphRender> {
+        let sdf =
+            self.atlas
+                .sdf_atlas
+                .get_or_insert_sdf(paint, font_system, &mut self.swash, glyph);
+
+        match sdf {
+            Ok(glyph) => glyph,
+            Err(()) => self.get_or_insert(paint, font_system, glyph),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -266,6 +741,29 @@ impl TextGlobalState {
             .get_or_insert(paint, font_system, glyph)
     }
 
+    pub fn get_or_insert_sdf(
+        &self,
+        paint: &mut PaintDom,
+        font_system: &mut cosmic_text::FontSystem,
+        glyph: &cosmic_text::LayoutGlyph,
+    ) -> Option<GlyphRender> {
+        self.inner
+            .borrow_mut()
+            .get_or_insert_sdf(paint, font_system, glyph)
+    }
+
+    pub(crate) fn get_or_insert_effect(
+        &self,
+        paint: &mut PaintDom,
+        font_system: &mut cosmic_text::FontSystem,
+        glyph: &cosmic_text::LayoutGlyph,
+        effect: GlyphEffect,
+    ) -> Option<GlyphRender> {
+        self.inner
+            .borrow_mut()
+            .get_or_insert_effect(paint, font_system, glyph, effect)
+    }
+
     pub fn new() -> Self {
         let state = InnerState {
             swash: cosmic_text::SwashCache::new(),
@@ -0,0 +1,94 @@
+//! Defines [`Theme`], which the built-in widgets take their colors, spacing,
+//! and text styles from.
+
+use yakui_core::geometry::Color;
+
+use crate::colors;
+use crate::style::TextStyle;
+use crate::widgets::{ContextProvider, Pad};
+
+/// The look of the built-in widgets.
+///
+/// Widgets use the theme of the nearest [`Themed`][crate::widgets::Themed]
+/// widget or [`ContextProvider`] of a theme that they're inside of, or
+/// [`Theme::default`] if there isn't one. The theme is read when a widget's
+/// props are created, so fields of the props can still be changed afterwards
+/// to style a single widget.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    /// The darkest background, used behind everything else.
+    pub background_1: Color,
+
+    /// Used for surfaces like windows and menus.
+    pub background_2: Color,
+
+    /// Used for controls like buttons, checkboxes and text boxes.
+    pub background_3: Color,
+
+    /// The color of most text and icons.
+    pub text: Color,
+
+    /// The color of text that's less important, like hints.
+    pub text_muted: Color,
+
+    /// The outline drawn around the selected widget when navigating with a
+    /// keyboard or gamepad.
+    pub focus: Color,
+
+    /// Drawn behind text that's selected in a text box.
+    pub text_selection: Color,
+
+    /// The text cursor in text boxes.
+    pub text_cursor: Color,
+
+    /// The style of text in controls like buttons and text boxes. Its color is
+    /// replaced with [`Theme::text`].
+    pub text_style: TextStyle,
+
+    /// How rounded the corners of controls are, in logical pixels.
+    pub corner_radius: f32,
+
+    /// The space around the text of a button.
+    pub button_padding: Pad,
+
+    /// The space around the text of a text box.
+    pub textbox_padding: Pad,
+
+    /// The space around the title of a window.
+    pub window_padding: Pad,
+}
+
+impl Theme {
+    /// Returns the theme that applies to the widget currently being built.
+    /// Outside of a frame, this is always [`Theme::default`].
+    pub fn current() -> Self {
+        ContextProvider::<Self>::current().unwrap_or_default()
+    }
+
+    /// Returns [`Theme::text_style`] in the theme's text color.
+    pub fn label(&self) -> TextStyle {
+        let mut style = self.text_style.clone();
+        style.color = self.text;
+        style
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            background_1: colors::BACKGROUND_1,
+            background_2: colors::BACKGROUND_2,
+            background_3: colors::BACKGROUND_3,
+            text: colors::TEXT,
+            text_muted: colors::TEXT_MUTED,
+            focus: Color::WHITE,
+            text_selection: Color::CORNFLOWER_BLUE.adjust(0.4),
+            text_cursor: Color::RED,
+            text_style: TextStyle::label(),
+            corner_radius: 6.0,
+            button_padding: Pad::balanced(20.0, 10.0),
+            textbox_padding: Pad::all(8.0),
+            window_padding: Pad::all(8.0),
+        }
+    }
+}
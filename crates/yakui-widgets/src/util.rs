@@ -23,3 +23,47 @@ where
     let dom = context::dom();
     dom.do_widget::<T>(props)
 }
+
+/// Implemented by the responses of widgets that edit a value, like checkboxes
+/// and sliders. Each of those responses also has a `changed` field, which is
+/// true only on the frame the user changed the value.
+///
+/// Application state should only be written back when this is true, which
+/// avoids comparing old and new values by hand. Slices of responses are changed
+/// if any of them are, which is handy for lists of similar widgets.
+///
+/// ```rust
+/// # let _handle = yakui_widgets::DocTest::start();
+/// use yakui_widgets::util::Changed;
+///
+/// let mut flags = [false, true, false];
+///
+/// let responses: Vec<_> = flags.iter().map(|&flag| yakui_widgets::checkbox(flag)).collect();
+/// if responses.changed() {
+///     for (flag, response) in flags.iter_mut().zip(&responses) {
+///         *flag = response.checked;
+///     }
+/// }
+/// ```
+pub trait Changed {
+    /// Whether the user changed the value this frame.
+    fn changed(&self) -> bool;
+}
+
+impl<T: Changed> Changed for Response<T> {
+    fn changed(&self) -> bool {
+        (**self).changed()
+    }
+}
+
+impl<T: Changed> Changed for [T] {
+    fn changed(&self) -> bool {
+        self.iter().any(|item| item.changed())
+    }
+}
+
+impl<T: Changed, const N: usize> Changed for [T; N] {
+    fn changed(&self) -> bool {
+        self.as_slice().changed()
+    }
+}
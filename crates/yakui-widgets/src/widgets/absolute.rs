@@ -0,0 +1,253 @@
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::Response;
+
+use crate::util::widget_children;
+
+/**
+A container that fills all of the space available to it and places each child
+at exact coordinates, ignoring the flow of other children. Useful for HUDs that
+need elements at specific pixel positions.
+
+Children wrapped in [Positioned] are placed by their distance from the edges of
+the container. Other children are placed in the top-left corner. Unlike
+[StackItem][super::StackItem], a child pinned to two opposite edges is
+stretched to fit between them.
+
+Responds with [AbsoluteResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{Absolute, Positioned};
+
+Absolute::new().show(|| {
+    Positioned::top_left(16.0, 16.0).show(|| {
+        yakui::label("Health: 100");
+    });
+
+    Positioned::bottom_right(16.0, 16.0).show(|| {
+        yakui::label("Ammo: 30");
+    });
+
+    // A bar stretched along the bottom of the screen
+    let mut bar = Positioned::new();
+    bar.left = Some(0.0);
+    bar.right = Some(0.0);
+    bar.bottom = Some(0.0);
+    bar.height = Some(24.0);
+    bar.show(|| {
+        yakui::colored_box_container(yakui::colors::BACKGROUND_1, || {});
+    });
+});
+```
+*/
+#[derive(Debug, Clone, Default)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Absolute {}
+
+impl Absolute {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<AbsoluteResponse> {
+        widget_children::<AbsoluteWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct AbsoluteWidget;
+
+pub type AbsoluteResponse = ();
+
+impl Widget for AbsoluteWidget {
+    type Props<'a> = Absolute;
+    type Response = AbsoluteResponse;
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+
+        // Without a bound, there's no far edge to position children against,
+        // so the container is as small as it's allowed to be.
+        let bound = |min: f32, max: f32| if max.is_finite() { max } else { min };
+        let max = constraints.max;
+        let size = Vec2::new(
+            bound(constraints.min.x, max.x),
+            bound(constraints.min.y, max.y),
+        );
+
+        for &child in &node.children {
+            let dom_node = ctx.dom.get(child).unwrap();
+            let positioned = dom_node
+                .widget
+                .downcast_ref::<PositionedWidget>()
+                .map(|positioned| positioned.props.clone())
+                .unwrap_or_default();
+
+            let (min_x, max_x) = span(positioned.left, positioned.right, positioned.width, max.x);
+            let (min_y, max_y) = span(positioned.top, positioned.bottom, positioned.height, max.y);
+            let child_constraints = Constraints {
+                min: Vec2::new(min_x, min_y),
+                max: Vec2::new(max_x, max_y),
+            };
+
+            let child_size = ctx.calculate_layout(child, child_constraints);
+
+            let x = place(positioned.left, positioned.right, child_size.x, size.x);
+            let y = place(positioned.top, positioned.bottom, child_size.y, size.y);
+            ctx.layout.set_pos(child, Vec2::new(x, y));
+        }
+
+        size
+    }
+}
+
+/// Returns the smallest and largest size a child can be along one axis of an
+/// [Absolute]. Children can only be stretched between two edges if the
+/// container is bounded.
+fn span(start: Option<f32>, end: Option<f32>, size: Option<f32>, container: f32) -> (f32, f32) {
+    match (start, end, size) {
+        (Some(start), Some(end), _) if container.is_finite() => {
+            let size = (container - start - end).max(0.0);
+            (size, size)
+        }
+        (_, _, Some(size)) => (size, size),
+        (start, end, _) => {
+            let room = container - start.unwrap_or(0.0) - end.unwrap_or(0.0);
+            (0.0, room.max(0.0))
+        }
+    }
+}
+
+/// Returns where a child should start along one axis of an [Absolute].
+fn place(start: Option<f32>, end: Option<f32>, child: f32, container: f32) -> f32 {
+    match (start, end) {
+        (Some(start), _) => start,
+        (None, Some(end)) => container - end - child,
+        (None, None) => 0.0,
+    }
+}
+
+/**
+Places a child of an [Absolute] at a given distance from its edges. Outside of
+an [Absolute], this widget has no effect.
+
+Distances are in logical pixels and aren't mirrored in right-to-left layouts.
+If both edges of an axis are set, the child is stretched between them and the
+size on that axis is ignored. If neither is set, the child is placed against
+the top or left edge.
+
+Responds with [PositionedResponse].
+*/
+#[derive(Debug, Clone, Default)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Positioned {
+    /// Distance from the left edge of the container to the left edge of the
+    /// child.
+    pub left: Option<f32>,
+
+    /// Distance from the top edge of the container to the top edge of the
+    /// child.
+    pub top: Option<f32>,
+
+    /// Distance from the right edge of the container to the right edge of
+    /// the child.
+    pub right: Option<f32>,
+
+    /// Distance from the bottom edge of the container to the bottom edge of
+    /// the child.
+    pub bottom: Option<f32>,
+
+    /// Forces the child to be this wide.
+    pub width: Option<f32>,
+
+    /// Forces the child to be this tall.
+    pub height: Option<f32>,
+}
+
+impl Positioned {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pins the child to the top-left corner of the container.
+    pub fn top_left(left: f32, top: f32) -> Self {
+        Self {
+            left: Some(left),
+            top: Some(top),
+            ..Self::default()
+        }
+    }
+
+    /// Pins the child to the top-right corner of the container.
+    pub fn top_right(right: f32, top: f32) -> Self {
+        Self {
+            right: Some(right),
+            top: Some(top),
+            ..Self::default()
+        }
+    }
+
+    /// Pins the child to the bottom-left corner of the container.
+    pub fn bottom_left(left: f32, bottom: f32) -> Self {
+        Self {
+            left: Some(left),
+            bottom: Some(bottom),
+            ..Self::default()
+        }
+    }
+
+    /// Pins the child to the bottom-right corner of the container.
+    pub fn bottom_right(right: f32, bottom: f32) -> Self {
+        Self {
+            right: Some(right),
+            bottom: Some(bottom),
+            ..Self::default()
+        }
+    }
+
+    /// Stretches the child to fill the container, keeping the given distance
+    /// from every edge.
+    pub fn fill(inset: f32) -> Self {
+        Self {
+            left: Some(inset),
+            top: Some(inset),
+            right: Some(inset),
+            bottom: Some(inset),
+            ..Self::default()
+        }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<PositionedResponse> {
+        widget_children::<PositionedWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct PositionedWidget {
+    props: Positioned,
+}
+
+pub type PositionedResponse = ();
+
+impl Widget for PositionedWidget {
+    type Props<'a> = Positioned;
+    type Response = PositionedResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Positioned::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+}
@@ -32,7 +32,7 @@ Align::new(yakui::Alignment::TOP_RIGHT).show(|| {
 });
 ```
 */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Align {
     pub alignment: Alignment,
@@ -57,6 +57,7 @@ impl Align {
 #[derive(Debug)]
 pub struct AlignWidget {
     props: Align,
+    layout_changed: bool,
 }
 
 pub type AlignResponse = ();
@@ -68,13 +69,19 @@ impl Widget for AlignWidget {
     fn new() -> Self {
         Self {
             props: Align::center(),
+            layout_changed: true,
         }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.layout_changed = props != self.props;
         self.props = props;
     }
 
+    fn needs_layout(&self) -> bool {
+        self.layout_changed
+    }
+
     fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
         let node = ctx.dom.get_current();
 
@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use yakui_core::geometry::{Color, Rect, Vec2};
+use yakui_core::widget::Widget;
+use yakui_core::{context, Response, TextureId};
+
+use crate::util::widget;
+
+use super::{Image, ImageFit, ImageRegion};
+
+/**
+Cycles through the frames of an animation, like an animated icon or a loading
+spinner.
+
+Frames advance based on the time given to
+[`Yakui::set_time`][yakui_core::Yakui::set_time], so the animation doesn't play
+if the host doesn't call it. The animation starts the first frame it is shown
+and restarts if its frames or speed change.
+
+Responds with [AnimatedImageResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# let sheet = yakui::TextureId::User(0);
+# use yakui::widgets::{AnimatedImage, AnimationFrames};
+# use yakui::Vec2;
+// A spinner with eight frames packed into a 4x2 sprite sheet.
+let frames = AnimationFrames::Sheet {
+    texture: sheet,
+    columns: 4,
+    rows: 2,
+    count: 8,
+};
+
+AnimatedImage::new(frames, 12.0, Vec2::splat(32.0)).show();
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct AnimatedImage {
+    pub frames: AnimationFrames,
+
+    /// How many frames to show per second.
+    pub fps: f32,
+
+    /// If true, the animation starts over after its last frame. Otherwise, it
+    /// stops on the last frame.
+    pub looping: bool,
+
+    pub size: Vec2,
+    pub color: Color,
+    pub fit: ImageFit,
+}
+
+/// Where the frames of an [`AnimatedImage`] come from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimationFrames {
+    /// Frames packed into a grid of equally-sized cells in one texture, read
+    /// from left to right and then top to bottom.
+    Sheet {
+        texture: TextureId,
+        columns: u32,
+        rows: u32,
+
+        /// How many cells of the grid are used, for sheets with empty cells at
+        /// the end.
+        count: u32,
+    },
+
+    /// Each frame is its own texture.
+    Textures(Vec<TextureId>),
+}
+
+impl AnimationFrames {
+    /// The number of frames in the animation.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Sheet {
+                columns,
+                rows,
+                count,
+                ..
+            } => (*count).min(columns.saturating_mul(*rows)) as usize,
+            Self::Textures(textures) => textures.len(),
+        }
+    }
+
+    /// Tells whether the animation has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the texture and region to show for the given frame.
+    fn frame(&self, index: usize) -> Option<(TextureId, Option<ImageRegion>)> {
+        match self {
+            Self::Sheet {
+                texture,
+                columns,
+                rows,
+                ..
+            } => {
+                let cell = Vec2::new(1.0 / *columns as f32, 1.0 / *rows as f32);
+                let column = index as u32 % columns;
+                let row = index as u32 / columns;
+                let pos = Vec2::new(column as f32, row as f32) * cell;
+
+                let region = ImageRegion::Uv(Rect::from_pos_size(pos, cell));
+                Some((*texture, Some(region)))
+            }
+
+            Self::Textures(textures) => textures.get(index).map(|&texture| (texture, None)),
+        }
+    }
+}
+
+impl AnimatedImage {
+    pub fn new(frames: AnimationFrames, fps: f32, size: Vec2) -> Self {
+        Self {
+            frames,
+            fps,
+            looping: true,
+            size,
+            color: Color::WHITE,
+            fit: ImageFit::Stretch,
+        }
+    }
+
+    pub fn show(self) -> Response<AnimatedImageResponse> {
+        widget::<AnimatedImageWidget>(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct AnimatedImageWidget {
+    frames: Option<AnimationFrames>,
+    fps: f32,
+    start: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AnimatedImageResponse {
+    /// The index of the frame being shown.
+    pub frame: usize,
+
+    /// Whether an animation that doesn't loop has reached its last frame.
+    /// Always false for looping animations.
+    pub finished: bool,
+}
+
+impl Widget for AnimatedImageWidget {
+    type Props<'a> = AnimatedImage;
+    type Response = AnimatedImageResponse;
+
+    fn new() -> Self {
+        Self {
+            frames: None,
+            fps: 0.0,
+            start: Duration::ZERO,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        let dom = context::dom();
+        let now = dom.time();
+
+        if self.frames.as_ref() != Some(&props.frames) || self.fps != props.fps {
+            self.frames = Some(props.frames.clone());
+            self.fps = props.fps;
+            self.start = now;
+        }
+
+        let len = props.frames.len();
+        if len == 0 {
+            return AnimatedImageResponse {
+                frame: 0,
+                finished: true,
+            };
+        }
+
+        let elapsed = now.saturating_sub(self.start).as_secs_f64();
+        let fps = f64::from(props.fps.max(0.0));
+        let step = (elapsed * fps) as usize;
+
+        let (frame, finished) = if props.looping {
+            (step % len, false)
+        } else {
+            (step.min(len - 1), step >= len - 1)
+        };
+
+        // Ask for a frame when the animation is due to advance, so hosts that
+        // only build frames when something changes keep it playing.
+        if fps > 0.0 && !finished {
+            if let Ok(offset) = Duration::try_from_secs_f64((step + 1) as f64 / fps) {
+                dom.request_repaint_at(self.start + offset);
+            }
+        }
+
+        if let Some((texture, source)) = props.frames.frame(frame) {
+            let mut image = Image::new(texture, props.size);
+            image.color = props.color;
+            image.fit = props.fit;
+            image.source = source;
+            image.show();
+        }
+
+        AnimatedImageResponse { frame, finished }
+    }
+}
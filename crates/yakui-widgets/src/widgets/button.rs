@@ -1,13 +1,16 @@
 use std::borrow::Cow;
+use std::time::Duration;
 
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
 use yakui_core::geometry::Color;
-use yakui_core::input::MouseButton;
-use yakui_core::widget::{EventContext, Widget};
-use yakui_core::{Alignment, Response};
+use yakui_core::input::{MouseButton, NavAction, TabIndex};
+use yakui_core::widget::{EventContext, PaintContext, Widget};
+use yakui_core::{context, Alignment, Response};
 
-use crate::colors;
+use crate::animation::{Easing, Transition};
+use crate::shapes;
 use crate::style::{TextAlignment, TextStyle};
+use crate::theme::Theme;
 use crate::util::widget;
 use crate::widgets::Pad;
 
@@ -36,8 +39,31 @@ pub struct Button {
     pub style: DynamicButtonStyle,
     pub hover_style: DynamicButtonStyle,
     pub down_style: DynamicButtonStyle,
+
+    /// How long the primary mouse button has to be held down on the button
+    /// before it counts as a long press.
+    pub long_press_time: Duration,
+
+    /// Where the button falls in the order that Tab moves the selection
+    /// through.
+    pub tab_index: TabIndex,
+
+    /// The outline drawn around the button when it's selected with a keyboard
+    /// or gamepad.
+    pub focus_color: Color,
+
+    /// How long the fill takes to blend to its new color when the button
+    /// changes between its normal, hovered and pressed styles. Zero switches
+    /// colors instantly.
+    pub transition_time: Duration,
 }
 
+/// How long a button has to be held by default to count as a long press.
+pub const DEFAULT_LONG_PRESS_TIME: Duration = Duration::from_millis(500);
+
+/// How long styled buttons take to blend between fill colors.
+pub const DEFAULT_TRANSITION_TIME: Duration = Duration::from_millis(100);
+
 /// Contains styles that can vary based on the state of the button.
 #[derive(Debug, Clone)]
 pub struct DynamicButtonStyle {
@@ -67,33 +93,47 @@ impl Button {
             style: DynamicButtonStyle::default(),
             hover_style: DynamicButtonStyle::default(),
             down_style: DynamicButtonStyle::default(),
+            long_press_time: DEFAULT_LONG_PRESS_TIME,
+            tab_index: TabIndex::Auto,
+            focus_color: Color::WHITE,
+            transition_time: Duration::ZERO,
         }
     }
 
+    /// Create a button styled by the current [`Theme`].
     pub fn styled(text: impl Into<Cow<'static, str>>) -> Self {
+        let theme = Theme::current();
+
+        let mut text_style = theme.label();
+        text_style.align = TextAlignment::Center;
+
         let style = DynamicButtonStyle {
-            fill: colors::BACKGROUND_3,
-            ..Default::default()
+            text: text_style,
+            fill: theme.background_3,
         };
 
         let hover_style = DynamicButtonStyle {
-            fill: colors::BACKGROUND_3.adjust(1.2),
-            ..Default::default()
+            fill: theme.background_3.adjust(1.2),
+            ..style.clone()
         };
 
         let down_style = DynamicButtonStyle {
-            fill: colors::BACKGROUND_3.adjust(0.8),
-            ..Default::default()
+            fill: theme.background_3.adjust(0.8),
+            ..style.clone()
         };
 
         Self {
             text: text.into(),
             alignment: Alignment::CENTER,
-            padding: Pad::balanced(20.0, 10.0),
-            border_radius: 6.0,
+            padding: theme.button_padding,
+            border_radius: theme.corner_radius,
             style,
             hover_style,
             down_style,
+            long_press_time: DEFAULT_LONG_PRESS_TIME,
+            tab_index: TabIndex::Auto,
+            focus_color: theme.focus,
+            transition_time: DEFAULT_TRANSITION_TIME,
         }
     }
 
@@ -107,13 +147,42 @@ pub struct ButtonWidget {
     props: Button,
     hovering: bool,
     mouse_down: bool,
+    pressed_at: Option<Duration>,
+    long_press_fired: bool,
+    focus_visible: bool,
     clicked: bool,
+    right_clicked: bool,
+    middle_clicked: bool,
+    double_clicked: bool,
+    fill: Transition<Color>,
 }
 
 #[derive(Debug)]
 pub struct ButtonResponse {
     pub hovering: bool,
+
+    /// The button was clicked with the primary mouse button, or activated
+    /// with [`NavAction::Accept`] while selected.
     pub clicked: bool,
+
+    /// The button was clicked with the secondary mouse button, usually the
+    /// right one.
+    pub right_clicked: bool,
+
+    /// The button was clicked with the middle mouse button.
+    pub middle_clicked: bool,
+
+    /// The button was clicked with the primary mouse button for the second
+    /// time in quick succession. `clicked` is also true when this is.
+    pub double_clicked: bool,
+
+    /// The primary mouse button has been held down on the button for
+    /// [`Button::long_press_time`]. This is only true for the frame the time
+    /// runs out, and releasing the button afterwards isn't a click.
+    ///
+    /// Presses are timed using [`Yakui::set_time`][yakui_core::Yakui::set_time],
+    /// so this is never true if the host doesn't call it.
+    pub long_pressed: bool,
 }
 
 impl Widget for ButtonWidget {
@@ -125,13 +194,40 @@ impl Widget for ButtonWidget {
             props: Button::unstyled(Cow::Borrowed("")),
             hovering: false,
             mouse_down: false,
+            pressed_at: None,
+            long_press_fired: false,
+            focus_visible: false,
             clicked: false,
+            right_clicked: false,
+            middle_clicked: false,
+            double_clicked: false,
+            fill: Transition::new(),
         }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
 
+        // A long press happens once the button has been held long enough, even
+        // if it hasn't been released yet. Moving off of the button cancels it.
+        if !self.hovering {
+            self.pressed_at = None;
+        }
+
+        let mut long_pressed = false;
+        if let Some(pressed_at) = self.pressed_at {
+            let dom = context::dom();
+            let deadline = pressed_at + self.props.long_press_time;
+
+            if dom.time() >= deadline {
+                long_pressed = true;
+                self.long_press_fired = true;
+                self.pressed_at = None;
+            } else {
+                dom.request_repaint_at(deadline);
+            }
+        }
+
         let mut color = self.props.style.fill;
         let mut text_style = self.props.style.text.clone();
 
@@ -145,6 +241,23 @@ impl Widget for ButtonWidget {
             text_style = style.text.clone();
         }
 
+        // Blend toward the new fill instead of snapping to it.
+        let color = {
+            let dom = context::dom();
+            let color = self.fill.update(
+                color,
+                self.props.transition_time,
+                Easing::default(),
+                dom.time(),
+            );
+
+            if self.fill.is_animating() {
+                dom.request_repaint();
+            }
+
+            color
+        };
+
         let align = match text_style.align {
             TextAlignment::Start => Alignment::CENTER_LEFT,
             TextAlignment::Center => Alignment::CENTER,
@@ -161,21 +274,56 @@ impl Widget for ButtonWidget {
             });
         });
 
-        let clicked = self.clicked;
+        let response = Self::Response {
+            hovering: self.hovering,
+            clicked: self.clicked,
+            right_clicked: self.right_clicked,
+            middle_clicked: self.middle_clicked,
+            double_clicked: self.double_clicked,
+            long_pressed,
+        };
+
         self.clicked = false;
+        self.right_clicked = false;
+        self.middle_clicked = false;
+        self.double_clicked = false;
 
-        Self::Response {
-            hovering: self.hovering,
-            clicked,
+        response
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        let PaintContext { dom, layout, paint } = ctx;
+        let rect = layout.get(dom.current()).unwrap().rect;
+
+        self.default_paint(PaintContext {
+            dom,
+            layout,
+            paint: &mut *paint,
+        });
+
+        if self.focus_visible {
+            shapes::selection_halo(paint, rect, self.props.focus_color);
         }
     }
 
     fn event_interest(&self) -> EventInterest {
-        EventInterest::MOUSE_INSIDE | EventInterest::MOUSE_OUTSIDE
+        EventInterest::MOUSE_INSIDE | EventInterest::FOCUS
     }
 
-    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+    fn tab_index(&self) -> TabIndex {
+        self.props.tab_index
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
         match event {
+            WidgetEvent::FocusChanged(focused) => {
+                self.focus_visible = *focused && ctx.input.focus_visible();
+                EventResponse::Sink
+            }
+            WidgetEvent::NavAction(NavAction::Accept) => {
+                self.clicked = true;
+                EventResponse::Sink
+            }
             WidgetEvent::MouseEnter => {
                 self.hovering = true;
                 EventResponse::Sink
@@ -188,24 +336,49 @@ impl Widget for ButtonWidget {
                 button: MouseButton::One,
                 down,
                 inside,
+                pressed_elsewhere,
+                click_count,
                 ..
             } => {
-                if *inside {
-                    if *down {
-                        self.mouse_down = true;
+                if *down {
+                    self.mouse_down = true;
+                    self.pressed_at = Some(ctx.dom.time());
+                    self.long_press_fired = false;
+                    EventResponse::Sink
+                } else if *pressed_elsewhere {
+                    EventResponse::Bubble
+                } else {
+                    self.mouse_down = false;
+                    self.pressed_at = None;
+
+                    if std::mem::take(&mut self.long_press_fired) {
                         EventResponse::Sink
-                    } else if self.mouse_down {
-                        self.mouse_down = false;
+                    } else if *inside {
                         self.clicked = true;
+                        self.double_clicked = *click_count == 2;
                         EventResponse::Sink
                     } else {
                         EventResponse::Bubble
                     }
-                } else {
-                    if !*down {
-                        self.mouse_down = false;
+                }
+            }
+            WidgetEvent::MouseButtonChanged {
+                button: button @ (MouseButton::Two | MouseButton::Three),
+                down,
+                inside,
+                pressed_elsewhere,
+                ..
+            } => {
+                if *down {
+                    EventResponse::Sink
+                } else if *inside && !*pressed_elsewhere {
+                    match button {
+                        MouseButton::Two => self.right_clicked = true,
+                        _ => self.middle_clicked = true,
                     }
 
+                    EventResponse::Sink
+                } else {
                     EventResponse::Bubble
                 }
             }
@@ -1,11 +1,13 @@
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
-use yakui_core::geometry::{Constraints, Vec2};
-use yakui_core::input::MouseButton;
+use yakui_core::geometry::{Color, Constraints, Vec2};
+use yakui_core::input::{MouseButton, NavAction, TabIndex};
 use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
+use crate::shapes;
 use crate::shapes::RoundedRectangle;
-use crate::{colors, shapes};
+use crate::theme::Theme;
+use crate::util::Changed;
 
 const OUTER_SIZE: f32 = 24.0;
 const INNER_SIZE: f32 = 16.0;
@@ -27,11 +29,18 @@ value = yakui::checkbox(value).checked;
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Checkbox {
     pub checked: bool,
+
+    /// Where the checkbox falls in the order that Tab moves the selection
+    /// through.
+    pub tab_index: TabIndex,
 }
 
 impl Checkbox {
     pub fn new(checked: bool) -> Self {
-        Self { checked }
+        Self {
+            checked,
+            tab_index: TabIndex::Auto,
+        }
     }
 
     pub fn show(self) -> Response<CheckboxResponse> {
@@ -43,13 +52,27 @@ impl Checkbox {
 pub struct CheckboxWidget {
     props: Checkbox,
     hovering: bool,
-    mouse_down: bool,
+    focus_visible: bool,
     just_toggled: bool,
+
+    /// The colors of the current theme, read when the checkbox is updated.
+    fill: Color,
+    check_color: Color,
+    focus_color: Color,
 }
 
 #[derive(Debug)]
 pub struct CheckboxResponse {
     pub checked: bool,
+
+    /// Whether the user toggled the checkbox this frame.
+    pub changed: bool,
+}
+
+impl Changed for CheckboxResponse {
+    fn changed(&self) -> bool {
+        self.changed
+    }
 }
 
 impl Widget for CheckboxWidget {
@@ -60,21 +83,29 @@ impl Widget for CheckboxWidget {
         Self {
             props: Checkbox::new(false),
             hovering: false,
-            mouse_down: false,
+            focus_visible: false,
             just_toggled: false,
+            fill: Color::CLEAR,
+            check_color: Color::CLEAR,
+            focus_color: Color::CLEAR,
         }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
 
+        let theme = Theme::current();
+        self.fill = theme.background_3;
+        self.check_color = theme.text;
+        self.focus_color = theme.focus;
+
+        let changed = std::mem::take(&mut self.just_toggled);
         let mut checked = self.props.checked;
-        if self.just_toggled {
+        if changed {
             checked = !checked;
-            self.just_toggled = false;
         }
 
-        CheckboxResponse { checked }
+        CheckboxResponse { checked, changed }
     }
 
     fn paint(&self, ctx: PaintContext<'_>) {
@@ -86,11 +117,15 @@ impl Widget for CheckboxWidget {
         check_rect.set_size(check_rect.size() - padding);
 
         let mut bg = RoundedRectangle::new(layout_node.rect, 6.0);
-        bg.color = colors::BACKGROUND_3;
+        bg.color = self.fill;
         bg.add(ctx.paint);
 
         if self.props.checked {
-            shapes::cross(ctx.paint, check_rect, colors::TEXT);
+            shapes::cross(ctx.paint, check_rect, self.check_color);
+        }
+
+        if self.focus_visible {
+            shapes::selection_halo(ctx.paint, layout_node.rect, self.focus_color);
         }
     }
 
@@ -99,11 +134,23 @@ impl Widget for CheckboxWidget {
     }
 
     fn event_interest(&self) -> EventInterest {
-        EventInterest::MOUSE_INSIDE | EventInterest::MOUSE_OUTSIDE
+        EventInterest::MOUSE_INSIDE | EventInterest::FOCUS
+    }
+
+    fn tab_index(&self) -> TabIndex {
+        self.props.tab_index
     }
 
-    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
         match event {
+            WidgetEvent::FocusChanged(focused) => {
+                self.focus_visible = *focused && ctx.input.focus_visible();
+                EventResponse::Sink
+            }
+            WidgetEvent::NavAction(NavAction::Accept) => {
+                self.just_toggled = true;
+                EventResponse::Sink
+            }
             WidgetEvent::MouseEnter => {
                 self.hovering = true;
                 EventResponse::Sink
@@ -116,21 +163,15 @@ impl Widget for CheckboxWidget {
                 button: MouseButton::One,
                 down,
                 inside,
+                pressed_elsewhere,
                 ..
             } => {
-                if *inside {
-                    if *down {
-                        self.mouse_down = true;
-                        EventResponse::Sink
-                    } else if self.mouse_down {
-                        self.mouse_down = false;
-                        self.just_toggled = true;
-                        EventResponse::Sink
-                    } else {
-                        EventResponse::Bubble
-                    }
+                if *down {
+                    EventResponse::Sink
+                } else if *inside && !*pressed_elsewhere {
+                    self.just_toggled = true;
+                    EventResponse::Sink
                 } else {
-                    self.mouse_down = false;
                     EventResponse::Bubble
                 }
             }
@@ -1,5 +1,5 @@
 use yakui_core::geometry::{Color, Constraints, Vec2};
-use yakui_core::paint::PaintRect;
+use yakui_core::paint::{Gradient, PaintRect};
 use yakui_core::widget::{LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
@@ -15,6 +15,9 @@ Responds with [ColoredBoxResponse].
 pub struct ColoredBox {
     pub color: Color,
     pub min_size: Vec2,
+
+    /// If set, the box is filled with this gradient instead of `color`.
+    pub gradient: Option<Gradient>,
 }
 
 impl ColoredBox {
@@ -22,6 +25,7 @@ impl ColoredBox {
         Self {
             color: Color::WHITE,
             min_size: Vec2::ZERO,
+            gradient: None,
         }
     }
 
@@ -29,6 +33,7 @@ impl ColoredBox {
         Self {
             color,
             min_size: size,
+            gradient: None,
         }
     }
 
@@ -36,6 +41,7 @@ impl ColoredBox {
         Self {
             color,
             min_size: Vec2::ZERO,
+            gradient: None,
         }
     }
 
@@ -51,6 +57,7 @@ impl ColoredBox {
 #[derive(Debug)]
 pub struct ColoredBoxWidget {
     props: ColoredBox,
+    layout_changed: bool,
 }
 
 pub type ColoredBoxResponse = ();
@@ -62,13 +69,20 @@ impl Widget for ColoredBoxWidget {
     fn new() -> Self {
         Self {
             props: ColoredBox::empty(),
+            layout_changed: true,
         }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        // Only the size of the box affects its layout.
+        self.layout_changed = props.min_size != self.props.min_size;
         self.props = props;
     }
 
+    fn needs_layout(&self) -> bool {
+        self.layout_changed
+    }
+
     fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
         let node = ctx.dom.get_current();
         let mut size = self.props.min_size;
@@ -87,6 +101,7 @@ impl Widget for ColoredBoxWidget {
 
         let mut rect = PaintRect::new(layout_node.rect);
         rect.color = self.props.color;
+        rect.gradient = self.props.gradient.clone();
         rect.add(ctx.paint);
 
         for &child in &node.children {
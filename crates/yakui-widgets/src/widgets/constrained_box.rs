@@ -9,7 +9,7 @@ A box that forces specific constraints onto its child.
 
 Responds with [ConstrainedBoxResponse].
 */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct ConstrainedBox {
     pub constraints: Constraints,
@@ -28,6 +28,7 @@ impl ConstrainedBox {
 #[derive(Debug)]
 pub struct ConstrainedBoxWidget {
     props: ConstrainedBox,
+    layout_changed: bool,
 }
 
 pub type ConstrainedBoxResponse = ();
@@ -42,13 +43,19 @@ impl Widget for ConstrainedBoxWidget {
                 min: Vec2::ZERO,
                 max: Vec2::ZERO,
             }),
+            layout_changed: true,
         }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.layout_changed = props != self.props;
         self.props = props;
     }
 
+    fn needs_layout(&self) -> bool {
+        self.layout_changed
+    }
+
     fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
         let node = ctx.dom.get_current();
         let mut size = Vec2::ZERO;
@@ -0,0 +1,90 @@
+use std::fmt::Debug;
+
+use yakui_core::context;
+use yakui_core::widget::Widget;
+use yakui_core::Response;
+
+use crate::util::widget_children;
+
+/**
+Makes a value available to every widget built inside of it, like a
+localization table or settings for one panel.
+
+Descendants read the value with [`ContextProvider::current`] or the
+[`use_context`][crate::use_context] shorthand. If providers of the same type
+are nested, the innermost one wins. Values are only visible while the UI is
+being built.
+
+Responds with [ContextProviderResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+#[derive(Debug, Clone, PartialEq)]
+struct Language(&'static str);
+
+yakui::provide_context(Language("en"), || {
+    assert_eq!(yakui::use_context::<Language>(), Some(Language("en")));
+
+    yakui::provide_context(Language("fr"), || {
+        assert_eq!(yakui::use_context::<Language>(), Some(Language("fr")));
+    });
+});
+
+assert_eq!(yakui::use_context::<Language>(), None);
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct ContextProvider<T> {
+    pub value: T,
+}
+
+impl<T: Debug + Clone + 'static> ContextProvider<T> {
+    pub fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<ContextProviderResponse> {
+        widget_children::<ContextProviderWidget<T>, F>(children, self)
+    }
+
+    /// Returns the value from the nearest provider of this type that the
+    /// widget currently being built is inside of, if there is one.
+    pub fn current() -> Option<T> {
+        let dom = context::try_dom()?;
+
+        let mut next = Some(dom.current());
+        while let Some(id) = next {
+            let node = dom.get(id)?;
+
+            if let Some(provider) = node.widget.downcast_ref::<ContextProviderWidget<T>>() {
+                return provider.value.clone();
+            }
+
+            next = node.parent;
+        }
+
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct ContextProviderWidget<T> {
+    value: Option<T>,
+}
+
+pub type ContextProviderResponse = ();
+
+impl<T: Debug + Clone + 'static> Widget for ContextProviderWidget<T> {
+    type Props<'a> = ContextProvider<T>;
+    type Response = ContextProviderResponse;
+
+    fn new() -> Self {
+        Self { value: None }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.value = Some(props.value);
+    }
+}
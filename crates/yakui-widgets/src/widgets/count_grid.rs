@@ -227,13 +227,13 @@ impl Widget for CountGridWidget {
 
         // only used in case the widget total cross is less than the minimum cross axis
         let offset_cross_global = match self.props.cross_axis_alignment {
-            CrossAxisAlignment::Start | CrossAxisAlignment::Stretch => 0.0,
             CrossAxisAlignment::Center => {
                 ((direction.get_cross_axis(input.min) - max_total_cross_size) / 2.0).max(0.0)
             }
             CrossAxisAlignment::End => {
                 (direction.get_cross_axis(input.min) - max_total_cross_size).max(0.0)
             }
+            _ => 0.0,
         };
 
         // Apply alignment by offsetting all children
@@ -249,9 +249,9 @@ impl Widget for CountGridWidget {
                 _ => max_sizes[cross_id],
             };
             let offset_cross = match self.props.cross_axis_alignment {
-                CrossAxisAlignment::Start | CrossAxisAlignment::Stretch => 0.0,
                 CrossAxisAlignment::Center => ((cell_cross_size - child_cross_size) / 2.0).max(0.0),
                 CrossAxisAlignment::End => (cell_cross_size - child_cross_size).max(0.0),
+                _ => 0.0,
             };
 
             let child_main_size = direction.get_main_axis(layout.rect.size());
@@ -0,0 +1,148 @@
+use std::cell::RefCell;
+
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{EventContext, LayoutContext, Widget};
+use yakui_core::{context, Response, UiActivity, WidgetId};
+
+use crate::util::widget_children;
+
+/**
+Tells when the user clicks anywhere outside of its children, which is how
+popups, dropdown menus, and context menus are usually closed.
+
+Clicks count as outside if the press lands outside of every descendant,
+including ones that were moved out of this widget's rect by widgets like
+[`Reflow`][super::Reflow]. Descendants that paint outside of their own rect,
+like [`Layer`][super::Layer] and [`Reflow`][super::Reflow], are only used to
+position other widgets, so their rects are ignored.
+
+The response is set after the button is released, so a button that toggles the
+popup sees its click in the same frame that the popup is dismissed, instead of
+reopening it right away.
+
+If the widget with keyboard focus is inside of this one when it's dismissed,
+focus is cleared. Focus that moved to another widget with the same click is
+left alone.
+
+While shown, this widget reports [`UiActivity::POPUP`] to the host.
+
+Responds with [DismissOnOutsideClickResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# let mut open = true;
+if open {
+    let response = yakui::dismiss_on_outside_click(|| {
+        yakui::label("Click anywhere else to close me");
+    });
+
+    if response.dismissed {
+        open = false;
+    }
+}
+```
+*/
+#[derive(Debug, Clone, Default)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct DismissOnOutsideClick {}
+
+impl DismissOnOutsideClick {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<DismissOnOutsideClickResponse> {
+        widget_children::<DismissOnOutsideClickWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct DismissOnOutsideClickWidget {
+    /// All of this widget's descendants as of the last layout, and whether
+    /// clicks inside of each one are inside of the popup. The DOM can't be
+    /// read while handling events, so this is collected ahead of time.
+    descendants: RefCell<Vec<(WidgetId, bool)>>,
+    pressed_outside: bool,
+    dismissed: bool,
+}
+
+#[derive(Debug)]
+pub struct DismissOnOutsideClickResponse {
+    /// Whether the user clicked outside of this widget since the last frame.
+    pub dismissed: bool,
+}
+
+impl Widget for DismissOnOutsideClickWidget {
+    type Props<'a> = DismissOnOutsideClick;
+    type Response = DismissOnOutsideClickResponse;
+
+    fn new() -> Self {
+        Self {
+            descendants: RefCell::new(Vec::new()),
+            pressed_outside: false,
+            dismissed: false,
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        context::dom().report_activity(UiActivity::POPUP);
+
+        Self::Response {
+            dismissed: std::mem::take(&mut self.dismissed),
+        }
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let dom = ctx.dom;
+        let size = self.default_layout(ctx, constraints);
+
+        let mut descendants = self.descendants.borrow_mut();
+        descendants.clear();
+
+        let mut queue = dom.get_current().children.to_vec();
+        while let Some(id) = queue.pop() {
+            if let Some(node) = dom.get(id) {
+                descendants.push((id, !node.widget.paints_outside_rect()));
+                queue.extend_from_slice(&node.children);
+            }
+        }
+
+        size
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE | EventInterest::MOUSE_OUTSIDE
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        if let WidgetEvent::MouseButtonChanged { down, position, .. } = *event {
+            let descendants = self.descendants.borrow();
+
+            if down {
+                let inside = descendants.iter().any(|&(id, is_area)| {
+                    let Some(node) = ctx.layout.get(id).filter(|_| is_area) else {
+                        return false;
+                    };
+
+                    let local = ctx.layout.untransform_point(id, position);
+                    node.hit_rect().contains_point(local)
+                });
+
+                self.pressed_outside = !inside;
+            } else if self.pressed_outside {
+                self.pressed_outside = false;
+                self.dismissed = true;
+
+                if let Some(selection) = ctx.input.selection() {
+                    if descendants.iter().any(|&(id, _)| id == selection) {
+                        ctx.input.set_selection(None);
+                    }
+                }
+            }
+        }
+
+        EventResponse::Bubble
+    }
+}
@@ -0,0 +1,264 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use yakui_core::dom::Dom;
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Affine2, Vec2};
+use yakui_core::input::{CursorIcon, MouseButton};
+use yakui_core::widget::{EventContext, PaintContext, Widget};
+use yakui_core::{Response, WidgetId};
+
+use crate::util::widget_children;
+
+/**
+Lets its children be dragged onto a [DropTarget][super::DropTarget], carrying a
+payload with them.
+
+While a drag is in progress, the children are painted under the cursor above
+everything else. Drop targets only accept payloads of the type they were
+created for, so unrelated drags can't be dropped onto them.
+
+Responds with [DragSourceResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+#[derive(Debug, Clone)]
+struct Item(u32);
+
+yakui::row(|| {
+    yakui::drag_source(Item(7), || {
+        yakui::text(16.0, "Sword");
+    });
+
+    let slot = yakui::drop_target::<Item>(|| {
+        yakui::text(16.0, "Empty slot");
+    });
+
+    if let Some(Item(id)) = slot.dropped {
+        println!("Item {id} was put in the slot");
+    }
+});
+```
+*/
+#[derive(Debug)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct DragSource<T> {
+    pub payload: T,
+}
+
+/// How far the mouse has to move while pressed before a drag starts, in
+/// logical pixels. This keeps clicks from turning into tiny drags.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+impl<T: Clone + fmt::Debug + 'static> DragSource<T> {
+    pub fn new(payload: T) -> Self {
+        Self { payload }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<DragSourceResponse> {
+        widget_children::<DragSourceWidget<T>, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct DragSourceWidget<T> {
+    payload: Option<T>,
+    pressed_at: Option<Vec2>,
+    mouse_position: Vec2,
+    dragging: bool,
+    dropped: bool,
+}
+
+#[derive(Debug)]
+pub struct DragSourceResponse {
+    /// Whether the children are being dragged.
+    pub dragging: bool,
+
+    /// Whether the payload was dropped onto a drop target that accepted it.
+    /// This is only true for the frame the drop happened.
+    pub dropped: bool,
+}
+
+impl<T: Clone + fmt::Debug + 'static> Widget for DragSourceWidget<T> {
+    type Props<'a> = DragSource<T>;
+    type Response = DragSourceResponse;
+
+    fn new() -> Self {
+        Self {
+            payload: None,
+            pressed_at: None,
+            mouse_position: Vec2::ZERO,
+            dragging: false,
+            dropped: false,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.payload = Some(props.payload);
+
+        DragSourceResponse {
+            dragging: self.dragging,
+            dropped: std::mem::take(&mut self.dropped),
+        }
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let Some(pressed_at) = self.pressed_at.filter(|_| self.dragging) else {
+            self.default_paint(ctx);
+            return;
+        };
+
+        // The preview is drawn on an overlay so that it shows up above
+        // everything else and isn't clipped by the containers it came from.
+        let offset = self.mouse_position - pressed_at;
+        ctx.paint.begin_overlay();
+        ctx.paint.push_transform(Affine2::from_translation(offset));
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+
+        ctx.paint.pop_transform();
+        ctx.paint.end_overlay();
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE | EventInterest::MOUSE_MOVE
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match *event {
+            WidgetEvent::MouseEnter => {
+                ctx.set_cursor_icon(CursorIcon::Grab);
+                EventResponse::Bubble
+            }
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: true,
+                inside: true,
+                position,
+                ..
+            } => {
+                self.pressed_at = Some(position);
+                self.mouse_position = position;
+                EventResponse::Sink
+            }
+            WidgetEvent::MouseMoved(Some(position)) => {
+                self.mouse_position = position;
+
+                let Some(pressed_at) = self.pressed_at else {
+                    return EventResponse::Bubble;
+                };
+
+                if !self.dragging && pressed_at.distance(position) > DRAG_THRESHOLD {
+                    if let Some(payload) = &self.payload {
+                        self.dragging = true;
+                        ctx.set_cursor_icon(CursorIcon::Grabbing);
+                        DragAndDrop::get(ctx.dom).start(ctx.dom.current(), payload.clone());
+                    }
+                }
+
+                EventResponse::Bubble
+            }
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: false,
+                ..
+            } if self.pressed_at.is_some() => {
+                self.pressed_at = None;
+
+                if std::mem::take(&mut self.dragging) {
+                    ctx.set_cursor_icon(CursorIcon::Grab);
+                    self.dropped = DragAndDrop::get(ctx.dom).finish(ctx.dom.current());
+                }
+
+                EventResponse::Sink
+            }
+            _ => EventResponse::Bubble,
+        }
+    }
+}
+
+/// The drag in progress, shared between drag sources and drop targets through
+/// a DOM global.
+#[derive(Clone, Default)]
+pub(super) struct DragAndDrop {
+    active: Rc<RefCell<Option<ActiveDrag>>>,
+}
+
+struct ActiveDrag {
+    source: WidgetId,
+    payload: Rc<dyn Any>,
+    accepted: bool,
+}
+
+impl DragAndDrop {
+    pub fn get(dom: &Dom) -> Self {
+        dom.get_global_or_init(Self::default)
+    }
+
+    fn start<T: 'static>(&self, source: WidgetId, payload: T) {
+        *self.active.borrow_mut() = Some(ActiveDrag {
+            source,
+            payload: Rc::new(payload),
+            accepted: false,
+        });
+    }
+
+    /// Ends the drag started by `source`, returning whether it was accepted by
+    /// a drop target.
+    fn finish(&self, source: WidgetId) -> bool {
+        let mut active = self.active.borrow_mut();
+
+        match active.take() {
+            Some(drag) if drag.source == source => drag.accepted,
+            other => {
+                *active = other;
+                false
+            }
+        }
+    }
+
+    /// Returns the payload being dragged, if there is one of the given type.
+    ///
+    /// This looks up the drag source in the DOM, so it can only be called
+    /// while updating widgets.
+    pub fn payload<T: Clone + 'static>(&self, dom: &Dom) -> Option<T> {
+        // A drag source that was removed mid-drag can't finish its drag.
+        let mut active = self.active.borrow_mut();
+        if active
+            .as_ref()
+            .is_some_and(|drag| dom.get(drag.source).is_none())
+        {
+            *active = None;
+        }
+        drop(active);
+
+        self.peek()
+    }
+
+    /// Takes the payload being dragged if it's of the given type, marking the
+    /// drag as accepted so no other target can take it too.
+    ///
+    /// This is called while handling events, when the DOM's nodes are already
+    /// borrowed, so it doesn't look at them.
+    pub fn accept<T: Clone + 'static>(&self) -> Option<T> {
+        let payload = self.peek::<T>()?;
+
+        if let Some(drag) = self.active.borrow_mut().as_mut() {
+            drag.accepted = true;
+        }
+
+        Some(payload)
+    }
+
+    fn peek<T: Clone + 'static>(&self) -> Option<T> {
+        let active = self.active.borrow();
+        let drag = active.as_ref().filter(|drag| !drag.accepted)?;
+        drag.payload.downcast_ref::<T>().cloned()
+    }
+}
@@ -1,18 +1,34 @@
+use std::time::Duration;
+
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
 use yakui_core::geometry::Vec2;
-use yakui_core::input::MouseButton;
+use yakui_core::input::{CursorIcon, MouseButton};
 use yakui_core::widget::{EventContext, Widget};
-use yakui_core::Response;
+use yakui_core::{context, Response};
 
 use crate::util::widget_children;
 
 #[derive(Debug)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
-pub struct Draggable {}
+pub struct Draggable {
+    /// How long the mouse button has to be held down before dragging starts,
+    /// which lets touch UIs tell drags apart from scrolling. If the pointer
+    /// moves too far before then, the drag is canceled.
+    ///
+    /// Presses are timed using [`Yakui::set_time`][yakui_core::Yakui::set_time],
+    /// so this should be left at zero if the host doesn't call it.
+    pub hold_time: Duration,
+}
+
+/// How far the pointer can move while waiting for [`Draggable::hold_time`]
+/// before the drag is canceled, in logical pixels.
+const HOLD_TOLERANCE: f32 = 8.0;
 
 impl Draggable {
     pub fn new() -> Self {
-        Draggable {}
+        Draggable {
+            hold_time: Duration::ZERO,
+        }
     }
 
     pub fn show<F: FnOnce()>(self, children: F) -> Response<DraggableResponse> {
@@ -22,7 +38,12 @@ impl Draggable {
 
 #[derive(Debug)]
 pub struct DraggableWidget {
+    props: Draggable,
     current_drag: Option<DragState>,
+
+    /// A drag that will start once the button has been held long enough,
+    /// along with when the button was pressed.
+    pending_drag: Option<(DragState, Duration)>,
 }
 
 #[derive(Debug)]
@@ -48,10 +69,27 @@ impl Widget for DraggableWidget {
     type Response = DraggableResponse;
 
     fn new() -> Self {
-        Self { current_drag: None }
+        Self {
+            props: Draggable::new(),
+            current_drag: None,
+            pending_drag: None,
+        }
     }
 
-    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+
+        if let Some((_, pressed_at)) = &self.pending_drag {
+            let dom = context::dom();
+            let deadline = *pressed_at + self.props.hold_time;
+
+            if dom.time() >= deadline {
+                self.current_drag = self.pending_drag.take().map(|(drag, _)| drag);
+            } else {
+                dom.request_repaint_at(deadline);
+            }
+        }
+
         let dragging = self.current_drag.as_ref().map(|drag| Dragging {
             start: drag.start_position,
             current: drag.mouse_position + drag.offset_from_mouse,
@@ -66,6 +104,10 @@ impl Widget for DraggableWidget {
 
     fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
         match *event {
+            WidgetEvent::MouseEnter => {
+                ctx.set_cursor_icon(CursorIcon::Grab);
+                EventResponse::Bubble
+            }
             WidgetEvent::MouseButtonChanged {
                 button: MouseButton::One,
                 down,
@@ -76,15 +118,25 @@ impl Widget for DraggableWidget {
                 if down && inside {
                     let node = ctx.layout.get(ctx.dom.current()).unwrap();
 
-                    self.current_drag = Some(DragState {
+                    let drag = DragState {
                         start_position: node.rect.pos(),
                         offset_from_mouse: node.rect.pos() - position,
                         mouse_position: position,
-                    });
+                    };
+
+                    ctx.set_cursor_icon(CursorIcon::Grabbing);
+
+                    if self.props.hold_time.is_zero() {
+                        self.current_drag = Some(drag);
+                    } else {
+                        self.pending_drag = Some((drag, ctx.dom.time()));
+                    }
 
                     EventResponse::Sink
-                } else if !down && self.current_drag.is_some() {
+                } else if !down && (self.current_drag.is_some() || self.pending_drag.is_some()) {
+                    ctx.set_cursor_icon(CursorIcon::Grab);
                     self.current_drag = None;
+                    self.pending_drag = None;
                     EventResponse::Sink
                 } else {
                     EventResponse::Bubble
@@ -95,6 +147,15 @@ impl Widget for DraggableWidget {
                     drag.mouse_position = position;
                 }
 
+                if let Some((drag, _)) = &mut self.pending_drag {
+                    let start = drag.start_position - drag.offset_from_mouse;
+                    if start.distance(position) > HOLD_TOLERANCE {
+                        self.pending_drag = None;
+                    } else {
+                        drag.mouse_position = position;
+                    }
+                }
+
                 EventResponse::Bubble
             }
             _ => EventResponse::Bubble,
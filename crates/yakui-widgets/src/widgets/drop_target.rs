@@ -0,0 +1,128 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Rect, Vec2};
+use yakui_core::input::MouseButton;
+use yakui_core::widget::{EventContext, Widget};
+use yakui_core::{context, Response};
+
+use crate::util::widget_children;
+
+use super::drag_source::DragAndDrop;
+
+/**
+Accepts payloads of type `T` dragged from a [DragSource][super::DragSource]
+and dropped onto its children.
+
+Responds with [DropTargetResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+let target = yakui::drop_target::<String>(|| {
+    yakui::text(16.0, "Drop files here");
+});
+
+if let Some(name) = &target.hovering {
+    println!("{name} is being dragged over the target");
+}
+```
+*/
+#[derive(Debug)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct DropTarget<T> {
+    _payload: PhantomData<T>,
+}
+
+impl<T: Clone + fmt::Debug + 'static> DropTarget<T> {
+    pub fn new() -> Self {
+        Self {
+            _payload: PhantomData,
+        }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<DropTargetResponse<T>> {
+        widget_children::<DropTargetWidget<T>, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct DropTargetWidget<T> {
+    hovering: bool,
+    dropped: Option<T>,
+}
+
+#[derive(Debug)]
+pub struct DropTargetResponse<T> {
+    /// The payload being dragged over the target, if there is one that it
+    /// would accept.
+    pub hovering: Option<T>,
+
+    /// The payload that was dropped onto the target. This is only set for the
+    /// frame the drop happened.
+    pub dropped: Option<T>,
+}
+
+impl<T: Clone + fmt::Debug + 'static> Widget for DropTargetWidget<T> {
+    type Props<'a> = DropTarget<T>;
+    type Response = DropTargetResponse<T>;
+
+    fn new() -> Self {
+        Self {
+            hovering: false,
+            dropped: None,
+        }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        let dom = context::dom();
+        let hovering = if self.hovering {
+            DragAndDrop::get(&dom).payload::<T>(&dom)
+        } else {
+            None
+        };
+
+        DropTargetResponse {
+            hovering,
+            dropped: self.dropped.take(),
+        }
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_INSIDE | EventInterest::MOUSE_MOVE
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match *event {
+            // Hovering is tracked from every mouse movement, since children
+            // like buttons keep mouse enter events from reaching the target.
+            WidgetEvent::MouseMoved(position) => {
+                let size = ctx
+                    .layout
+                    .get(ctx.dom.current())
+                    .map(|node| node.rect.size())
+                    .unwrap_or(Vec2::ZERO);
+                let bounds = Rect::from_pos_size(Vec2::ZERO, size);
+
+                self.hovering =
+                    position.is_some_and(|position| bounds.contains_point(ctx.to_local(position)));
+
+                EventResponse::Bubble
+            }
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: false,
+                inside: true,
+                ..
+            } => match DragAndDrop::get(ctx.dom).accept::<T>() {
+                Some(payload) => {
+                    self.dropped = Some(payload);
+                    EventResponse::Sink
+                }
+                None => EventResponse::Bubble,
+            },
+            _ => EventResponse::Bubble,
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::borrow::Cow;
+
+use yakui_core::geometry::Dim2;
+use yakui_core::input::Shortcut;
+use yakui_core::widget::Widget;
+use yakui_core::{context, Alignment, CrossAxisAlignment, Pivot, Response};
+
+use crate::colors;
+use crate::style::TextAlignment;
+use crate::util::widget;
+
+use super::{
+    Button, ColoredBox, DismissOnOutsideClick, FocusScope, KeyHint, Layer, List, Pad, Reflow,
+    Stack, StackItem,
+};
+
+/**
+A button that opens a menu of items when clicked.
+
+Responds with [DropdownButtonResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+let response = yakui::dropdown_button("File", ["New", "Open", "Save"]);
+
+if let Some(index) = response.selected {
+    println!("Item {index} was picked");
+}
+```
+
+Items can have a shortcut, which is shown next to them and picks them even
+while the menu is closed:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::input::Shortcut;
+use yakui::widgets::MenuItem;
+
+let items = [
+    MenuItem::new("New").shortcut(Shortcut::primary("n")),
+    MenuItem::new("Open").shortcut(Shortcut::primary("o")),
+];
+
+let response = yakui::dropdown_button("File", items);
+```
+*/
+#[derive(Debug)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct DropdownButton {
+    pub text: Cow<'static, str>,
+    pub items: Vec<MenuItem>,
+}
+
+impl DropdownButton {
+    pub fn new<S, I, T>(text: S, items: I) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+        I: IntoIterator<Item = T>,
+        T: Into<MenuItem>,
+    {
+        Self {
+            text: text.into(),
+            items: items.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn show(self) -> Response<DropdownButtonResponse> {
+        widget::<DropdownButtonWidget>(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct DropdownButtonWidget {
+    open: bool,
+}
+
+#[derive(Debug)]
+pub struct DropdownButtonResponse {
+    /// The index of the item that was picked from the menu this frame, if any.
+    pub selected: Option<usize>,
+
+    /// Whether the menu is currently open.
+    pub open: bool,
+}
+
+impl Widget for DropdownButtonWidget {
+    type Props<'a> = DropdownButton;
+    type Response = DropdownButtonResponse;
+
+    fn new() -> Self {
+        Self { open: false }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        if Button::styled(format!("{} \u{25BE}", props.text))
+            .show()
+            .clicked
+        {
+            self.open = !self.open;
+        }
+
+        let mut selected = menu_shortcuts(&props.items);
+        if self.open {
+            let (picked, dismissed) = show_menu(&props.items);
+            selected = selected.or(picked);
+
+            if selected.is_some() || dismissed {
+                self.open = false;
+            }
+        }
+
+        Self::Response {
+            selected,
+            open: self.open,
+        }
+    }
+}
+
+/// An item in a menu, like the ones opened by [`DropdownButton`] and
+/// [`SplitButton`][super::SplitButton].
+#[derive(Debug, Clone)]
+pub struct MenuItem {
+    pub text: Cow<'static, str>,
+
+    /// A shortcut that picks this item, which is shown next to it in the menu.
+    /// It works whether or not the menu is open.
+    pub shortcut: Option<Shortcut>,
+}
+
+impl MenuItem {
+    pub fn new<S: Into<Cow<'static, str>>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            shortcut: None,
+        }
+    }
+
+    pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+}
+
+impl From<&'static str> for MenuItem {
+    fn from(text: &'static str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for MenuItem {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<Cow<'static, str>> for MenuItem {
+    fn from(text: Cow<'static, str>) -> Self {
+        Self::new(text)
+    }
+}
+
+/// Registers the shortcuts of each item in a menu, returning the index of the
+/// first item whose shortcut was pressed, if any.
+pub(super) fn menu_shortcuts(items: &[MenuItem]) -> Option<usize> {
+    let dom = context::dom();
+    let mut selected = None;
+
+    for (index, item) in items.iter().enumerate() {
+        if let Some(shortcut) = &item.shortcut {
+            if dom.shortcut(shortcut) && selected.is_none() {
+                selected = Some(index);
+            }
+        }
+    }
+
+    selected
+}
+
+/// Shows a menu of items floating below the widget that calls it, returning
+/// the index of the item that was clicked, if any, and whether the user
+/// clicked somewhere outside of the menu.
+pub(super) fn show_menu(items: &[MenuItem]) -> (Option<usize>, bool) {
+    let mut selected = None;
+
+    let dismiss = DismissOnOutsideClick::new().show(|| {
+        // Navigation stays inside of the menu while it's open, and goes back to
+        // the button that opened it once it closes.
+        FocusScope::new().show(|| {
+            Layer::new().show(|| {
+                Reflow::new(Alignment::BOTTOM_LEFT, Pivot::TOP_LEFT, Dim2::ZERO).show(|| {
+                    ColoredBox::container(colors::BACKGROUND_2).show_children(|| {
+                        let mut list = List::column();
+                        list.cross_axis_alignment = CrossAxisAlignment::Stretch;
+                        list.show(|| {
+                            for (index, item) in items.iter().enumerate() {
+                                let mut button = Button::styled(item.text.clone());
+                                button.border_radius = 0.0;
+                                button.style.fill = colors::BACKGROUND_2;
+                                button.style.text.align = TextAlignment::Start;
+                                button.hover_style.text.align = TextAlignment::Start;
+                                button.down_style.text.align = TextAlignment::Start;
+
+                                // Leave room at the end of the button for the
+                                // shortcut's key caps, which are drawn over it.
+                                if let Some(shortcut) = &item.shortcut {
+                                    let keys = shortcut.key_labels();
+                                    let chars: usize =
+                                        keys.iter().map(|key| key.chars().count()).sum();
+                                    button.padding.right +=
+                                        chars as f32 * 8.0 + keys.len() as f32 * 20.0;
+                                }
+
+                                Stack::new().show(|| {
+                                    if button.show().clicked {
+                                        selected = Some(index);
+                                    }
+
+                                    if let Some(shortcut) = &item.shortcut {
+                                        StackItem::new(Alignment::CENTER_RIGHT).show(|| {
+                                            Pad::horizontal(8.0).show(|| {
+                                                KeyHint::new(shortcut.clone()).show();
+                                            });
+                                        });
+                                    }
+                                });
+                            }
+                        });
+                    });
+                });
+            });
+        });
+    });
+
+    (selected, dismiss.dismissed)
+}
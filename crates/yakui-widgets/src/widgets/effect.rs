@@ -0,0 +1,135 @@
+use std::any::Any;
+use std::fmt;
+
+use yakui_core::widget::Widget;
+use yakui_core::Response;
+
+use crate::util;
+
+/**
+Runs a side effect the first time it's shown and again whenever its
+dependencies change, like loading a texture when a path changes.
+
+Whatever the effect returns is kept until the effect runs again or this widget
+is removed from the tree, and is dropped then. Return a [`Cleanup`] or any other
+value with a [`Drop`] implementation to undo the effect. Cleanup happens after
+the frame is built, so it can't create widgets.
+
+Responds with [EffectResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Cleanup;
+
+let path = "assets/logo.png";
+
+yakui::use_effect(path, |path| {
+    println!("loading {path}");
+    Cleanup::new(|| println!("unloading"))
+});
+```
+*/
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Effect<D> {
+    deps: D,
+    effect: EffectFn<D>,
+}
+
+/// Runs an effect, boxing up whatever it returns so that it can be dropped
+/// when the effect runs again.
+type EffectFn<D> = Box<dyn FnOnce(&D) -> Box<dyn Any>>;
+
+impl<D: PartialEq + fmt::Debug + 'static> Effect<D> {
+    pub fn new<F, C>(deps: D, effect: F) -> Self
+    where
+        F: FnOnce(&D) -> C + 'static,
+        C: 'static,
+    {
+        Self {
+            deps,
+            effect: Box::new(move |deps| Box::new(effect(deps))),
+        }
+    }
+
+    pub fn show(self) -> Response<EffectResponse> {
+        util::widget::<EffectWidget<D>>(self)
+    }
+}
+
+impl<D: fmt::Debug> fmt::Debug for Effect<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Effect").field("deps", &self.deps).finish()
+    }
+}
+
+/// Runs a closure when dropped. Return one from an [`Effect`] to clean up after
+/// it.
+#[must_use = "the closure runs as soon as a Cleanup is dropped"]
+pub struct Cleanup {
+    cleanup: Option<Box<dyn FnOnce()>>,
+}
+
+impl Cleanup {
+    pub fn new<F: FnOnce() + 'static>(cleanup: F) -> Self {
+        Self {
+            cleanup: Some(Box::new(cleanup)),
+        }
+    }
+}
+
+impl Drop for Cleanup {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
+}
+
+impl fmt::Debug for Cleanup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("Cleanup")
+    }
+}
+
+pub struct EffectWidget<D> {
+    deps: Option<D>,
+    output: Option<Box<dyn Any>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EffectResponse {
+    /// Whether the effect ran during this update.
+    pub ran: bool,
+}
+
+impl<D: PartialEq + fmt::Debug + 'static> Widget for EffectWidget<D> {
+    type Props<'a> = Effect<D>;
+    type Response = EffectResponse;
+
+    fn new() -> Self {
+        Self {
+            deps: None,
+            output: None,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        if self.deps.as_ref() == Some(&props.deps) {
+            return EffectResponse { ran: false };
+        }
+
+        // The previous run is cleaned up before the effect runs again.
+        self.output = None;
+        self.output = Some((props.effect)(&props.deps));
+        self.deps = Some(props.deps);
+
+        EffectResponse { ran: true }
+    }
+}
+
+impl<D> fmt::Debug for EffectWidget<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("EffectWidget")
+    }
+}
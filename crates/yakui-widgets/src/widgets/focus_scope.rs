@@ -0,0 +1,69 @@
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::Response;
+
+use crate::util::widget_children;
+
+/**
+Keeps keyboard and gamepad navigation inside of its children while it's shown,
+for things like modals and menus.
+
+Tab and directional navigation only select widgets inside of the scope. When
+the scope is removed, the widget that was selected before it was shown is
+selected again. See [`InputState::push_focus_scope`][yakui_core::input::InputState::push_focus_scope].
+
+Responds with [FocusScopeResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+let show_dialog = true;
+
+if show_dialog {
+    yakui::focus_scope(|| {
+        yakui::button("OK");
+        yakui::button("Cancel");
+    });
+}
+```
+*/
+#[derive(Debug, Clone, Default)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct FocusScope {}
+
+impl FocusScope {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<FocusScopeResponse> {
+        widget_children::<FocusScopeWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct FocusScopeWidget {
+    props: FocusScope,
+}
+
+pub type FocusScopeResponse = ();
+
+impl Widget for FocusScopeWidget {
+    type Props<'a> = FocusScope;
+    type Response = FocusScopeResponse;
+
+    fn new() -> Self {
+        Self {
+            props: FocusScope::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        ctx.input.push_focus_scope(ctx.dom.current());
+        self.default_layout(ctx, constraints)
+    }
+}
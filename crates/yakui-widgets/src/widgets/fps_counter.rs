@@ -0,0 +1,211 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use yakui_core::geometry::{Color, Constraints, Vec2};
+use yakui_core::paint::{PaintPath, PaintRect};
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::{context, Response};
+
+use crate::colors;
+use crate::style::TextStyle;
+use crate::util::widget;
+
+use super::RenderText;
+
+/**
+Shows how quickly the UI is being updated: frames per second, the average and
+99th percentile frame time, and a graph of recent frame times.
+
+Frame times are measured using [`Yakui::set_time`][yakui_core::Yakui::set_time],
+so the counter shows nothing if the host doesn't call it.
+
+Responds with [FpsCounterResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+let stats = yakui::fps_counter();
+if stats.p99 > std::time::Duration::from_millis(33) {
+    println!("Hitching!");
+}
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct FpsCounter {
+    /// How many of the most recent frames to keep track of.
+    pub history: usize,
+    pub graph_size: Vec2,
+    pub graph_color: Color,
+    pub background: Color,
+    pub style: TextStyle,
+}
+
+impl FpsCounter {
+    pub fn new() -> Self {
+        let mut style = TextStyle::label();
+        style.font_size = 12.0;
+
+        Self {
+            history: 120,
+            graph_size: Vec2::new(120.0, 24.0),
+            graph_color: Color::GREEN,
+            background: colors::BACKGROUND_1.with_alpha(0.8),
+            style,
+        }
+    }
+
+    pub fn show(self) -> Response<FpsCounterResponse> {
+        widget::<FpsCounterWidget>(self)
+    }
+}
+
+impl Default for FpsCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct FpsCounterWidget {
+    props: FpsCounter,
+    frame_times: RefCell<VecDeque<Duration>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FpsCounterResponse {
+    /// How long the most recent frame took.
+    pub current: Duration,
+
+    /// The average frame time over the recorded history.
+    pub average: Duration,
+
+    /// The frame time that 99% of recorded frames were faster than.
+    pub p99: Duration,
+}
+
+const PADDING: f32 = 4.0;
+
+impl Widget for FpsCounterWidget {
+    type Props<'a> = FpsCounter;
+    type Response = FpsCounterResponse;
+
+    fn new() -> Self {
+        Self {
+            props: FpsCounter::new(),
+            frame_times: RefCell::new(VecDeque::new()),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+
+        let delta_time = context::dom().delta_time();
+        let frame_times = self.frame_times.get_mut();
+
+        // If the host isn't advancing time, there's nothing to measure.
+        if !delta_time.is_zero() {
+            frame_times.push_back(delta_time);
+        }
+
+        while frame_times.len() > self.props.history.max(1) {
+            frame_times.pop_front();
+        }
+
+        let current = frame_times.back().copied().unwrap_or_default();
+        let average = if frame_times.is_empty() {
+            Duration::ZERO
+        } else {
+            frame_times.iter().sum::<Duration>() / frame_times.len() as u32
+        };
+
+        let mut sorted: Vec<Duration> = frame_times.iter().copied().collect();
+        sorted.sort_unstable();
+        let p99 = match sorted.len() {
+            0 => Duration::ZERO,
+            len => sorted[(len - 1) * 99 / 100],
+        };
+
+        let fps = if average.is_zero() {
+            0.0
+        } else {
+            1.0 / average.as_secs_f64()
+        };
+
+        let text = format!(
+            "{fps:.0} fps  {:.1} ms avg  {:.1} ms p99",
+            average.as_secs_f64() * 1000.0,
+            p99.as_secs_f64() * 1000.0,
+        );
+        RenderText::with_style(text, self.props.style.clone()).show();
+
+        FpsCounterResponse {
+            current,
+            average,
+            p99,
+        }
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let mut text_size = Vec2::ZERO;
+
+        for &child in &node.children {
+            text_size = ctx.calculate_layout(child, Constraints::none());
+            ctx.layout.set_pos(child, Vec2::splat(PADDING));
+        }
+
+        let graph_size = self.props.graph_size;
+        let size = Vec2::new(
+            text_size.x.max(graph_size.x),
+            text_size.y + PADDING + graph_size.y,
+        );
+
+        constraints.constrain(size + Vec2::splat(PADDING * 2.0))
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let node = ctx.dom.get_current();
+        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+        let rect = layout_node.rect;
+
+        let mut background = PaintRect::new(rect);
+        background.color = self.props.background;
+        background.add(ctx.paint);
+
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+
+        let frame_times = self.frame_times.borrow();
+        let Some(slowest) = frame_times.iter().max().filter(|max| !max.is_zero()) else {
+            return;
+        };
+
+        let graph_size = self.props.graph_size;
+        let graph_origin = Vec2::new(
+            rect.pos().x + PADDING,
+            rect.max().y - PADDING - graph_size.y,
+        );
+        let step = graph_size.x / (self.props.history.max(2) - 1) as f32;
+
+        // Newer frames are drawn on the right, so the graph scrolls to the left
+        // as time goes on.
+        let start = self.props.history.saturating_sub(frame_times.len());
+
+        let mut path = PaintPath::new();
+        for (i, frame_time) in frame_times.iter().enumerate() {
+            let height = frame_time.as_secs_f32() / slowest.as_secs_f32() * graph_size.y;
+            let point = graph_origin + Vec2::new((start + i) as f32 * step, graph_size.y - height);
+
+            if i == 0 {
+                path.move_to(point);
+            } else {
+                path.line_to(point);
+            }
+        }
+
+        ctx.stroke_path(&path, 1.0, self.props.graph_color);
+    }
+}
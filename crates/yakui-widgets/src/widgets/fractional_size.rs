@@ -0,0 +1,115 @@
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::Response;
+
+use crate::util::widget_children;
+
+/**
+Sizes its children to a fraction of the space available to it, like a menu
+that should take up half of the screen's width at any resolution.
+
+Each axis with a factor is forced to that fraction of the largest size allowed
+on that axis. Axes without a factor, or where the available space is
+unbounded, are sized by the children instead.
+
+Responds with [FractionalSizeResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+yakui::fractional_width(0.5, || {
+    yakui::button("Half as wide as the screen");
+});
+
+yakui::fractional_size(0.5, 0.3, || {
+    yakui::colored_box(yakui::colors::BACKGROUND_2, [0.0, 0.0]);
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct FractionalSize {
+    /// The fraction of the available width to use, usually between 0 and 1.
+    pub width_factor: Option<f32>,
+
+    /// The fraction of the available height to use, usually between 0 and 1.
+    pub height_factor: Option<f32>,
+}
+
+impl FractionalSize {
+    pub fn new(width_factor: f32, height_factor: f32) -> Self {
+        Self {
+            width_factor: Some(width_factor),
+            height_factor: Some(height_factor),
+        }
+    }
+
+    /// Only size the width, letting the children decide their height.
+    pub fn width(width_factor: f32) -> Self {
+        Self {
+            width_factor: Some(width_factor),
+            height_factor: None,
+        }
+    }
+
+    /// Only size the height, letting the children decide their width.
+    pub fn height(height_factor: f32) -> Self {
+        Self {
+            width_factor: None,
+            height_factor: Some(height_factor),
+        }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<FractionalSizeResponse> {
+        widget_children::<FractionalSizeWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct FractionalSizeWidget {
+    props: FractionalSize,
+}
+
+pub type FractionalSizeResponse = ();
+
+impl Widget for FractionalSizeWidget {
+    type Props<'a> = FractionalSize;
+    type Response = FractionalSizeResponse;
+
+    fn new() -> Self {
+        Self {
+            props: FractionalSize::new(1.0, 1.0),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+
+        let fraction = |factor: Option<f32>, min: f32, max: f32| match factor {
+            Some(factor) if max.is_finite() => {
+                let size = (max * factor).clamp(min, max);
+                (size, size)
+            }
+            _ => (min, max),
+        };
+
+        let (min_x, max_x) = fraction(self.props.width_factor, input.min.x, input.max.x);
+        let (min_y, max_y) = fraction(self.props.height_factor, input.min.y, input.max.y);
+        let constraints = Constraints {
+            min: Vec2::new(min_x, min_y),
+            max: Vec2::new(max_x, max_y),
+        };
+
+        let mut size = constraints.min;
+        for &child in &node.children {
+            let child_size = ctx.calculate_layout(child, constraints);
+            size = size.max(child_size);
+        }
+
+        constraints.constrain(size)
+    }
+}
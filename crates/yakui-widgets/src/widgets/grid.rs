@@ -0,0 +1,232 @@
+use std::cell::RefCell;
+
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{Alignment, LayoutDirection, Response};
+
+use crate::util::widget_children;
+
+/**
+Lays out its children in a grid with a declared set of columns, similar to a
+CSS grid template. Children fill the grid row by row, and a new row is started
+whenever the current one is full. Each row is as tall as its tallest cell.
+
+Unlike [`CountGrid`][super::CountGrid], every column can be sized differently,
+which makes this useful for things like inspectors with a label column and a
+value column.
+
+Responds with [GridResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{Grid, GridTrack};
+
+let mut grid = Grid::new(vec![GridTrack::Auto, GridTrack::Flex(1.0)]);
+grid.column_gap = 8.0;
+grid.show(|| {
+    yakui::label("Name");
+    yakui::label("Ferris");
+
+    yakui::label("Favorite color");
+    yakui::label("Orange");
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Grid {
+    pub columns: Vec<GridTrack>,
+
+    /// Space between each column.
+    pub column_gap: f32,
+
+    /// Space between each row.
+    pub row_gap: f32,
+
+    /// Where each child is placed inside of its cell when it's smaller than
+    /// the cell.
+    pub cell_alignment: Alignment,
+
+    /// If true, children in fixed and flex columns are stretched to fill the
+    /// width of their column. Auto columns are already as wide as their widest
+    /// child.
+    pub fill_columns: bool,
+}
+
+/// How wide a column of a [`Grid`] should be.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridTrack {
+    /// A fixed width in logical pixels.
+    Fixed(f32),
+
+    /// A share of the space left over by every other column, in proportion to
+    /// the flex factors of the other flex columns. If the grid has unbounded
+    /// width, flex columns are sized like [`GridTrack::Auto`] instead.
+    Flex(f32),
+
+    /// As wide as the widest child in the column.
+    Auto,
+}
+
+impl Grid {
+    pub fn new(columns: Vec<GridTrack>) -> Self {
+        Self {
+            columns,
+            column_gap: 0.0,
+            row_gap: 0.0,
+            cell_alignment: Alignment::TOP_LEFT,
+            fill_columns: false,
+        }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<GridResponse> {
+        widget_children::<GridWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct GridWidget {
+    props: Grid,
+
+    // Kept around to avoid reallocating every frame.
+    widths: RefCell<Vec<f32>>,
+}
+
+pub type GridResponse = ();
+
+impl Widget for GridWidget {
+    type Props<'a> = Grid;
+    type Response = GridResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Grid::new(Vec::new()),
+            widths: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let columns = &self.props.columns;
+        if columns.is_empty() {
+            return input.min;
+        }
+
+        let n_columns = columns.len();
+        let bounded = input.max.x.is_finite();
+        let is_auto = |track: GridTrack| match track {
+            GridTrack::Auto => true,
+            GridTrack::Flex(_) => !bounded,
+            GridTrack::Fixed(_) => false,
+        };
+
+        let mut widths = std::mem::take(&mut *self.widths.borrow_mut());
+        widths.clear();
+        widths.resize(n_columns, 0.0);
+
+        // Fixed columns are known up front. Everything else has to fit in the
+        // space they leave over.
+        let mut used = self.props.column_gap * (n_columns - 1) as f32;
+        let mut total_flex = 0.0;
+        for (width, &track) in widths.iter_mut().zip(columns) {
+            match track {
+                GridTrack::Fixed(fixed) => {
+                    *width = fixed;
+                    used += fixed;
+                }
+                GridTrack::Flex(flex) if bounded => total_flex += flex,
+                _ => {}
+            }
+        }
+
+        // Auto columns are as wide as their widest child. Those children are
+        // laid out here and their sizes are reused when placing rows below.
+        let available = (input.max.x - used).max(0.0);
+        let auto_constraints = Constraints::loose(Vec2::new(available, f32::INFINITY));
+        for (i, &child) in node.children.iter().enumerate() {
+            let column = i % n_columns;
+            if is_auto(columns[column]) {
+                let size = ctx.calculate_layout(child, auto_constraints);
+                widths[column] = widths[column].max(size.x);
+            }
+        }
+
+        for (&width, &track) in widths.iter().zip(columns) {
+            if is_auto(track) {
+                used += width;
+            }
+        }
+
+        if total_flex > 0.0 {
+            let remaining = (input.max.x - used).max(0.0);
+            for (width, &track) in widths.iter_mut().zip(columns) {
+                if let GridTrack::Flex(flex) = track {
+                    *width = remaining * flex / total_flex;
+                }
+            }
+        }
+
+        let content_width =
+            widths.iter().sum::<f32>() + self.props.column_gap * (n_columns - 1) as f32;
+        let grid_width = input.constrain_width(content_width);
+
+        // Grid columns run from right to left in right-to-left layouts.
+        let mirror = ctx.layout.layout_direction() == LayoutDirection::RightToLeft;
+        let mut alignment = self.props.cell_alignment.as_vec2();
+        if mirror {
+            alignment.x = 1.0 - alignment.x;
+        }
+
+        let mut next_y = 0.0;
+        for row in node.children.chunks(n_columns) {
+            let mut row_height: f32 = 0.0;
+
+            for ((&child, &width), &track) in row.iter().zip(&widths).zip(columns) {
+                let size = if is_auto(track) {
+                    let layout = ctx.layout.get(child);
+                    layout.map(|layout| layout.rect.size()).unwrap_or_default()
+                } else {
+                    let min_width = if self.props.fill_columns { width } else { 0.0 };
+                    let constraints = Constraints {
+                        min: Vec2::new(min_width, 0.0),
+                        max: Vec2::new(width, f32::INFINITY),
+                    };
+
+                    ctx.calculate_layout(child, constraints)
+                };
+
+                row_height = row_height.max(size.y);
+            }
+
+            let mut next_x = 0.0;
+            for (&child, &width) in row.iter().zip(&widths) {
+                let layout = ctx.layout.get_mut(child).unwrap();
+                let cell_size = Vec2::new(width, row_height);
+                let offset = (cell_size - layout.rect.size()).max(Vec2::ZERO) * alignment;
+
+                let x = if mirror {
+                    grid_width - next_x - width
+                } else {
+                    next_x
+                };
+                layout.rect.set_pos(Vec2::new(x, next_y) + offset);
+
+                next_x += width + self.props.column_gap;
+            }
+
+            next_y += row_height + self.props.row_gap;
+        }
+
+        let grid_height = (next_y - self.props.row_gap).max(0.0);
+
+        // Put widths back to be reused
+        *self.widths.borrow_mut() = widths;
+
+        Vec2::new(grid_width, input.constrain_height(grid_height))
+    }
+}
@@ -0,0 +1,92 @@
+use yakui_core::geometry::{Constraints, Rect, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{LayoutDirection, Response};
+
+use crate::util::widget_children;
+
+use super::Pad;
+
+/**
+Changes the area that its children can be clicked or hovered in, without
+changing their size or position.
+
+Positive values grow the area past the edges of each child, making small
+widgets like icon buttons easier to hit. Negative values shrink it, which can
+be used to ignore decorations around the edges of a widget.
+
+Only the direct children of `HitSlop` are affected.
+
+Responds with [HitSlopResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::widgets::Pad;
+yakui::hit_slop(Pad::all(8.0), || {
+    yakui::button("x");
+});
+```
+*/
+#[derive(Debug, Clone, Copy)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct HitSlop {
+    pub slop: Pad,
+}
+
+impl HitSlop {
+    pub fn new(slop: Pad) -> Self {
+        Self { slop }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<HitSlopResponse> {
+        widget_children::<HitSlopWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct HitSlopWidget {
+    props: HitSlop,
+}
+
+pub type HitSlopResponse = ();
+
+impl Widget for HitSlopWidget {
+    type Props<'a> = HitSlop;
+    type Response = HitSlopResponse;
+
+    fn new() -> Self {
+        Self {
+            props: HitSlop::new(Pad::ZERO),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let slop = self.props.slop;
+
+        // Like padding, slop is mirrored for right-to-left layouts.
+        let left = match ctx.layout.layout_direction() {
+            LayoutDirection::LeftToRight => slop.left,
+            LayoutDirection::RightToLeft => slop.right,
+        };
+
+        let offset = Vec2::new(left, slop.top);
+        let total = Vec2::new(slop.left + slop.right, slop.top + slop.bottom);
+
+        let mut size = Vec2::ZERO;
+        for &child in &node.children {
+            let child_size = ctx.calculate_layout(child, constraints);
+            size = size.max(child_size);
+
+            let interact_size = (child_size + total).max(Vec2::ZERO);
+            let interact_rect = Rect::from_pos_size(-offset, interact_size);
+            ctx.layout.set_interact_rect(child, Some(interact_rect));
+        }
+
+        constraints.constrain_min(size)
+    }
+}
@@ -0,0 +1,64 @@
+use yakui_core::input::Shortcut;
+use yakui_core::widget::Widget;
+use yakui_core::{context, Response};
+
+use crate::util::widget;
+
+/**
+Listens for a keyboard shortcut, like Ctrl+S or F2, anywhere in the UI for as
+long as it is part of the UI.
+
+The selected widget gets each key press first, so shortcuts don't fire while
+the user is typing the same keys into a text box.
+
+Responds with [HotkeyResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::input::Shortcut;
+
+if yakui::use_shortcut(Shortcut::primary("s")).triggered {
+    println!("Saving...");
+}
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Hotkey {
+    pub shortcut: Shortcut,
+}
+
+impl Hotkey {
+    pub fn new(shortcut: Shortcut) -> Self {
+        Self { shortcut }
+    }
+
+    pub fn show(self) -> Response<HotkeyResponse> {
+        widget::<HotkeyWidget>(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct HotkeyWidget;
+
+#[derive(Debug, Clone, Copy)]
+pub struct HotkeyResponse {
+    /// Whether the shortcut was pressed since the last frame.
+    pub triggered: bool,
+}
+
+impl Widget for HotkeyWidget {
+    type Props<'a> = Hotkey;
+    type Response = HotkeyResponse;
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        HotkeyResponse {
+            triggered: context::dom().shortcut(&props.shortcut),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::geometry::{Color, Constraints, Rect, UVec2, Vec2};
 use yakui_core::paint::PaintRect;
 use yakui_core::widget::{LayoutContext, PaintContext, Widget};
 use yakui_core::{Response, TextureId};
@@ -9,13 +9,85 @@ use crate::util::widget;
 Displays an image.
 
 Responds with [ImageResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# let atlas = yakui::TextureId::User(0);
+# use yakui::widgets::{Image, ImageRegion};
+# use yakui::{Rect, Vec2};
+// Show a 16x16 icon packed into a larger atlas texture.
+let mut icon = Image::new(atlas, Vec2::splat(32.0));
+icon.source = Some(ImageRegion::Uv(Rect::from_pos_size(
+    Vec2::new(0.25, 0.5),
+    Vec2::splat(0.125),
+)));
+icon.show();
+```
 */
 #[derive(Debug, Clone)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Image {
     pub image: Option<TextureId>,
     pub size: Vec2,
+
+    /// A color multiplied with the image, useful for tinting it.
     pub color: Color,
+
+    /// The part of the texture to show. If unset, the whole texture is shown.
+    pub source: Option<ImageRegion>,
+
+    /// How the image is sized to fit into the widget.
+    pub fit: ImageFit,
+
+    /// Mirror the image horizontally.
+    pub flip_x: bool,
+
+    /// Mirror the image vertically.
+    pub flip_y: bool,
+
+    /// The size of the texture in pixels. yakui looks this up for textures it
+    /// manages, but it has to be given for other textures for fit modes and
+    /// pixel regions to work.
+    pub texture_size: Option<UVec2>,
+}
+
+/// How an [`Image`] is placed into the space it's laid out in.
+///
+/// Every mode other than [`ImageFit::Stretch`] needs to know the size of the
+/// texture, so they only work with textures managed by yakui or when
+/// [`Image::texture_size`] is set. Other textures are always stretched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageFit {
+    /// Stretch the image to fill the space, ignoring its aspect ratio.
+    #[default]
+    Stretch,
+
+    /// Scale the image to be as large as possible while still fitting
+    /// entirely inside the space, keeping its aspect ratio.
+    Contain,
+
+    /// Scale the image to cover the whole space, keeping its aspect ratio and
+    /// cropping off any parts that don't fit.
+    Cover,
+
+    /// Show the image at its natural size, with one texel per logical pixel,
+    /// cropping off any parts that don't fit.
+    None,
+}
+
+/// A region of a texture, used to show part of an image like a sprite from an
+/// atlas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageRegion {
+    /// A region in texture coordinates, where (0, 0) is the top-left corner
+    /// of the texture and (1, 1) is the bottom-right corner.
+    Uv(Rect),
+
+    /// A region in pixels. The size of the texture is only known for textures
+    /// managed by yakui or when [`Image::texture_size`] is set, so other
+    /// textures are shown in full.
+    Pixels(Rect),
 }
 
 impl Image {
@@ -27,6 +99,11 @@ impl Image {
             image: Some(image.into()),
             size,
             color: Color::WHITE,
+            source: None,
+            fit: ImageFit::Stretch,
+            flip_x: false,
+            flip_y: false,
+            texture_size: None,
         }
     }
 
@@ -52,6 +129,11 @@ impl Widget for ImageWidget {
                 image: None,
                 size: Vec2::ZERO,
                 color: Color::WHITE,
+                source: None,
+                fit: ImageFit::Stretch,
+                flip_x: false,
+                flip_y: false,
+                texture_size: None,
             },
         }
     }
@@ -67,11 +149,89 @@ impl Widget for ImageWidget {
     fn paint(&self, ctx: PaintContext<'_>) {
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
 
-        if let Some(image) = self.props.image {
-            let mut rect = PaintRect::new(layout_node.rect);
-            rect.color = self.props.color;
-            rect.texture = Some((image, Rect::ONE));
-            rect.add(ctx.paint);
+        let Some(image) = self.props.image else {
+            return;
+        };
+
+        // The size of the texture is only known if yakui manages it or we were
+        // told what it is.
+        let texture_size = self.props.texture_size.or_else(|| match image {
+            TextureId::Managed(id) => ctx.paint.texture(id).map(|texture| texture.size()),
+            TextureId::User(_) => None,
+        });
+        // An empty texture has no pixels to divide a region by, so it's treated
+        // like one whose size isn't known.
+        let texture_size = texture_size
+            .map(|size| size.as_vec2())
+            .filter(|size| size.min_element() > 0.0);
+
+        let uv = match (self.props.source, texture_size) {
+            (None, _) => Rect::ONE,
+            (Some(ImageRegion::Uv(uv)), _) => uv,
+            (Some(ImageRegion::Pixels(pixels)), Some(size)) => {
+                Rect::from_pos_size(pixels.pos() / size, pixels.size() / size)
+            }
+            (Some(ImageRegion::Pixels(_)), None) => Rect::ONE,
+        };
+
+        // Nothing can be seen in an empty rect, and fitting the image into one
+        // would divide by zero.
+        let bounds = layout_node.rect;
+        if bounds.size().min_element() <= 0.0 {
+            return;
+        }
+
+        let natural_size = texture_size.map(|size| uv.size().abs() * size);
+        let (rect, mut uv) = match natural_size {
+            Some(natural_size) if natural_size.min_element() > 0.0 => {
+                fit(self.props.fit, bounds, uv, natural_size)
+            }
+            _ => (bounds, uv),
+        };
+
+        if self.props.flip_x {
+            uv = Rect::from_pos_size(
+                Vec2::new(uv.max().x, uv.pos().y),
+                Vec2::new(-uv.size().x, uv.size().y),
+            );
+        }
+
+        if self.props.flip_y {
+            uv = Rect::from_pos_size(
+                Vec2::new(uv.pos().x, uv.max().y),
+                Vec2::new(uv.size().x, -uv.size().y),
+            );
         }
+
+        let mut paint_rect = PaintRect::new(rect);
+        paint_rect.color = self.props.color;
+        paint_rect.texture = Some((image, uv));
+        paint_rect.add(ctx.paint);
     }
 }
+
+/// Places an image with the given natural size inside of `bounds`, returning
+/// where to draw it and which part of `uv` is visible.
+fn fit(fit: ImageFit, bounds: Rect, uv: Rect, natural_size: Vec2) -> (Rect, Rect) {
+    let scale = match fit {
+        ImageFit::Stretch => return (bounds, uv),
+        ImageFit::Contain => (bounds.size() / natural_size).min_element(),
+        ImageFit::Cover => (bounds.size() / natural_size).max_element(),
+        ImageFit::None => 1.0,
+    };
+
+    let size = natural_size * scale;
+    let pos = bounds.pos() + (bounds.size() - size) / 2.0;
+
+    // Anything that spills outside of the bounds is cropped off.
+    let min = pos.max(bounds.pos());
+    let max = (pos + size).min(bounds.max());
+
+    let uv_min = uv.pos() + (min - pos) / size * uv.size();
+    let uv_max = uv.pos() + (max - pos) / size * uv.size();
+
+    (
+        Rect::from_pos_size(min, max - min),
+        Rect::from_pos_size(uv_min, uv_max - uv_min),
+    )
+}
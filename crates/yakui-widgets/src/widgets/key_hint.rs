@@ -0,0 +1,96 @@
+use yakui_core::geometry::Color;
+use yakui_core::input::Shortcut;
+use yakui_core::widget::Widget;
+use yakui_core::{CrossAxisAlignment, Response};
+
+use crate::colors;
+use crate::style::TextStyle;
+use crate::util::widget;
+
+use super::{List, Pad, RoundRect, Text};
+
+/**
+Shows the keys of a [`Shortcut`] as a row of key caps, like `[Ctrl]+[S]`, for
+menus and tooltips. Keys are named the way the current platform names them,
+so the same shortcut shows as `[⌘][S]` on macOS.
+
+Responds with [KeyHintResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::input::Shortcut;
+use yakui::widgets::KeyHint;
+
+yakui::row(|| {
+    yakui::label("Save");
+    KeyHint::new(Shortcut::primary("s")).show();
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct KeyHint {
+    pub shortcut: Shortcut,
+    pub style: TextStyle,
+
+    /// The color of each key cap.
+    pub fill: Color,
+}
+
+impl KeyHint {
+    pub fn new(shortcut: Shortcut) -> Self {
+        let mut style = TextStyle::label();
+        style.font_size = 12.0;
+        style.color = colors::TEXT_MUTED;
+
+        Self {
+            shortcut,
+            style,
+            fill: colors::BACKGROUND_3,
+        }
+    }
+
+    pub fn show(self) -> Response<KeyHintResponse> {
+        widget::<KeyHintWidget>(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct KeyHintWidget;
+
+pub type KeyHintResponse = ();
+
+impl Widget for KeyHintWidget {
+    type Props<'a> = KeyHint;
+    type Response = KeyHintResponse;
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        // macOS shows modifier symbols right next to each other, while other
+        // platforms put a plus between each key.
+        let separator = !cfg!(target_os = "macos");
+
+        let mut row = List::row();
+        row.item_spacing = 2.0;
+        row.cross_axis_alignment = CrossAxisAlignment::Center;
+        row.show(|| {
+            for (index, label) in props.shortcut.key_labels().into_iter().enumerate() {
+                if index > 0 && separator {
+                    Text::with_style("+", props.style.clone()).show();
+                }
+
+                let mut cap = RoundRect::new(3.0);
+                cap.color = props.fill;
+                cap.show_children(|| {
+                    let mut text = Text::with_style(label, props.style.clone());
+                    text.padding = Pad::balanced(5.0, 1.0);
+                    text.show();
+                });
+            }
+        });
+    }
+}
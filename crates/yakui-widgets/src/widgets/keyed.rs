@@ -0,0 +1,47 @@
+use std::hash::Hash;
+
+use yakui_core::context;
+
+/**
+Gives its children a stable identity based on a key instead of their position
+among their siblings.
+
+yakui normally matches up widgets from one update to the next by their
+position. When items in a list are inserted, removed, or reordered, wrapping
+each item in a `Keyed` keeps its state attached to the right item.
+
+`Keyed` doesn't add a widget of its own. The key is given to the widgets that
+its children create, so they lay out exactly as they would without it, and a
+[`Flexible`][super::Flexible] child still flexes inside a list.
+
+Responds with whatever its children return.
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+let items = ["apple", "banana", "cherry"];
+
+yakui::column(|| {
+    for item in items {
+        yakui::keyed(item, || {
+            yakui::label(item);
+        });
+    }
+});
+```
+*/
+#[derive(Debug)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Keyed<K> {
+    pub key: K,
+}
+
+impl<K: Hash> Keyed<K> {
+    pub fn new(key: K) -> Self {
+        Self { key }
+    }
+
+    pub fn show<R, F: FnOnce() -> R>(self, children: F) -> R {
+        context::dom().with_key(self.key, children)
+    }
+}
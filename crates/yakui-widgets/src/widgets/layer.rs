@@ -44,6 +44,12 @@ impl Widget for LayerWidget {
         self.props = props;
     }
 
+    fn paints_outside_rect(&self) -> bool {
+        // Layers are used for popups and tooltips, which often extend past
+        // the widget that contains them.
+        true
+    }
+
     fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
         ctx.layout.new_layer(ctx.dom);
 
@@ -1,9 +1,15 @@
+use std::hash::Hash;
+
 use yakui_core::geometry::{Constraints, FlexFit, Vec2};
 use yakui_core::widget::{LayoutContext, Widget};
-use yakui_core::{CrossAxisAlignment, Direction, Flow, MainAxisAlignment, MainAxisSize, Response};
+use yakui_core::{
+    CrossAxisAlignment, Direction, Flow, LayoutDirection, MainAxisAlignment, MainAxisSize, Response,
+};
 
 use crate::util::widget_children;
 
+use super::Keyed;
+
 /**
 Lays out children in a single direction. Supports flex sizing.
 
@@ -25,7 +31,7 @@ yakui::row(|| {
 });
 ```
 */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct List {
     pub direction: Direction,
@@ -58,11 +64,34 @@ impl List {
     pub fn show<F: FnOnce()>(self, children: F) -> Response<ListResponse> {
         widget_children::<ListWidget, F>(children, self)
     }
+
+    /// Shows one set of children for each item, identifying each item by the
+    /// key returned by `key`. Items keep their state when the collection they
+    /// come from has items inserted, removed, or reordered.
+    pub fn show_items<I, K, KF, F>(
+        self,
+        items: I,
+        key: KF,
+        mut children: F,
+    ) -> Response<ListResponse>
+    where
+        I: IntoIterator,
+        K: Hash,
+        KF: Fn(&I::Item) -> K,
+        F: FnMut(I::Item),
+    {
+        self.show(|| {
+            for item in items {
+                Keyed::new(key(&item)).show(|| children(item));
+            }
+        })
+    }
 }
 
 #[derive(Debug)]
 pub struct ListWidget {
     props: List,
+    layout_changed: bool,
 }
 
 pub type ListResponse = ();
@@ -72,13 +101,21 @@ impl Widget for ListWidget {
     type Response = ListResponse;
 
     fn new() -> Self {
-        Self { props: List::row() }
+        Self {
+            props: List::row(),
+            layout_changed: true,
+        }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.layout_changed = props != self.props;
         self.props = props;
     }
 
+    fn needs_layout(&self) -> bool {
+        self.layout_changed
+    }
+
     fn flex(&self) -> (u32, FlexFit) {
         let flex = if self.props.cross_axis_alignment == CrossAxisAlignment::Stretch {
             1
@@ -181,6 +218,33 @@ impl Widget for ListWidget {
             max_cross_axis_size = f32::max(max_cross_axis_size, direction.get_cross_axis(size));
         }
 
+        // Baseline alignment lines up each child's baseline with the lowest
+        // one, which can make the list taller than its tallest child.
+        let baseline = if self.props.cross_axis_alignment == CrossAxisAlignment::Baseline
+            && direction == Direction::Right
+        {
+            let mut above: f32 = 0.0;
+            let mut below: f32 = 0.0;
+
+            for &child_index in &node.children {
+                let child = ctx.dom.get(child_index).unwrap();
+                if child.widget.flow() != Flow::Inline {
+                    continue;
+                }
+
+                let child_layout = ctx.layout.get(child_index).unwrap();
+                let height = child_layout.rect.size().y;
+                let baseline = child_layout.baseline.unwrap_or(height);
+                above = above.max(baseline);
+                below = below.max(height - baseline);
+            }
+
+            max_cross_axis_size = f32::max(max_cross_axis_size, above + below);
+            Some(above)
+        } else {
+            None
+        };
+
         let cross_size = max_cross_axis_size.max(direction.get_cross_axis(input.min));
 
         let main_axis_size = match self.props.main_axis_size {
@@ -255,6 +319,11 @@ impl Widget for ListWidget {
 
         let mut next_main = leading_space;
 
+        // Horizontal lists run from right to left in right-to-left layouts.
+        let container_main = direction.get_main_axis(container_size);
+        let mirror = direction == Direction::Right
+            && ctx.layout.layout_direction() == LayoutDirection::RightToLeft;
+
         for &child_index in &node.children {
             let child = ctx.dom.get(child_index).unwrap();
             if child.widget.flow() != Flow::Inline {
@@ -267,11 +336,20 @@ impl Widget for ListWidget {
             let child_cross = direction.get_cross_axis(child_size);
 
             let cross = match self.props.cross_axis_alignment {
-                CrossAxisAlignment::Start | CrossAxisAlignment::Stretch => 0.0,
                 CrossAxisAlignment::Center => (cross_size - child_cross) / 2.0,
                 CrossAxisAlignment::End => cross_size - child_cross,
+                CrossAxisAlignment::Baseline => match baseline {
+                    Some(above) => above - child_layout.baseline.unwrap_or(child_cross),
+                    None => 0.0,
+                },
+                _ => 0.0,
+            };
+            let main = if mirror {
+                container_main - next_main - child_main
+            } else {
+                next_main
             };
-            child_layout.rect.set_pos(direction.vec2(next_main, cross));
+            child_layout.rect.set_pos(direction.vec2(main, cross));
 
             next_main += child_main;
             next_main += between_space;
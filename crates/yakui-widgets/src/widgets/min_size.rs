@@ -0,0 +1,88 @@
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::Response;
+
+use crate::util::widget_children;
+
+/**
+A box that enforces a minimum size upon its children, like keeping buttons
+and panels from shrinking below a usable size. The minimum is never larger
+than the space available.
+
+Responds with [MinSizeResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+yakui::min_width(120.0, || {
+    yakui::button("OK");
+});
+
+yakui::min_size([200.0, 100.0], || {
+    yakui::label("A panel");
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct MinSize {
+    pub min_size: Vec2,
+}
+
+impl MinSize {
+    pub fn new(min_size: Vec2) -> Self {
+        Self { min_size }
+    }
+
+    /// Only enforce a minimum width.
+    pub fn width(min_width: f32) -> Self {
+        Self::new(Vec2::new(min_width, 0.0))
+    }
+
+    /// Only enforce a minimum height.
+    pub fn height(min_height: f32) -> Self {
+        Self::new(Vec2::new(0.0, min_height))
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<MinSizeResponse> {
+        widget_children::<MinSizeWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct MinSizeWidget {
+    props: MinSize,
+}
+
+pub type MinSizeResponse = ();
+
+impl Widget for MinSizeWidget {
+    type Props<'a> = MinSize;
+    type Response = MinSizeResponse;
+
+    fn new() -> Self {
+        Self {
+            props: MinSize::new(Vec2::ZERO),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, mut constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        constraints.min = constraints
+            .min
+            .max(self.props.min_size)
+            .min(constraints.max);
+
+        let mut size = constraints.min;
+        for &child in &node.children {
+            let child_size = ctx.calculate_layout(child, constraints);
+            size = size.max(child_size);
+        }
+
+        size
+    }
+}
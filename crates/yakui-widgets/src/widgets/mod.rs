@@ -1,67 +1,137 @@
+mod absolute;
 mod align;
+mod animated_image;
 mod button;
 mod canvas;
 mod checkbox;
 mod circle;
 mod colored_box;
 mod constrained_box;
+mod context_provider;
 mod count_grid;
 mod cutout;
+mod dismiss_on_outside_click;
 mod divider;
+mod drag_source;
 mod draggable;
+mod drop_target;
+mod dropdown_button;
+mod effect;
 mod flexible;
+mod focus_scope;
+mod fps_counter;
+mod fractional_size;
+mod grid;
+mod hit_slop;
+mod hotkey;
 mod image;
+mod key_hint;
+mod keyed;
 mod layer;
 mod list;
 mod max_width;
+mod min_size;
 mod nineslice;
 mod offset;
 mod opaque;
+mod outline;
+mod overlay_canvas;
 mod pad;
 mod panel;
+mod paste_target;
+mod player_input;
+mod portal;
 mod reflow;
 mod render_text;
 mod round_rect;
 mod scrollable;
+mod shadow;
 mod slider;
 mod spacer;
+mod split_button;
+mod spring_animation;
 mod stack;
 mod state;
+mod status_bar;
+mod streaming_image;
+#[cfg(feature = "svg")]
+mod svg;
+mod table;
 mod text;
 mod textbox;
+mod themed;
+mod timer;
+mod transform;
+mod tween;
 mod unconstrained_box;
 mod window;
+mod wrap;
 
+pub use self::absolute::*;
 pub use self::align::*;
+pub use self::animated_image::*;
 pub use self::button::*;
 pub use self::canvas::*;
 pub use self::checkbox::*;
 pub use self::circle::*;
 pub use self::colored_box::*;
 pub use self::constrained_box::*;
+pub use self::context_provider::*;
 pub use self::count_grid::*;
 pub use self::cutout::*;
+pub use self::dismiss_on_outside_click::*;
 pub use self::divider::*;
+pub use self::drag_source::*;
 pub use self::draggable::*;
+pub use self::drop_target::*;
+pub use self::dropdown_button::*;
+pub use self::effect::*;
 pub use self::flexible::*;
+pub use self::focus_scope::*;
+pub use self::fps_counter::*;
+pub use self::fractional_size::*;
+pub use self::grid::*;
+pub use self::hit_slop::*;
+pub use self::hotkey::*;
 pub use self::image::*;
+pub use self::key_hint::*;
+pub use self::keyed::*;
 pub use self::layer::*;
 pub use self::list::*;
 pub use self::max_width::*;
+pub use self::min_size::*;
 pub use self::nineslice::*;
 pub use self::offset::*;
 pub use self::opaque::*;
+pub use self::outline::*;
+pub use self::overlay_canvas::*;
 pub use self::pad::*;
 pub use self::panel::*;
+pub use self::paste_target::*;
+pub use self::player_input::*;
+pub use self::portal::*;
 pub use self::reflow::*;
 pub use self::render_text::*;
 pub use self::round_rect::*;
 pub use self::scrollable::*;
+pub use self::shadow::*;
 pub use self::slider::*;
 pub use self::spacer::*;
+pub use self::split_button::*;
+pub use self::spring_animation::*;
 pub use self::stack::*;
 pub use self::state::*;
+pub use self::status_bar::*;
+pub use self::streaming_image::*;
+#[cfg(feature = "svg")]
+pub use self::svg::*;
+pub use self::table::*;
 pub use self::text::*;
 pub use self::textbox::*;
+pub use self::themed::*;
+pub use self::timer::*;
+pub use self::transform::*;
+pub use self::tween::*;
 pub use self::unconstrained_box::*;
 pub use self::window::*;
+pub use self::wrap::*;
@@ -7,6 +7,24 @@ use yakui_core::{
 
 use crate::{shorthand::pad, util::widget_children, widgets::pad::Pad};
 
+/// How a region of a [`NineSlice`] fills the space between the corners,
+/// similar to CSS's `border-image-repeat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SliceMode {
+    /// Stretch the region to fill the space.
+    #[default]
+    Stretch,
+
+    /// Repeat the region at its scaled size, cutting off the last tile. Past
+    /// 256 tiles along an axis, the tiles are stretched to fit like with
+    /// [`SliceMode::Round`].
+    Repeat,
+
+    /// Repeat the region a whole number of times, squashing or stretching the
+    /// tiles slightly so that they fit exactly.
+    Round,
+}
+
 #[derive(Debug)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct NineSlice {
@@ -15,6 +33,8 @@ pub struct NineSlice {
     /// scaling.
     margins: Pad,
     scale: f32,
+    edge_mode: SliceMode,
+    center_mode: SliceMode,
 }
 
 impl NineSlice {
@@ -23,9 +43,26 @@ impl NineSlice {
             texture,
             margins,
             scale,
+            edge_mode: SliceMode::Stretch,
+            center_mode: SliceMode::Stretch,
         }
     }
 
+    /// Sets how the four edges fill the space between the corners. Top and
+    /// bottom edges tile horizontally, and left and right edges tile
+    /// vertically.
+    pub fn edge_mode(mut self, mode: SliceMode) -> Self {
+        self.edge_mode = mode;
+        self
+    }
+
+    /// Sets how the center fills the space between the edges. The center tiles
+    /// in both directions.
+    pub fn center_mode(mut self, mode: SliceMode) -> Self {
+        self.center_mode = mode;
+        self
+    }
+
     pub fn show(self, children: impl FnOnce()) -> Response<()> {
         let scaled_margins = {
             let mut m = self.margins;
@@ -75,6 +112,7 @@ impl Widget for NineSliceWidget {
                     ..
                 },
             scale,
+            ..
         } = *props;
 
         let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
@@ -93,28 +131,54 @@ impl Widget for NineSliceWidget {
         let pixel_us = [0.0, left, texture_size.x - right, texture_size.x];
         let pixel_vs = [0.0, top, texture_size.y - bottom, texture_size.y];
 
-        // Convert to 0.0-1.0 range
-        let us = pixel_us.map(|pixel_u| pixel_u / texture_size.x);
-        let vs = pixel_vs.map(|pixel_v| pixel_v / texture_size.y);
-
-        // Vertices are laid out from left to right, then top to bottom.
-        let vertices = rel_ys.into_iter().zip(vs).flat_map(|(y, v)| {
-            rel_xs.into_iter().zip(us).map(move |(x, u)| {
-                let rel_pos = Vec2::new(x, y);
-                let tex_coords = Vec2::new(u, v);
-
-                let pos = top_left + rel_pos;
-                Vertex::new(pos, tex_coords, Vec4::splat(1.0))
-            })
-        });
-
-        // Build rectangles between the vertices.
-        let indices = (0..3).flat_map(|i| {
-            (0..3).flat_map(move |j| {
-                let first = i * 4 + j;
-                [first, first + 5, first + 1, first, first + 4, first + 5]
-            })
-        });
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        // Each of the nine regions is split into tiles along the axes it
+        // stretches over. Corners never stretch, so they're always one tile.
+        for i in 0..3 {
+            for j in 0..3 {
+                let (x_mode, y_mode) = match (i, j) {
+                    (1, 1) => (props.center_mode, props.center_mode),
+                    (_, 1) => (props.edge_mode, SliceMode::Stretch),
+                    (1, _) => (SliceMode::Stretch, props.edge_mode),
+                    _ => (SliceMode::Stretch, SliceMode::Stretch),
+                };
+
+                let rows = tiles(
+                    (rel_ys[i], rel_ys[i + 1]),
+                    (pixel_vs[i], pixel_vs[i + 1]),
+                    scale,
+                    y_mode,
+                );
+                let columns = tiles(
+                    (rel_xs[j], rel_xs[j + 1]),
+                    (pixel_us[j], pixel_us[j + 1]),
+                    scale,
+                    x_mode,
+                );
+
+                for &(y, v) in &rows {
+                    for &(x, u) in &columns {
+                        let base = vertices.len() as u32;
+
+                        // Vertices go clockwise from the top-left corner.
+                        for (x, u, y, v) in [
+                            (x.0, u.0, y.0, v.0),
+                            (x.1, u.1, y.0, v.0),
+                            (x.1, u.1, y.1, v.1),
+                            (x.0, u.0, y.1, v.1),
+                        ] {
+                            let pos = top_left + Vec2::new(x, y);
+                            let tex_coords = Vec2::new(u, v) / texture_size;
+                            vertices.push(Vertex::new(pos, tex_coords, Vec4::splat(1.0)));
+                        }
+
+                        indices.extend([base, base + 2, base + 1, base, base + 3, base + 2]);
+                    }
+                }
+            }
+        }
 
         let mut mesh = PaintMesh::new(vertices, indices);
         mesh.texture = Some((
@@ -129,3 +193,52 @@ impl Widget for NineSliceWidget {
         }
     }
 }
+
+/// The most tiles a region can be split into along one axis. Past this, tiles
+/// are stretched so that a tiny tile repeated over a huge area can't produce an
+/// enormous mesh.
+const MAX_TILES: f32 = 256.0;
+
+/// Splits a region along one axis into tiles, returning the start and end of
+/// each tile's position and texture coordinates in pixels.
+fn tiles(
+    span: (f32, f32),
+    pixels: (f32, f32),
+    scale: f32,
+    mode: SliceMode,
+) -> Vec<((f32, f32), (f32, f32))> {
+    let length = span.1 - span.0;
+    let tile = (pixels.1 - pixels.0) * scale;
+
+    if mode == SliceMode::Stretch || tile <= 0.0 || length <= 0.0 {
+        return vec![(span, pixels)];
+    }
+
+    let count = match mode {
+        SliceMode::Round => (length / tile).round().max(1.0),
+        _ => (length / tile).ceil(),
+    };
+
+    if count > MAX_TILES || mode == SliceMode::Round {
+        let count = count.min(MAX_TILES);
+        let tile = length / count;
+
+        return (0..count as usize)
+            .map(|n| {
+                let start = span.0 + n as f32 * tile;
+                ((start, start + tile), pixels)
+            })
+            .collect();
+    }
+
+    (0..count as usize)
+        .map(|n| {
+            let start = span.0 + n as f32 * tile;
+            let end = (start + tile).min(span.1);
+            let fraction = (end - start) / tile;
+            let pixel_end = pixels.0 + (pixels.1 - pixels.0) * fraction;
+
+            ((start, end), (pixels.0, pixel_end))
+        })
+        .collect()
+}
@@ -44,6 +44,10 @@ impl Widget for OffsetWidget {
         self.props = props;
     }
 
+    fn paints_outside_rect(&self) -> bool {
+        true
+    }
+
     fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
         let node = ctx.dom.get_current();
 
@@ -0,0 +1,102 @@
+use yakui_core::geometry::{Color, Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::Response;
+
+use crate::shapes::{self, OutlineWidths, StrokeJoin, StrokeStyle};
+use crate::util::{widget, widget_children};
+
+/**
+Draws an outline on top of its children, on the inside of their edges.
+
+Responds with [OutlineResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::widgets::Outline;
+# use yakui::shapes::StrokeStyle;
+# use yakui::Color;
+let mut marquee = Outline::new(Color::WHITE, 1.0);
+marquee.style = StrokeStyle::Dashed { dash: 4.0, gap: 4.0 };
+marquee.show_children(|| {
+    yakui::label("Selected");
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Outline {
+    pub color: Color,
+    pub widths: OutlineWidths,
+    pub style: StrokeStyle,
+    pub join: StrokeJoin,
+}
+
+impl Outline {
+    pub fn new(color: Color, width: f32) -> Self {
+        Self {
+            color,
+            widths: OutlineWidths::all(width),
+            style: StrokeStyle::Solid,
+            join: StrokeJoin::Miter,
+        }
+    }
+
+    pub fn show(self) -> Response<OutlineResponse> {
+        widget::<OutlineWidget>(self)
+    }
+
+    pub fn show_children<F: FnOnce()>(self, children: F) -> Response<OutlineResponse> {
+        widget_children::<OutlineWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct OutlineWidget {
+    props: Outline,
+}
+
+pub type OutlineResponse = ();
+
+impl Widget for OutlineWidget {
+    type Props<'a> = Outline;
+    type Response = OutlineResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Outline::new(Color::WHITE, 1.0),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let mut size = Vec2::ZERO;
+
+        for &child in &node.children {
+            let child_size = ctx.calculate_layout(child, input);
+            size = size.max(child_size);
+        }
+
+        input.constrain_min(size)
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let node = ctx.dom.get_current();
+        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+
+        let mut outline = shapes::Outline::new(layout_node.rect, 0.0);
+        outline.widths = self.props.widths;
+        outline.color = self.props.color;
+        outline.style = self.props.style;
+        outline.join = self.props.join;
+        outline.add(ctx.paint);
+    }
+}
@@ -0,0 +1,185 @@
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::paint::{PaintMesh, PaintRect, Vertex};
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::Response;
+
+use crate::font::{self, Fonts};
+use crate::ignore_debug::IgnoreDebug;
+use crate::shapes;
+use crate::style::TextStyle;
+use crate::text_renderer::TextGlobalState;
+use crate::util::widget;
+
+use super::render_text::paint_text;
+
+type DrawCallback = Box<dyn Fn(&mut OverlayPainter<'_, '_>) + 'static>;
+
+/**
+Draws graphics in window coordinates on top of everything else, without taking
+part in layout or input.
+
+Useful for debug gizmos, FPS counters, and drag previews. The overlay is drawn
+after every other layer and ignores clipping from its containing widgets. It
+never receives mouse or keyboard input, so widgets underneath it stay usable.
+
+Responds with [OverlayCanvasResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::widgets::OverlayCanvas;
+# use yakui::{Color, Vec2};
+OverlayCanvas::new(|overlay| {
+    overlay.line(Vec2::new(10.0, 10.0), Vec2::new(100.0, 50.0), 2.0, Color::RED);
+    overlay.circle(Vec2::new(100.0, 50.0), 4.0, Color::YELLOW);
+    overlay.text(Vec2::new(10.0, 60.0), "gizmo", 12.0, Color::WHITE);
+})
+.show();
+```
+*/
+#[derive(Debug)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct OverlayCanvas {
+    draw: IgnoreDebug<Option<DrawCallback>>,
+}
+
+impl OverlayCanvas {
+    pub fn new(draw: impl Fn(&mut OverlayPainter<'_, '_>) + 'static) -> Self {
+        Self {
+            draw: IgnoreDebug(Some(Box::new(draw))),
+        }
+    }
+
+    pub fn show(self) -> Response<OverlayCanvasResponse> {
+        widget::<OverlayCanvasWidget>(self)
+    }
+}
+
+/// Helpers for drawing onto an [`OverlayCanvas`]. All positions are in
+/// logical pixels relative to the top-left corner of the window.
+pub struct OverlayPainter<'a, 'b> {
+    ctx: &'a mut PaintContext<'b>,
+}
+
+impl<'b> OverlayPainter<'_, 'b> {
+    /// Gives access to the underlying paint context, for drawing anything the
+    /// other helpers don't cover.
+    pub fn context(&mut self) -> &mut PaintContext<'b> {
+        self.ctx
+    }
+
+    /// Draws a line from `start` to `end`.
+    pub fn line(&mut self, start: Vec2, end: Vec2, width: f32, color: Color) {
+        let Some(direction) = (end - start).try_normalize() else {
+            return;
+        };
+
+        let normal = direction.perp() * (width / 2.0);
+        let color = color.to_linear();
+
+        let vertices = [start + normal, start - normal, end - normal, end + normal]
+            .map(|pos| Vertex::new(pos, [0.0, 0.0], color));
+
+        self.ctx
+            .paint
+            .add_mesh(PaintMesh::new(vertices, [0, 1, 2, 3, 0, 2]));
+    }
+
+    /// Draws a filled circle.
+    pub fn circle(&mut self, center: Vec2, radius: f32, color: Color) {
+        let mut circle = shapes::Circle::new(center, radius);
+        circle.color = color;
+        circle.add(self.ctx.paint);
+    }
+
+    /// Draws a filled rectangle.
+    pub fn rect(&mut self, rect: Rect, color: Color) {
+        let mut paint_rect = PaintRect::new(rect);
+        paint_rect.color = color;
+        paint_rect.add(self.ctx.paint);
+    }
+
+    /// Draws the outline of a rectangle.
+    pub fn outline(&mut self, rect: Rect, width: f32, color: Color) {
+        shapes::outline(self.ctx.paint, rect, width, color);
+    }
+
+    /// Draws a single line of text with its top-left corner at `pos`.
+    pub fn text(&mut self, pos: Vec2, text: &str, font_size: f32, color: Color) {
+        let mut style = TextStyle::label();
+        style.font_size = font_size;
+        style.color = color;
+        self.styled_text(pos, text, &style);
+    }
+
+    /// Draws text with the given style with its top-left corner at `pos`.
+    pub fn styled_text(&mut self, pos: Vec2, text: &str, style: &TextStyle) {
+        let fonts = self.ctx.dom.get_global_or_init(Fonts::default);
+        let text_global = self.ctx.dom.get_global_or_init(TextGlobalState::new);
+        let fallback = fonts.fallback_families();
+        let scale_factor = self.ctx.layout.scale_factor();
+
+        fonts.with_system(|font_system| {
+            let mut buffer = cosmic_text::Buffer::new(font_system, style.to_metrics(scale_factor));
+
+            font::set_text_with_fallback(
+                &mut buffer,
+                font_system,
+                text,
+                style.attrs.as_attrs(),
+                &fallback,
+            );
+            buffer.shape_until_scroll(font_system, true);
+
+            for run in buffer.layout_runs() {
+                for glyph in run.glyphs {
+                    if let Some(render) =
+                        text_global.get_or_insert(self.ctx.paint, font_system, glyph)
+                    {
+                        paint_text(self.ctx, style.color, glyph, render, pos, run.line_y);
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct OverlayCanvasWidget {
+    props: OverlayCanvas,
+}
+
+pub type OverlayCanvasResponse = ();
+
+impl Widget for OverlayCanvasWidget {
+    type Props<'a> = OverlayCanvas;
+    type Response = OverlayCanvasResponse;
+
+    fn new() -> Self {
+        Self {
+            props: OverlayCanvas {
+                draw: IgnoreDebug(None),
+            },
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn paints_outside_rect(&self) -> bool {
+        true
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
+        Vec2::ZERO
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        if let IgnoreDebug(Some(draw)) = &self.props.draw {
+            ctx.paint.begin_overlay();
+            (draw)(&mut OverlayPainter { ctx: &mut ctx });
+            ctx.paint.end_overlay();
+        }
+    }
+}
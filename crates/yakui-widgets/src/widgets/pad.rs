@@ -1,6 +1,6 @@
 use yakui_core::geometry::{Constraints, Vec2};
 use yakui_core::widget::{LayoutContext, Widget};
-use yakui_core::Response;
+use yakui_core::{LayoutDirection, Response};
 
 use crate::util::widget_children;
 
@@ -9,7 +9,7 @@ Applies padding around a single child widget.
 
 Responds with [PadResponse].
 */
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Pad {
     pub left: f32,
@@ -69,6 +69,7 @@ impl Pad {
 #[derive(Debug)]
 pub struct PadWidget {
     props: Pad,
+    layout_changed: bool,
 }
 
 pub type PadResponse = ();
@@ -78,21 +79,36 @@ impl Widget for PadWidget {
     type Response = PadResponse;
 
     fn new() -> Self {
-        Self { props: Pad::ZERO }
+        Self {
+            props: Pad::ZERO,
+            layout_changed: true,
+        }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.layout_changed = props != self.props;
         self.props = props;
     }
 
+    fn needs_layout(&self) -> bool {
+        self.layout_changed
+    }
+
     fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
         let node = ctx.dom.get_current();
 
+        // Padding is mirrored horizontally for right-to-left layouts, so the
+        // left padding is always on the side where content starts.
+        let left = match ctx.layout.layout_direction() {
+            LayoutDirection::LeftToRight => self.props.left,
+            LayoutDirection::RightToLeft => self.props.right,
+        };
+
         let total_padding = Vec2::new(
             self.props.left + self.props.right,
             self.props.top + self.props.bottom,
         );
-        let offset = Vec2::new(self.props.left, self.props.top);
+        let offset = Vec2::new(left, self.props.top);
 
         let child_constraints = Constraints {
             min: (input.min - total_padding).max(Vec2::ZERO),
@@ -0,0 +1,87 @@
+use std::mem;
+
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::input::ClipboardContent;
+use yakui_core::paint::Texture;
+use yakui_core::widget::{EventContext, Widget};
+use yakui_core::Response;
+
+use crate::util::widget_children;
+
+/**
+Accepts images pasted from the clipboard while its children are selected or
+under the mouse, for widgets like avatar pickers or drawing canvases.
+
+The host is responsible for reading the clipboard when the user pastes, and
+sending what it finds with [`Event::Paste`][yakui_core::event::Event::Paste].
+Pasted text is ignored so that it can reach other widgets.
+
+Responds with [PasteTargetResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+let response = yakui::paste_target(|| {
+    yakui::label("Paste an image here");
+});
+
+if let Some(image) = response.image.as_ref() {
+    println!("Pasted a {}x{} image", image.size().x, image.size().y);
+}
+```
+*/
+#[derive(Debug, Clone, Default)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct PasteTarget {}
+
+impl PasteTarget {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<PasteTargetResponse> {
+        widget_children::<PasteTargetWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct PasteTargetWidget {
+    pasted: Option<Texture>,
+}
+
+#[derive(Debug)]
+pub struct PasteTargetResponse {
+    /// The image that was pasted since the last update, if any.
+    pub image: Option<Texture>,
+}
+
+impl Widget for PasteTargetWidget {
+    type Props<'a> = PasteTarget;
+    type Response = PasteTargetResponse;
+
+    fn new() -> Self {
+        Self { pasted: None }
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
+        PasteTargetResponse {
+            image: mem::take(&mut self.pasted),
+        }
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        // Mouse interest puts this widget into hit tests, which is how pastes
+        // find it while it's hovered.
+        EventInterest::MOUSE_INSIDE | EventInterest::PASTE_TARGET
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match event {
+            WidgetEvent::Paste(ClipboardContent::Image(image)) => {
+                self.pasted = Some(image.clone());
+                EventResponse::Sink
+            }
+            _ => EventResponse::Bubble,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::input::PlayerId;
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::Response;
+
+use crate::util::widget_children;
+
+/**
+Limits its children to only accepting input from one player, for split-screen
+games where each player navigates their own menu with their own controller.
+
+Events sent with [`Yakui::handle_player_event`][yakui_core::Yakui::handle_player_event]
+by other players never reach these children, and keyboard events from this
+player go to the widget this player has selected, which is tracked separately
+from every other player's selection. Events sent without a player, like from
+the mouse, still reach every widget.
+
+Responds with [PlayerInputResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::input::PlayerId;
+
+yakui::row(|| {
+    for player in 0..2 {
+        yakui::expanded(|| {
+            yakui::player_input(PlayerId(player), || {
+                yakui::button("Ready");
+            });
+        });
+    }
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct PlayerInput {
+    pub player: PlayerId,
+}
+
+impl PlayerInput {
+    pub fn new(player: PlayerId) -> Self {
+        Self { player }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<PlayerInputResponse> {
+        widget_children::<PlayerInputWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct PlayerInputWidget {
+    props: PlayerInput,
+}
+
+pub type PlayerInputResponse = ();
+
+impl Widget for PlayerInputWidget {
+    type Props<'a> = PlayerInput;
+    type Response = PlayerInputResponse;
+
+    fn new() -> Self {
+        Self {
+            props: PlayerInput::new(PlayerId(0)),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        ctx.layout.set_player(ctx.dom, self.props.player);
+        self.default_layout(ctx, constraints)
+    }
+}
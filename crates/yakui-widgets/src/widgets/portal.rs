@@ -0,0 +1,94 @@
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::Response;
+
+use crate::util::widget_children;
+
+/**
+Builds its children where it's used, but lays them out and paints them as if
+they were children of the root widget. Children of a portal are laid out within
+the whole viewport, draw above everything else, and aren't clipped or moved by any of the
+portal's ancestors, like a [`Scrollable`][super::Scrollable] or a
+[`Transform`][super::Transform].
+
+This is useful for things like dropdown lists, tooltips, and drag previews that
+are owned by a widget deep inside the tree but need to escape its bounds. The
+portal itself takes up no space. Position children inside of it with widgets
+like [`Absolute`][super::Absolute] or [`Align`][super::Align].
+
+Responds with [PortalResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Positioned;
+
+yakui::scroll_vertical(|| {
+    yakui::portal(|| {
+        yakui::absolute(|| {
+            Positioned::top_left(16.0, 16.0).show(|| {
+                yakui::label("Not clipped by the scroll area");
+            });
+        });
+    });
+});
+```
+*/
+#[derive(Debug, Clone, Default)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Portal {}
+
+impl Portal {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<PortalResponse> {
+        widget_children::<PortalWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct PortalWidget;
+
+pub type PortalResponse = ();
+
+impl Widget for PortalWidget {
+    type Props<'a> = Portal;
+    type Response = PortalResponse;
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+
+    fn paints_outside_rect(&self) -> bool {
+        true
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        ctx.layout.detach(ctx.dom);
+        ctx.layout.new_layer(ctx.dom);
+
+        let viewport = ctx.layout.viewport().size();
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.calculate_layout(child, Constraints::loose(viewport));
+        }
+
+        // Nothing is left behind where the portal was used.
+        constraints.min
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        ctx.paint.begin_overlay();
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+
+        ctx.paint.end_overlay();
+    }
+}
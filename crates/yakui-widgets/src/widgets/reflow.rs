@@ -62,6 +62,12 @@ impl Widget for ReflowWidget {
         }
     }
 
+    fn paints_outside_rect(&self) -> bool {
+        // Reflowed children are positioned relative to this widget, but
+        // usually end up outside of its zero-sized rect.
+        true
+    }
+
     fn layout(&self, mut ctx: LayoutContext<'_>, _constraints: Constraints) -> Vec2 {
         let node = ctx.dom.get_current();
         let mut size = Vec2::ZERO;
@@ -3,11 +3,11 @@ use std::cell::{Cell, RefCell};
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
 use yakui_core::paint::{PaintRect, Pipeline};
 use yakui_core::widget::{LayoutContext, PaintContext, Widget};
-use yakui_core::{Response, TextureId};
+use yakui_core::{context, Response, TextureId};
 
-use crate::font::Fonts;
-use crate::style::{TextAlignment, TextStyle};
-use crate::text_renderer::{GlyphRender, Kind, TextGlobalState};
+use crate::font::{self, Fonts};
+use crate::style::{TextAlignment, TextOverflow, TextRendering, TextStyle};
+use crate::text_renderer::{GlyphEffect, GlyphRender, Kind, TextGlobalState};
 use crate::util::widget;
 
 /**
@@ -25,6 +25,11 @@ pub struct RenderText {
 
 pub struct RenderTextResponse {
     pub size: Option<Vec2>,
+
+    /// Whether the text was truncated to fit, like with
+    /// [`TextOverflow::Ellipsis`], or had lines cut off by
+    /// [`TextStyle::max_lines`].
+    pub truncated: bool,
 }
 
 impl RenderText {
@@ -60,11 +65,18 @@ pub struct RenderTextWidget {
     buffer: RefCell<Option<cosmic_text::Buffer>>,
     line_offsets: RefCell<Vec<f32>>,
     size: Cell<Option<Vec2>>,
+    truncated: Cell<bool>,
+    ellipsized: Cell<bool>,
     last_text: RefCell<String>,
+    last_attrs: RefCell<Option<cosmic_text::AttrsOwned>>,
+    last_fonts_generation: Cell<Option<u64>>,
+    last_overflow: Cell<Option<TextOverflow>>,
+    last_wrap: Cell<Option<cosmic_text::Wrap>>,
     max_size: Cell<Option<(Option<f32>, Option<f32>)>>,
     scale_factor: Cell<Option<f32>>,
     last_scroll: Cell<Option<cosmic_text::Scroll>>,
     scroll: Option<cosmic_text::Scroll>,
+    layout_changed: bool,
 }
 
 impl Widget for RenderTextWidget {
@@ -77,23 +89,41 @@ impl Widget for RenderTextWidget {
             buffer: RefCell::default(),
             line_offsets: RefCell::default(),
             size: Cell::default(),
+            truncated: Cell::default(),
+            ellipsized: Cell::default(),
             last_text: RefCell::new(String::new()),
+            last_attrs: RefCell::default(),
+            last_fonts_generation: Cell::default(),
+            last_overflow: Cell::default(),
+            last_wrap: Cell::default(),
             max_size: Cell::default(),
             scale_factor: Cell::default(),
             last_scroll: Cell::default(),
             scroll: None,
+            layout_changed: true,
         }
     }
 
     fn update(&mut self, (props, scroll): Self::Props<'_>) -> Self::Response {
+        let fonts = context::dom().get_global_or_init(Fonts::default);
+        self.layout_changed = props.text != self.props.text
+            || props.style != self.props.style
+            || scroll != self.scroll
+            || self.last_fonts_generation.get() != Some(fonts.generation());
+
         self.props = props;
         self.scroll = scroll;
 
         Self::Response {
             size: self.size.get(),
+            truncated: self.truncated.get(),
         }
     }
 
+    fn needs_layout(&self) -> bool {
+        self.layout_changed
+    }
+
     fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
         let max_width = constraints
             .max
@@ -108,8 +138,10 @@ impl Widget for RenderTextWidget {
         let max_size = (max_width, max_height);
 
         let fonts = ctx.dom.get_global_or_init(Fonts::default);
+        let fallback = fonts.fallback_families();
+        let fonts_generation = fonts.generation();
 
-        fonts.with_system(|font_system| {
+        let (size, baseline) = fonts.with_system(|font_system| {
             let mut buffer_ref = self.buffer.borrow_mut();
             let buffer = buffer_ref.get_or_insert_with(|| {
                 cosmic_text::Buffer::new(
@@ -118,6 +150,7 @@ impl Widget for RenderTextWidget {
                 )
             });
 
+            let mut size_changed = false;
             if self.scale_factor.get() != Some(ctx.layout.scale_factor())
                 || self.max_size.get() != Some(max_size)
             {
@@ -130,6 +163,7 @@ impl Widget for RenderTextWidget {
 
                 self.max_size.set(Some(max_size));
                 self.scale_factor.set(Some(ctx.layout.scale_factor()));
+                size_changed = true;
             }
 
             if self.last_scroll.get() != self.scroll {
@@ -140,20 +174,65 @@ impl Widget for RenderTextWidget {
                 self.last_scroll.set(self.scroll);
             }
 
-            if self.last_text.borrow().as_str() != self.props.text.as_str() {
-                buffer.set_text(
+            let overflow = self.props.style.overflow;
+            let ellipsis = overflow == TextOverflow::Ellipsis;
+            let overflow_changed = self.last_overflow.get() != Some(overflow);
+
+            let wrap = if ellipsis {
+                cosmic_text::Wrap::None
+            } else {
+                self.props.style.wrap.into()
+            };
+
+            if self.last_wrap.get() != Some(wrap) {
+                buffer.set_wrap(font_system, wrap);
+                self.last_wrap.set(Some(wrap));
+            }
+
+            self.last_overflow.set(Some(overflow));
+
+            // Text needs to be shaped again when its font changes, either
+            // because the style picked another one or because fonts were
+            // loaded or swapped out.
+            let font_changed = self.last_attrs.borrow().as_ref() != Some(&self.props.style.attrs)
+                || self.last_fonts_generation.get() != Some(fonts_generation);
+
+            // Truncated text depends on the available width, so it needs to be
+            // recomputed whenever that changes.
+            if self.last_text.borrow().as_str() != self.props.text.as_str()
+                || overflow_changed
+                || font_changed
+                || (ellipsis && size_changed)
+            {
+                let attrs = self.props.style.attrs.as_attrs();
+                font::set_text_with_fallback(
+                    buffer,
                     font_system,
                     &self.props.text,
-                    self.props.style.attrs.as_attrs(),
-                    cosmic_text::Shaping::Advanced,
+                    attrs,
+                    &fallback,
                 );
 
+                let ellipsized = match (ellipsis, max_width) {
+                    (true, Some(max_width)) => {
+                        truncate_with_ellipsis(buffer, font_system, attrs, &fallback, max_width)
+                    }
+                    _ => false,
+                };
+
+                self.ellipsized.set(ellipsized);
                 self.last_text.replace(self.props.text.clone());
+                self.last_attrs
+                    .replace(Some(self.props.style.attrs.clone()));
+                self.last_fonts_generation.set(Some(fonts_generation));
             }
 
+            // Lines are aligned below once the size of the text is known, so
+            // cosmic-text lays every line out from the left.
+            //
             // Perf note: https://github.com/pop-os/cosmic-text/issues/166
             for buffer_line in buffer.lines.iter_mut() {
-                buffer_line.set_align(Some(self.props.style.align.into()));
+                buffer_line.set_align(Some(cosmic_text::Align::Left));
             }
 
             buffer.shape_until_scroll(font_system, true);
@@ -161,27 +240,26 @@ impl Widget for RenderTextWidget {
             let mut line_offsets = self.line_offsets.borrow_mut();
             line_offsets.clear();
 
+            let max_lines = self.props.style.max_lines.unwrap_or(usize::MAX);
+
+            // How many lines there are depends on wrapping, so lines cut off by
+            // the limit are counted on every layout.
+            let lines_cut = buffer.layout_runs().nth(max_lines).is_some();
+            self.truncated.set(self.ellipsized.get() || lines_cut);
+
             let widest_line = buffer
                 .layout_runs()
+                .take(max_lines)
                 .map(|layout| layout.line_w)
                 .max_by(|a, b| a.total_cmp(b))
                 .unwrap_or_default()
                 .ceil()
                 .max(constraints.min.x * ctx.layout.scale_factor());
 
-            for run in buffer.layout_runs() {
-                let offset = match self.props.style.align {
-                    TextAlignment::Start => 0.0,
-                    TextAlignment::Center => (widest_line - run.line_w) / 2.0,
-                    TextAlignment::End => widest_line - run.line_w,
-                };
-
-                line_offsets.push(offset / ctx.layout.scale_factor());
-            }
-
             let mut size = {
                 let size_y = buffer
                     .layout_runs()
+                    .take(max_lines)
                     .map(|layout| layout.line_height)
                     .sum::<f32>()
                     .ceil();
@@ -198,8 +276,33 @@ impl Widget for RenderTextWidget {
             let size = constraints.constrain(size);
             self.size.set(Some(size));
 
-            size
-        })
+            let width = size.x * ctx.layout.scale_factor();
+            for run in buffer.layout_runs().take(max_lines) {
+                // Right-to-left lines start on the right.
+                let offset = match (self.props.style.align, run.rtl) {
+                    (TextAlignment::Start, false) | (TextAlignment::End, true) => 0.0,
+                    (TextAlignment::Center, _) => (width - run.line_w) / 2.0,
+                    (TextAlignment::Start, true) | (TextAlignment::End, false) => {
+                        width - run.line_w
+                    }
+                };
+
+                line_offsets.push(offset / ctx.layout.scale_factor());
+            }
+
+            let baseline = buffer
+                .layout_runs()
+                .next()
+                .map(|run| run.line_y / ctx.layout.scale_factor());
+
+            (size, baseline)
+        });
+
+        if let Some(baseline) = baseline {
+            ctx.layout.set_baseline(ctx.dom, baseline);
+        }
+
+        size
     }
 
     fn paint(&self, mut ctx: PaintContext<'_>) {
@@ -215,15 +318,46 @@ impl Widget for RenderTextWidget {
             let line_offsets = self.line_offsets.borrow();
             let text_global = ctx.dom.get_global_or_init(TextGlobalState::new);
 
-            for (layout, x_offset) in buffer.layout_runs().zip(line_offsets.iter().copied()) {
-                for glyph in layout.glyphs {
-                    if let Some(render) = text_global.get_or_insert(ctx.paint, font_system, glyph) {
+            // Shadows and outlines are drawn by painting every glyph once more
+            // underneath the text, blurred or grown and in a different color.
+            let mut passes = effect_passes(&self.props.style, ctx.layout.scale_factor());
+            passes.push((Vec2::ZERO, self.props.style.color, None));
+            let text_pass = passes.len() - 1;
+
+            for (index, &(pass_offset, color, effect)) in passes.iter().enumerate() {
+                for (layout, x_offset) in buffer.layout_runs().zip(line_offsets.iter().copied()) {
+                    for glyph in layout.glyphs {
+                        let render = match (effect, self.props.style.rendering) {
+                            (Some(effect), _) => text_global.get_or_insert_effect(
+                                ctx.paint,
+                                font_system,
+                                glyph,
+                                effect,
+                            ),
+                            (None, TextRendering::Raster) => {
+                                text_global.get_or_insert(ctx.paint, font_system, glyph)
+                            }
+                            (None, TextRendering::Sdf) => {
+                                text_global.get_or_insert_sdf(ctx.paint, font_system, glyph)
+                            }
+                        };
+
+                        let Some(render) = render else {
+                            continue;
+                        };
+
+                        // Color glyphs like emoji can't be tinted, so they
+                        // don't get effects.
+                        if index != text_pass && render.kind == Kind::Color {
+                            continue;
+                        }
+
                         paint_text(
                             &mut ctx,
-                            self.props.style.color,
+                            color,
                             glyph,
                             render,
-                            layout_node.rect.pos() + Vec2::new(x_offset, 0.0),
+                            layout_node.rect.pos() + Vec2::new(x_offset, 0.0) + pass_offset,
                             layout.line_y,
                         )
                     }
@@ -233,7 +367,80 @@ impl Widget for RenderTextWidget {
     }
 }
 
-fn paint_text(
+/// Returns the offset, color, and glyph effect of each pass that glyphs are
+/// painted in before the text itself to draw its shadow and outline, from
+/// bottom to top.
+fn effect_passes(style: &TextStyle, scale_factor: f32) -> Vec<(Vec2, Color, Option<GlyphEffect>)> {
+    let mut passes = Vec::new();
+
+    if let Some(shadow) = style.shadow {
+        let blur = GlyphEffect::blur(shadow.blur * scale_factor);
+        passes.push((shadow.offset, shadow.color, blur));
+    }
+
+    if let Some(outline) = style.outline {
+        if let Some(dilate) = GlyphEffect::dilate(outline.thickness * scale_factor) {
+            passes.push((Vec2::ZERO, outline.color, Some(dilate)));
+        }
+    }
+
+    passes
+}
+
+/// Cuts off any lines in the buffer that are wider than `max_width`, replacing
+/// the end of them with an ellipsis. Returns whether any lines were truncated.
+fn truncate_with_ellipsis(
+    buffer: &mut cosmic_text::Buffer,
+    font_system: &mut cosmic_text::FontSystem,
+    attrs: cosmic_text::Attrs<'_>,
+    fallback: &[String],
+    max_width: f32,
+) -> bool {
+    const ELLIPSIS: &str = "\u{2026}";
+
+    buffer.shape_until_scroll(font_system, false);
+    if buffer.layout_runs().all(|run| run.line_w <= max_width) {
+        return false;
+    }
+
+    let ellipsis_width = {
+        let mut measure = cosmic_text::Buffer::new(font_system, buffer.metrics());
+        font::set_text_with_fallback(&mut measure, font_system, ELLIPSIS, attrs, fallback);
+        measure.shape_until_scroll(font_system, false);
+        measure
+            .layout_runs()
+            .map(|run| run.line_w)
+            .next()
+            .unwrap_or_default()
+    };
+
+    let available = max_width - ellipsis_width;
+
+    let lines: Vec<String> = buffer
+        .layout_runs()
+        .map(|run| {
+            if run.line_w <= max_width {
+                return run.text.to_owned();
+            }
+
+            let end = run
+                .glyphs
+                .iter()
+                .take_while(|glyph| glyph.x + glyph.w <= available)
+                .map(|glyph| glyph.end)
+                .max()
+                .unwrap_or(0);
+
+            format!("{}{ELLIPSIS}", run.text[..end].trim_end())
+        })
+        .collect();
+
+    font::set_text_with_fallback(buffer, font_system, &lines.join("\n"), attrs, fallback);
+
+    true
+}
+
+pub(crate) fn paint_text(
     ctx: &mut PaintContext<'_>,
     color: Color,
     glyph: &cosmic_text::LayoutGlyph,
@@ -243,24 +450,27 @@ fn paint_text(
 ) {
     let inv_scale_factor = 1.0 / ctx.layout.scale_factor();
 
-    let size = render.rect.size().as_vec2();
+    let size = render.rect.size().as_vec2() * render.scale;
+    let offset = render.offset * render.scale;
 
     let physical = glyph.physical((0.0, 0.0), 1.0);
     let pos = Vec2::new(physical.x as f32, physical.y as f32);
 
     let mut rect = PaintRect::new(Rect::from_pos_size(
-        Vec2::new(pos.x + render.offset.x, pos.y - render.offset.y + line_y) * inv_scale_factor
-            + layout_pos,
+        Vec2::new(pos.x + offset.x, pos.y - offset.y + line_y) * inv_scale_factor + layout_pos,
         Vec2::new(size.x, size.y) * inv_scale_factor,
     ));
 
-    if render.kind == Kind::Mask {
-        rect.color = color;
-    } else {
+    if render.kind == Kind::Color {
         rect.color = Color::CLEAR;
+    } else {
+        rect.color = color;
     }
     rect.texture = Some((TextureId::Managed(render.texture), render.tex_rect));
-    rect.pipeline = Pipeline::Text;
+    rect.pipeline = match render.kind {
+        Kind::Sdf => Pipeline::TextSdf,
+        Kind::Mask | Kind::Color => Pipeline::Text,
+    };
 
     rect.add(ctx.paint);
 }
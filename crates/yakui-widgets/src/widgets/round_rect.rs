@@ -1,10 +1,13 @@
 use yakui_core::geometry::{Color, Constraints, Vec2};
+use yakui_core::paint::Gradient;
 use yakui_core::widget::{LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
 use crate::shapes;
 use crate::util::{widget, widget_children};
 
+use super::Shadow;
+
 /**
 A colored box with rounded corners that can contain children.
 
@@ -16,6 +19,13 @@ pub struct RoundRect {
     pub radius: f32,
     pub color: Color,
     pub min_size: Vec2,
+
+    /// If set, the box is filled with this gradient instead of `color`.
+    pub gradient: Option<Gradient>,
+
+    /// If set, a shadow is drawn behind the box. The shadow's radius is
+    /// replaced with the radius of the box.
+    pub shadow: Option<Shadow>,
 }
 
 impl RoundRect {
@@ -24,6 +34,8 @@ impl RoundRect {
             radius,
             color: Color::WHITE,
             min_size: Vec2::ZERO,
+            gradient: None,
+            shadow: None,
         }
     }
 
@@ -73,8 +85,15 @@ impl Widget for RoundRectWidget {
         let node = ctx.dom.get_current();
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
 
+        if let Some(shadow) = &self.props.shadow {
+            let mut shadow = shadow.clone();
+            shadow.radius = self.props.radius;
+            shadow.add(ctx.paint, layout_node.rect);
+        }
+
         let mut rect = shapes::RoundedRectangle::new(layout_node.rect, self.props.radius);
         rect.color = self.props.color;
+        rect.gradient = self.props.gradient.clone();
         rect.add(ctx.paint);
 
         for &child in &node.children {
@@ -1,26 +1,89 @@
 use std::cell::Cell;
+use std::time::Duration;
 
+use yakui_core::dom::Dom;
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
 use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::input::ScrollUnit;
 use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
-use yakui_core::Response;
+use yakui_core::{Response, WidgetId};
 
 use crate::util::widget_children;
 
+/// Observed logical pixels per scroll wheel increment in Windows on Chrome.
+pub const DEFAULT_LINE_HEIGHT: f32 = 100.0 / 3.0;
+
+/// How quickly smooth scrolling catches up with where it's headed. The gap
+/// shrinks by a factor of `e` every `1 / SMOOTH_SCROLL_RATE` seconds.
+const SMOOTH_SCROLL_RATE: f32 = 20.0;
+
 #[derive(Debug)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Scrollable {
     pub direction: Option<ScrollDirection>,
+
+    /// If set, each child is laid out with exactly this height and the
+    /// children are stacked vertically, instead of overlapping.
+    ///
+    /// Because every child's position is known ahead of time, children that
+    /// are far enough out of view are not laid out at all. This makes very
+    /// long lists of same-sized rows cheap to show.
+    pub item_extent: Option<f32>,
+
+    /// A widget inside the scrollable that should stay in the same place on
+    /// screen when content around it changes size. If content is inserted
+    /// above the anchor, like older messages being loaded at the top of a
+    /// chat, the scroll position moves by the same amount so that the anchor
+    /// doesn't appear to move.
+    ///
+    /// The anchor is usually a child that was visible last frame. Children
+    /// should be [keyed][super::Keyed] so that they keep their IDs when items
+    /// are inserted before them.
+    pub anchor: Option<WidgetId>,
+
+    /// How far one line of scrolling moves, in logical pixels. Mouse wheels
+    /// usually scroll by lines, while trackpads scroll by pixels.
+    pub line_height: f32,
+
+    /// Whether scrolling by lines glides to the new position instead of
+    /// jumping there. Scrolling by pixels is always applied right away, since
+    /// it's already smooth.
+    ///
+    /// The animation uses [`Dom::time`], so it jumps instead if the host
+    /// doesn't call [`Yakui::set_time`][yakui_core::Yakui::set_time].
+    pub smooth: bool,
 }
 
 impl Scrollable {
     pub fn none() -> Self {
-        Scrollable { direction: None }
+        Scrollable {
+            direction: None,
+            item_extent: None,
+            anchor: None,
+            line_height: DEFAULT_LINE_HEIGHT,
+            smooth: false,
+        }
     }
 
     pub fn vertical() -> Self {
         Scrollable {
             direction: Some(ScrollDirection::Y),
+            item_extent: None,
+            anchor: None,
+            line_height: DEFAULT_LINE_HEIGHT,
+            smooth: false,
+        }
+    }
+
+    /// Create a vertical scrollable whose children are rows of the given
+    /// height. See [`Scrollable::item_extent`].
+    pub fn fixed_rows(item_extent: f32) -> Self {
+        Scrollable {
+            direction: Some(ScrollDirection::Y),
+            item_extent: Some(item_extent),
+            anchor: None,
+            line_height: DEFAULT_LINE_HEIGHT,
+            smooth: false,
         }
     }
 
@@ -39,6 +102,16 @@ pub struct ScrollableWidget {
     props: Scrollable,
     scroll_position: Cell<Vec2>,
     canvas_size: Cell<Vec2>,
+
+    /// The anchor from the last layout and its position within the canvas.
+    anchor_position: Cell<Option<(WidgetId, f32)>>,
+
+    /// Where smooth scrolling is headed, if it's still moving.
+    scroll_target: Cell<Option<Vec2>>,
+
+    /// When smooth scrolling last moved. `None` until the first layout after
+    /// it starts.
+    last_step: Cell<Option<Duration>>,
 }
 
 pub type ScrollableResponse = ();
@@ -52,6 +125,9 @@ impl Widget for ScrollableWidget {
             props: Scrollable::none(),
             scroll_position: Cell::new(Vec2::ZERO),
             canvas_size: Cell::new(Vec2::ZERO),
+            anchor_position: Cell::new(None),
+            scroll_target: Cell::new(None),
+            last_step: Cell::new(None),
         }
     }
 
@@ -61,6 +137,15 @@ impl Widget for ScrollableWidget {
 
     fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
         ctx.layout.enable_clipping(ctx.dom);
+        self.step_smooth_scroll(ctx.dom);
+
+        if let (Some(ScrollDirection::Y), Some(extent)) =
+            (self.props.direction, self.props.item_extent)
+        {
+            if extent > 0.0 {
+                return self.layout_fixed_rows(ctx, constraints, extent);
+            }
+        }
 
         let node = ctx.dom.get_current();
         let mut canvas_size = Vec2::ZERO;
@@ -79,6 +164,12 @@ impl Widget for ScrollableWidget {
         }
         self.canvas_size.set(canvas_size);
 
+        let anchor_y = self
+            .props
+            .anchor
+            .and_then(|anchor| position_in(&ctx, anchor, ctx.dom.current()));
+        self.follow_anchor(anchor_y);
+
         let size = constraints.constrain(canvas_size);
 
         let max_scroll_position = (canvas_size - size).max(Vec2::ZERO);
@@ -94,6 +185,10 @@ impl Widget for ScrollableWidget {
         }
 
         self.scroll_position.set(scroll_position);
+        self.clamp_scroll_target(match self.props.direction {
+            None => Vec2::ZERO,
+            Some(ScrollDirection::Y) => Vec2::new(0.0, max_scroll_position.y),
+        });
 
         for &child in &node.children {
             ctx.layout.set_pos(child, -scroll_position);
@@ -106,7 +201,10 @@ impl Widget for ScrollableWidget {
         let node = ctx.dom.get_current();
 
         for &child in &node.children {
-            ctx.paint(child);
+            // Children that were culled during layout have nothing to paint.
+            if ctx.layout.get(child).is_some() {
+                ctx.paint(child);
+            }
         }
     }
 
@@ -116,12 +214,174 @@ impl Widget for ScrollableWidget {
 
     fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
         match *event {
-            WidgetEvent::MouseScroll { delta } => {
+            WidgetEvent::MouseScroll { delta, unit } => {
                 let pos = self.scroll_position.get();
-                self.scroll_position.set(pos + delta);
+
+                match unit {
+                    ScrollUnit::Lines if self.props.smooth => {
+                        let target = self.scroll_target.get().unwrap_or(pos);
+                        if self.scroll_target.get().is_none() {
+                            self.last_step.set(None);
+                        }
+
+                        self.scroll_target
+                            .set(Some(target + delta * self.props.line_height));
+                    }
+                    ScrollUnit::Lines => {
+                        self.scroll_position
+                            .set(pos + delta * self.props.line_height);
+                    }
+                    ScrollUnit::Pixels => {
+                        self.scroll_position.set(pos + delta);
+
+                        // Keep any glide that's in progress headed to the same
+                        // place relative to the content.
+                        if let Some(target) = self.scroll_target.get() {
+                            self.scroll_target.set(Some(target + delta));
+                        }
+                    }
+                }
+
                 EventResponse::Sink
             }
             _ => EventResponse::Bubble,
         }
     }
 }
+
+impl ScrollableWidget {
+    /// Moves the scroll position part of the way to where smooth scrolling is
+    /// headed, based on how much time passed since it last moved.
+    fn step_smooth_scroll(&self, dom: &Dom) {
+        let Some(target) = self.scroll_target.get() else {
+            return;
+        };
+
+        let now = dom.time();
+        let fraction = if now.is_zero() {
+            // The host isn't keeping time, so the glide would never finish.
+            1.0
+        } else {
+            match self.last_step.replace(Some(now)) {
+                // The first frame of a glide only notes the time, since the
+                // last frame could have been built long before the scroll.
+                None => 0.0,
+
+                Some(last) => {
+                    let elapsed = now.saturating_sub(last).as_secs_f32();
+                    1.0 - (-elapsed * SMOOTH_SCROLL_RATE).exp()
+                }
+            }
+        };
+
+        let pos = self.scroll_position.get();
+        let next = pos + (target - pos) * fraction;
+
+        if next.distance(target) < 0.5 {
+            self.scroll_position.set(target);
+            self.scroll_target.set(None);
+        } else {
+            self.scroll_position.set(next);
+            dom.request_repaint();
+        }
+    }
+
+    /// Keeps smooth scrolling from heading past the ends of the content, where
+    /// it would never arrive.
+    fn clamp_scroll_target(&self, max_scroll_position: Vec2) {
+        let Some(target) = self.scroll_target.get() else {
+            return;
+        };
+
+        let target = target.clamp(Vec2::ZERO, max_scroll_position);
+        if target == self.scroll_position.get() {
+            self.scroll_target.set(None);
+        } else {
+            self.scroll_target.set(Some(target));
+        }
+    }
+
+    /// Moves the scroll position by however much the anchor moved since the
+    /// last layout.
+    fn follow_anchor(&self, anchor_y: Option<f32>) {
+        let current = self.props.anchor.zip(anchor_y);
+
+        if let (Some((old_id, old_y)), Some((id, y))) = (self.anchor_position.get(), current) {
+            if old_id == id {
+                let mut scroll = self.scroll_position.get();
+                scroll.y += y - old_y;
+                self.scroll_position.set(scroll);
+            }
+        }
+
+        self.anchor_position.set(current);
+    }
+
+    fn layout_fixed_rows(
+        &self,
+        mut ctx: LayoutContext<'_>,
+        constraints: Constraints,
+        extent: f32,
+    ) -> Vec2 {
+        let node = ctx.dom.get_current();
+
+        let canvas_height = extent * node.children.len() as f32;
+        let height = canvas_height.clamp(constraints.min.y, constraints.max.y);
+
+        // Rows might not be laid out, but their positions are always known.
+        let anchor_y = self.props.anchor.and_then(|anchor| {
+            let index = node.children.iter().position(|&child| child == anchor)?;
+            Some(index as f32 * extent)
+        });
+        self.follow_anchor(anchor_y);
+
+        let max_scroll = (canvas_height - height).max(0.0);
+        let scroll = self.scroll_position.get().y.clamp(0.0, max_scroll);
+        self.scroll_position.set(Vec2::new(0.0, scroll));
+        self.clamp_scroll_target(Vec2::new(0.0, max_scroll));
+
+        // Rows within one viewport's height of the visible region are still
+        // laid out, so that scrolling a little doesn't reveal empty space
+        // before the next layout pass.
+        let band_start = ((scroll - height) / extent).floor().max(0.0) as usize;
+        let band_end = ((scroll + height * 2.0) / extent).ceil() as usize;
+
+        let row_constraints = Constraints {
+            min: Vec2::new(constraints.min.x, extent),
+            max: Vec2::new(constraints.max.x, extent),
+        };
+
+        let mut width: f32 = 0.0;
+
+        for (index, &child) in node.children.iter().enumerate() {
+            if index < band_start || index >= band_end {
+                ctx.layout.cull(ctx.dom, child);
+                continue;
+            }
+
+            let child_size = ctx.calculate_layout(child, row_constraints);
+            width = width.max(child_size.x);
+
+            ctx.layout
+                .set_pos(child, Vec2::new(0.0, index as f32 * extent - scroll));
+        }
+
+        self.canvas_size.set(Vec2::new(width, canvas_height));
+
+        constraints.constrain(Vec2::new(width, height))
+    }
+}
+
+/// Finds the vertical position of a widget relative to one of its ancestors,
+/// or `None` if it isn't a descendant that has been laid out.
+fn position_in(ctx: &LayoutContext<'_>, id: WidgetId, ancestor: WidgetId) -> Option<f32> {
+    let mut y = 0.0;
+    let mut current = id;
+
+    while current != ancestor {
+        y += ctx.layout.get(current)?.rect.pos().y;
+        current = ctx.dom.get(current)?.parent?;
+    }
+
+    Some(y)
+}
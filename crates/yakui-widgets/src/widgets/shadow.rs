@@ -0,0 +1,110 @@
+use yakui_core::geometry::{Color, Rect, Vec2};
+use yakui_core::paint::{PaintDom, PaintShadow};
+use yakui_core::widget::{PaintContext, Widget};
+use yakui_core::Response;
+
+use crate::util::widget_children;
+
+/**
+Draws a soft drop shadow behind its children, making them look like they float
+above the content behind them.
+
+Responds with [ShadowResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::widgets::Shadow;
+let mut shadow = Shadow::new();
+shadow.radius = 6.0;
+shadow.offset = yakui::geometry::Vec2::new(0.0, 4.0);
+shadow.show(|| {
+    yakui::button("Floating");
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Shadow {
+    /// The color of the shadow at its most opaque.
+    pub color: Color,
+
+    /// The corner radius of the shape casting the shadow.
+    pub radius: f32,
+
+    /// How far the shadow is moved from the children casting it.
+    pub offset: Vec2,
+
+    /// The distance over which the edge of the shadow fades out.
+    pub blur_radius: f32,
+
+    /// How far the shadow is grown past the children casting it.
+    pub spread: f32,
+}
+
+impl Shadow {
+    pub fn new() -> Self {
+        Self {
+            color: Color::BLACK.with_alpha(0.5),
+            radius: 0.0,
+            offset: Vec2::ZERO,
+            blur_radius: 8.0,
+            spread: 0.0,
+        }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<ShadowResponse> {
+        widget_children::<ShadowWidget, F>(children, self)
+    }
+
+    /// Paints this shadow behind the given rectangle. Used by widgets that draw
+    /// their own shadows, like [`RoundRect`][super::RoundRect].
+    pub fn add(&self, output: &mut PaintDom, rect: Rect) {
+        let mut shadow = PaintShadow::new(rect);
+        shadow.radius = self.radius;
+        shadow.color = self.color;
+        shadow.offset = self.offset;
+        shadow.blur_radius = self.blur_radius;
+        shadow.spread = self.spread;
+        shadow.add(output);
+    }
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct ShadowWidget {
+    props: Shadow,
+}
+
+pub type ShadowResponse = ();
+
+impl Widget for ShadowWidget {
+    type Props<'a> = Shadow;
+    type Response = ShadowResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Shadow::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let node = ctx.dom.get_current();
+        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+
+        self.props.add(ctx.paint, layout_node.rect);
+
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+    }
+}
@@ -1,15 +1,14 @@
 use std::cell::Cell;
 
-use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::geometry::{Constraints, Rect, Vec2};
 use yakui_core::widget::{LayoutContext, PaintContext, Widget};
 use yakui_core::Response;
 
-use crate::{colored_circle, colors, draggable, util};
+use crate::{colored_circle, draggable, util};
 
 use crate::colored_box;
-
-const TRACK_COLOR: Color = colors::BACKGROUND_3;
-const KNOB_COLOR: Color = colors::TEXT_MUTED;
+use crate::theme::Theme;
+use crate::util::Changed;
 
 const DEFAULT_WIDTH: f32 = 150.0;
 const TRACK_HEIGHT: f32 = 10.0;
@@ -42,7 +41,19 @@ impl Slider {
 
 #[derive(Debug)]
 pub struct SliderResponse {
+    /// If the user moved the slider to a different value, contains the new
+    /// value.
     pub value: Option<f64>,
+
+    /// Whether the user changed the value this frame. Same as
+    /// `value.is_some()`.
+    pub changed: bool,
+}
+
+impl Changed for SliderResponse {
+    fn changed(&self) -> bool {
+        self.changed
+    }
 }
 
 #[derive(Debug)]
@@ -65,9 +76,11 @@ impl Widget for SliderWidget {
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
 
-        colored_box(TRACK_COLOR, [0.0, TRACK_HEIGHT]);
+        let theme = Theme::current();
+
+        colored_box(theme.background_3, [0.0, TRACK_HEIGHT]);
         let res = draggable(|| {
-            colored_circle(KNOB_COLOR, KNOB_SIZE);
+            colored_circle(theme.text_muted, KNOB_SIZE);
         });
 
         let mut value = self.props.value;
@@ -86,9 +99,15 @@ impl Widget for SliderWidget {
         }
 
         if value != self.props.value {
-            SliderResponse { value: Some(value) }
+            SliderResponse {
+                value: Some(value),
+                changed: true,
+            }
         } else {
-            SliderResponse { value: None }
+            SliderResponse {
+                value: None,
+                changed: false,
+            }
         }
     }
 
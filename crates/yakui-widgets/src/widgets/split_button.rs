@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+
+use yakui_core::widget::Widget;
+use yakui_core::Response;
+
+use crate::util::widget;
+
+use super::dropdown_button::{menu_shortcuts, show_menu};
+use super::{Button, List, MenuItem, Pad};
+
+/**
+A button with a primary action, plus an attached arrow that opens a menu of
+secondary actions.
+
+Responds with [SplitButtonResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+let response = yakui::split_button("Build", ["Build All", "Clean"]);
+
+if response.clicked {
+    println!("Build clicked");
+}
+
+if let Some(index) = response.selected {
+    println!("Secondary action {index} was picked");
+}
+```
+*/
+#[derive(Debug)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct SplitButton {
+    pub text: Cow<'static, str>,
+    pub items: Vec<MenuItem>,
+}
+
+impl SplitButton {
+    pub fn new<S, I, T>(text: S, items: I) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+        I: IntoIterator<Item = T>,
+        T: Into<MenuItem>,
+    {
+        Self {
+            text: text.into(),
+            items: items.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn show(self) -> Response<SplitButtonResponse> {
+        widget::<SplitButtonWidget>(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct SplitButtonWidget {
+    open: bool,
+}
+
+#[derive(Debug)]
+pub struct SplitButtonResponse {
+    /// Whether the primary part of the button was clicked this frame.
+    pub clicked: bool,
+
+    /// The index of the secondary action that was picked from the menu this
+    /// frame, if any.
+    pub selected: Option<usize>,
+
+    /// Whether the menu of secondary actions is currently open.
+    pub open: bool,
+}
+
+impl Widget for SplitButtonWidget {
+    type Props<'a> = SplitButton;
+    type Response = SplitButtonResponse;
+
+    fn new() -> Self {
+        Self { open: false }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        let mut clicked = false;
+
+        List::row().show(|| {
+            clicked = Button::styled(props.text.clone()).show().clicked;
+
+            let mut arrow = Button::styled("\u{25BE}");
+            arrow.padding = Pad::balanced(8.0, 10.0);
+            if arrow.show().clicked {
+                self.open = !self.open;
+            }
+        });
+
+        let mut selected = menu_shortcuts(&props.items);
+        if self.open {
+            let (picked, dismissed) = show_menu(&props.items);
+            selected = selected.or(picked);
+
+            if selected.is_some() || dismissed {
+                self.open = false;
+            }
+        }
+
+        Self::Response {
+            clicked,
+            selected,
+            open: self.open,
+        }
+    }
+}
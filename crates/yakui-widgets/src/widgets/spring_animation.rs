@@ -0,0 +1,111 @@
+use yakui_core::widget::Widget;
+use yakui_core::{context, Response};
+
+use crate::animation::{Spring, SpringState, SpringValue};
+use crate::util::widget;
+
+/**
+Pulls a value toward a target using a [`Spring`].
+
+Unlike [`Tween`][super::Tween], there's no fixed duration: the value keeps its
+momentum when the target changes, so it curves smoothly toward the new target.
+Set [`SpringAnimation::value`] to move the value directly, like while it's being
+dragged, and [`SpringAnimation::velocity`] to give it momentum, like when it's
+let go.
+
+Time comes from [`Yakui::set_time`][yakui_core::Yakui::set_time]. If the host
+doesn't call it, the value jumps straight to the target.
+
+Responds with [SpringAnimationResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::animation::Spring;
+
+let open = true;
+let target: f32 = if open { 250.0 } else { 0.0 };
+
+let width = yakui::use_spring(target, Spring::WOBBLY);
+yakui::colored_box(yakui::Color::GRAY, [width.value, 40.0]);
+```
+*/
+#[derive(Debug, Clone, Copy)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct SpringAnimation<T> {
+    pub target: T,
+    pub spring: Spring,
+
+    /// If set, the value is moved here before the spring is stepped.
+    pub value: Option<T>,
+
+    /// If set, replaces how fast the value is moving, in units per second.
+    pub velocity: Option<T>,
+}
+
+impl<T: SpringValue> SpringAnimation<T> {
+    pub fn new(target: T, spring: Spring) -> Self {
+        Self {
+            target,
+            spring,
+            value: None,
+            velocity: None,
+        }
+    }
+
+    pub fn show(self) -> Response<SpringAnimationResponse<T>> {
+        widget::<SpringAnimationWidget<T>>(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct SpringAnimationWidget<T> {
+    state: SpringState<T>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SpringAnimationResponse<T> {
+    /// The value for this frame.
+    pub value: T,
+
+    /// How fast the value is moving, in units per second.
+    pub velocity: T,
+
+    /// Whether the value is still moving or hasn't settled on the target.
+    pub moving: bool,
+}
+
+impl<T: SpringValue> Widget for SpringAnimationWidget<T> {
+    type Props<'a> = SpringAnimation<T>;
+    type Response = SpringAnimationResponse<T>;
+
+    fn new() -> Self {
+        Self {
+            state: SpringState::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        if let Some(value) = props.value {
+            self.state.set_value(value);
+        }
+
+        if let Some(velocity) = props.velocity {
+            self.state.set_velocity(velocity);
+        }
+
+        let dom = context::dom();
+        let value = self.state.update(props.target, props.spring, dom.time());
+
+        let moving = self.state.is_moving(props.target);
+        if moving {
+            dom.request_repaint();
+        }
+
+        SpringAnimationResponse {
+            value,
+            velocity: self.state.velocity(),
+            moving,
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use yakui_core::{widget::Widget, Response};
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{Alignment, Response};
 
 use crate::util::widget_children;
 
@@ -6,7 +8,8 @@ use crate::util::widget_children;
 A [Stack] widget. This widget does nothing interesting on its own, but
 when used "inside" other layouts, such as [List](crate::widgets::List),
 it will stacks its own children, rather than following the layout of its own parent.
-This internal layouting is just using yakui's default layout algorithm.
+Children are laid out on top of each other, and the stack is as big as its
+largest child.
 
 Responds with [StackResponse].
 
@@ -25,14 +28,49 @@ yakui::column(|| {
     yakui::label("on bottom");
 });
 ```
+
+Children are placed in the top-left corner of the stack unless they're wrapped
+in a [StackItem], which gives a child its own alignment and offset:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{Stack, StackItem};
+use yakui::{Alignment, Vec2};
+
+Stack::new().show(|| {
+    StackItem::new(Alignment::TOP_LEFT).show(|| {
+        yakui::label("Health: 100");
+    });
+
+    StackItem::new(Alignment::CENTER).show(|| {
+        yakui::label("+");
+    });
+
+    let mut minimap = StackItem::new(Alignment::BOTTOM_RIGHT);
+    minimap.offset = Vec2::new(-8.0, -8.0);
+    minimap.show(|| {
+        yakui::label("minimap");
+    });
+});
+```
 */
-#[derive(Debug, Default)]
-pub struct Stack {}
+#[derive(Debug)]
+pub struct Stack {
+    /// Where children that aren't wrapped in a [StackItem] are placed.
+    pub alignment: Alignment,
+}
+
+impl Default for Stack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Stack {
     /// Creates a new [Stack].
     pub fn new() -> Self {
-        Self {}
+        Self {
+            alignment: Alignment::TOP_LEFT,
+        }
     }
 
     /// Shows the [Stack] along with its children.
@@ -42,7 +80,9 @@ impl Stack {
 }
 
 #[derive(Debug)]
-pub struct StackWidget;
+pub struct StackWidget {
+    props: Stack,
+}
 
 pub type StackResponse = ();
 
@@ -52,10 +92,105 @@ impl Widget for StackWidget {
     type Response = StackResponse;
 
     fn new() -> Self {
-        Self
+        Self {
+            props: Stack::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+
+        let mut size = Vec2::ZERO;
+        for &child in &node.children {
+            // Stack items can be smaller than the stack so that they have room
+            // to be aligned within it.
+            let is_item = ctx
+                .dom
+                .get(child)
+                .unwrap()
+                .widget
+                .downcast_ref::<StackItemWidget>()
+                .is_some();
+            let child_constraints = if is_item {
+                Constraints::loose(constraints.max)
+            } else {
+                constraints
+            };
+
+            let child_size = ctx.calculate_layout(child, child_constraints);
+            size = size.max(child_size);
+        }
+
+        let size = constraints.constrain_min(size);
+
+        for &child in &node.children {
+            let dom_node = ctx.dom.get(child).unwrap();
+            let (alignment, offset) = match dom_node.widget.downcast_ref::<StackItemWidget>() {
+                Some(item) => (item.props.alignment, item.props.offset),
+                None => (self.props.alignment, Vec2::ZERO),
+            };
+
+            let layout = ctx.layout.get_mut(child).unwrap();
+            let align = alignment.as_vec2();
+            let pos = align * size - align * layout.rect.size() + offset;
+            layout.rect.set_pos(pos);
+        }
+
+        size
+    }
+}
+
+/**
+Places a child of a [Stack] somewhere other than the stack's default position.
+Outside of a stack, this widget has no effect.
+
+Responds with [StackItemResponse].
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct StackItem {
+    /// Where the child is placed within the stack.
+    pub alignment: Alignment,
+
+    /// Moves the child from its aligned position, in logical pixels.
+    pub offset: Vec2,
+}
+
+impl StackItem {
+    pub fn new(alignment: Alignment) -> Self {
+        Self {
+            alignment,
+            offset: Vec2::ZERO,
+        }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<StackItemResponse> {
+        widget_children::<StackItemWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct StackItemWidget {
+    props: StackItem,
+}
+
+pub type StackItemResponse = ();
+
+impl Widget for StackItemWidget {
+    type Props<'a> = StackItem;
+    type Response = StackItemResponse;
+
+    fn new() -> Self {
+        Self {
+            props: StackItem::new(Alignment::TOP_LEFT),
+        }
     }
 
-    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
-        // nothing here
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
     }
 }
@@ -1,15 +1,36 @@
+use std::any::Any;
+use std::borrow::Cow;
 use std::cell::{Ref, RefCell, RefMut};
+use std::collections::HashMap;
 use std::fmt;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 use yakui_core::widget::Widget;
 use yakui_core::Response;
 
 use crate::util;
 
+/**
+Holds a piece of state that persists between updates for as long as this
+widget stays in the tree.
+
+State can optionally be given a key with [`State::key`]. Keyed state can be
+read from anywhere else in the tree, like a sibling subtree, using a
+[`StateHandle`].
+
+Responds with [StateResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+let counter = yakui::use_state(|| 0);
+counter.modify(|count| count + 1);
+```
+*/
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct State<T> {
     default: Box<dyn FnOnce() -> T>,
+    key: Option<Cow<'static, str>>,
 }
 
 impl<T: 'static> State<T> {
@@ -19,9 +40,17 @@ impl<T: 'static> State<T> {
     {
         Self {
             default: Box::new(default),
+            key: None,
         }
     }
 
+    /// Registers this state under the given key so that it can be read from
+    /// other parts of the tree with a [`StateHandle`].
+    pub fn key<S: Into<Cow<'static, str>>>(mut self, key: S) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
     pub fn show(self) -> Response<StateResponse<T>> {
         util::widget::<StateWidget<T>>(self)
     }
@@ -29,14 +58,27 @@ impl<T: 'static> State<T> {
 
 impl<T> fmt::Debug for State<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.pad("State")
+        f.debug_struct("State").field("key", &self.key).finish()
     }
 }
 
+/// Implemented by state types that can be updated with [`StateResponse::dispatch`].
+pub trait Reducer<A> {
+    fn reduce(&mut self, action: A);
+}
+
 pub struct StateResponse<T> {
     value: Rc<RefCell<T>>,
 }
 
+impl<T> Clone for StateResponse<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+        }
+    }
+}
+
 impl<T> StateResponse<T> {
     pub fn borrow(&self) -> Ref<'_, T> {
         self.value.borrow()
@@ -49,6 +91,30 @@ impl<T> StateResponse<T> {
     pub fn set(&self, value: T) {
         self.value.replace(value);
     }
+
+    /// Updates the state by handing the given action to its [`Reducer`]
+    /// implementation.
+    pub fn dispatch<A>(&self, action: A)
+    where
+        T: Reducer<A>,
+    {
+        self.value.borrow_mut().reduce(action);
+    }
+}
+
+impl<T: PartialEq> StateResponse<T> {
+    /// Replaces the state only if the new value differs from the current one.
+    /// Returns whether the state was changed.
+    pub fn set_if_changed(&self, value: T) -> bool {
+        let mut handle = self.value.borrow_mut();
+
+        if *handle == value {
+            false
+        } else {
+            *handle = value;
+            true
+        }
+    }
 }
 
 impl<T: Copy> StateResponse<T> {
@@ -65,6 +131,67 @@ impl<T: Copy> StateResponse<T> {
     }
 }
 
+/// Looks up state that was registered with [`State::key`].
+///
+/// Keyed state is visible to handles for as long as the [`State`] widget that
+/// owns it stays in the tree. If the owning widget is built later in the same
+/// update, the handle sees the value from the previous update.
+pub struct StateHandle<T> {
+    key: Cow<'static, str>,
+    _phantom: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> StateHandle<T> {
+    pub fn new<S: Into<Cow<'static, str>>>(key: S) -> Self {
+        Self {
+            key: key.into(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Fetches the state, if a [`State`] with this key and type is in the
+    /// tree.
+    pub fn get(&self) -> Option<StateResponse<T>> {
+        let registry = KeyedStates::get();
+        let entries = registry.entries.borrow();
+        let value = entries.get(&self.key)?.upgrade()?;
+        let value = value.downcast::<RefCell<T>>().ok()?;
+
+        Some(StateResponse { value })
+    }
+}
+
+impl<T> fmt::Debug for StateHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StateHandle")
+            .field("key", &self.key)
+            .finish()
+    }
+}
+
+/// Every live keyed state by its key. Entries are weak so that states are
+/// dropped once no widget holds a handle to them.
+type KeyedEntries = HashMap<Cow<'static, str>, Weak<dyn Any>>;
+
+#[derive(Clone, Default)]
+struct KeyedStates {
+    entries: Rc<RefCell<KeyedEntries>>,
+}
+
+impl KeyedStates {
+    fn get() -> Self {
+        yakui_core::context::dom().get_global_or_init(Self::default)
+    }
+
+    fn register<T: 'static>(&self, key: Cow<'static, str>, value: &Rc<RefCell<T>>) {
+        let value: Rc<dyn Any> = value.clone();
+        let mut entries = self.entries.borrow_mut();
+
+        entries.retain(|_, entry| entry.strong_count() > 0);
+        entries.insert(key, Rc::downgrade(&value));
+    }
+}
+
 pub struct StateWidget<T> {
     value: Option<Rc<RefCell<T>>>,
 }
@@ -83,6 +210,10 @@ impl<T: 'static> Widget for StateWidget<T> {
             .get_or_insert_with(|| Rc::new(RefCell::new((props.default)())))
             .clone();
 
+        if let Some(key) = props.key {
+            KeyedStates::get().register(key, &value);
+        }
+
         StateResponse { value }
     }
 }
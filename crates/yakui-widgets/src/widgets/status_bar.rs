@@ -0,0 +1,279 @@
+use std::borrow::Cow;
+
+use yakui_core::geometry::{Color, Constraints, Vec2};
+use yakui_core::input::TabIndex;
+use yakui_core::paint::PaintRect;
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::{Alignment, Response};
+
+use crate::colors;
+use crate::style::{TextAlignment, TextOverflow, TextStyle};
+use crate::util::widget;
+
+use super::{Align, Button, DynamicButtonStyle, List, Pad, RenderText};
+
+/**
+A bar pinned to the bottom edge of its container, split into left, center, and
+right regions of text segments.
+
+The left and right regions are always shown in full. The center region takes
+up whatever space is left between them, and its text is cut off with an
+ellipsis if it doesn't fit.
+
+Responds with [StatusBarResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::widgets::{StatusBar, StatusSegment};
+let mut bar = StatusBar::new();
+bar.left.push(StatusSegment::new("main.rs"));
+bar.center = Some(StatusSegment::new("Compiling...").click_through());
+bar.right.push(StatusSegment::new("Ln 12, Col 4"));
+
+let response = bar.show();
+if let Some((region, index)) = response.clicked {
+    println!("Clicked segment {index} in {region:?}");
+}
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct StatusBar {
+    pub left: Vec<StatusSegment>,
+    pub center: Option<StatusSegment>,
+    pub right: Vec<StatusSegment>,
+    pub color: Color,
+    pub style: TextStyle,
+    pub padding: Pad,
+}
+
+/// A piece of text shown in a [`StatusBar`].
+#[derive(Debug, Clone)]
+pub struct StatusSegment {
+    pub text: Cow<'static, str>,
+
+    /// If true, the segment ignores the mouse, letting clicks fall through to
+    /// whatever is behind it. Otherwise, clicks on the segment are reported in
+    /// [`StatusBarResponse::clicked`].
+    pub click_through: bool,
+}
+
+impl StatusSegment {
+    pub fn new<S: Into<Cow<'static, str>>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            click_through: false,
+        }
+    }
+
+    pub fn click_through(mut self) -> Self {
+        self.click_through = true;
+        self
+    }
+}
+
+/// Identifies one of the regions of a [`StatusBar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusBarRegion {
+    Left,
+    Center,
+    Right,
+}
+
+impl StatusBar {
+    pub fn new() -> Self {
+        let mut style = TextStyle::label();
+        style.font_size = 12.0;
+        style.color = colors::TEXT_MUTED;
+
+        Self {
+            left: Vec::new(),
+            center: None,
+            right: Vec::new(),
+            color: colors::BACKGROUND_1,
+            style,
+            padding: Pad::balanced(8.0, 4.0),
+        }
+    }
+
+    pub fn show(self) -> Response<StatusBarResponse> {
+        let mut response = None;
+
+        Align::new(Alignment::BOTTOM_LEFT).show(|| {
+            response = Some(widget::<StatusBarWidget>(self));
+        });
+
+        response.unwrap()
+    }
+}
+
+impl Default for StatusBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct StatusBarWidget {
+    props: StatusBar,
+}
+
+#[derive(Debug)]
+pub struct StatusBarResponse {
+    /// The region and index within that region of the segment that was
+    /// clicked this frame, if any.
+    pub clicked: Option<(StatusBarRegion, usize)>,
+}
+
+impl Widget for StatusBarWidget {
+    type Props<'a> = StatusBar;
+    type Response = StatusBarResponse;
+
+    fn new() -> Self {
+        Self {
+            props: StatusBar::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+
+        let mut clicked = None;
+
+        let mut row = List::row();
+        row.item_spacing = self.props.padding.left;
+        row.show(|| {
+            for (index, segment) in self.props.left.iter().enumerate() {
+                if show_segment(segment, &self.props.style, self.props.color) {
+                    clicked = Some((StatusBarRegion::Left, index));
+                }
+            }
+        });
+
+        let mut center_style = self.props.style.clone();
+        center_style.overflow = TextOverflow::Ellipsis;
+        center_style.align = TextAlignment::Center;
+
+        let center = self
+            .props
+            .center
+            .clone()
+            .unwrap_or_else(|| StatusSegment::new("").click_through());
+        if show_segment(&center, &center_style, self.props.color) {
+            clicked = Some((StatusBarRegion::Center, 0));
+        }
+
+        let mut row = List::row();
+        row.item_spacing = self.props.padding.right;
+        row.show(|| {
+            for (index, segment) in self.props.right.iter().enumerate() {
+                if show_segment(segment, &self.props.style, self.props.color) {
+                    clicked = Some((StatusBarRegion::Right, index));
+                }
+            }
+        });
+
+        StatusBarResponse { clicked }
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let &[left, center, right] = node.children.as_slice() else {
+            return constraints.min;
+        };
+
+        let padding = self.props.padding;
+        let inner_max = Vec2::new(
+            constraints.max.x - padding.left - padding.right,
+            constraints.max.y - padding.top - padding.bottom,
+        )
+        .max(Vec2::ZERO);
+
+        let left_size = ctx.calculate_layout(left, Constraints::loose(inner_max));
+        let right_size = ctx.calculate_layout(right, Constraints::loose(inner_max));
+
+        let spacing = padding.left + padding.right;
+        let center_max = Vec2::new(
+            (inner_max.x - left_size.x - right_size.x - spacing * 2.0).max(0.0),
+            inner_max.y,
+        );
+        let center_size = ctx.calculate_layout(center, Constraints::loose(center_max));
+
+        let height = left_size.y.max(center_size.y).max(right_size.y);
+        let inner_width = if inner_max.x.is_finite() {
+            inner_max.x
+        } else {
+            left_size.x + center_size.x + right_size.x + spacing * 2.0
+        };
+
+        let center_y = |size: Vec2| padding.top + (height - size.y) / 2.0;
+
+        ctx.layout
+            .set_pos(left, Vec2::new(padding.left, center_y(left_size)));
+
+        ctx.layout.set_pos(
+            right,
+            Vec2::new(
+                padding.left + inner_width - right_size.x,
+                center_y(right_size),
+            ),
+        );
+
+        // Keep the center region centered in the bar when there's room, but
+        // never let it overlap the left or right regions.
+        let center_start = left_size.x + spacing;
+        let center_end = inner_width - right_size.x - spacing - center_size.x;
+        let center_x = ((inner_width - center_size.x) / 2.0)
+            .min(center_end)
+            .max(center_start);
+        ctx.layout.set_pos(
+            center,
+            Vec2::new(padding.left + center_x, center_y(center_size)),
+        );
+
+        constraints.constrain(Vec2::new(
+            inner_width + padding.left + padding.right,
+            height + padding.top + padding.bottom,
+        ))
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let node = ctx.dom.get_current();
+        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+
+        let mut rect = PaintRect::new(layout_node.rect);
+        rect.color = self.props.color;
+        rect.add(ctx.paint);
+
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+    }
+}
+
+/// Shows one segment, returning whether it was clicked. Segments that can be
+/// clicked are buttons that blend in with the bar until they're hovered.
+fn show_segment(segment: &StatusSegment, style: &TextStyle, bar_color: Color) -> bool {
+    if segment.click_through {
+        RenderText::with_style(segment.text.clone(), style.clone()).show();
+        return false;
+    }
+
+    let mut button = Button::unstyled(segment.text.clone());
+    button.tab_index = TabIndex::Skip;
+    button.style = DynamicButtonStyle {
+        text: style.clone(),
+        fill: Color::CLEAR,
+    };
+    button.hover_style = DynamicButtonStyle {
+        fill: bar_color.adjust(1.2),
+        ..button.style.clone()
+    };
+    button.down_style = DynamicButtonStyle {
+        fill: bar_color.adjust(0.8),
+        ..button.style.clone()
+    };
+
+    button.show().clicked
+}
@@ -0,0 +1,117 @@
+use yakui_core::geometry::{Color, UVec2, Vec2};
+use yakui_core::widget::Widget;
+use yakui_core::{context, Response, TextureId};
+
+use crate::util::widget;
+
+use super::{Image, ImageFit};
+
+/**
+Displays a texture whose contents change all the time, like video playback, a
+webcam feed, or a preview of another render target.
+
+Unlike [`Image`], this widget asks for a new frame every time it's shown, so
+hosts that only draw when yakui says something changed keep showing new
+frames. Set `live` to false while the stream is paused to let the application
+go idle.
+
+Managed textures can be updated in place with
+[`Yakui::texture_mut`][yakui_core::Yakui::texture_mut] followed by
+[`Yakui::mark_texture_modified`][yakui_core::Yakui::mark_texture_modified], or
+[`Yakui::mark_texture_region_modified`][yakui_core::Yakui::mark_texture_region_modified]
+if only part of it changed.
+
+Responds with [StreamingImageResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# let video = yakui::TextureId::User(0);
+# use yakui::widgets::StreamingImage;
+# use yakui::{UVec2, Vec2};
+let mut image = StreamingImage::new(video, Vec2::new(320.0, 180.0));
+
+// The renderer owns this texture, so yakui needs to be told how big its
+// frames are to keep their aspect ratio.
+image.frame_size = Some(UVec2::new(1920, 1080));
+image.show();
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct StreamingImage {
+    /// The texture frames are drawn to. If unset, nothing is shown, which is
+    /// useful while waiting for the first frame.
+    pub texture: Option<TextureId>,
+    pub size: Vec2,
+
+    /// The size of each frame in pixels. yakui looks this up for textures it
+    /// manages, but it has to be given for other textures to keep their aspect
+    /// ratio.
+    pub frame_size: Option<UVec2>,
+
+    /// How frames are sized to fit into the widget. Defaults to
+    /// [`ImageFit::Contain`].
+    pub fit: ImageFit,
+
+    /// A color multiplied with each frame.
+    pub color: Color,
+
+    /// Whether the texture is currently changing. While true, the widget asks
+    /// for a new frame every frame.
+    pub live: bool,
+}
+
+impl StreamingImage {
+    pub fn new<I>(texture: I, size: Vec2) -> Self
+    where
+        I: Into<TextureId>,
+    {
+        Self {
+            texture: Some(texture.into()),
+            size,
+            frame_size: None,
+            fit: ImageFit::Contain,
+            color: Color::WHITE,
+            live: true,
+        }
+    }
+
+    pub fn show(self) -> Response<StreamingImageResponse> {
+        widget::<StreamingImageWidget>(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct StreamingImageWidget;
+
+pub type StreamingImageResponse = ();
+
+impl Widget for StreamingImageWidget {
+    type Props<'a> = StreamingImage;
+    type Response = StreamingImageResponse;
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        // The DOM doesn't change when a new frame arrives, so nothing else
+        // would tell the host to draw it.
+        if props.live {
+            context::dom().request_repaint();
+        }
+
+        Image {
+            image: props.texture,
+            size: props.size,
+            color: props.color,
+            source: None,
+            fit: props.fit,
+            flip_x: false,
+            flip_y: false,
+            texture_size: props.frame_size,
+        }
+        .show();
+    }
+}
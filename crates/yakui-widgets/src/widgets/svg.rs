@@ -0,0 +1,220 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg;
+use yakui_core::geometry::{Color, Constraints, Rect, UVec2, Vec2};
+use yakui_core::paint::{PaintRect, Texture, TextureFormat};
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::{context, ManagedTextureId, Response};
+
+use crate::util::widget;
+
+/// A parsed SVG document, ready to be shown with [`Svg`].
+///
+/// Parsing is relatively expensive, so documents should be parsed once and
+/// reused between frames. Cloning is cheap.
+#[derive(Clone)]
+pub struct SvgData {
+    tree: Rc<usvg::Tree>,
+}
+
+impl SvgData {
+    /// Parse an SVG document from its source text or gzip-compressed bytes.
+    pub fn from_data(data: &[u8]) -> Result<Self, usvg::Error> {
+        let tree = usvg::Tree::from_data(data, &usvg::Options::default())?;
+
+        Ok(Self {
+            tree: Rc::new(tree),
+        })
+    }
+
+    /// The size the document asks to be shown at, in logical pixels.
+    pub fn size(&self) -> Vec2 {
+        let size = self.tree.size();
+        Vec2::new(size.width(), size.height())
+    }
+}
+
+impl fmt::Debug for SvgData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SvgData")
+            .field("size", &self.size())
+            .finish_non_exhaustive()
+    }
+}
+
+/**
+Displays a vector image from an SVG document.
+
+The image is rasterized at the UI's scale factor, and again whenever its size on
+screen changes, so it stays crisp at any size.
+
+Responds with [SvgResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::widgets::{Svg, SvgData};
+let icon = SvgData::from_data(br#"
+    <svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">
+        <circle cx="8" cy="8" r="6" fill="white"/>
+    </svg>
+"#).unwrap();
+
+Svg::new(icon).show();
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Svg {
+    pub data: Option<SvgData>,
+
+    /// The size to show the image at. If unset, the size from the document is
+    /// used.
+    pub size: Option<Vec2>,
+
+    /// A color multiplied with the image, useful for tinting single-color
+    /// icons.
+    pub color: Color,
+}
+
+impl Svg {
+    pub fn new(data: SvgData) -> Self {
+        Self {
+            data: Some(data),
+            size: None,
+            color: Color::WHITE,
+        }
+    }
+
+    pub fn show(self) -> Response<SvgResponse> {
+        widget::<SvgWidget>(self)
+    }
+}
+
+/// Textures that belonged to removed [`Svg`] widgets and still need to be
+/// freed. Widgets can't reach the paint DOM when they're dropped, so the next
+/// `Svg` to paint takes care of it instead.
+#[derive(Debug, Default, Clone)]
+struct SvgGarbage(Rc<RefCell<Vec<ManagedTextureId>>>);
+
+#[derive(Debug)]
+struct Raster {
+    texture: ManagedTextureId,
+    size: UVec2,
+    data: SvgData,
+}
+
+#[derive(Debug)]
+pub struct SvgWidget {
+    props: Svg,
+    raster: RefCell<Option<Raster>>,
+    garbage: SvgGarbage,
+}
+
+pub type SvgResponse = ();
+
+impl Widget for SvgWidget {
+    type Props<'a> = Svg;
+    type Response = SvgResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Svg {
+                data: None,
+                size: None,
+                color: Color::WHITE,
+            },
+            raster: RefCell::new(None),
+            garbage: context::dom().get_global_or_init(SvgGarbage::default),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, _ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let size = match (&self.props.size, &self.props.data) {
+            (Some(size), _) => *size,
+            (None, Some(data)) => data.size(),
+            (None, None) => Vec2::ZERO,
+        };
+
+        input.constrain_min(size)
+    }
+
+    fn paint(&self, ctx: PaintContext<'_>) {
+        for texture in self.garbage.0.borrow_mut().drain(..) {
+            ctx.paint.remove_texture(texture);
+        }
+
+        let Some(data) = &self.props.data else {
+            return;
+        };
+
+        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+        let rect = layout_node.rect;
+        let size = (rect.size() * ctx.layout.scale_factor()).round().as_uvec2();
+
+        if size.x == 0 || size.y == 0 {
+            return;
+        }
+
+        let mut raster = self.raster.borrow_mut();
+        let stale = match &*raster {
+            Some(raster) => raster.size != size || !Rc::ptr_eq(&raster.data.tree, &data.tree),
+            None => true,
+        };
+
+        if stale {
+            if let Some(old) = raster.take() {
+                ctx.paint.remove_texture(old.texture);
+            }
+
+            let Some(texture) = rasterize(&data.tree, size) else {
+                return;
+            };
+
+            *raster = Some(Raster {
+                texture: ctx.paint.add_texture(texture),
+                size,
+                data: data.clone(),
+            });
+        }
+
+        if let Some(raster) = &*raster {
+            let mut paint_rect = PaintRect::new(rect);
+            paint_rect.color = self.props.color;
+            paint_rect.texture = Some((raster.texture.into(), Rect::ONE));
+            paint_rect.add(ctx.paint);
+        }
+    }
+}
+
+impl Drop for SvgWidget {
+    fn drop(&mut self) {
+        if let Some(raster) = self.raster.get_mut().take() {
+            self.garbage.0.borrow_mut().push(raster.texture);
+        }
+    }
+}
+
+fn rasterize(tree: &usvg::Tree, size: UVec2) -> Option<Texture> {
+    let mut pixmap = Pixmap::new(size.x, size.y)?;
+
+    let tree_size = tree.size();
+    let transform = Transform::from_scale(
+        size.x as f32 / tree_size.width(),
+        size.y as f32 / tree_size.height(),
+    );
+    resvg::render(tree, transform, &mut pixmap.as_mut());
+
+    Some(Texture::new(
+        TextureFormat::Rgba8SrgbPremultiplied,
+        size,
+        pixmap.take(),
+    ))
+}
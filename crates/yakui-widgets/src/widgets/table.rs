@@ -0,0 +1,354 @@
+use std::borrow::Cow;
+
+use yakui_core::geometry::{Constraints, Dim2, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{context, Alignment, CrossAxisAlignment, MainAxisSize, Pivot, Response};
+
+use crate::style::TextAlignment;
+use crate::theme::Theme;
+
+use super::{
+    Button, Checkbox, ColoredBox, DismissOnOutsideClick, FocusScope, Grid, GridTrack, GridWidget,
+    Layer, List, Pad, Reflow, Text, TextBox,
+};
+
+/**
+A grid of rows under a header with a title for each column. Columns can have
+a filter, which is edited in a popup that opens from the column's header.
+
+The table doesn't hide rows by itself. The rows are built by a closure that's
+given the table's response, which holds the filters that are active, and can
+use [TableResponse::matches] to skip rows that are filtered out. Each row
+should show one widget per column.
+
+Responds with [TableResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{ColumnFilter, Table, TableColumn};
+
+let fruits = [("Apple", "Red"), ("Banana", "Yellow"), ("Cherry", "Red")];
+
+let columns = vec![
+    TableColumn::new("Name").filter(ColumnFilter::Text),
+    TableColumn::new("Color").filter(ColumnFilter::values(["Red", "Yellow"])),
+];
+
+Table::new(columns).show(|table| {
+    for (name, color) in fruits {
+        if table.matches([name, color]) {
+            yakui::label(name);
+            yakui::label(color);
+        }
+    }
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Table {
+    pub columns: Vec<TableColumn>,
+
+    /// Space between each column.
+    pub column_gap: f32,
+
+    /// Space between each row, including the header.
+    pub row_gap: f32,
+}
+
+/// A column of a [`Table`].
+#[derive(Debug, Clone)]
+pub struct TableColumn {
+    /// Shown in the column's header.
+    pub title: Cow<'static, str>,
+
+    /// How wide the column should be. Columns share the table's width evenly by
+    /// default.
+    pub track: GridTrack,
+
+    /// How the column can be filtered.
+    pub filter: ColumnFilter,
+}
+
+impl TableColumn {
+    pub fn new<S: Into<Cow<'static, str>>>(title: S) -> Self {
+        Self {
+            title: title.into(),
+            track: GridTrack::Flex(1.0),
+            filter: ColumnFilter::None,
+        }
+    }
+
+    pub fn track(mut self, track: GridTrack) -> Self {
+        self.track = track;
+        self
+    }
+
+    pub fn filter(mut self, filter: ColumnFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+/// How a column of a [`Table`] can be filtered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnFilter {
+    /// The column can't be filtered.
+    None,
+
+    /// The popup has a text box, and only cells that contain its text, ignoring
+    /// case, are kept.
+    Text,
+
+    /// The popup has a checkbox for each value the column can have, and only
+    /// cells with a checked value are kept.
+    Values(Vec<Cow<'static, str>>),
+}
+
+impl ColumnFilter {
+    pub fn values<I, S>(values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        Self::Values(values.into_iter().map(Into::into).collect())
+    }
+}
+
+/// The filter that the user set on a column of a [`Table`]. A column that
+/// can't be filtered always has an empty filter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterState {
+    /// The text that cells have to contain, for [`ColumnFilter::Text`].
+    pub text: String,
+
+    /// The values that were unchecked, for [`ColumnFilter::Values`].
+    pub hidden: Vec<Cow<'static, str>>,
+}
+
+impl FilterState {
+    /// Whether this filter hides any cells.
+    pub fn is_active(&self) -> bool {
+        !self.text.is_empty() || !self.hidden.is_empty()
+    }
+
+    /// Whether a cell with the given value is kept by this filter.
+    pub fn matches(&self, value: &str) -> bool {
+        if self.hidden.iter().any(|hidden| hidden == value) {
+            return false;
+        }
+
+        self.text.is_empty() || value.to_lowercase().contains(&self.text.to_lowercase())
+    }
+}
+
+impl Table {
+    pub fn new(columns: Vec<TableColumn>) -> Self {
+        Self {
+            columns,
+            column_gap: 8.0,
+            row_gap: 4.0,
+        }
+    }
+
+    pub fn show<F: FnOnce(&TableResponse)>(self, rows: F) -> Response<TableResponse> {
+        let dom = context::dom();
+        let response = dom.begin_widget::<TableWidget>(self);
+        rows(&response);
+        dom.end_widget::<TableWidget>(response.id);
+        response
+    }
+}
+
+#[derive(Debug)]
+pub struct TableWidget {
+    grid: GridWidget,
+    filters: Vec<FilterState>,
+    open: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct TableResponse {
+    /// The filter of each column, in the same order as the columns.
+    pub filters: Vec<FilterState>,
+
+    /// Whether the user changed any of the filters this frame.
+    pub changed: bool,
+}
+
+impl TableResponse {
+    /// Whether a row with the given cell values is kept by every filter. Cells
+    /// past the last column are ignored.
+    pub fn matches<'a, I>(&self, cells: I) -> bool
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        self.filters
+            .iter()
+            .zip(cells)
+            .all(|(filter, cell)| filter.matches(cell))
+    }
+
+    /// The columns that have an active filter, along with their filters.
+    pub fn active(&self) -> impl Iterator<Item = (usize, &FilterState)> {
+        self.filters
+            .iter()
+            .enumerate()
+            .filter(|(_, filter)| filter.is_active())
+    }
+}
+
+impl Widget for TableWidget {
+    type Props<'a> = Table;
+    type Response = TableResponse;
+
+    fn new() -> Self {
+        Self {
+            grid: GridWidget::new(),
+            filters: Vec::new(),
+            open: None,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        let mut grid = Grid::new(props.columns.iter().map(|column| column.track).collect());
+        grid.column_gap = props.column_gap;
+        grid.row_gap = props.row_gap;
+        grid.fill_columns = true;
+        self.grid.update(grid);
+
+        self.filters
+            .resize_with(props.columns.len(), FilterState::default);
+
+        let mut changed = false;
+        for (index, column) in props.columns.iter().enumerate() {
+            let mut open = self.open == Some(index);
+            changed |= show_header(column, &mut self.filters[index], &mut open);
+
+            if open {
+                self.open = Some(index);
+            } else if self.open == Some(index) {
+                self.open = None;
+            }
+        }
+
+        Self::Response {
+            filters: self.filters.clone(),
+            changed,
+        }
+    }
+
+    fn layout(&self, ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        self.grid.layout(ctx, input)
+    }
+}
+
+/// Shows the header cell of a column, and its filter popup if it's open.
+/// Clicking the header toggles the popup, and clicking outside of it closes
+/// it. Returns whether the filter was changed.
+fn show_header(column: &TableColumn, filter: &mut FilterState, open: &mut bool) -> bool {
+    let theme = Theme::current();
+
+    if column.filter == ColumnFilter::None {
+        Pad::all(8.0).show(|| {
+            Text::label(column.title.clone()).show();
+        });
+
+        return false;
+    }
+
+    let mut button = Button::styled(format!("{} \u{25BE}", column.title));
+    button.style.text.align = TextAlignment::Start;
+    button.hover_style.text.align = TextAlignment::Start;
+    button.down_style.text.align = TextAlignment::Start;
+    if filter.is_active() {
+        button.style.text.color = theme.focus;
+    }
+
+    let mut changed = false;
+
+    // The popup is a child of the header's column so that it's placed under
+    // the header, like the menu of a dropdown button.
+    let mut header = List::column();
+    header.main_axis_size = MainAxisSize::Min;
+    header.show(|| {
+        if button.show().clicked {
+            *open = !*open;
+        }
+
+        if *open {
+            let dismissed = DismissOnOutsideClick::new()
+                .show(|| {
+                    FocusScope::new().show(|| {
+                        Layer::new().show(|| {
+                            Reflow::new(Alignment::BOTTOM_LEFT, Pivot::TOP_LEFT, Dim2::ZERO).show(
+                                || {
+                                    ColoredBox::container(theme.background_2).show_children(|| {
+                                        Pad::all(8.0).show(|| {
+                                            changed = show_filter(&column.filter, filter);
+                                        });
+                                    });
+                                },
+                            );
+                        });
+                    });
+                })
+                .dismissed;
+
+            if dismissed {
+                *open = false;
+            }
+        }
+    });
+
+    changed
+}
+
+/// Shows the controls that edit a filter, returning whether it was changed.
+fn show_filter(kind: &ColumnFilter, filter: &mut FilterState) -> bool {
+    let mut changed = false;
+
+    let mut list = List::column();
+    list.main_axis_size = MainAxisSize::Min;
+    list.item_spacing = 4.0;
+    list.show(|| match kind {
+        ColumnFilter::None => {}
+
+        ColumnFilter::Text => {
+            let mut textbox = TextBox::new(filter.text.clone());
+            textbox.placeholder = "Filter".to_owned();
+
+            if let Some(text) = textbox.show().into_inner().text {
+                filter.text = text;
+                changed = true;
+            }
+        }
+
+        ColumnFilter::Values(values) => {
+            for value in values {
+                let hidden = filter.hidden.contains(value);
+
+                let mut row = List::row();
+                row.main_axis_size = MainAxisSize::Min;
+                row.item_spacing = 8.0;
+                row.cross_axis_alignment = CrossAxisAlignment::Center;
+                row.show(|| {
+                    let checkbox = Checkbox::new(!hidden).show();
+                    Text::label(value.clone()).show();
+
+                    if checkbox.changed {
+                        if checkbox.checked {
+                            filter.hidden.retain(|hidden| hidden != value);
+                        } else {
+                            filter.hidden.push(value.clone());
+                        }
+                        changed = true;
+                    }
+                });
+            }
+        }
+    });
+
+    changed
+}
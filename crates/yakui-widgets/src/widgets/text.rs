@@ -1,13 +1,15 @@
 use std::borrow::Cow;
 
-use yakui_core::widget::Widget;
-use yakui_core::Response;
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::Dim2;
+use yakui_core::widget::{EventContext, Widget};
+use yakui_core::{Alignment, Pivot, Response};
 
-use crate::pad;
-use crate::style::TextStyle;
+use crate::style::{TextOverflow, TextStyle};
 use crate::util::widget;
+use crate::{colors, pad};
 
-use super::{Pad, RenderText};
+use super::{ColoredBox, Layer, Pad, Reflow, RenderText};
 
 /**
 Puts text onto the screen.
@@ -33,6 +35,11 @@ pub struct Text {
     pub text: Cow<'static, str>,
     pub style: TextStyle,
     pub padding: Pad,
+
+    /// If the text is truncated, like with [`TextOverflow::Ellipsis`] or
+    /// [`TextStyle::max_lines`], show the full text in a tooltip when the text
+    /// is hovered.
+    pub tooltip_when_truncated: bool,
 }
 
 impl Text {
@@ -44,6 +51,7 @@ impl Text {
             text: text.into(),
             style,
             padding: Pad::ZERO,
+            tooltip_when_truncated: false,
         }
     }
 
@@ -52,6 +60,7 @@ impl Text {
             text: text.into(),
             style,
             padding: Pad::ZERO,
+            tooltip_when_truncated: false,
         }
     }
 
@@ -60,6 +69,7 @@ impl Text {
             text,
             style: TextStyle::label(),
             padding: Pad::all(8.0),
+            tooltip_when_truncated: false,
         }
     }
 
@@ -71,6 +81,8 @@ impl Text {
 #[derive(Debug)]
 pub struct TextWidget {
     props: Text,
+    hovered: bool,
+    interest_changed: bool,
 }
 
 pub type TextResponse = ();
@@ -82,17 +94,69 @@ impl Widget for TextWidget {
     fn new() -> Self {
         Self {
             props: Text::new(0.0, Cow::Borrowed("")),
+            hovered: false,
+            interest_changed: true,
         }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.interest_changed = props.tooltip_when_truncated != self.props.tooltip_when_truncated;
         self.props = props;
 
         let mut render = RenderText::new(self.props.text.clone());
         render.style = self.props.style.clone();
 
+        let mut truncated = false;
         pad(self.props.padding, || {
-            render.show();
+            truncated = render.show().truncated;
+        });
+
+        if truncated && self.props.tooltip_when_truncated && self.hovered {
+            self.show_tooltip();
+        }
+    }
+
+    fn needs_layout(&self) -> bool {
+        // Text is laid out by its children, but its event interest is only
+        // recorded when it's laid out.
+        self.interest_changed
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        if self.props.tooltip_when_truncated {
+            EventInterest::MOUSE_INSIDE
+        } else {
+            EventInterest::empty()
+        }
+    }
+
+    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match event {
+            WidgetEvent::MouseEnter => self.hovered = true,
+            WidgetEvent::MouseLeave => self.hovered = false,
+            _ => {}
+        }
+
+        EventResponse::Bubble
+    }
+}
+
+impl TextWidget {
+    fn show_tooltip(&self) {
+        let mut style = self.props.style.clone();
+        style.overflow = TextOverflow::Visible;
+        style.max_lines = None;
+
+        let text = self.props.text.clone();
+
+        Layer::new().show(|| {
+            Reflow::new(Alignment::BOTTOM_LEFT, Pivot::TOP_LEFT, Dim2::ZERO).show(|| {
+                ColoredBox::container(colors::BACKGROUND_1).show_children(|| {
+                    pad(Pad::all(4.0), || {
+                        RenderText::with_style(text, style).show();
+                    });
+                });
+            });
         });
     }
 }
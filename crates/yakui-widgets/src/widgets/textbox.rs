@@ -2,18 +2,22 @@ use std::cell::{Cell, RefCell};
 use std::mem;
 
 use cosmic_text::Edit;
+use unicode_segmentation::UnicodeSegmentation;
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
-use yakui_core::input::{KeyCode, Modifiers, MouseButton};
+use yakui_core::input::{
+    ClipboardContent, CursorIcon, KeyCode, Modifiers, MouseButton, Shortcut, TabIndex,
+};
 use yakui_core::paint::PaintRect;
 use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
-use yakui_core::Response;
+use yakui_core::{context, Response, UiActivity};
 
-use crate::font::Fonts;
+use crate::font::{self, Fonts};
+use crate::pad;
 use crate::shapes::{self, RoundedRectangle};
 use crate::style::{TextAlignment, TextStyle};
-use crate::util::widget;
-use crate::{colors, pad};
+use crate::theme::Theme;
+use crate::util::{widget, Changed};
 
 use super::{Pad, RenderText};
 
@@ -42,29 +46,37 @@ pub struct TextBox {
 
     /// Drawn when no text has been set
     pub placeholder: String,
+
+    /// Where the text box falls in the order that Tab moves the selection
+    /// through.
+    pub tab_index: TabIndex,
 }
 
 impl TextBox {
+    /// Create a text box styled by the current [`Theme`].
     pub fn new<S: Into<String>>(text: S) -> Self {
-        let mut style = TextStyle::label();
+        let theme = Theme::current();
+
+        let mut style = theme.label();
         style.align = TextAlignment::Start;
 
         Self {
             text: text.into(),
 
             style,
-            padding: Pad::all(8.0),
-            fill: Some(colors::BACKGROUND_3),
-            radius: 6.0,
+            padding: theme.textbox_padding,
+            fill: Some(theme.background_3),
+            radius: theme.corner_radius,
 
             inline_edit: true,
             multiline: false,
 
-            selection_halo_color: Color::WHITE,
-            selected_bg_color: Color::CORNFLOWER_BLUE.adjust(0.4),
-            cursor_color: Color::RED,
+            selection_halo_color: theme.focus,
+            selected_bg_color: theme.text_selection,
+            cursor_color: theme.text_cursor,
 
             placeholder: String::new(),
+            tab_index: TabIndex::Auto,
         }
     }
 
@@ -96,12 +108,17 @@ pub struct TextBoxWidget {
     /// Whether this widget is focused and receiving input from the user.
     active: bool,
 
+    /// Whether this widget was focused using the keyboard, which means it
+    /// should draw a selection halo.
+    focus_visible: bool,
+
     activated: bool,
     lost_focus: bool,
     drag: DragState,
     cosmic_editor: RefCell<Option<cosmic_text::Editor<'static>>>,
     max_size: Cell<Option<(Option<f32>, Option<f32>)>>,
     scale_factor: Cell<Option<f32>>,
+    fonts_generation: Cell<Option<u64>>,
 }
 
 pub struct TextBoxResponse {
@@ -115,6 +132,16 @@ pub struct TextBoxResponse {
 
     /// Whether the textbox lost focus.
     pub lost_focus: bool,
+
+    /// Whether the user edited the text this frame. Same as
+    /// `text.is_some()`.
+    pub changed: bool,
+}
+
+impl Changed for TextBoxResponse {
+    fn changed(&self) -> bool {
+        self.changed
+    }
 }
 
 impl Widget for TextBoxWidget {
@@ -126,6 +153,7 @@ impl Widget for TextBoxWidget {
             props: TextBox::new(String::new()),
             text_changed_by_caller: false,
             active: false,
+            focus_visible: false,
             activated: false,
             lost_focus: false,
             drag: DragState::None,
@@ -133,6 +161,7 @@ impl Widget for TextBoxWidget {
             max_size: Cell::default(),
             text_changed_by_cosmic: Cell::default(),
             scale_factor: Cell::default(),
+            fonts_generation: Cell::default(),
         }
     }
 
@@ -193,12 +222,19 @@ impl Widget for TextBoxWidget {
             self.props.text = editor_text.clone();
         }
 
+        if self.active {
+            context::dom().report_activity(UiActivity::TEXT_INPUT);
+        }
+
+        let text = if self.text_changed_by_cosmic.take() {
+            Some(editor_text)
+        } else {
+            None
+        };
+
         Self::Response {
-            text: if self.text_changed_by_cosmic.take() {
-                Some(editor_text)
-            } else {
-                None
-            },
+            changed: text.is_some(),
+            text,
             activated: mem::take(&mut self.activated),
             lost_focus: mem::take(&mut self.lost_focus),
         }
@@ -214,6 +250,8 @@ impl Widget for TextBoxWidget {
         let max_size = (max_width, max_height);
 
         let fonts = ctx.dom.get_global_or_init(Fonts::default);
+        let fallback = fonts.fallback_families();
+        let fonts_generation = fonts.generation();
 
         fonts.with_system(|font_system| {
             if self.cosmic_editor.borrow().is_none() {
@@ -244,21 +282,44 @@ impl Widget for TextBoxWidget {
 
                 if self.text_changed_by_caller {
                     editor.with_buffer_mut(|buffer| {
-                        buffer.set_text(
+                        font::set_text_with_fallback(
+                            buffer,
                             font_system,
                             &self.props.text,
                             self.props.style.attrs.as_attrs(),
-                            cosmic_text::Shaping::Advanced,
+                            &fallback,
                         );
                     });
 
                     editor.set_cursor(cosmic_text::Cursor::new(0, 0));
+                } else if self.fonts_generation.get() != Some(fonts_generation) {
+                    // Fonts were loaded or swapped out, so the text needs to
+                    // be shaped again. The text is the same, so the cursor
+                    // can stay where it is.
+                    editor.with_buffer_mut(|buffer| {
+                        let text = buffer
+                            .lines
+                            .iter()
+                            .map(|line| line.text())
+                            .collect::<Vec<_>>()
+                            .join("\n");
+
+                        font::set_text_with_fallback(
+                            buffer,
+                            font_system,
+                            &text,
+                            self.props.style.attrs.as_attrs(),
+                            &fallback,
+                        );
+                    });
                 }
 
+                self.fonts_generation.set(Some(fonts_generation));
+
                 // Perf note: https://github.com/pop-os/cosmic-text/issues/166
                 editor.with_buffer_mut(|buffer| {
                     for buffer_line in buffer.lines.iter_mut() {
-                        buffer_line.set_align(Some(self.props.style.align.into()));
+                        buffer_line.set_align(self.props.style.align.to_cosmic());
                     }
                     buffer.shape_until_scroll(font_system, true);
                 });
@@ -333,7 +394,7 @@ impl Widget for TextBoxWidget {
             }
         });
 
-        if self.active {
+        if self.active && self.focus_visible {
             shapes::selection_halo(ctx.paint, layout_node.rect, self.props.selection_halo_color);
         }
 
@@ -341,13 +402,22 @@ impl Widget for TextBoxWidget {
     }
 
     fn event_interest(&self) -> EventInterest {
-        EventInterest::MOUSE_INSIDE | EventInterest::FOCUSED_KEYBOARD | EventInterest::MOUSE_MOVE
+        EventInterest::MOUSE_INSIDE
+            | EventInterest::FOCUS
+            | EventInterest::FOCUSED_KEYBOARD
+            | EventInterest::MOUSE_MOVE
+            | EventInterest::PASTE_TARGET
+    }
+
+    fn tab_index(&self) -> TabIndex {
+        self.props.tab_index
     }
 
     fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
         match event {
             WidgetEvent::FocusChanged(focused) => {
                 self.active = *focused;
+                self.focus_visible = *focused && ctx.input.focus_visible();
                 if !*focused {
                     self.lost_focus = true;
                     if let Some(editor) = self.cosmic_editor.get_mut() {
@@ -363,25 +433,22 @@ impl Widget for TextBoxWidget {
 
                     EventResponse::Sink
                 } else if self.drag == DragState::Dragging {
-                    if let Some(layout) = ctx.layout.get(ctx.dom.current()) {
-                        let scale_factor = ctx.layout.scale_factor();
-                        let relative_pos =
-                            *position - layout.rect.pos() - self.props.padding.offset();
-                        let glyph_pos = (relative_pos * scale_factor).round().as_ivec2();
-
-                        let fonts = ctx.dom.get_global_or_init(Fonts::default);
-                        fonts.with_system(|font_system| {
-                            if let Some(editor) = self.cosmic_editor.get_mut() {
-                                editor.action(
-                                    font_system,
-                                    cosmic_text::Action::Drag {
-                                        x: glyph_pos.x,
-                                        y: glyph_pos.y,
-                                    },
-                                );
-                            }
-                        });
-                    }
+                    let scale_factor = ctx.layout.scale_factor();
+                    let relative_pos = ctx.to_local(*position) - self.props.padding.offset();
+                    let glyph_pos = (relative_pos * scale_factor).round().as_ivec2();
+
+                    let fonts = ctx.dom.get_global_or_init(Fonts::default);
+                    fonts.with_system(|font_system| {
+                        if let Some(editor) = self.cosmic_editor.get_mut() {
+                            editor.action(
+                                font_system,
+                                cosmic_text::Action::Drag {
+                                    x: glyph_pos.x,
+                                    y: glyph_pos.y,
+                                },
+                            );
+                        }
+                    });
 
                     EventResponse::Sink
                 } else {
@@ -389,6 +456,11 @@ impl Widget for TextBoxWidget {
                 }
             }
 
+            WidgetEvent::MouseEnter => {
+                ctx.set_cursor_icon(CursorIcon::Text);
+                EventResponse::Bubble
+            }
+
             WidgetEvent::MouseButtonChanged {
                 button: MouseButton::One,
                 inside,
@@ -401,44 +473,42 @@ impl Widget for TextBoxWidget {
                     return EventResponse::Sink;
                 }
 
-                if let Some(layout) = ctx.layout.get(ctx.dom.current()) {
-                    let scale_factor = ctx.layout.scale_factor();
-                    let relative_pos = *position - layout.rect.pos() - self.props.padding.offset();
-                    let glyph_pos = (relative_pos * scale_factor).round().as_ivec2();
+                let scale_factor = ctx.layout.scale_factor();
+                let relative_pos = ctx.to_local(*position) - self.props.padding.offset();
+                let glyph_pos = (relative_pos * scale_factor).round().as_ivec2();
 
-                    let fonts = ctx.dom.get_global_or_init(Fonts::default);
-                    fonts.with_system(|font_system| {
-                        if *down {
-                            if self.drag == DragState::None {
-                                self.drag = DragState::DragStart;
-                            }
+                let fonts = ctx.dom.get_global_or_init(Fonts::default);
+                fonts.with_system(|font_system| {
+                    if *down {
+                        if self.drag == DragState::None {
+                            self.drag = DragState::DragStart;
+                        }
 
-                            if let Some(editor) = self.cosmic_editor.get_mut() {
-                                if modifiers.shift() {
-                                    // TODO wait for cosmic text for shift clicking selection
-                                    // Madeline Sparkles: emulating this with a drag
-                                    editor.action(
-                                        font_system,
-                                        cosmic_text::Action::Drag {
-                                            x: glyph_pos.x,
-                                            y: glyph_pos.y,
-                                        },
-                                    );
-                                } else {
-                                    editor.action(
-                                        font_system,
-                                        cosmic_text::Action::Click {
-                                            x: glyph_pos.x,
-                                            y: glyph_pos.y,
-                                        },
-                                    );
-                                }
+                        if let Some(editor) = self.cosmic_editor.get_mut() {
+                            if modifiers.shift() {
+                                // TODO wait for cosmic text for shift clicking selection
+                                // Madeline Sparkles: emulating this with a drag
+                                editor.action(
+                                    font_system,
+                                    cosmic_text::Action::Drag {
+                                        x: glyph_pos.x,
+                                        y: glyph_pos.y,
+                                    },
+                                );
+                            } else {
+                                editor.action(
+                                    font_system,
+                                    cosmic_text::Action::Click {
+                                        x: glyph_pos.x,
+                                        y: glyph_pos.y,
+                                    },
+                                );
                             }
-                        } else {
-                            self.drag = DragState::None;
                         }
-                    });
-                }
+                    } else {
+                        self.drag = DragState::None;
+                    }
+                });
 
                 ctx.input.set_selection(Some(ctx.dom.current()));
 
@@ -457,13 +527,11 @@ impl Widget for TextBoxWidget {
                         match key {
                             KeyCode::ArrowLeft => {
                                 if *down {
-                                    if modifiers.ctrl() {
-                                        editor.action(
-                                            font_system,
-                                            cosmic_text::Action::Motion(
-                                                cosmic_text::Motion::LeftWord,
-                                            ),
-                                        );
+                                    if word_modifier(modifiers) {
+                                        let target = editor.with_buffer(|buffer| {
+                                            prev_word(buffer, editor.cursor())
+                                        });
+                                        move_cursor(editor, target, modifiers.shift());
                                     } else {
                                         editor.action(
                                             font_system,
@@ -476,13 +544,11 @@ impl Widget for TextBoxWidget {
 
                             KeyCode::ArrowRight => {
                                 if *down {
-                                    if modifiers.ctrl() {
-                                        editor.action(
-                                            font_system,
-                                            cosmic_text::Action::Motion(
-                                                cosmic_text::Motion::RightWord,
-                                            ),
-                                        );
+                                    if word_modifier(modifiers) {
+                                        let target = editor.with_buffer(|buffer| {
+                                            next_word(buffer, editor.cursor())
+                                        });
+                                        move_cursor(editor, target, modifiers.shift());
                                     } else {
                                         editor.action(
                                             font_system,
@@ -535,7 +601,16 @@ impl Widget for TextBoxWidget {
 
                             KeyCode::Backspace => {
                                 if *down {
-                                    editor.action(font_system, cosmic_text::Action::Backspace);
+                                    if word_modifier(modifiers)
+                                        && editor.selection() == cosmic_text::Selection::None
+                                    {
+                                        let target = editor.with_buffer(|buffer| {
+                                            prev_word(buffer, editor.cursor())
+                                        });
+                                        delete_to(editor, target);
+                                    } else {
+                                        editor.action(font_system, cosmic_text::Action::Backspace);
+                                    }
                                     self.text_changed_by_cosmic.set(true);
                                 }
                                 EventResponse::Sink
@@ -543,7 +618,16 @@ impl Widget for TextBoxWidget {
 
                             KeyCode::Delete => {
                                 if *down {
-                                    editor.action(font_system, cosmic_text::Action::Delete);
+                                    if word_modifier(modifiers)
+                                        && editor.selection() == cosmic_text::Selection::None
+                                    {
+                                        let target = editor.with_buffer(|buffer| {
+                                            next_word(buffer, editor.cursor())
+                                        });
+                                        delete_to(editor, target);
+                                    } else {
+                                        editor.action(font_system, cosmic_text::Action::Delete);
+                                    }
                                     self.text_changed_by_cosmic.set(true);
                                 }
                                 EventResponse::Sink
@@ -597,7 +681,11 @@ impl Widget for TextBoxWidget {
                                 EventResponse::Sink
                             }
 
-                            KeyCode::KeyA if *down && main_modifier(modifiers) => {
+                            // Tab moves the selection to the next widget
+                            // instead of being typed.
+                            KeyCode::Tab => EventResponse::Bubble,
+
+                            _ if Shortcut::primary("a").matches_event(event) => {
                                 editor.set_selection(cosmic_text::Selection::Line(editor.cursor()));
 
                                 if let Some((_start, end)) = editor.selection_bounds() {
@@ -607,15 +695,14 @@ impl Widget for TextBoxWidget {
                                 EventResponse::Sink
                             }
 
-                            KeyCode::KeyC if *down && main_modifier(modifiers) => {
+                            _ if Shortcut::primary("c").matches_event(event) => {
                                 println!("TODO: Copy!");
                                 EventResponse::Sink
                             }
 
-                            KeyCode::KeyV if *down && main_modifier(modifiers) => {
-                                println!("TODO: Paste!");
-                                EventResponse::Sink
-                            }
+                            // Hosts read the clipboard themselves and send
+                            // what they find as a paste event.
+                            _ if Shortcut::primary("v").matches_event(event) => EventResponse::Sink,
 
                             _ => EventResponse::Sink,
                         }
@@ -641,17 +728,104 @@ impl Widget for TextBoxWidget {
 
                 EventResponse::Sink
             }
+            WidgetEvent::Paste(ClipboardContent::Text(text)) => {
+                if !self.active {
+                    return EventResponse::Bubble;
+                }
+
+                let text = if self.props.multiline {
+                    text.clone()
+                } else {
+                    text.lines().collect::<Vec<_>>().join(" ")
+                };
+
+                if let Some(editor) = self.cosmic_editor.get_mut() {
+                    editor.insert_string(&text, None);
+                    self.text_changed_by_cosmic.set(true);
+                }
+
+                EventResponse::Sink
+            }
             _ => EventResponse::Bubble,
         }
     }
 }
 
-/// Tells whether the set of modifiers contains the primary modifier, like ctrl
-/// on Windows or Linux or Command on macOS.
-fn main_modifier(modifiers: &Modifiers) -> bool {
+/// Tells whether the set of modifiers contains the modifier used for moving
+/// and deleting by word, like ctrl on Windows or Linux or Option on macOS.
+fn word_modifier(modifiers: &Modifiers) -> bool {
     if cfg!(target_os = "macos") {
-        modifiers.meta()
+        modifiers.alt()
     } else {
         modifiers.ctrl()
     }
 }
+
+fn is_word(segment: &str) -> bool {
+    segment.chars().any(|c| c.is_alphanumeric())
+}
+
+/// Finds the start of the word before the cursor, wrapping to the end of the
+/// previous line if the cursor is at the start of a line.
+fn prev_word(buffer: &cosmic_text::Buffer, cursor: cosmic_text::Cursor) -> cosmic_text::Cursor {
+    if cursor.index == 0 {
+        if cursor.line == 0 {
+            return cursor;
+        }
+
+        let line = cursor.line - 1;
+        return cosmic_text::Cursor::new(line, buffer.lines[line].text().len());
+    }
+
+    let text = buffer.lines[cursor.line].text();
+    let index = text[..cursor.index]
+        .split_word_bound_indices()
+        .rfind(|(_, segment)| is_word(segment))
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+
+    cosmic_text::Cursor::new(cursor.line, index)
+}
+
+/// Finds the end of the word after the cursor, wrapping to the start of the
+/// next line if the cursor is at the end of a line.
+fn next_word(buffer: &cosmic_text::Buffer, cursor: cosmic_text::Cursor) -> cosmic_text::Cursor {
+    let text = buffer.lines[cursor.line].text();
+
+    if cursor.index >= text.len() {
+        if cursor.line + 1 >= buffer.lines.len() {
+            return cursor;
+        }
+
+        return cosmic_text::Cursor::new(cursor.line + 1, 0);
+    }
+
+    let index = text[cursor.index..]
+        .split_word_bound_indices()
+        .find(|(_, segment)| is_word(segment))
+        .map(|(index, segment)| cursor.index + index + segment.len())
+        .unwrap_or(text.len());
+
+    cosmic_text::Cursor::new(cursor.line, index)
+}
+
+fn move_cursor(
+    editor: &mut cosmic_text::Editor<'static>,
+    target: cosmic_text::Cursor,
+    extend: bool,
+) {
+    if extend {
+        if editor.selection() == cosmic_text::Selection::None {
+            editor.set_selection(cosmic_text::Selection::Normal(editor.cursor()));
+        }
+    } else {
+        editor.set_selection(cosmic_text::Selection::None);
+    }
+
+    editor.set_cursor(target);
+}
+
+fn delete_to(editor: &mut cosmic_text::Editor<'static>, target: cosmic_text::Cursor) {
+    editor.set_selection(cosmic_text::Selection::Normal(target));
+    editor.delete_selection();
+}
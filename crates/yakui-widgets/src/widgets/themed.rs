@@ -0,0 +1,43 @@
+use yakui_core::Response;
+
+use crate::theme::Theme;
+
+use super::{ContextProvider, ContextProviderResponse};
+
+/**
+Restyles the built-in widgets inside of it with a different [`Theme`].
+
+This is a [`ContextProvider`] for a theme.
+
+Responds with [ContextProviderResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::theme::Theme;
+use yakui::Color;
+
+let mut theme = Theme::default();
+theme.background_3 = Color::hex(0x2b5797);
+theme.corner_radius = 0.0;
+
+yakui::themed(theme, || {
+    yakui::button("Square and blue");
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Themed {
+    pub theme: Theme,
+}
+
+impl Themed {
+    pub fn new(theme: Theme) -> Self {
+        Self { theme }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<ContextProviderResponse> {
+        ContextProvider::new(self.theme).show(children)
+    }
+}
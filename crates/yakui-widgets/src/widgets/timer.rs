@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use yakui_core::widget::Widget;
+use yakui_core::{context, Response};
+
+use crate::util::widget;
+
+/**
+Keeps track of time passing for as long as it is part of the UI.
+
+The timer starts the first frame it is shown and is reset if it stops being
+shown or if its period changes. Time comes from
+[`Yakui::set_time`][yakui_core::Yakui::set_time], so timers don't advance if
+the host doesn't call it.
+
+Responds with [TimerResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+// Blink something on and off twice a second.
+let blink = yakui::use_interval(std::time::Duration::from_millis(500));
+if blink.count % 2 == 0 {
+    yakui::label("Recording");
+}
+
+// Show a message for three seconds.
+if !yakui::use_timer(std::time::Duration::from_secs(3)).finished {
+    yakui::label("Saved!");
+}
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Timer {
+    pub period: Duration,
+
+    /// If true, the timer fires once every `period`. Otherwise, it fires once
+    /// after `period` has passed and then stops.
+    pub repeat: bool,
+}
+
+impl Timer {
+    pub fn once(duration: Duration) -> Self {
+        Self {
+            period: duration,
+            repeat: false,
+        }
+    }
+
+    pub fn interval(period: Duration) -> Self {
+        Self {
+            period,
+            repeat: true,
+        }
+    }
+
+    pub fn show(self) -> Response<TimerResponse> {
+        widget::<TimerWidget>(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct TimerWidget {
+    props: Option<Timer>,
+    start: Duration,
+    count: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TimerResponse {
+    /// How much time has passed since the timer started.
+    pub elapsed: Duration,
+
+    /// Whether the timer fired this frame.
+    pub fired: bool,
+
+    /// How many times the timer has fired so far.
+    pub count: u32,
+
+    /// Whether a timer that doesn't repeat has fired. Always false for timers
+    /// that repeat.
+    pub finished: bool,
+}
+
+impl Widget for TimerWidget {
+    type Props<'a> = Timer;
+    type Response = TimerResponse;
+
+    fn new() -> Self {
+        Self {
+            props: None,
+            start: Duration::ZERO,
+            count: 0,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        let now = context::dom().time();
+
+        if self.props != Some(props) {
+            self.props = Some(props);
+            self.start = now;
+            self.count = 0;
+        }
+
+        let elapsed = now.saturating_sub(self.start);
+
+        let mut count = if props.period.is_zero() {
+            1
+        } else {
+            (elapsed.as_nanos() / props.period.as_nanos()).min(u32::MAX as u128) as u32
+        };
+
+        if !props.repeat {
+            count = count.min(1);
+        }
+
+        let fired = count > self.count;
+        self.count = count;
+
+        // Hosts that only build frames when something changes need to know
+        // when the timer will fire next.
+        if props.repeat || count == 0 {
+            let next = props.period.checked_mul(count + 1);
+            if let Some(next) = next.and_then(|next| self.start.checked_add(next)) {
+                context::dom().request_repaint_at(next);
+            }
+        }
+
+        TimerResponse {
+            elapsed,
+            fired,
+            count,
+            finished: !props.repeat && count > 0,
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use yakui_core::geometry::{Affine2, Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::{Alignment, Response};
+
+use crate::util::widget_children;
+
+/**
+Rotates, scales, and moves how its children are drawn, without changing their
+layout.
+
+Children are laid out as if they weren't transformed, but are hit tested where
+they're drawn. This is best suited for visual effects like a spinning compass
+needle or a card flip.
+
+Responds with [TransformResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::widgets::Transform;
+Transform::rotation(std::f32::consts::FRAC_PI_4).show(|| {
+    yakui::label("Tilted");
+});
+```
+*/
+#[derive(Debug, Clone, Copy)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Transform {
+    /// Clockwise rotation in radians.
+    pub rotation: f32,
+    pub scale: Vec2,
+    pub translation: Vec2,
+
+    /// The point within this widget that it is rotated and scaled around.
+    pub origin: Alignment,
+}
+
+impl Transform {
+    pub fn new() -> Self {
+        Self {
+            rotation: 0.0,
+            scale: Vec2::ONE,
+            translation: Vec2::ZERO,
+            origin: Alignment::CENTER,
+        }
+    }
+
+    pub fn rotation(rotation: f32) -> Self {
+        Self {
+            rotation,
+            ..Self::new()
+        }
+    }
+
+    pub fn scaled(scale: Vec2) -> Self {
+        Self {
+            scale,
+            ..Self::new()
+        }
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<TransformResponse> {
+        widget_children::<TransformWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct TransformWidget {
+    props: Transform,
+}
+
+pub type TransformResponse = ();
+
+impl Widget for TransformWidget {
+    type Props<'a> = Transform;
+    type Response = TransformResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Transform::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn paints_outside_rect(&self) -> bool {
+        true
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let mut size = Vec2::ZERO;
+        for &child in &node.children {
+            let child_size = ctx.calculate_layout(child, constraints);
+            size = size.max(child_size);
+        }
+        let size = constraints.constrain_min(size);
+
+        let origin = size * self.props.origin.as_vec2();
+        let transform = Affine2::from_translation(origin + self.props.translation)
+            * Affine2::from_angle(self.props.rotation)
+            * Affine2::from_scale(self.props.scale)
+            * Affine2::from_translation(-origin);
+        ctx.layout.set_transform(ctx.dom, transform);
+
+        size
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let node = ctx.dom.get_current();
+        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+        let transform = layout_node.world_transform().unwrap_or_default();
+
+        ctx.paint.push_transform(transform);
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+        ctx.paint.pop_transform();
+    }
+}
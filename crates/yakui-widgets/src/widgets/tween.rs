@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use yakui_core::widget::Widget;
+use yakui_core::{context, Response};
+
+use crate::animation::{Animatable, Easing, Transition};
+use crate::util::widget;
+
+/**
+Smoothly moves a value toward a target over a fixed amount of time.
+
+The first time the tween is shown, its value starts at the target. Whenever the
+target changes after that, the value moves from wherever it currently is to the
+new target, so changing targets mid-flight doesn't cause a jump.
+
+Time comes from [`Yakui::set_time`][yakui_core::Yakui::set_time]. If the host
+doesn't call it, the value jumps straight to the target.
+
+Responds with [TweenResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use std::time::Duration;
+
+let open = false;
+let target: f32 = if open { 200.0 } else { 0.0 };
+
+let height = yakui::use_tween(target, Duration::from_millis(150));
+yakui::colored_box(yakui::Color::GRAY, [100.0, height.value]);
+```
+*/
+#[derive(Debug, Clone, Copy)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Tween<T> {
+    pub target: T,
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl<T: Animatable> Tween<T> {
+    pub fn new(target: T, duration: Duration) -> Self {
+        Self {
+            target,
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    pub fn show(self) -> Response<TweenResponse<T>> {
+        widget::<TweenWidget<T>>(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct TweenWidget<T> {
+    transition: Transition<T>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TweenResponse<T> {
+    /// The value for this frame.
+    pub value: T,
+
+    /// Whether the value is still moving toward the target.
+    pub animating: bool,
+}
+
+impl<T: Animatable> Widget for TweenWidget<T> {
+    type Props<'a> = Tween<T>;
+    type Response = TweenResponse<T>;
+
+    fn new() -> Self {
+        Self {
+            transition: Transition::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        let dom = context::dom();
+        let value = self
+            .transition
+            .update(props.target, props.duration, props.easing, dom.time());
+
+        let animating = self.transition.is_animating();
+        if animating {
+            dom.request_repaint();
+        }
+
+        TweenResponse { value, animating }
+    }
+}
@@ -4,9 +4,9 @@ use yakui_core::geometry::{Color, Constraints, Vec2};
 use yakui_core::widget::Widget;
 use yakui_core::Response;
 
-use crate::colors;
+use crate::theme::Theme;
 use crate::util::widget;
-use crate::widgets::Pad;
+use crate::widgets::{Pad, Shadow, Text};
 
 /**
 A floating window within the application.
@@ -16,6 +16,11 @@ Responds with [WindowResponse].
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Window {
     pub initial_size: Vec2,
+
+    /// The shadow drawn behind the window, if any. Windows don't have a shadow
+    /// by default.
+    pub shadow: Option<Shadow>,
+
     children: Option<Box<dyn Fn()>>,
 }
 
@@ -23,6 +28,7 @@ impl Window {
     pub fn new<S: Into<Vec2>>(initial_size: S) -> Self {
         Self {
             initial_size: initial_size.into(),
+            shadow: None,
             children: None,
         }
     }
@@ -53,17 +59,32 @@ impl Widget for WindowWidget {
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
 
-        crate::colored_box_container(colors::BACKGROUND_2, || {
+        match self.props.shadow.clone() {
+            Some(shadow) => {
+                shadow.show(|| self.window_contents());
+            }
+            None => self.window_contents(),
+        }
+    }
+}
+
+impl WindowWidget {
+    fn window_contents(&self) {
+        let theme = Theme::current();
+
+        crate::colored_box_container(theme.background_2, || {
             crate::column(|| {
                 // Window Title Bar
                 let constraints = Constraints::loose(self.props.initial_size);
                 crate::constrained(constraints, || {
-                    crate::pad(Pad::all(8.0), || {
+                    crate::pad(theme.window_padding, || {
                         crate::row(|| {
                             crate::colored_box(Color::BLUE, [16.0, 16.0]);
                             crate::expanded(|| {
                                 crate::pad(Pad::balanced(8.0, 0.0), || {
-                                    crate::text(16.0, "Yakui Window");
+                                    let mut title = theme.label();
+                                    title.font_size = 16.0;
+                                    Text::with_style("Yakui Window", title).show();
                                 });
                             });
                             crate::colored_box(Color::RED, [16.0, 16.0]);
@@ -86,6 +107,7 @@ impl fmt::Debug for Window {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Window")
             .field("size", &self.initial_size)
+            .field("shadow", &self.shadow)
             .finish_non_exhaustive()
     }
 }
@@ -0,0 +1,227 @@
+use std::cell::RefCell;
+use std::ops::Range;
+
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{CrossAxisAlignment, Direction, LayoutDirection, MainAxisAlignment, Response};
+
+use crate::util::widget_children;
+
+/**
+Lays out children in a direction like a [List][super::List], but starts a new
+line whenever the next child doesn't fit in the space that's left. Useful for
+tag lists, item grids, and toolbars.
+
+If the wrap has unbounded space along its main axis, every child is placed on
+one line.
+
+Responds with [WrapResponse].
+
+## Examples
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Wrap;
+
+let mut tags = Wrap::row();
+tags.item_spacing = 4.0;
+tags.line_spacing = 4.0;
+tags.show(|| {
+    for tag in ["rust", "gamedev", "ui", "immediate-mode"] {
+        yakui::button(tag);
+    }
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Wrap {
+    pub direction: Direction,
+
+    /// Space between each child on the same line.
+    pub item_spacing: f32,
+
+    /// Space between each line.
+    pub line_spacing: f32,
+
+    /// How children are placed along each line.
+    pub main_axis_alignment: MainAxisAlignment,
+
+    /// How children are placed within the height of their line, for rows, or
+    /// the width of their line, for columns. Children are measured before
+    /// they're split into lines, so [`CrossAxisAlignment::Stretch`] behaves
+    /// like [`CrossAxisAlignment::Start`]. So does
+    /// [`CrossAxisAlignment::Baseline`].
+    pub cross_axis_alignment: CrossAxisAlignment,
+}
+
+impl Wrap {
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            item_spacing: 0.0,
+            line_spacing: 0.0,
+            main_axis_alignment: MainAxisAlignment::Start,
+            cross_axis_alignment: CrossAxisAlignment::Start,
+        }
+    }
+
+    pub fn row() -> Self {
+        Self::new(Direction::Right)
+    }
+
+    pub fn column() -> Self {
+        Self::new(Direction::Down)
+    }
+
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<WrapResponse> {
+        widget_children::<WrapWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct WrapWidget {
+    props: Wrap,
+
+    // Kept around to avoid reallocating every frame.
+    lines: RefCell<Vec<Line>>,
+}
+
+#[derive(Debug)]
+struct Line {
+    children: Range<usize>,
+    main: f32,
+    cross: f32,
+}
+
+pub type WrapResponse = ();
+
+impl Widget for WrapWidget {
+    type Props<'a> = Wrap;
+    type Response = WrapResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Wrap::row(),
+            lines: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let direction = self.props.direction;
+        let spacing = self.props.item_spacing;
+
+        let main_max = direction.get_main_axis(input.max);
+        let constraints = Constraints::loose(direction.vec2(main_max, f32::INFINITY));
+
+        let mut lines = std::mem::take(&mut *self.lines.borrow_mut());
+        lines.clear();
+
+        let mut line = Line {
+            children: 0..0,
+            main: 0.0,
+            cross: 0.0,
+        };
+
+        for (index, &child) in node.children.iter().enumerate() {
+            let size = ctx.calculate_layout(child, constraints);
+            let main = direction.get_main_axis(size);
+            let cross = direction.get_cross_axis(size);
+
+            if !line.children.is_empty() && line.main + spacing + main > main_max {
+                let next = Line {
+                    children: index..index,
+                    main: 0.0,
+                    cross: 0.0,
+                };
+                lines.push(std::mem::replace(&mut line, next));
+            }
+
+            if !line.children.is_empty() {
+                line.main += spacing;
+            }
+
+            line.children.end = index + 1;
+            line.main += main;
+            line.cross = line.cross.max(cross);
+        }
+
+        if !line.children.is_empty() {
+            lines.push(line);
+        }
+
+        let widest = lines.iter().map(|line| line.main).fold(0.0, f32::max);
+        let total_cross = lines.iter().map(|line| line.cross).sum::<f32>()
+            + self.props.line_spacing * lines.len().saturating_sub(1) as f32;
+
+        let main_size = if main_max.is_finite() {
+            main_max
+        } else {
+            widest
+        };
+        let size = input.constrain(direction.vec2(main_size, total_cross));
+        let main_size = direction.get_main_axis(size);
+
+        // Horizontal wraps run from right to left in right-to-left layouts.
+        let mirror = direction == Direction::Right
+            && ctx.layout.layout_direction() == LayoutDirection::RightToLeft;
+
+        let mut next_cross = 0.0;
+        for line in &lines {
+            let count = line.children.len();
+            let (leading, between) =
+                distribute(self.props.main_axis_alignment, main_size - line.main, count);
+
+            let mut next_main = leading;
+            for &child in &node.children[line.children.clone()] {
+                let layout = ctx.layout.get_mut(child).unwrap();
+                let child_main = direction.get_main_axis(layout.rect.size());
+                let child_cross = direction.get_cross_axis(layout.rect.size());
+
+                let cross = match self.props.cross_axis_alignment {
+                    CrossAxisAlignment::Center => (line.cross - child_cross) / 2.0,
+                    CrossAxisAlignment::End => line.cross - child_cross,
+                    _ => 0.0,
+                };
+                let main = if mirror {
+                    main_size - next_main - child_main
+                } else {
+                    next_main
+                };
+                layout
+                    .rect
+                    .set_pos(direction.vec2(main, next_cross + cross));
+
+                next_main += child_main + spacing + between;
+            }
+
+            next_cross += line.cross + self.props.line_spacing;
+        }
+
+        // Put lines back to be reused
+        *self.lines.borrow_mut() = lines;
+
+        size
+    }
+}
+
+/// Returns the space before the first child of a line and the extra space
+/// between each child, given how much free space the line has.
+fn distribute(alignment: MainAxisAlignment, free: f32, count: usize) -> (f32, f32) {
+    let free = free.max(0.0);
+    let count = count as f32;
+
+    match alignment {
+        MainAxisAlignment::Start => (0.0, 0.0),
+        MainAxisAlignment::Center => (free / 2.0, 0.0),
+        MainAxisAlignment::End => (free, 0.0),
+        MainAxisAlignment::SpaceAround if count > 0.0 => (free / count / 2.0, free / count),
+        MainAxisAlignment::SpaceBetween if count > 1.0 => (0.0, free / (count - 1.0)),
+        MainAxisAlignment::SpaceEvenly => (free / (count + 1.0), free / (count + 1.0)),
+        _ => (0.0, 0.0),
+    }
+}
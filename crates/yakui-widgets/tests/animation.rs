@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use yakui_widgets::animation::{Easing, Spring, SpringState, Transition};
+
+const EASINGS: [Easing; 4] = [
+    Easing::Linear,
+    Easing::EaseIn,
+    Easing::EaseOut,
+    Easing::EaseInOut,
+];
+
+#[test]
+fn easing_curves_start_at_zero_and_end_at_one() {
+    for easing in EASINGS {
+        assert_eq!(easing.apply(0.0), 0.0, "{easing:?}");
+        assert_eq!(easing.apply(1.0), 1.0, "{easing:?}");
+
+        // Times outside of the animation are clamped to its ends.
+        assert_eq!(easing.apply(-1.0), 0.0, "{easing:?}");
+        assert_eq!(easing.apply(2.0), 1.0, "{easing:?}");
+
+        // Every curve only moves forward.
+        let samples: Vec<f32> = (0..=20).map(|i| easing.apply(i as f32 / 20.0)).collect();
+        assert!(
+            samples.windows(2).all(|pair| pair[0] <= pair[1]),
+            "{easing:?}: {samples:?}"
+        );
+    }
+
+    assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+    assert!(Easing::EaseIn.apply(0.5) < 0.5);
+    assert!(Easing::EaseOut.apply(0.5) > 0.5);
+}
+
+#[test]
+fn transitions_reach_their_target_after_their_duration() {
+    let duration = Duration::from_millis(100);
+    let at = |ms: u64| Duration::from_millis(ms);
+    let mut transition = Transition::new();
+
+    // The first update starts at the target.
+    assert_eq!(
+        transition.update(0.0, duration, Easing::Linear, at(10)),
+        0.0
+    );
+    assert!(!transition.is_animating());
+
+    assert_eq!(
+        transition.update(10.0, duration, Easing::Linear, at(20)),
+        0.0
+    );
+    assert_eq!(
+        transition.update(10.0, duration, Easing::Linear, at(70)),
+        5.0
+    );
+    assert!(transition.is_animating());
+
+    // Changing the target partway picks up from the current value.
+    assert_eq!(
+        transition.update(0.0, duration, Easing::Linear, at(70)),
+        5.0
+    );
+    assert_eq!(
+        transition.update(0.0, duration, Easing::Linear, at(120)),
+        2.5
+    );
+    assert_eq!(
+        transition.update(0.0, duration, Easing::Linear, at(500)),
+        0.0
+    );
+    assert!(!transition.is_animating());
+}
+
+#[test]
+fn transitions_jump_to_their_target_without_time() {
+    let duration = Duration::from_millis(100);
+    let mut transition = Transition::new();
+
+    transition.update(0.0, duration, Easing::EaseOut, Duration::ZERO);
+    let value = transition.update(1.0, duration, Easing::EaseOut, Duration::ZERO);
+    assert_eq!(value, 1.0);
+    assert!(!transition.is_animating());
+}
+
+/// Updates a spring from 0 toward 1 at 60 frames per second for up to five
+/// seconds, returning each value it passed through.
+fn run_spring(spring: Spring, state: &mut SpringState<f32>) -> Vec<f32> {
+    let frame = Duration::from_secs(1) / 60;
+    let mut now = Duration::from_secs(1);
+    state.update(0.0, spring, now);
+
+    let mut values = Vec::new();
+    for _ in 0..300 {
+        now += frame;
+        values.push(state.update(1.0, spring, now));
+        if !state.is_moving(1.0) {
+            break;
+        }
+    }
+    values
+}
+
+#[test]
+fn springs_settle_on_their_target() {
+    for spring in [
+        Spring::DEFAULT,
+        Spring::GENTLE,
+        Spring::WOBBLY,
+        Spring::STIFF,
+    ] {
+        let mut state = SpringState::new();
+        let values = run_spring(spring, &mut state);
+
+        assert_eq!(values.last(), Some(&1.0), "{spring:?} never settled");
+        assert_eq!(state.velocity(), 0.0);
+        assert!(!state.is_moving(1.0));
+    }
+
+    // Less damping lets the value bounce past the target.
+    let mut state = SpringState::new();
+    let values = run_spring(Spring::WOBBLY, &mut state);
+    assert!(values.iter().any(|&value| value > 1.0));
+
+    let mut state = SpringState::new();
+    let values = run_spring(Spring::new(170.0, 40.0), &mut state);
+    assert!(values.iter().all(|&value| value <= 1.0));
+}
+
+#[test]
+fn long_frames_are_clamped() {
+    let start = Duration::from_secs(1);
+    let spring = Spring::DEFAULT;
+
+    let mut paused = SpringState::new();
+    paused.update(0.0, spring, start);
+    let after_pause = paused.update(1.0, spring, start + Duration::from_secs(10));
+
+    // A long frame moves the spring as far as a tenth of a second would, so
+    // it's still on its way instead of jumping to the target.
+    let mut short = SpringState::new();
+    short.update(0.0, spring, start);
+    let after_short = short.update(1.0, spring, start + Duration::from_millis(100));
+
+    assert_eq!(after_pause, after_short);
+    assert!(after_pause > 0.0 && after_pause < 1.0);
+    assert_eq!(paused.velocity(), short.velocity());
+}
+
+#[test]
+fn springs_jump_to_their_target_without_time() {
+    let mut state = SpringState::new();
+    state.update(0.0, Spring::DEFAULT, Duration::ZERO);
+    assert_eq!(state.update(1.0, Spring::DEFAULT, Duration::ZERO), 1.0);
+    assert!(!state.is_moving(1.0));
+}
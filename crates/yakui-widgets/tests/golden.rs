@@ -0,0 +1,392 @@
+use yakui::shapes::{OutlineWidths, StrokeStyle};
+use yakui::widgets::{List, Outline, Pad, RenderText};
+use yakui::{Alignment, Color, Constraints, LayoutDirection, MainAxisSize, Vec2};
+use yakui_test::image::RgbaImage;
+use yakui_test::{assert_golden, compare, render, Harness, Mismatch, Tolerance};
+use yakui_widgets::style::{TextAlignment, TextOutline, TextShadow, TextStyle};
+use yakui_widgets::{align, button, checkbox, colored_box, column, constrained, pad, row, slider};
+
+/// Renders a 50x50 box in the corner of a 100x100 viewport. The box is
+/// aligned so that it isn't stretched to fill the viewport.
+fn render_box(color: Color) -> yakui_test::image::RgbaImage {
+    let mut harness = Harness::with_size(Vec2::new(100.0, 100.0));
+    harness.frame(|| {
+        align(Alignment::TOP_LEFT, || {
+            colored_box(color, [50.0, 50.0]);
+        });
+    });
+
+    render(harness.yak_mut())
+}
+
+/// Renders "Hi" in white in the corner of a 100x60 viewport, returning the
+/// image and how many vertices were painted.
+fn render_text(style: impl FnOnce(&mut TextStyle)) -> (RgbaImage, usize) {
+    let mut harness = Harness::with_size(Vec2::new(100.0, 60.0));
+    harness.frame(|| {
+        align(Alignment::TOP_LEFT, || {
+            pad(Pad::all(10.0), || {
+                let mut text = RenderText::new("Hi");
+                text.style.font_size = 24.0;
+                style(&mut text.style);
+                text.show();
+            });
+        });
+    });
+
+    let image = render(harness.yak_mut());
+    (image, harness.frame_stats().vertices)
+}
+
+/// Counts the pixels that aren't fully transparent and match `filter`.
+fn count_pixels(image: &RgbaImage, filter: impl Fn([u8; 4]) -> bool) -> usize {
+    image
+        .pixels()
+        .filter(|pixel| pixel.0[3] > 0 && filter(pixel.0))
+        .count()
+}
+
+#[test]
+fn rendering_is_deterministic() {
+    let first = render_box(Color::RED);
+    let second = render_box(Color::RED);
+
+    assert!(compare(&first, &second, Tolerance::exact()).is_ok());
+}
+
+#[test]
+fn small_color_changes_stay_within_tolerance() {
+    let expected = render_box(Color::rgb(200, 0, 0));
+    let actual = render_box(Color::rgb(201, 0, 0));
+
+    assert!(compare(&expected, &actual, Tolerance::default()).is_ok());
+    assert!(compare(&expected, &actual, Tolerance::exact()).is_err());
+}
+
+#[test]
+fn mismatches_count_differing_pixels() {
+    let expected = render_box(Color::RED);
+    let actual = render_box(Color::BLUE);
+
+    match compare(&expected, &actual, Tolerance::default()) {
+        Err(Mismatch::Pixels { count, diff, .. }) => {
+            assert_eq!(count, 50 * 50);
+            assert_eq!(diff.dimensions(), (100, 100));
+        }
+        other => panic!("expected pixels to differ, got {other:?}"),
+    }
+
+    let tolerance = Tolerance {
+        pixels: 50 * 50,
+        ..Tolerance::default()
+    };
+    assert!(compare(&expected, &actual, tolerance).is_ok());
+}
+
+#[test]
+fn golden_colored_boxes() {
+    let mut harness = Harness::with_size(Vec2::new(120.0, 60.0));
+    harness.frame(|| {
+        pad(Pad::all(10.0), || {
+            row(|| {
+                colored_box(Color::RED, [40.0, 40.0]);
+                colored_box(Color::GREEN, [20.0, 40.0]);
+                colored_box(Color::BLUE, [40.0, 20.0]);
+            });
+        });
+    });
+
+    assert_golden!(*harness.yak_mut(), "colored_boxes");
+}
+
+#[test]
+fn golden_controls() {
+    let mut harness = Harness::with_size(Vec2::new(200.0, 140.0));
+    harness.frame(|| {
+        pad(Pad::all(8.0), || {
+            column(|| {
+                let _ = button("Hello");
+                let _ = checkbox(true);
+                let _ = slider(0.25, 0.0, 1.0);
+            });
+        });
+    });
+
+    assert_golden!(*harness.yak_mut(), "controls");
+}
+
+#[test]
+fn text_effects_paint_each_glyph_once_per_effect() {
+    let shadow = TextShadow {
+        offset: Vec2::new(2.0, 2.0),
+        blur: 4.0,
+        color: Color::BLACK,
+    };
+    let outline = TextOutline {
+        thickness: 3.0,
+        color: Color::RED,
+    };
+
+    let (_, plain) = render_text(|_| {});
+    let (_, shadowed) = render_text(|style| style.shadow = Some(shadow));
+    let (_, outlined) = render_text(|style| style.outline = Some(outline));
+    let (_, both) = render_text(|style| {
+        style.shadow = Some(shadow);
+        style.outline = Some(outline);
+    });
+
+    assert!(plain > 0);
+    assert_eq!(shadowed, plain * 2);
+    assert_eq!(outlined, plain * 2);
+    assert_eq!(both, plain * 3);
+}
+
+#[test]
+fn text_outline_surrounds_the_glyphs() {
+    let (plain, _) = render_text(|_| {});
+    let (outlined, _) = render_text(|style| {
+        style.outline = Some(TextOutline {
+            thickness: 3.0,
+            color: Color::RED,
+        });
+    });
+
+    let red = |[r, g, b, _]: [u8; 4]| r > 128 && g < 64 && b < 64;
+    assert_eq!(count_pixels(&plain, red), 0);
+
+    // The outline is about 3 pixels thick all the way around, so it covers a
+    // lot more than the text does.
+    let plain_coverage = count_pixels(&plain, |_| true);
+    assert!(count_pixels(&outlined, red) > plain_coverage);
+
+    // The text itself is still painted on top of the outline.
+    let white = |[r, g, b, _]: [u8; 4]| r > 200 && g > 200 && b > 200;
+    assert!(count_pixels(&outlined, white) > plain_coverage / 2);
+}
+
+#[test]
+fn text_shadow_is_painted_at_its_offset_and_blurred() {
+    let blue = |[r, g, b, _]: [u8; 4]| b > r && b > g;
+    let shadow = |blur| {
+        move |style: &mut TextStyle| {
+            style.shadow = Some(TextShadow {
+                offset: Vec2::new(0.0, 20.0),
+                blur,
+                color: Color::BLUE,
+            });
+        }
+    };
+
+    let (plain, _) = render_text(|_| {});
+    let (hard, _) = render_text(shadow(0.0));
+    let (soft, _) = render_text(shadow(4.0));
+
+    let lowest = |image: &RgbaImage| {
+        image
+            .enumerate_pixels()
+            .filter(|(_, _, pixel)| pixel.0[3] > 0)
+            .map(|(_, y, _)| y)
+            .max()
+            .unwrap()
+    };
+
+    // Moving the shadow down 20 pixels moves the bottom of everything that's
+    // painted down by the same amount.
+    assert_eq!(lowest(&hard), lowest(&plain) + 20);
+    assert!(count_pixels(&hard, blue) > 0);
+
+    // Blurring spreads the shadow over more pixels, but it stays centered on
+    // the same spot.
+    assert!(count_pixels(&soft, blue) > count_pixels(&hard, blue));
+    assert!(lowest(&soft) > lowest(&hard));
+    assert!(lowest(&soft) <= lowest(&hard) + 4);
+}
+
+/// Renders a 100x50 white outline in the corner of a 100x100 viewport,
+/// returning the image and how many vertices were painted.
+fn render_outline(change: impl FnOnce(&mut Outline)) -> (RgbaImage, usize) {
+    let mut harness = Harness::with_size(Vec2::new(100.0, 100.0));
+    harness.frame(|| {
+        align(Alignment::TOP_LEFT, || {
+            constrained(Constraints::tight(Vec2::new(100.0, 50.0)), || {
+                let mut outline = Outline::new(Color::WHITE, 2.0);
+                change(&mut outline);
+                outline.show();
+            });
+        });
+    });
+
+    let image = render(harness.yak_mut());
+    (image, harness.frame_stats().vertices)
+}
+
+fn is_white(image: &RgbaImage, x: u32, y: u32) -> bool {
+    image.get_pixel(x, y).0 == [255, 255, 255, 255]
+}
+
+#[test]
+fn dashed_outlines_leave_gaps_along_each_side() {
+    let (image, _) = render_outline(|outline| {
+        outline.style = StrokeStyle::Dashed {
+            dash: 10.0,
+            gap: 10.0,
+        };
+    });
+
+    // Dashes start where each side starts, just inside of the corners.
+    assert!(is_white(&image, 0, 0));
+    assert!(is_white(&image, 7, 1));
+    assert!(!is_white(&image, 17, 1));
+    assert!(is_white(&image, 27, 1));
+
+    assert!(is_white(&image, 1, 7));
+    assert!(!is_white(&image, 1, 17));
+    assert!(is_white(&image, 98, 27));
+    assert!(!is_white(&image, 50, 25));
+}
+
+#[test]
+fn tiny_dashes_are_limited_to_a_reasonable_number_of_quads() {
+    let (_, solid) = render_outline(|_| {});
+    let (image, tiny) = render_outline(|outline| {
+        outline.style = StrokeStyle::Dashed {
+            dash: 0.001,
+            gap: 0.001,
+        };
+    });
+
+    // The sides add up to less than 300 pixels, and every dash starts at
+    // least half a pixel after the one before it.
+    assert!(tiny > solid);
+    assert!(tiny < solid + 4 * 600, "{tiny} vertices");
+    assert!(!is_white(&image, 50, 25));
+
+    for style in [
+        StrokeStyle::Dashed {
+            dash: -5.0,
+            gap: 2.0,
+        },
+        StrokeStyle::Dashed {
+            dash: f32::NAN,
+            gap: 2.0,
+        },
+    ] {
+        let (image, _) = render_outline(|outline| outline.style = style);
+        assert!(!is_white(&image, 50, 1), "{style:?}");
+    }
+}
+
+#[test]
+fn outline_sides_can_have_their_own_widths() {
+    let (image, _) = render_outline(|outline| {
+        outline.widths = OutlineWidths {
+            left: 4.0,
+            right: 0.0,
+            top: 2.0,
+            bottom: 0.0,
+        };
+    });
+
+    assert!(is_white(&image, 3, 25));
+    assert!(!is_white(&image, 4, 25));
+    assert!(is_white(&image, 50, 1));
+    assert!(!is_white(&image, 50, 2));
+    assert!(!is_white(&image, 99, 25));
+    assert!(!is_white(&image, 50, 49));
+
+    // Sides that are too wide for the rectangle stop at its far edge.
+    let (image, _) = render_outline(|outline| {
+        outline.widths = OutlineWidths {
+            left: 500.0,
+            right: 0.0,
+            top: 0.0,
+            bottom: 0.0,
+        };
+    });
+    assert!(is_white(&image, 99, 25));
+    assert!(!is_white(&image, 50, 75));
+}
+
+/// Renders a 24px label in a 200x40 viewport, returning the range of columns
+/// that its glyphs cover. The label is `width` wide if given, or as wide as
+/// its text, and is pushed to the right by a right-to-left row.
+fn render_label_columns(
+    direction: LayoutDirection,
+    text: &str,
+    align: TextAlignment,
+    width: Option<f32>,
+) -> (u32, u32) {
+    let mut harness = Harness::with_size(Vec2::new(200.0, 40.0));
+    harness.yak_mut().set_layout_direction(direction);
+    harness.frame(|| {
+        let mut row = List::row();
+        row.main_axis_size = MainAxisSize::Max;
+        row.show(|| {
+            let constraints = match width {
+                Some(width) => Constraints {
+                    min: Vec2::new(width, 0.0),
+                    max: Vec2::new(width, f32::INFINITY),
+                },
+                None => Constraints::none(),
+            };
+
+            constrained(constraints, || {
+                let mut label = RenderText::new(text);
+                label.style.font_size = 24.0;
+                label.style.align = align;
+                label.show();
+            });
+        });
+    });
+
+    let image = render(harness.yak_mut());
+    let columns: Vec<u32> = image
+        .enumerate_pixels()
+        .filter(|(_, _, pixel)| pixel.0[3] > 0 && pixel.0[0] > 128)
+        .map(|(x, _, _)| x)
+        .collect();
+
+    let first = *columns.iter().min().expect("no glyphs were painted");
+    let last = *columns.iter().max().unwrap();
+    (first, last)
+}
+
+#[test]
+fn right_to_left_labels_are_aligned_once() {
+    use LayoutDirection::{LeftToRight, RightToLeft};
+
+    // U+200F makes the paragraph right-to-left without needing a font with
+    // right-to-left glyphs.
+    let rtl = "\u{200F}Hi";
+
+    let (first, _) = render_label_columns(LeftToRight, "Hi", TextAlignment::Start, None);
+    assert!(
+        first < 4,
+        "left-to-right text starts on the left, at {first}"
+    );
+
+    // A label as narrow as its text is put on the right by the row, and its
+    // glyphs aren't moved again inside it.
+    let (first, last) = render_label_columns(RightToLeft, rtl, TextAlignment::Start, None);
+    assert!(
+        last > 195,
+        "right-to-left text ends on the right, at {last}"
+    );
+    assert!(first > 170, "the text is only as wide as its glyphs");
+
+    // A label wider than its text starts its right-to-left lines on the right.
+    for width in [100.0, 200.0] {
+        let (_, last) = render_label_columns(RightToLeft, rtl, TextAlignment::Start, Some(width));
+        assert!(
+            last > 195,
+            "right-aligned at {width} wide, but ended at {last}"
+        );
+
+        let (first, _) = render_label_columns(RightToLeft, rtl, TextAlignment::End, Some(width));
+        let start = 200 - width as u32;
+        assert!(
+            (start..start + 4).contains(&first),
+            "end-aligned at {width} wide, but started at {first}"
+        );
+    }
+}
@@ -0,0 +1,954 @@
+use std::time::Duration;
+
+use yakui::event::Event;
+use yakui::input::{KeyCode, LogicalKey, Modifiers, MouseButton, ScrollUnit};
+use yakui::{
+    Alignment, Color, Constraints, Dim2, Pivot, Rect, Response, TextureId, UVec2, Vec2, WidgetId,
+};
+use yakui_test::Harness;
+use yakui_widgets::widgets::{
+    ButtonResponse, ColumnFilter, DragSourceResponse, DropTargetResponse, Grid, GridTrack, Image,
+    ImageFit, ImageRegion, List, Scrollable, StatusBar, StatusBarRegion, StatusBarResponse,
+    StatusSegment, TableColumn, TableResponse, Text, TextBoxResponse, Transform,
+};
+use yakui_widgets::{
+    align, button, checkbox, colored_box, column, constrained, dismiss_on_outside_click,
+    drag_source, drop_target, expanded, keyed, label, offset, reflow, row, table, textbox,
+    use_state,
+};
+
+#[test]
+fn clicking_a_button_reports_a_click() {
+    let mut harness = Harness::new();
+
+    let id = harness.frame(|| button("Save").id);
+    harness.click_widget(id);
+
+    let clicked = harness.frame(|| button("Save").clicked);
+    assert!(clicked);
+
+    let clicked = harness.frame(|| button("Save").clicked);
+    assert!(!clicked, "clicks are only reported once");
+}
+
+fn hold_button() -> ButtonResponse {
+    button("Hold").into_inner()
+}
+
+#[test]
+fn holding_a_button_reports_a_long_press_instead_of_a_click() {
+    let mut harness = Harness::new();
+    let id = harness.frame(|| button("Hold").id);
+    let center = harness.rect(id).pos() + harness.rect(id).size() / 2.0;
+
+    harness.move_mouse(center);
+    set_mouse_button(&mut harness, true);
+
+    harness.advance_time(Duration::from_millis(499));
+    assert!(!harness.frame(hold_button).long_pressed);
+
+    harness.advance_time(Duration::from_millis(1));
+    assert!(harness.frame(hold_button).long_pressed);
+
+    harness.advance_time(Duration::from_millis(500));
+    let held = harness.frame(hold_button);
+    assert!(!held.long_pressed, "long presses are only reported once");
+
+    set_mouse_button(&mut harness, false);
+    let released = harness.frame(hold_button);
+    assert!(
+        !released.clicked,
+        "releasing after a long press isn't a click"
+    );
+    assert!(!released.long_pressed);
+}
+
+#[test]
+fn moving_off_a_held_button_cancels_its_long_press() {
+    let mut harness = Harness::new();
+    let id = harness.frame(|| button("Hold").id);
+    let center = harness.rect(id).pos() + harness.rect(id).size() / 2.0;
+
+    harness.move_mouse(center);
+    set_mouse_button(&mut harness, true);
+    harness.frame(hold_button);
+
+    harness.leave_window();
+    harness.advance_time(Duration::from_millis(500));
+    assert!(!harness.frame(hold_button).long_pressed);
+
+    harness.move_mouse(center);
+    harness.advance_time(Duration::from_millis(500));
+    assert!(!harness.frame(hold_button).long_pressed);
+}
+
+#[test]
+fn clicking_a_checkbox_toggles_only_that_checkbox() {
+    let mut harness = Harness::new();
+
+    let (first, second) = harness.frame(|| {
+        let mut ids = None;
+        column(|| {
+            ids = Some((checkbox(false).id, checkbox(false).id));
+        });
+        ids.unwrap()
+    });
+    assert!(harness.rect(second).pos().y >= harness.rect(first).max().y);
+
+    harness.click_widget(second);
+
+    let (first, second) = harness.frame(|| {
+        let mut checked = (false, false);
+        column(|| {
+            checked = (checkbox(false).checked, checkbox(false).checked);
+        });
+        checked
+    });
+    assert!(!first);
+    assert!(second);
+}
+
+const FRUITS: [(&str, &str); 3] = [("Apple", "Red"), ("Banana", "Yellow"), ("Cherry", "Red")];
+
+/// Shows a table of fruits, returning its response and the names of the
+/// fruits that weren't filtered out.
+fn fruit_table() -> (TableResponse, Vec<&'static str>) {
+    let columns = vec![
+        TableColumn::new("Name").filter(ColumnFilter::Text),
+        TableColumn::new("Color").filter(ColumnFilter::values(["Red", "Yellow"])),
+    ];
+
+    let mut shown = Vec::new();
+    let response = table(columns, |table| {
+        for (name, color) in FRUITS {
+            if table.matches([name, color]) {
+                label(name);
+                label(color);
+                shown.push(name);
+            }
+        }
+    });
+
+    (response.into_inner(), shown)
+}
+
+#[test]
+fn table_filter_popup_hides_unchecked_values() {
+    let mut harness = Harness::new();
+
+    let (response, shown) = harness.frame(fruit_table);
+    assert!(response.active().next().is_none());
+    assert_eq!(shown, ["Apple", "Banana", "Cherry"]);
+
+    // The second header button is the color column's.
+    let header = harness
+        .inspect()
+        .find_by_name("ButtonWidget")
+        .nth(1)
+        .unwrap()
+        .id;
+    harness.click_widget(header);
+    harness.frame(fruit_table);
+
+    // The popup has a checkbox for each color, starting with red.
+    let red = harness
+        .inspect()
+        .find_by_name("CheckboxWidget")
+        .next()
+        .unwrap()
+        .id;
+    harness.click_widget(red);
+
+    let (response, shown) = harness.frame(fruit_table);
+    assert!(response.changed);
+    assert_eq!(response.filters[1].hidden, ["Red"]);
+    assert_eq!(
+        response
+            .active()
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>(),
+        [1]
+    );
+    assert_eq!(shown, ["Banana"]);
+
+    // Clicking outside of the popup closes it, and the filter stays.
+    harness.click(Vec2::new(900.0, 900.0));
+    harness.frame(fruit_table);
+    let (response, shown) = harness.frame(fruit_table);
+    assert!(!response.changed);
+    assert_eq!(harness.inspect().find_by_name("CheckboxWidget").count(), 0);
+    assert_eq!(shown, ["Banana"]);
+}
+
+#[test]
+fn table_text_filter_keeps_matching_cells() {
+    let mut harness = Harness::new();
+
+    harness.frame(fruit_table);
+    let header = harness
+        .inspect()
+        .find_by_name("ButtonWidget")
+        .next()
+        .unwrap()
+        .id;
+    harness.click_widget(header);
+    harness.frame(fruit_table);
+
+    let textbox = harness
+        .inspect()
+        .find_by_name("TextBoxWidget")
+        .next()
+        .unwrap()
+        .id;
+    harness.click_widget(textbox);
+    harness.frame(fruit_table);
+    harness.type_text("an");
+
+    let (response, shown) = harness.frame(fruit_table);
+    assert!(response.changed);
+    assert_eq!(response.filters[0].text, "an");
+    assert_eq!(shown, ["Banana"]);
+}
+
+#[test]
+fn grid_sizes_fixed_auto_and_flex_columns() {
+    let mut harness = Harness::new();
+
+    let cells = harness.frame(|| {
+        let mut grid = Grid::new(vec![
+            GridTrack::Fixed(100.0),
+            GridTrack::Auto,
+            GridTrack::Flex(1.0),
+        ]);
+        grid.column_gap = 10.0;
+        grid.row_gap = 5.0;
+
+        let mut cells = Vec::new();
+        grid.show(|| {
+            for size in [
+                [80.0, 20.0],
+                [30.0, 40.0],
+                [60.0, 10.0],
+                [10.0, 10.0],
+                [50.0, 15.0],
+                [20.0, 25.0],
+            ] {
+                cells.push(colored_box(Color::RED, size).id);
+            }
+        });
+        cells
+    });
+
+    // The auto column is as wide as its widest child, and the flex column gets
+    // the rest of the 1000 pixel wide viewport.
+    let xs: Vec<f32> = cells.iter().map(|&id| harness.rect(id).pos().x).collect();
+    assert_eq!(xs, [0.0, 110.0, 170.0, 0.0, 110.0, 170.0]);
+
+    // Each row is as tall as its tallest cell, and children in the auto column
+    // keep the size they were measured at.
+    let ys: Vec<f32> = cells.iter().map(|&id| harness.rect(id).pos().y).collect();
+    assert_eq!(ys, [0.0, 0.0, 0.0, 45.0, 45.0, 45.0]);
+    assert_eq!(harness.rect(cells[1]).size(), Vec2::new(30.0, 40.0));
+    assert_eq!(harness.rect(cells[4]).size(), Vec2::new(50.0, 15.0));
+}
+
+#[test]
+fn clicking_an_offset_button() {
+    let mut harness = Harness::new();
+    let show = || {
+        let mut clicked = false;
+        offset(Vec2::new(300.0, 200.0), || {
+            clicked = button("Offset").clicked;
+        });
+        clicked
+    };
+
+    harness.frame(show);
+    harness.click(Vec2::new(10.0, 10.0));
+    assert!(
+        !harness.frame(show),
+        "clicks where the button isn't do nothing"
+    );
+
+    harness.click(Vec2::new(310.0, 210.0));
+    assert!(harness.frame(show));
+}
+
+#[test]
+fn clicking_a_reflowed_button() {
+    let mut harness = Harness::new();
+    let show = || {
+        let mut clicked = false;
+        column(|| {
+            colored_box(Color::RED, [200.0, 100.0]);
+            reflow(
+                Alignment::BOTTOM_RIGHT,
+                Pivot::BOTTOM_RIGHT,
+                Dim2::ZERO,
+                || {
+                    clicked = button("Reflowed").clicked;
+                },
+            );
+        });
+        clicked
+    };
+
+    harness.frame(show);
+
+    // The button would be under the box if it wasn't reflowed. Instead, it's
+    // in the bottom right corner of the column.
+    harness.click(Vec2::new(10.0, 110.0));
+    assert!(!harness.frame(show));
+
+    harness.click(Vec2::new(990.0, 990.0));
+    assert!(harness.frame(show));
+}
+
+#[test]
+fn clicking_a_transformed_checkbox() {
+    let mut harness = Harness::new();
+    let show = || {
+        let mut response = None;
+        align(Alignment::TOP_LEFT, || {
+            let mut transform = Transform::new();
+            transform.translation = Vec2::new(300.0, 0.0);
+            transform.show(|| {
+                response = Some(checkbox(false));
+            });
+        });
+
+        let response = response.unwrap();
+        (response.id, response.checked)
+    };
+
+    let (id, _) = harness.frame(show);
+    let rect = harness.rect(id);
+    let center = rect.pos() + rect.size() / 2.0;
+
+    // Widgets are hit tested where they're painted, not where they were laid
+    // out.
+    harness.click(center);
+    assert!(!harness.frame(show).1);
+
+    harness.click(center + Vec2::new(300.0, 0.0));
+    assert!(harness.frame(show).1);
+}
+
+fn status_bar() -> StatusBarResponse {
+    let mut bar = StatusBar::new();
+    bar.left.push(StatusSegment::new("main.rs"));
+    bar.center = Some(StatusSegment::new("Compiling...").click_through());
+    bar.right.push(StatusSegment::new("Ln 1"));
+    bar.right.push(StatusSegment::new("UTF-8"));
+    bar.show().into_inner()
+}
+
+#[test]
+fn clicking_a_status_bar_segment_reports_its_region() {
+    let mut harness = Harness::new();
+    harness.frame(status_bar);
+
+    // Only the segments that aren't click-through are buttons.
+    let buttons: Vec<_> = harness
+        .inspect()
+        .find_by_name("ButtonWidget")
+        .map(|node| node.id)
+        .collect();
+    assert_eq!(buttons.len(), 3);
+
+    harness.click_widget(buttons[2]);
+    let response = harness.frame(status_bar);
+    assert_eq!(response.clicked, Some((StatusBarRegion::Right, 1)));
+
+    let response = harness.frame(status_bar);
+    assert_eq!(response.clicked, None);
+}
+
+#[test]
+fn keyed_flexible_children_still_flex() {
+    let mut harness = Harness::with_size(Vec2::new(300.0, 100.0));
+
+    let id = harness.frame(|| {
+        let mut id = None;
+        row(|| {
+            colored_box(Color::RED, [100.0, 10.0]);
+            id = Some(keyed("fill", || {
+                expanded(|| {
+                    colored_box(Color::BLUE, [10.0, 10.0]);
+                })
+                .id
+            }));
+        });
+        id.unwrap()
+    });
+
+    assert_eq!(harness.rect(id).size().x, 200.0);
+}
+
+/// Shows each item, returning the state that each item started with. Each item
+/// creates more than one widget, which are keyed by their order in the item.
+fn keyed_items(items: &[&'static str]) -> Vec<&'static str> {
+    let mut seen = Vec::new();
+    column(|| {
+        for &item in items {
+            keyed(item, || {
+                label(item);
+                seen.push(use_state(move || item).get());
+            });
+        }
+    });
+    seen
+}
+
+#[test]
+fn keyed_children_keep_their_state_when_reordered() {
+    let mut harness = Harness::new();
+
+    assert_eq!(harness.frame(|| keyed_items(&["a", "b"])), ["a", "b"]);
+    assert_eq!(harness.frame(|| keyed_items(&["b", "a"])), ["b", "a"]);
+    assert_eq!(
+        harness.frame(|| keyed_items(&["c", "b", "a"])),
+        ["c", "b", "a"]
+    );
+}
+
+/// Shows a long label in a narrow box, with a tooltip when it's truncated.
+fn long_label(max_lines: Option<usize>) -> WidgetId {
+    let mut id = None;
+    align(Alignment::TOP_LEFT, || {
+        constrained(Constraints::loose(Vec2::new(120.0, 500.0)), || {
+            let mut text = Text::new(20.0, "A label that is much too long for one line");
+            text.style.max_lines = max_lines;
+            text.tooltip_when_truncated = true;
+            id = Some(text.show().id);
+        });
+    });
+    id.unwrap()
+}
+
+#[test]
+fn text_cut_off_by_max_lines_shows_a_tooltip() {
+    for (max_lines, tooltip) in [(Some(1), true), (None, false)] {
+        let mut harness = Harness::new();
+
+        let id = harness.frame(|| long_label(max_lines));
+        harness.move_mouse(harness.rect(id).pos() + Vec2::splat(5.0));
+        harness.frame(|| long_label(max_lines));
+
+        // The tooltip is another copy of the text, on top of everything else.
+        let texts = harness.inspect().find_by_name("RenderTextWidget").count();
+        assert_eq!(texts == 2, tooltip, "max_lines: {max_lines:?}");
+    }
+}
+
+/// Shows an image in the corner of a 100x100 viewport, returning the position
+/// and texture coordinate of each painted vertex, in pixels and UVs.
+fn paint_image(change: impl FnOnce(&mut Image)) -> Vec<(Vec2, Vec2)> {
+    let mut harness = Harness::with_size(Vec2::new(100.0, 100.0));
+    harness.frame(|| {
+        align(Alignment::TOP_LEFT, || {
+            let mut image = Image::new(TextureId::User(0), Vec2::new(100.0, 100.0));
+            image.texture_size = Some(UVec2::new(200, 100));
+            change(&mut image);
+            image.show();
+        });
+    });
+
+    let paint = harness.yak_mut().paint();
+    paint
+        .layers()
+        .iter()
+        .flat_map(|layer| &layer.calls)
+        .flat_map(|call| &call.vertices)
+        .map(|vertex| (vertex.position * 100.0, vertex.texcoord))
+        .collect()
+}
+
+/// Returns the smallest rect containing all of the points.
+fn bounds(points: impl Iterator<Item = Vec2>) -> Rect {
+    let (min, max) = points.fold((Vec2::INFINITY, Vec2::NEG_INFINITY), |(min, max), point| {
+        (min.min(point), max.max(point))
+    });
+    Rect::from_pos_size(min, max - min)
+}
+
+fn assert_rect_near(actual: Rect, expected: Rect) {
+    let near = actual.pos().abs_diff_eq(expected.pos(), 0.001)
+        && actual.size().abs_diff_eq(expected.size(), 0.001);
+    assert!(near, "expected {expected:?}, got {actual:?}");
+}
+
+#[test]
+fn image_sources_pick_a_region_of_the_texture() {
+    let uv = Rect::from_pos_size(Vec2::new(0.5, 0.25), Vec2::splat(0.25));
+    let vertices = paint_image(|image| image.source = Some(ImageRegion::Uv(uv)));
+    assert_rect_near(bounds(vertices.iter().map(|v| v.1)), uv);
+
+    // Pixel regions are divided by the size of the texture.
+    let pixels = Rect::from_pos_size(Vec2::new(100.0, 25.0), Vec2::splat(50.0));
+    let vertices = paint_image(|image| image.source = Some(ImageRegion::Pixels(pixels)));
+    let uv = Rect::from_pos_size(Vec2::new(0.5, 0.25), Vec2::new(0.25, 0.5));
+    assert_rect_near(bounds(vertices.iter().map(|v| v.1)), uv);
+
+    // An empty texture has no pixels to pick from, so it's shown in full.
+    let vertices = paint_image(|image| {
+        image.texture_size = Some(UVec2::ZERO);
+        image.source = Some(ImageRegion::Pixels(pixels));
+    });
+    assert_rect_near(bounds(vertices.iter().map(|v| v.1)), Rect::ONE);
+}
+
+#[test]
+fn images_fit_their_texture_into_their_rect() {
+    // The texture is twice as wide as the image, so containing it leaves
+    // space above and below, and covering crops off its sides.
+    let vertices = paint_image(|image| image.fit = ImageFit::Contain);
+    let rect = Rect::from_pos_size(Vec2::new(0.0, 25.0), Vec2::new(100.0, 50.0));
+    assert_rect_near(bounds(vertices.iter().map(|v| v.0)), rect);
+    assert_rect_near(bounds(vertices.iter().map(|v| v.1)), Rect::ONE);
+
+    let vertices = paint_image(|image| image.fit = ImageFit::Cover);
+    let uv = Rect::from_pos_size(Vec2::new(0.25, 0.0), Vec2::new(0.5, 1.0));
+    let full = Rect::from_pos_size(Vec2::ZERO, Vec2::new(100.0, 100.0));
+    assert_rect_near(bounds(vertices.iter().map(|v| v.0)), full);
+    assert_rect_near(bounds(vertices.iter().map(|v| v.1)), uv);
+
+    // A 50x50 region at its natural size fills half of the image.
+    let vertices = paint_image(|image| {
+        image.fit = ImageFit::None;
+        image.source = Some(ImageRegion::Pixels(Rect::from_pos_size(
+            Vec2::new(100.0, 0.0),
+            Vec2::new(50.0, 50.0),
+        )));
+    });
+    let rect = Rect::from_pos_size(Vec2::new(25.0, 25.0), Vec2::new(50.0, 50.0));
+    let uv = Rect::from_pos_size(Vec2::new(0.5, 0.0), Vec2::new(0.25, 0.5));
+    assert_rect_near(bounds(vertices.iter().map(|v| v.0)), rect);
+    assert_rect_near(bounds(vertices.iter().map(|v| v.1)), uv);
+}
+
+#[test]
+fn flipped_images_mirror_their_texture_coordinates() {
+    let texcoord_at = |vertices: &[(Vec2, Vec2)], pos: Vec2| {
+        let vertex = vertices.iter().find(|v| v.0.abs_diff_eq(pos, 0.001));
+        vertex.expect("no vertex at that position").1
+    };
+
+    let vertices = paint_image(|image| image.flip_x = true);
+    assert_eq!(texcoord_at(&vertices, Vec2::ZERO), Vec2::new(1.0, 0.0));
+    assert_eq!(
+        texcoord_at(&vertices, Vec2::splat(100.0)),
+        Vec2::new(0.0, 1.0)
+    );
+
+    let vertices = paint_image(|image| {
+        image.flip_y = true;
+        image.source = Some(ImageRegion::Uv(Rect::from_pos_size(
+            Vec2::new(0.5, 0.5),
+            Vec2::splat(0.25),
+        )));
+    });
+    assert_eq!(texcoord_at(&vertices, Vec2::ZERO), Vec2::new(0.5, 0.75));
+    assert_eq!(
+        texcoord_at(&vertices, Vec2::splat(100.0)),
+        Vec2::new(0.75, 0.5)
+    );
+}
+
+#[test]
+fn images_with_empty_rects_never_paint_nan() {
+    let assert_no_nan = |name: &str, vertices: Vec<(Vec2, Vec2)>| {
+        let nan = vertices.iter().any(|v| v.0.is_nan() || v.1.is_nan());
+        assert!(!nan, "{name} painted NaN: {vertices:?}");
+    };
+
+    let vertices = paint_image(|image| {
+        image.size = Vec2::ZERO;
+        image.fit = ImageFit::Contain;
+    });
+    assert!(vertices.is_empty(), "an image with no room isn't painted");
+
+    let vertices = paint_image(|image| {
+        image.size = Vec2::new(100.0, 0.0);
+        image.fit = ImageFit::Cover;
+    });
+    assert_no_nan("zero height", vertices);
+
+    let vertices = paint_image(|image| {
+        image.fit = ImageFit::Contain;
+        image.source = Some(ImageRegion::Uv(Rect::from_pos_size(
+            Vec2::splat(0.5),
+            Vec2::ZERO,
+        )));
+    });
+    assert_no_nan("empty source", vertices);
+}
+
+/// Shows 50 pixel tall boxes in a 100x100 vertical scrollable, keyed by their
+/// item, returning the ID of each box.
+fn scrolling_boxes(items: &[u32], anchor: Option<WidgetId>) -> Vec<WidgetId> {
+    let mut ids = Vec::new();
+    align(Alignment::TOP_LEFT, || {
+        constrained(Constraints::loose(Vec2::new(100.0, 100.0)), || {
+            let mut scroll = Scrollable::vertical();
+            scroll.anchor = anchor;
+            scroll.show(|| {
+                column(|| {
+                    for &item in items {
+                        ids.push(keyed(item, || colored_box(Color::RED, [100.0, 50.0]).id));
+                    }
+                });
+            });
+        });
+    });
+    ids
+}
+
+fn scroll_by(harness: &mut Harness, y: f32) {
+    harness.move_mouse(Vec2::new(50.0, 50.0));
+    harness.send(Event::MouseScroll {
+        delta: Vec2::new(0.0, y),
+        unit: ScrollUnit::Pixels,
+    });
+}
+
+#[test]
+fn scroll_anchor_stays_put_when_items_are_inserted_above_it() {
+    for anchored in [true, false] {
+        let mut harness = Harness::with_size(Vec2::new(200.0, 200.0));
+
+        let ids = harness.frame(|| scrolling_boxes(&[3, 4, 5, 6, 7], None));
+        scroll_by(&mut harness, 100.0);
+
+        // The third box is scrolled to the top.
+        let anchor = anchored.then_some(ids[2]);
+        harness.frame(|| scrolling_boxes(&[3, 4, 5, 6, 7], anchor));
+        assert_eq!(harness.rect(ids[2]).pos().y, 0.0);
+
+        let new_ids = harness.frame(|| scrolling_boxes(&[0, 1, 2, 3, 4, 5, 6, 7], anchor));
+        assert_eq!(new_ids[5], ids[2], "the box keeps its ID");
+
+        // Without an anchor, the scroll position stays the same and the box
+        // is pushed down by the inserted items.
+        let y = if anchored { 0.0 } else { 150.0 };
+        assert_eq!(harness.rect(ids[2]).pos().y, y, "anchored: {anchored}");
+    }
+}
+
+/// Shows a 10 pixel tall row for each item in a 100x100 fixed-row scrollable,
+/// keyed by the item, returning the ID of each row.
+fn fixed_rows(items: impl Iterator<Item = u32>, anchor: Option<WidgetId>) -> Vec<WidgetId> {
+    let mut ids = Vec::new();
+    align(Alignment::TOP_LEFT, || {
+        constrained(Constraints::loose(Vec2::new(100.0, 100.0)), || {
+            let mut scroll = Scrollable::fixed_rows(10.0);
+            scroll.anchor = anchor;
+            scroll.show(|| {
+                for item in items {
+                    ids.push(keyed(item, || colored_box(Color::RED, [100.0, 10.0]).id));
+                }
+            });
+        });
+    });
+    ids
+}
+
+#[test]
+fn fixed_rows_only_lay_out_rows_near_the_viewport() {
+    let mut harness = Harness::with_size(Vec2::new(200.0, 200.0));
+
+    let ids = harness.frame(|| fixed_rows(0..100, None));
+    scroll_by(&mut harness, 500.0);
+    harness.frame(|| fixed_rows(0..100, None));
+
+    // Rows 50 to 59 are visible, and rows within one viewport's height of them
+    // are laid out too.
+    let laid_out =
+        |harness: &Harness, index: usize| harness.yak().layout_dom().get(ids[index]).is_some();
+    assert_eq!(harness.rect(ids[50]).pos().y, 0.0);
+    assert!(!laid_out(&harness, 39));
+    assert!(laid_out(&harness, 40));
+    assert!(laid_out(&harness, 69));
+    assert!(!laid_out(&harness, 70));
+
+    // Scrolling to the end lays out the last rows.
+    scroll_by(&mut harness, 10_000.0);
+    harness.frame(|| fixed_rows(0..100, None));
+    assert_eq!(harness.rect(ids[99]).pos().y, 90.0);
+    assert!(!laid_out(&harness, 79));
+    assert!(laid_out(&harness, 80));
+}
+
+#[test]
+fn fixed_rows_follow_their_anchor() {
+    let mut harness = Harness::with_size(Vec2::new(200.0, 200.0));
+
+    let ids = harness.frame(|| fixed_rows(10..100, None));
+    scroll_by(&mut harness, 200.0);
+    harness.frame(|| fixed_rows(10..100, Some(ids[20])));
+    assert_eq!(harness.rect(ids[20]).pos().y, 0.0);
+
+    // The anchor can be found without it being laid out, so the rows inserted
+    // above it are skipped over.
+    let new_ids = harness.frame(|| fixed_rows(0..100, Some(ids[20])));
+    assert_eq!(new_ids[30], ids[20]);
+    assert_eq!(harness.rect(ids[20]).pos().y, 0.0);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Item(u32);
+
+struct Inventory {
+    source: Response<DragSourceResponse>,
+    slot: Response<DropTargetResponse<Item>>,
+    label: Response<DropTargetResponse<String>>,
+}
+
+/// Shows an item that can be dragged, a slot that accepts items, and a target
+/// that only accepts strings, 50 pixels apart.
+fn inventory() -> Inventory {
+    let mut inventory = None;
+    align(Alignment::TOP_LEFT, || {
+        let mut row = List::row();
+        row.item_spacing = 50.0;
+        row.show(|| {
+            let source = drag_source(Item(7), || {
+                colored_box(Color::RED, [50.0, 50.0]);
+            });
+            let slot = drop_target::<Item>(|| {
+                colored_box(Color::BLUE, [50.0, 50.0]);
+            });
+            let label = drop_target::<String>(|| {
+                colored_box(Color::GREEN, [50.0, 50.0]);
+            });
+            inventory = Some(Inventory {
+                source,
+                slot,
+                label,
+            });
+        });
+    });
+    inventory.unwrap()
+}
+
+fn set_mouse_button(harness: &mut Harness, down: bool) {
+    harness.send(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down,
+    });
+}
+
+#[test]
+fn dragging_onto_a_drop_target_delivers_the_payload() {
+    let mut harness = Harness::new();
+    let first = harness.frame(inventory);
+    let center = |id| harness.rect(id).pos() + Vec2::splat(25.0);
+    let (source, slot) = (center(first.source.id), center(first.slot.id));
+
+    harness.move_mouse(source);
+    set_mouse_button(&mut harness, true);
+    harness.move_mouse(slot);
+
+    let dragging = harness.frame(inventory);
+    assert!(dragging.source.dragging);
+    assert_eq!(dragging.slot.hovering, Some(Item(7)));
+    assert_eq!(dragging.label.hovering, None);
+
+    set_mouse_button(&mut harness, false);
+    let dropped = harness.frame(inventory);
+    assert!(!dropped.source.dragging);
+    assert!(dropped.source.dropped);
+    assert_eq!(dropped.slot.dropped, Some(Item(7)));
+
+    // Drops are only reported once.
+    let after = harness.frame(inventory);
+    assert!(!after.source.dropped);
+    assert_eq!(after.slot.dropped, None);
+    assert_eq!(after.slot.hovering, None);
+}
+
+#[test]
+fn drop_targets_ignore_payloads_of_other_types() {
+    let mut harness = Harness::new();
+    let first = harness.frame(inventory);
+    let center = |id| harness.rect(id).pos() + Vec2::splat(25.0);
+    let (source, label) = (center(first.source.id), center(first.label.id));
+
+    harness.move_mouse(source);
+    set_mouse_button(&mut harness, true);
+    harness.move_mouse(label);
+    set_mouse_button(&mut harness, false);
+
+    let dropped = harness.frame(inventory);
+    assert!(!dropped.source.dropped);
+    assert_eq!(dropped.label.dropped, None);
+    assert_eq!(dropped.slot.dropped, None);
+}
+
+#[test]
+fn small_movements_do_not_start_a_drag() {
+    let mut harness = Harness::new();
+    let first = harness.frame(inventory);
+    let source = harness.rect(first.source.id).pos() + Vec2::splat(25.0);
+
+    harness.move_mouse(source);
+    set_mouse_button(&mut harness, true);
+    harness.move_mouse(source + Vec2::new(2.0, 2.0));
+
+    assert!(!harness.frame(inventory).source.dragging);
+}
+
+/// Shows a 50 wide popup in the corner with a 50x50 box reflowed past its right
+/// edge, returning whether it was dismissed.
+fn popup() -> bool {
+    let mut dismissed = false;
+    align(Alignment::TOP_LEFT, || {
+        dismissed = dismiss_on_outside_click(|| {
+            column(|| {
+                colored_box(Color::RED, [50.0, 50.0]);
+                reflow(Alignment::TOP_RIGHT, Pivot::TOP_LEFT, Dim2::ZERO, || {
+                    colored_box(Color::BLUE, [50.0, 50.0]);
+                });
+            });
+        })
+        .dismissed;
+    });
+    dismissed
+}
+
+#[test]
+fn pressing_outside_a_popup_dismisses_it_on_release() {
+    let mut harness = Harness::new();
+    harness.frame(popup);
+
+    harness.move_mouse(Vec2::new(300.0, 300.0));
+    set_mouse_button(&mut harness, true);
+    assert!(!harness.frame(popup), "a press alone doesn't dismiss");
+
+    set_mouse_button(&mut harness, false);
+    assert!(harness.frame(popup));
+    assert!(!harness.frame(popup), "dismissals are only reported once");
+}
+
+#[test]
+fn pressing_inside_a_popup_does_not_dismiss_it() {
+    let mut harness = Harness::new();
+    harness.frame(popup);
+
+    // The reflowed box is outside of the popup's rect, but still part of it.
+    for pos in [Vec2::new(25.0, 25.0), Vec2::new(75.0, 25.0)] {
+        harness.click(pos);
+        assert!(!harness.frame(popup), "clicked at {pos}");
+    }
+
+    // Only where the press lands matters, not the release.
+    harness.move_mouse(Vec2::new(25.0, 25.0));
+    set_mouse_button(&mut harness, true);
+    harness.move_mouse(Vec2::new(300.0, 300.0));
+    set_mouse_button(&mut harness, false);
+    assert!(!harness.frame(popup));
+}
+
+/// Focuses a textbox showing `text`, moves its cursor with `key`, and holds
+/// down the modifier for moving by word.
+fn focus_textbox(text: &str, key: KeyCode) -> (Harness, TextBoxEdits) {
+    let mut harness = Harness::new();
+    let mut edits = TextBoxEdits(text.to_owned());
+
+    let id = harness.frame(|| edits.show().id);
+    harness.click_widget(id);
+    harness.frame(|| edits.show());
+
+    harness.press_key(key, LogicalKey::Unidentified);
+    harness.frame(|| edits.show());
+
+    // Option is used instead of ctrl on macOS.
+    let word = if cfg!(target_os = "macos") {
+        Modifiers::ALT
+    } else {
+        Modifiers::CONTROL
+    };
+    harness.send(Event::ModifiersChanged(word));
+
+    (harness, edits)
+}
+
+/// The text of a textbox, kept up to date with the user's edits.
+struct TextBoxEdits(String);
+
+impl TextBoxEdits {
+    fn show(&mut self) -> Response<TextBoxResponse> {
+        let response = textbox(self.0.clone());
+        if let Some(text) = &response.text {
+            self.0.clone_from(text);
+        }
+        response
+    }
+
+    fn press(&mut self, harness: &mut Harness, key: KeyCode) -> &str {
+        harness.press_key(key, LogicalKey::Unidentified);
+        harness.frame(|| self.show());
+        &self.0
+    }
+}
+
+#[test]
+fn deleting_words_backwards_skips_punctuation_and_spaces() {
+    let (mut harness, mut edits) = focus_textbox("one, two  three!", KeyCode::End);
+
+    let mut deleted = Vec::new();
+    for _ in 0..4 {
+        let text = edits.press(&mut harness, KeyCode::Backspace);
+        deleted.push(text.to_owned());
+    }
+
+    assert_eq!(deleted, ["one, two  ", "one, ", "", ""]);
+}
+
+#[test]
+fn deleting_words_forwards_skips_punctuation_and_spaces() {
+    let (mut harness, mut edits) = focus_textbox("one, two  three!", KeyCode::Home);
+
+    let mut deleted = Vec::new();
+    for _ in 0..5 {
+        let text = edits.press(&mut harness, KeyCode::Delete);
+        deleted.push(text.to_owned());
+    }
+
+    assert_eq!(deleted, [", two  three!", "  three!", "!", "", ""]);
+}
+
+#[test]
+fn moving_by_word_stops_at_word_boundaries() {
+    let (mut harness, mut edits) = focus_textbox("one, two  three!", KeyCode::Home);
+
+    edits.press(&mut harness, KeyCode::ArrowLeft);
+    edits.press(&mut harness, KeyCode::ArrowRight);
+    edits.press(&mut harness, KeyCode::ArrowRight);
+    harness.send(Event::ModifiersChanged(Modifiers::empty()));
+    harness.type_text("X");
+    harness.frame(|| edits.show());
+    assert_eq!(edits.0, "one, twoX  three!");
+
+    let (mut harness, mut edits) = focus_textbox("one, two  three!", KeyCode::End);
+
+    edits.press(&mut harness, KeyCode::ArrowRight);
+    edits.press(&mut harness, KeyCode::ArrowLeft);
+    harness.send(Event::ModifiersChanged(Modifiers::empty()));
+    harness.type_text("X");
+    harness.frame(|| edits.show());
+    assert_eq!(edits.0, "one, two  Xthree!");
+}
+
+#[test]
+fn deleting_words_at_the_edge_of_a_line_joins_lines() {
+    let (mut harness, mut edits) = focus_textbox("one\ntwo", KeyCode::ArrowDown);
+
+    edits.press(&mut harness, KeyCode::Home);
+    assert_eq!(edits.press(&mut harness, KeyCode::Backspace), "onetwo");
+
+    let (mut harness, mut edits) = focus_textbox("one\ntwo", KeyCode::ArrowUp);
+
+    edits.press(&mut harness, KeyCode::End);
+    assert_eq!(edits.press(&mut harness, KeyCode::Delete), "onetwo");
+}
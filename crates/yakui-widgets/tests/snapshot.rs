@@ -1,11 +1,17 @@
-use yakui::{Constraints, CrossAxisAlignment, Dim2, MainAxisAlignment, MainAxisSize, Vec2};
+use yakui::{
+    Constraints, CrossAxisAlignment, Dim2, LayoutDirection, MainAxisAlignment, MainAxisSize, Vec2,
+};
 use yakui_core::geometry::Color;
 use yakui_core::{Alignment, Pivot};
 use yakui_test::{run, Test};
-use yakui_widgets::widgets::{Button, List, Pad, UnconstrainedBox};
+use yakui_widgets::widgets::{
+    Button, ColumnFilter, List, Pad, Positioned, Stack, StackItem, TableColumn, UnconstrainedBox,
+    Wrap,
+};
 use yakui_widgets::{
-    align, button, center, checkbox, colored_box, colored_box_container, column, constrained,
-    expanded, pad, reflow, row, text,
+    absolute, align, button, center, checkbox, colored_box, colored_box_container, column,
+    constrained, expanded, fractional_height, fractional_size, fractional_width, min_height,
+    min_size, min_width, pad, reflow, row, stack, table, text,
 };
 
 #[test]
@@ -391,3 +397,228 @@ impl IntoF32 for i32 {
         self as f32
     }
 }
+
+#[test]
+fn table_basic() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            let columns = vec![
+                TableColumn::new("Name"),
+                TableColumn::new("Color").filter(ColumnFilter::values(["Red", "Yellow"])),
+            ];
+
+            table(columns, |_| {
+                text(20.0, "Apple");
+                text(20.0, "Red");
+                text(20.0, "Banana");
+                text(20.0, "Yellow");
+            });
+        });
+    });
+}
+
+/// Four 50x30 boxes in a wrap that only has room for two of them per line.
+fn wrap_four_boxes() {
+    align(Alignment::TOP_LEFT, || {
+        constrained(Constraints::loose(Vec2::new(120.0, 500.0)), || {
+            let mut wrap = Wrap::row();
+            wrap.item_spacing = 10.0;
+            wrap.line_spacing = 5.0;
+            wrap.show(|| {
+                rect(50, 30);
+                rect(50, 30);
+                rect(50, 30);
+                rect(50, 30);
+            });
+        });
+    });
+}
+
+#[test]
+fn wrap_basic() {
+    run!({
+        wrap_four_boxes();
+    });
+}
+
+#[test]
+fn wrap_right_to_left() {
+    let mut test = Test::new();
+    test.layout_direction = LayoutDirection::RightToLeft;
+
+    run!(test, {
+        wrap_four_boxes();
+    });
+}
+
+#[test]
+fn wrap_cross_center() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            constrained(Constraints::loose(Vec2::new(120.0, 500.0)), || {
+                let mut wrap = Wrap::row();
+                wrap.main_axis_alignment = MainAxisAlignment::Center;
+                wrap.cross_axis_alignment = CrossAxisAlignment::Center;
+                wrap.show(|| {
+                    rect(50, 50);
+                    rect(50, 20);
+                    rect(30, 30);
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn stack_items() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            stack(|| {
+                rect(100, 100);
+
+                StackItem::new(Alignment::CENTER).show(|| {
+                    rect(20, 20);
+                });
+
+                let mut corner = StackItem::new(Alignment::BOTTOM_RIGHT);
+                corner.offset = Vec2::new(-5.0, -5.0);
+                corner.show(|| {
+                    rect(30, 10);
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn stack_alignment() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            let mut stack = Stack::new();
+            stack.alignment = Alignment::BOTTOM_CENTER;
+            stack.show(|| {
+                rect(100, 100);
+                rect(40, 40);
+            });
+        });
+    });
+}
+
+#[test]
+fn min_size_basic() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            row(|| {
+                min_width(100.0, || {
+                    rect_50x50();
+                });
+                min_height(100.0, || {
+                    rect_50x50();
+                });
+                // Children bigger than the minimum keep their size.
+                min_size([20.0, 20.0], || {
+                    rect_50x50();
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn min_size_larger_than_available() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            constrained(Constraints::loose(Vec2::new(80.0, 80.0)), || {
+                min_size([200.0, 200.0], || {
+                    rect_50x50();
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn fractional_size_basic() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            constrained(Constraints::loose(Vec2::new(400.0, 200.0)), || {
+                column(|| {
+                    fractional_width(0.5, || {
+                        rect_50x50();
+                    });
+                    // Columns don't bound the height of their children, so
+                    // only the width is a fraction here.
+                    fractional_size(0.25, 0.5, || {
+                        rect_50x50();
+                    });
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn fractional_size_unbounded() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            // Rows don't bound the width of their children, so the children
+            // decide it instead.
+            row(|| {
+                fractional_width(0.5, || {
+                    rect_50x50();
+                });
+                fractional_height(0.5, || {
+                    rect_50x50();
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn absolute_positioned() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            constrained(Constraints::loose(Vec2::new(200.0, 100.0)), || {
+                absolute(|| {
+                    rect(10, 10);
+
+                    Positioned::top_left(10.0, 20.0).show(|| {
+                        rect(30, 30);
+                    });
+
+                    Positioned::bottom_right(5.0, 5.0).show(|| {
+                        rect(20, 20);
+                    });
+
+                    // Pinned to both sides, so it's stretched between them.
+                    let mut bar = Positioned::new();
+                    bar.left = Some(50.0);
+                    bar.right = Some(50.0);
+                    bar.bottom = Some(0.0);
+                    bar.height = Some(10.0);
+                    bar.show(|| {
+                        rect(0, 0);
+                    });
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn absolute_unbounded() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            // A row doesn't bound the width of its children, so the container
+            // has no width and the child is placed from its left edge.
+            row(|| {
+                absolute(|| {
+                    Positioned::top_right(10.0, 10.0).show(|| {
+                        rect(20, 20);
+                    });
+                });
+            });
+        });
+    });
+}
@@ -0,0 +1,133 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use yakui_test::Harness;
+use yakui_widgets::widgets::{Cleanup, Reducer};
+use yakui_widgets::{column, use_effect, use_keyed_state, use_state, use_state_handle};
+
+#[derive(Debug, Default)]
+struct Counter(i32);
+
+enum CounterAction {
+    Increment,
+    Add(i32),
+}
+
+impl Reducer<CounterAction> for Counter {
+    fn reduce(&mut self, action: CounterAction) {
+        match action {
+            CounterAction::Increment => self.0 += 1,
+            CounterAction::Add(amount) => self.0 += amount,
+        }
+    }
+}
+
+#[test]
+fn dispatch_runs_the_reducer() {
+    let mut harness = Harness::new();
+
+    for _ in 0..2 {
+        harness.frame(|| {
+            let counter = use_state(Counter::default);
+            counter.dispatch(CounterAction::Increment);
+            counter.dispatch(CounterAction::Add(10));
+        });
+    }
+
+    let value = harness.frame(|| use_state(Counter::default).borrow().0);
+    assert_eq!(value, 22);
+}
+
+#[test]
+fn set_if_changed_reports_changes() {
+    let mut harness = Harness::new();
+
+    harness.frame(|| {
+        let state = use_state(|| 5);
+        assert!(!state.set_if_changed(5));
+        assert!(state.set_if_changed(6));
+        assert_eq!(state.get(), 6);
+    });
+}
+
+#[test]
+fn keyed_state_is_visible_to_handles_elsewhere() {
+    let mut harness = Harness::new();
+
+    let seen = harness.frame(|| {
+        let mut seen = None;
+        column(|| {
+            use_keyed_state("volume", || 3).set(7);
+        });
+        column(|| {
+            seen = use_state_handle::<i32, _>("volume").map(|state| state.get());
+        });
+        seen
+    });
+    assert_eq!(seen, Some(7));
+
+    let wrong_type = harness.frame(|| {
+        use_keyed_state("volume", || 3);
+        use_state_handle::<String, _>("volume").is_some()
+    });
+    assert!(!wrong_type);
+}
+
+#[test]
+fn keyed_state_goes_away_with_its_widget() {
+    let mut harness = Harness::new();
+
+    harness.frame(|| {
+        use_keyed_state("volume", || 3);
+    });
+
+    // The widget is removed at the end of the frame it wasn't built in.
+    harness.frame(|| {});
+
+    let seen = harness.frame(|| use_state_handle::<i32, _>("volume").is_some());
+    assert!(!seen);
+}
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+/// Shows an effect that logs when it runs and when it's cleaned up.
+fn logged_effect(log: &Log, deps: u32) -> bool {
+    let log = log.clone();
+    use_effect(deps, move |deps| {
+        log.borrow_mut().push(format!("run {deps}"));
+
+        let deps = *deps;
+        Cleanup::new(move || log.borrow_mut().push(format!("clean {deps}")))
+    })
+    .ran
+}
+
+#[test]
+fn effects_run_only_when_their_deps_change() {
+    let mut harness = Harness::new();
+    let log = Log::default();
+
+    let ran: Vec<bool> = [1, 1, 2, 2]
+        .into_iter()
+        .map(|deps| harness.frame(|| logged_effect(&log, deps)))
+        .collect();
+
+    assert_eq!(ran, [true, false, true, false]);
+    assert_eq!(*log.borrow(), ["run 1", "clean 1", "run 2"]);
+}
+
+#[test]
+fn effects_are_cleaned_up_when_removed() {
+    let mut harness = Harness::new();
+    let log = Log::default();
+
+    harness.frame(|| logged_effect(&log, 1));
+    assert_eq!(*log.borrow(), ["run 1"]);
+
+    harness.frame(|| {});
+    assert_eq!(*log.borrow(), ["run 1", "clean 1"]);
+
+    // Showing it again starts over.
+    harness.frame(|| logged_effect(&log, 1));
+    assert_eq!(*log.borrow(), ["run 1", "clean 1", "run 1"]);
+}
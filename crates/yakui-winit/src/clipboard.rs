@@ -0,0 +1,92 @@
+use yakui_core::input::ClipboardContent;
+
+/// Reads the system clipboard when the user pastes.
+///
+/// Browsers only hand out the clipboard's contents asynchronously, so on the
+/// web a paste is started by [`Clipboard::read`] and delivered later through
+/// [`Clipboard::take_pending`].
+pub(crate) struct Clipboard {
+    #[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+    inner: Option<arboard::Clipboard>,
+
+    #[cfg(all(feature = "clipboard", target_arch = "wasm32"))]
+    pending: std::rc::Rc<std::cell::RefCell<Vec<String>>>,
+}
+
+#[cfg(all(feature = "clipboard", not(target_arch = "wasm32")))]
+impl Clipboard {
+    pub fn new() -> Self {
+        Self {
+            inner: arboard::Clipboard::new().ok(),
+        }
+    }
+
+    /// Reads whatever is on the system clipboard, preferring images over text
+    /// since copied images often come with a text description too.
+    pub fn read(&mut self) -> Option<ClipboardContent> {
+        use yakui_core::geometry::UVec2;
+        use yakui_core::paint::{Texture, TextureFormat};
+
+        let clipboard = self.inner.as_mut()?;
+
+        if let Ok(image) = clipboard.get_image() {
+            let size = UVec2::new(image.width as u32, image.height as u32);
+            let texture = Texture::new(TextureFormat::Rgba8Srgb, size, image.bytes.into_owned());
+            return Some(ClipboardContent::Image(texture));
+        }
+
+        clipboard.get_text().ok().map(ClipboardContent::Text)
+    }
+
+    pub fn take_pending(&mut self) -> Vec<ClipboardContent> {
+        Vec::new()
+    }
+}
+
+#[cfg(all(feature = "clipboard", target_arch = "wasm32"))]
+impl Clipboard {
+    pub fn new() -> Self {
+        Self {
+            pending: Default::default(),
+        }
+    }
+
+    /// Starts reading text from the browser's clipboard. The browser may ask
+    /// the user for permission first, and only text can be read.
+    pub fn read(&mut self) -> Option<ClipboardContent> {
+        let clipboard = web_sys::window()?.navigator().clipboard();
+        let promise = clipboard.read_text();
+        let pending = self.pending.clone();
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let text = wasm_bindgen_futures::JsFuture::from(promise).await;
+
+            if let Some(text) = text.ok().and_then(|text| text.as_string()) {
+                pending.borrow_mut().push(text);
+            }
+        });
+
+        None
+    }
+
+    /// Takes the content of any pastes that have finished since the last call.
+    pub fn take_pending(&mut self) -> Vec<ClipboardContent> {
+        let pending = std::mem::take(&mut *self.pending.borrow_mut());
+        pending.into_iter().map(ClipboardContent::Text).collect()
+    }
+}
+
+#[cfg(not(feature = "clipboard"))]
+impl Clipboard {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn read(&mut self) -> Option<ClipboardContent> {
+        None
+    }
+
+    pub fn take_pending(&mut self) -> Vec<ClipboardContent> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,19 @@
+use yakui_core::input::CursorIcon;
+
+pub fn to_winit_cursor_icon(icon: CursorIcon) -> winit::window::CursorIcon {
+    use winit::window::CursorIcon as WinitIcon;
+    match icon {
+        CursorIcon::Default => WinitIcon::Default,
+        CursorIcon::Pointer => WinitIcon::Pointer,
+        CursorIcon::Text => WinitIcon::Text,
+        CursorIcon::Grab => WinitIcon::Grab,
+        CursorIcon::Grabbing => WinitIcon::Grabbing,
+        CursorIcon::Move => WinitIcon::Move,
+        CursorIcon::NotAllowed => WinitIcon::NotAllowed,
+        CursorIcon::Crosshair => WinitIcon::Crosshair,
+        CursorIcon::ResizeHorizontal => WinitIcon::EwResize,
+        CursorIcon::ResizeVertical => WinitIcon::NsResize,
+        CursorIcon::ResizeNwSe => WinitIcon::NwseResize,
+        CursorIcon::ResizeNeSw => WinitIcon::NeswResize,
+    }
+}
@@ -1,4 +1,4 @@
-use yakui_core::input::{KeyCode, Modifiers};
+use yakui_core::input::{KeyCode, KeyLocation, LogicalKey, Modifiers};
 
 pub fn from_winit_key(key: winit::keyboard::KeyCode) -> Option<KeyCode> {
     use winit::keyboard::KeyCode as WinitKey;
@@ -84,6 +84,99 @@ pub fn from_winit_key(key: winit::keyboard::KeyCode) -> Option<KeyCode> {
     })
 }
 
+pub fn from_winit_logical_key(key: &winit::keyboard::Key) -> LogicalKey {
+    use winit::keyboard::{Key, NamedKey};
+
+    let named = match key {
+        Key::Character(text) => return LogicalKey::Character(text.to_string()),
+        Key::Dead(_) => return LogicalKey::Dead,
+        Key::Unidentified(_) => return LogicalKey::Unidentified,
+        Key::Named(named) => named,
+    };
+
+    match named {
+        // winit calls the Windows and Command keys "Super", which the web
+        // calls "Meta". winit's "Meta" is the legacy key the web calls "Super".
+        NamedKey::Super => LogicalKey::Meta,
+        NamedKey::Meta => LogicalKey::Super,
+        NamedKey::Space => LogicalKey::Character(" ".to_owned()),
+
+        NamedKey::Alt => LogicalKey::Alt,
+        NamedKey::AltGraph => LogicalKey::AltGraph,
+        NamedKey::CapsLock => LogicalKey::CapsLock,
+        NamedKey::Control => LogicalKey::Control,
+        NamedKey::Fn => LogicalKey::Fn,
+        NamedKey::FnLock => LogicalKey::FnLock,
+        NamedKey::NumLock => LogicalKey::NumLock,
+        NamedKey::ScrollLock => LogicalKey::ScrollLock,
+        NamedKey::Shift => LogicalKey::Shift,
+        NamedKey::Symbol => LogicalKey::Symbol,
+        NamedKey::SymbolLock => LogicalKey::SymbolLock,
+        NamedKey::Hyper => LogicalKey::Hyper,
+        NamedKey::Enter => LogicalKey::Enter,
+        NamedKey::Tab => LogicalKey::Tab,
+        NamedKey::ArrowDown => LogicalKey::ArrowDown,
+        NamedKey::ArrowLeft => LogicalKey::ArrowLeft,
+        NamedKey::ArrowRight => LogicalKey::ArrowRight,
+        NamedKey::ArrowUp => LogicalKey::ArrowUp,
+        NamedKey::End => LogicalKey::End,
+        NamedKey::Home => LogicalKey::Home,
+        NamedKey::PageDown => LogicalKey::PageDown,
+        NamedKey::PageUp => LogicalKey::PageUp,
+        NamedKey::Backspace => LogicalKey::Backspace,
+        NamedKey::Clear => LogicalKey::Clear,
+        NamedKey::Copy => LogicalKey::Copy,
+        NamedKey::Cut => LogicalKey::Cut,
+        NamedKey::Delete => LogicalKey::Delete,
+        NamedKey::Insert => LogicalKey::Insert,
+        NamedKey::Paste => LogicalKey::Paste,
+        NamedKey::Redo => LogicalKey::Redo,
+        NamedKey::Undo => LogicalKey::Undo,
+        NamedKey::ContextMenu => LogicalKey::ContextMenu,
+        NamedKey::Escape => LogicalKey::Escape,
+        NamedKey::Pause => LogicalKey::Pause,
+        NamedKey::PrintScreen => LogicalKey::PrintScreen,
+
+        NamedKey::F1 => LogicalKey::F1,
+        NamedKey::F2 => LogicalKey::F2,
+        NamedKey::F3 => LogicalKey::F3,
+        NamedKey::F4 => LogicalKey::F4,
+        NamedKey::F5 => LogicalKey::F5,
+        NamedKey::F6 => LogicalKey::F6,
+        NamedKey::F7 => LogicalKey::F7,
+        NamedKey::F8 => LogicalKey::F8,
+        NamedKey::F9 => LogicalKey::F9,
+        NamedKey::F10 => LogicalKey::F10,
+        NamedKey::F11 => LogicalKey::F11,
+        NamedKey::F12 => LogicalKey::F12,
+        NamedKey::F13 => LogicalKey::F13,
+        NamedKey::F14 => LogicalKey::F14,
+        NamedKey::F15 => LogicalKey::F15,
+        NamedKey::F16 => LogicalKey::F16,
+        NamedKey::F17 => LogicalKey::F17,
+        NamedKey::F18 => LogicalKey::F18,
+        NamedKey::F19 => LogicalKey::F19,
+        NamedKey::F20 => LogicalKey::F20,
+        NamedKey::F21 => LogicalKey::F21,
+        NamedKey::F22 => LogicalKey::F22,
+        NamedKey::F23 => LogicalKey::F23,
+        NamedKey::F24 => LogicalKey::F24,
+
+        _ => LogicalKey::Unidentified,
+    }
+}
+
+pub fn from_winit_key_location(location: winit::keyboard::KeyLocation) -> KeyLocation {
+    use winit::keyboard::KeyLocation as WinitLocation;
+
+    match location {
+        WinitLocation::Standard => KeyLocation::Standard,
+        WinitLocation::Left => KeyLocation::Left,
+        WinitLocation::Right => KeyLocation::Right,
+        WinitLocation::Numpad => KeyLocation::Numpad,
+    }
+}
+
 pub fn from_winit_modifiers(winit_mods: winit::keyboard::ModifiersState) -> Modifiers {
     let mut mods = Modifiers::default();
     if winit_mods.shift_key() {
@@ -1,5 +1,7 @@
 #![doc = include_str!("../README.md")]
 
+mod clipboard;
+mod cursor;
 mod keys;
 
 use winit::dpi::PhysicalSize;
@@ -7,14 +9,25 @@ use winit::event::{ElementState, MouseButton as WinitMouseButton, MouseScrollDel
 use winit::window::Window;
 use yakui_core::event::Event;
 use yakui_core::geometry::{Rect, Vec2};
-use yakui_core::input::MouseButton;
+use yakui_core::input::{
+    CursorIcon, Modifiers, MouseButton, ScrollUnit, Shortcut, TouchPhase, ViewportRouter,
+};
+use yakui_core::Yakui;
 
-pub use self::keys::{from_winit_key, from_winit_modifiers};
+pub use self::cursor::to_winit_cursor_icon;
+pub use self::keys::{
+    from_winit_key, from_winit_key_location, from_winit_logical_key, from_winit_modifiers,
+};
+
+use self::clipboard::Clipboard;
 
 pub struct YakuiWinit {
     auto_scale: bool,
     auto_viewport: bool,
     init: Option<InitState>,
+    modifiers: Modifiers,
+    clipboard: Clipboard,
+    cursor_icon: Option<CursorIcon>,
 }
 
 struct InitState {
@@ -32,6 +45,9 @@ impl YakuiWinit {
             auto_scale: true,
             auto_viewport: true,
             init: Some(InitState { size, scale }),
+            modifiers: Modifiers::empty(),
+            clipboard: Clipboard::new(),
+            cursor_icon: None,
         }
     }
 
@@ -51,6 +67,20 @@ impl YakuiWinit {
         self.auto_viewport = enabled;
     }
 
+    /// Shows the cursor icon that yakui's widgets asked for on the window.
+    /// Should be called every frame after the UI is built.
+    ///
+    /// For split-screen UIs, pass the state that the cursor is over, as told
+    /// by [`ViewportRouter::hovered`].
+    pub fn apply_cursor_icon(&mut self, state: &Yakui, window: &Window) {
+        let icon = state.cursor_icon();
+
+        if self.cursor_icon != Some(icon) {
+            self.cursor_icon = Some(icon);
+            window.set_cursor(to_winit_cursor_icon(icon));
+        }
+    }
+
     pub fn handle_window_event(
         &mut self,
         state: &mut yakui_core::Yakui,
@@ -87,11 +117,93 @@ impl YakuiWinit {
 
                 false
             }
+            _ => {
+                let scale_factor = state.layout_dom().scale_factor();
+                self.handle_input_event(event, scale_factor, |event| state.handle_event(event))
+            }
+        }
+    }
+
+    /// Handles a window event for several yakui states drawn into different
+    /// viewports of the window, like in a split-screen game. Input is sent to
+    /// the right state by `router`.
+    ///
+    /// Every state is given the window's size and, if automatic scaling is
+    /// enabled, its scale factor. Each state's viewport must be set by the
+    /// application with [`Yakui::set_unscaled_viewport`], since the automatic
+    /// viewport would cover the whole window.
+    pub fn handle_split_window_event(
+        &mut self,
+        router: &mut ViewportRouter,
+        states: &mut [Yakui],
+        event: &WindowEvent,
+    ) -> bool {
+        if let Some(init) = self.init.take() {
+            let size = Vec2::new(init.size.width as f32, init.size.height as f32);
+
+            for state in states.iter_mut() {
+                state.set_surface_size(size);
+
+                if self.auto_scale {
+                    state.set_scale_factor(init.scale);
+                }
+            }
+        }
+
+        match event {
+            WindowEvent::Resized(size) => {
+                let size = Vec2::new(size.width as f32, size.height as f32);
+
+                for state in states.iter_mut() {
+                    state.set_surface_size(size);
+                }
+
+                false
+            }
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                if self.auto_scale {
+                    for state in states.iter_mut() {
+                        state.set_scale_factor(*scale_factor as f32);
+                    }
+                }
+
+                false
+            }
+            _ => {
+                // Scroll deltas are converted using the scale of the state
+                // under the cursor, which is the one they're sent to.
+                let scale_factor = router
+                    .hovered()
+                    .and_then(|index| states.get(index))
+                    .map_or(1.0, |state| state.layout_dom().scale_factor());
+
+                self.handle_input_event(event, scale_factor, |event| {
+                    router.handle_event(states, event)
+                })
+            }
+        }
+    }
+
+    /// Converts an input event from winit into yakui events and passes them to
+    /// `send`, which returns whether each one was sunk.
+    fn handle_input_event(
+        &mut self,
+        event: &WindowEvent,
+        scale_factor: f32,
+        mut send: impl FnMut(Event) -> bool,
+    ) -> bool {
+        // Pastes on the web finish some time after the shortcut was pressed,
+        // so they're sent along with whichever event comes next.
+        for content in self.clipboard.take_pending() {
+            send(Event::Paste(content));
+        }
+
+        match event {
             WindowEvent::CursorMoved { position, .. } => {
                 let pos = Vec2::new(position.x as f32, position.y as f32);
-                state.handle_event(Event::CursorMoved(Some(pos)))
+                send(Event::CursorMoved(Some(pos)))
             }
-            WindowEvent::CursorLeft { .. } => state.handle_event(Event::CursorMoved(None)),
+            WindowEvent::CursorLeft { .. } => send(Event::CursorMoved(None)),
 
             WindowEvent::MouseInput {
                 button,
@@ -102,7 +214,9 @@ impl YakuiWinit {
                     WinitMouseButton::Left => MouseButton::One,
                     WinitMouseButton::Right => MouseButton::Two,
                     WinitMouseButton::Middle => MouseButton::Three,
-                    _ => return false,
+                    WinitMouseButton::Back => MouseButton::Back,
+                    WinitMouseButton::Forward => MouseButton::Forward,
+                    WinitMouseButton::Other(id) => MouseButton::Other(*id),
                 };
 
                 let down = match button_state {
@@ -110,33 +224,51 @@ impl YakuiWinit {
                     ElementState::Released => false,
                 };
 
-                state.handle_event(Event::MouseButtonChanged { button, down })
+                send(Event::MouseButtonChanged { button, down })
             }
             WindowEvent::MouseWheel { delta, .. } => {
-                // Observed logical pixels per scroll wheel increment in Windows on Chrome
-                const LINE_HEIGHT: f32 = 100.0 / 3.0;
-
-                let delta = match *delta {
-                    MouseScrollDelta::LineDelta(x, y) => Vec2::new(x, y) * LINE_HEIGHT,
-                    MouseScrollDelta::PixelDelta(offset) => {
-                        Vec2::new(offset.x as f32, offset.y as f32)
-                            / state.layout_dom().scale_factor()
-                    }
+                let (delta, unit) = match *delta {
+                    MouseScrollDelta::LineDelta(x, y) => (Vec2::new(x, y), ScrollUnit::Lines),
+                    MouseScrollDelta::PixelDelta(offset) => (
+                        Vec2::new(offset.x as f32, offset.y as f32) / scale_factor,
+                        ScrollUnit::Pixels,
+                    ),
                 };
 
                 // Flip delta axis from winit's expectations.
                 let delta = -delta;
 
-                state.handle_event(Event::MouseScroll { delta })
+                send(Event::MouseScroll { delta, unit })
+            }
+            WindowEvent::Touch(touch) => {
+                let phase = match touch.phase {
+                    winit::event::TouchPhase::Started => TouchPhase::Started,
+                    winit::event::TouchPhase::Moved => TouchPhase::Moved,
+                    winit::event::TouchPhase::Ended => TouchPhase::Ended,
+                    winit::event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+                };
+
+                let pos = Vec2::new(touch.location.x as f32, touch.location.y as f32);
+                send(Event::Touch {
+                    id: touch.id,
+                    phase,
+                    pos,
+                })
             }
             WindowEvent::ModifiersChanged(mods) => {
-                state.handle_event(Event::ModifiersChanged(from_winit_modifiers(mods.state())))
+                self.modifiers = from_winit_modifiers(mods.state());
+                send(Event::ModifiersChanged(self.modifiers))
             }
             WindowEvent::KeyboardInput { event, .. } => {
-                if event.state == ElementState::Pressed {
+                // Browsers report text for shortcuts like Ctrl+C too, which
+                // shouldn't be typed into text boxes.
+                let shortcut = cfg!(target_arch = "wasm32")
+                    && (self.modifiers.ctrl() || self.modifiers.meta());
+
+                if event.state == ElementState::Pressed && !shortcut {
                     if let Some(text) = event.text.as_ref() {
                         for c in text.chars() {
-                            state.handle_event(Event::TextInput(c));
+                            send(Event::TextInput(c));
                         }
                     }
                 }
@@ -144,21 +276,40 @@ impl YakuiWinit {
                     winit::keyboard::PhysicalKey::Code(k) => from_winit_key(k),
                     winit::keyboard::PhysicalKey::Unidentified(_) => None,
                 };
-                if let Some(key) = key {
-                    let pressed = match event.state {
-                        ElementState::Pressed => true,
-                        ElementState::Released => false,
-                    };
+                let logical_key = from_winit_logical_key(&event.logical_key);
+                let pressed = match event.state {
+                    ElementState::Pressed => true,
+                    ElementState::Released => false,
+                };
 
-                    state.handle_event(Event::KeyChanged { key, down: pressed })
+                let paste = pressed
+                    && !event.repeat
+                    && Shortcut::primary("v").matches(&logical_key, self.modifiers);
+
+                let mut sunk = if let Some(key) = key {
+                    send(Event::KeyChanged {
+                        key,
+                        logical_key,
+                        location: from_winit_key_location(event.location),
+                        down: pressed,
+                        repeat: event.repeat,
+                    })
                 } else {
                     false
+                };
+
+                if paste {
+                    if let Some(content) = self.clipboard.read() {
+                        sunk |= send(Event::Paste(content));
+                    }
                 }
+
+                sunk
             }
 
             WindowEvent::Ime(winit::event::Ime::Commit(text)) => {
                 for c in text.chars() {
-                    state.handle_event(Event::TextInput(c));
+                    send(Event::TextInput(c));
                 }
                 true
             }
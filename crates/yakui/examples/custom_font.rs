@@ -13,9 +13,10 @@ pub fn run() {
         text.style.color = Color::GREEN;
         text.show();
 
-        // ...or you can name the font family directly
+        // ...or you can name the font family directly, like fonts registered
+        // with `Fonts::add`
         let mut text = Text::new(32.0, "Custom Font (by name)");
-        text.style.attrs.family_owned = FamilyOwned::Name("Hack".to_owned());
+        text.style = text.style.with_family("Hack");
         text.style.color = Color::GREEN;
         text.show();
     });
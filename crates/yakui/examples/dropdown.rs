@@ -4,7 +4,10 @@
 #![allow(clippy::collapsible_if)]
 
 use yakui::widgets::Layer;
-use yakui::{align, button, column, reflow, use_state, widgets::Pad, Alignment, Dim2};
+use yakui::{
+    align, button, column, dropdown_button, reflow, split_button, use_state, widgets::Pad,
+    Alignment, Dim2,
+};
 use yakui_core::Pivot;
 
 pub fn run() {
@@ -47,6 +50,18 @@ pub fn run() {
             if button("Lower Button").clicked {
                 println!("Lower button clicked");
             }
+
+            if let Some(index) = dropdown_button("Dropdown", options).selected {
+                println!("Picked {} from dropdown", options[index]);
+            }
+
+            let split = split_button("Split", options);
+            if split.clicked {
+                println!("Split button clicked");
+            }
+            if let Some(index) = split.selected {
+                println!("Picked {} from split button", options[index]);
+            }
         });
     });
 }
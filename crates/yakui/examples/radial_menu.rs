@@ -85,7 +85,7 @@ fn draw_segment(ctx: &mut PaintContext<'_>, center: Vec2, start_angle: f32, end_
             Vertex::new(pos, pos, Color::hex(0x333333).to_linear())
         });
 
-        let mesh = PaintMesh::new(vertices, buffers.indices);
+        let mesh = PaintMesh::new(vertices, buffers.indices.into_iter().map(u32::from));
         ctx.paint.add_mesh(mesh);
     }
 
@@ -107,7 +107,7 @@ fn draw_segment(ctx: &mut PaintContext<'_>, center: Vec2, start_angle: f32, end_
             Vertex::new(pos, pos, Color::WHITE.to_linear())
         });
 
-        let mesh = PaintMesh::new(vertices, buffers.indices);
+        let mesh = PaintMesh::new(vertices, buffers.indices.into_iter().map(u32::from));
         ctx.paint.add_mesh(mesh);
     }
 }
@@ -0,0 +1,65 @@
+//! This example shows a DOM snapshot, like one attached to a bug report, by
+//! drawing an outline around every widget where it was laid out.
+//!
+//! Pass the path to a report saved from `Yakui::snapshot` to view it. Without
+//! one, a small built-in snapshot is shown instead.
+
+use yakui::snapshot::{DomSnapshot, SnapshotNode};
+use yakui::widgets::{Outline, Positioned};
+use yakui::{absolute, colors, text, use_state, Color};
+
+const SAMPLE: &str = "\
+yakui-snapshot 1
+viewport 0 0 400 300 1
+yakui_core::dom::root::RootWidget 0 0 400 300
+  yakui_widgets::widgets::pad::PadWidget 0 0 176 104
+    yakui_widgets::widgets::list::ListWidget 16 16 144 72
+      yakui_widgets::widgets::text::TextWidget 16 16 144 24
+      yakui_widgets::widgets::button::ButtonWidget 16 48 88 40
+        yakui_widgets::widgets::text::TextWidget 24 56 72 24
+";
+
+const DEPTH_COLORS: [Color; 4] = [
+    colors::TEXT,
+    Color::CORNFLOWER_BLUE,
+    Color::GREEN,
+    Color::RED,
+];
+
+pub fn run() {
+    let snapshot = use_state(|| {
+        let report = match std::env::args().nth(1) {
+            Some(path) => std::fs::read_to_string(path).expect("could not read snapshot"),
+            None => SAMPLE.to_owned(),
+        };
+
+        DomSnapshot::parse(&report).expect("not a valid snapshot")
+    });
+
+    absolute(|| {
+        show_node(&snapshot.borrow().root, 0);
+    });
+}
+
+fn show_node(node: &SnapshotNode, depth: usize) {
+    if let Some(rect) = node.rect {
+        let mut positioned = Positioned::top_left(rect.pos().x, rect.pos().y);
+        positioned.width = Some(rect.size().x);
+        positioned.height = Some(rect.size().y);
+
+        positioned.show(|| {
+            let color = DEPTH_COLORS[depth % DEPTH_COLORS.len()];
+            Outline::new(color, 1.0).show_children(|| {
+                text(10.0, node.short_name());
+            });
+        });
+    }
+
+    for child in &node.children {
+        show_node(child, depth + 1);
+    }
+}
+
+fn main() {
+    bootstrap::start(run as fn());
+}
@@ -0,0 +1,42 @@
+//! Shows a table whose columns can be filtered from popups in their headers.
+
+use yakui::widgets::{ColumnFilter, TableColumn};
+use yakui::{label, table};
+
+const FRUITS: &[(&str, &str, &str)] = &[
+    ("Apple", "Red", "Tree"),
+    ("Banana", "Yellow", "Plant"),
+    ("Cherry", "Red", "Tree"),
+    ("Grape", "Purple", "Vine"),
+    ("Lemon", "Yellow", "Tree"),
+    ("Strawberry", "Red", "Plant"),
+];
+
+pub fn run() {
+    let columns = vec![
+        TableColumn::new("Name").filter(ColumnFilter::Text),
+        TableColumn::new("Color").filter(ColumnFilter::values(["Red", "Yellow", "Purple"])),
+        TableColumn::new("Grows on").filter(ColumnFilter::values(["Tree", "Plant", "Vine"])),
+    ];
+
+    let response = table(columns, |table| {
+        for &(name, color, grows_on) in FRUITS {
+            if table.matches([name, color, grows_on]) {
+                label(name);
+                label(color);
+                label(grows_on);
+            }
+        }
+    });
+
+    if response.changed {
+        println!(
+            "Active filters: {:?}",
+            response.active().collect::<Vec<_>>()
+        );
+    }
+}
+
+fn main() {
+    bootstrap::start(run as fn());
+}
@@ -1,7 +1,6 @@
-use std::{cell::Cell, sync::Arc};
+use std::cell::Cell;
 
 use bootstrap::OPENMOJI;
-use yakui::cosmic_text::fontdb;
 use yakui::{column, font::Fonts, text, util::widget, widget::Widget, Vec2};
 
 #[derive(Debug)]
@@ -30,7 +29,10 @@ impl Widget for LoadFontsWidget {
         if !self.loaded.get() {
             let fonts = ctx.dom.get_global_or_init(Fonts::default);
 
-            fonts.load_font_source(fontdb::Source::Binary(Arc::from(&OPENMOJI)));
+            // Emoji that the default font can't display fall back to
+            // OpenMoji, which contains color glyphs.
+            fonts.add("OpenMoji", OPENMOJI);
+            fonts.set_fallback_families(["OpenMoji"]);
 
             self.loaded.set(true);
         }
@@ -1,8 +1,15 @@
-use yakui::widgets::Window;
+use yakui::geometry::Vec2;
+use yakui::widgets::{Shadow, Window};
 use yakui::{center, text};
 
 pub fn run() {
-    Window::new([300.0, 200.0]).show(|| {
+    let mut window = Window::new([300.0, 200.0]);
+    window.shadow = Some(Shadow {
+        offset: Vec2::new(0.0, 4.0),
+        ..Shadow::new()
+    });
+
+    window.show(|| {
         center(|| {
             text(32.0, "Window body!");
         });
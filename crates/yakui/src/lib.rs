@@ -6,6 +6,7 @@ pub use yakui_core::*;
 #[doc(no_inline)]
 pub use yakui_widgets::widgets;
 
+pub use yakui_widgets::animation;
 pub use yakui_widgets::colors;
 pub use yakui_widgets::cosmic_text;
 pub use yakui_widgets::font;
@@ -13,4 +14,5 @@ pub use yakui_widgets::shapes;
 pub use yakui_widgets::shorthand::*;
 pub use yakui_widgets::style;
 pub use yakui_widgets::text_renderer;
+pub use yakui_widgets::theme;
 pub use yakui_widgets::util;